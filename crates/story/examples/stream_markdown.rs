@@ -1,6 +1,7 @@
 use gpui::*;
 use gpui_component::{
-    button::Button,
+    Selectable as _,
+    button::{Button, ButtonVariants as _},
     h_flex,
     text::{TextView, TextViewState},
     v_flex,
@@ -11,6 +12,7 @@ pub struct Example {
     markdown_state: Entity<TextViewState>,
     tx: smol::channel::Sender<String>,
     scroll_handle: ScrollHandle,
+    raw: bool,
     _task: Task<()>,
     _update_task: Task<()>,
 }
@@ -42,6 +44,7 @@ impl Example {
         Self {
             markdown_state,
             scroll_handle,
+            raw: false,
             tx,
             _task,
             _update_task: Task::ready(()),
@@ -85,6 +88,7 @@ impl Render for Example {
             .child(
                 h_flex()
                     .w_full()
+                    .gap_2()
                     .child(
                         Button::new("replay")
                             .outline()
@@ -92,6 +96,16 @@ impl Render for Example {
                             .on_click(cx.listener(move |this, _, window, cx| {
                                 this.replay(window, cx);
                             })),
+                    )
+                    .child(
+                        Button::new("raw")
+                            .ghost()
+                            .label("Raw")
+                            .selected(self.raw)
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.raw = !this.raw;
+                                cx.notify();
+                            })),
                     ),
             )
             .child(
@@ -102,7 +116,11 @@ impl Render for Example {
                     .track_scroll(&self.scroll_handle)
                     .overflow_y_scroll()
                     .size_full()
-                    .child(TextView::new(&self.markdown_state).selectable(true)),
+                    .child(
+                        TextView::new(&self.markdown_state)
+                            .selectable(true)
+                            .raw(self.raw),
+                    ),
             )
     }
 }
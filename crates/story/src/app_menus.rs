@@ -63,9 +63,11 @@ fn build_menus(title: impl Into<SharedString>, cx: &App) -> Vec<Menu> {
                     name: "Appearance".into(),
                     items: vec![
                         MenuItem::action("Light", SwitchThemeMode(ThemeMode::Light))
-                            .checked(!cx.theme().mode.is_dark()),
+                            .checked(!cx.theme().follow_system && !cx.theme().mode.is_dark()),
                         MenuItem::action("Dark", SwitchThemeMode(ThemeMode::Dark))
-                            .checked(cx.theme().mode.is_dark()),
+                            .checked(!cx.theme().follow_system && cx.theme().mode.is_dark()),
+                        MenuItem::action("System", SwitchThemeMode(ThemeMode::System))
+                            .checked(cx.theme().follow_system),
                     ],
                     disabled: false,
                 }),
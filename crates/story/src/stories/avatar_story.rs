@@ -101,6 +101,24 @@ impl Render for AvatarStory {
                     .child(Avatar::new().xsmall())
                     .child(Avatar::new().placeholder(IconName::Building2)),
             )
+            .child(
+                section("Image fallback")
+                    .max_w_md()
+                    .child(
+                        Avatar::new()
+                            .name("Jason Lee")
+                            .src("https://example.com/not-found.png")
+                            .large(),
+                    )
+                    .child(Avatar::new().src("https://example.com/not-found.png"))
+                    .child(
+                        Avatar::new()
+                            .name("Floyd Wang")
+                            .src("https://avatars.githubusercontent.com/u/28998859?v=4")
+                            .loading(true),
+                    )
+                    .child(Avatar::new().loading(true).small()),
+            )
             .child(
                 section("Avatar Group")
                     .v_flex()
@@ -12,6 +12,7 @@ use gpui_component::{
     v_flex,
 };
 use serde::Deserialize;
+use std::time::Duration;
 
 use crate::section;
 
@@ -385,6 +386,37 @@ impl Render for ButtonStory {
                         ),
                 ),
             )
+            .child(
+                section("Async Click").child(
+                    h_flex()
+                        .gap_4()
+                        .child(
+                            Button::new("async-button-1")
+                                .primary()
+                                .icon(IconName::Check)
+                                .label("Save")
+                                .on_click_task(|_, _, cx| {
+                                    cx.spawn(async move |cx| {
+                                        cx.background_executor()
+                                            .timer(Duration::from_secs(2))
+                                            .await;
+                                    })
+                                }),
+                        )
+                        .child(
+                            Button::new("async-button-2")
+                                .outline()
+                                .label("Send")
+                                .on_click_task(|_, _, cx| {
+                                    cx.spawn(async move |cx| {
+                                        cx.background_executor()
+                                            .timer(Duration::from_secs(2))
+                                            .await;
+                                    })
+                                }),
+                        ),
+                ),
+            )
             .child(
                 section("Outline Button")
                     .max_w_lg()
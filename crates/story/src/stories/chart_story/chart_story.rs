@@ -1,3 +1,4 @@
+use chrono::NaiveDate;
 use gpui::{
     App, AppContext, Context, Entity, FocusHandle, Focusable, Hsla, IntoElement, ParentElement,
     Render, SharedString, Styled, Window, div, linear_color_stop, linear_gradient,
@@ -5,7 +6,10 @@ use gpui::{
 };
 use gpui_component::{
     ActiveTheme, StyledExt,
-    chart::{AreaChart, BarChart, CandlestickChart, LineChart, PieChart},
+    chart::{
+        ActivityHeatmap, AreaChart, BarChart, CandlestickChart, Legend, LegendItem, LineChart,
+        PieChart, SparklineChart,
+    },
     divider::Divider,
     dock::PanelControl,
     h_flex, v_flex,
@@ -51,6 +55,7 @@ pub struct ChartStory {
     daily_devices: Vec<DailyDevice>,
     monthly_devices: Vec<MonthlyDevice>,
     stock_prices: Vec<StockPrice>,
+    active_pie_index: Option<usize>,
 }
 
 impl ChartStory {
@@ -72,6 +77,7 @@ impl ChartStory {
             daily_devices,
             monthly_devices,
             stock_prices,
+            active_pie_index: None,
             focus_handle: cx.focus_handle(),
         }
     }
@@ -79,6 +85,11 @@ impl ChartStory {
     pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
         cx.new(|cx| Self::new(window, cx))
     }
+
+    fn set_active_pie_index(&mut self, ix: &Option<usize>, _: &mut Window, cx: &mut Context<Self>) {
+        self.active_pie_index = *ix;
+        cx.notify();
+    }
 }
 
 impl Story for ChartStory {
@@ -105,6 +116,35 @@ impl Focusable for ChartStory {
     }
 }
 
+fn sparkline_card(
+    title: &str,
+    value: impl Into<SharedString>,
+    chart: impl IntoElement,
+    cx: &mut Context<ChartStory>,
+) -> impl IntoElement {
+    h_flex()
+        .flex_1()
+        .min_w(px(240.))
+        .gap_4()
+        .justify_between()
+        .border_1()
+        .border_color(cx.theme().border)
+        .rounded(cx.theme().radius_lg)
+        .p_4()
+        .child(
+            v_flex()
+                .gap_1()
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(title.to_string()),
+                )
+                .child(div().text_xl().font_semibold().child(value.into())),
+        )
+        .child(div().w(px(120.)).h(px(40.)).child(chart))
+}
+
 fn chart_container(
     title: &str,
     chart: impl IntoElement,
@@ -202,6 +242,36 @@ impl Render for ChartStory {
                         true,
                         cx,
                     ))
+                    .child(chart_container(
+                        "Pie Chart - Legend",
+                        v_flex()
+                            .size_full()
+                            .gap_2()
+                            .child(
+                                div().flex_1().child(
+                                    PieChart::new(self.monthly_devices.clone())
+                                        .value(|d| d.desktop as f32)
+                                        .inner_radius(60.)
+                                        .outer_radius(90.)
+                                        .color(move |d| d.color(color))
+                                        .active_index(self.active_pie_index)
+                                        .on_hover(cx.listener(Self::set_active_pie_index)),
+                                ),
+                            )
+                            .child(
+                                Legend::new("pie-legend")
+                                    .justify_center()
+                                    .items(
+                                        self.monthly_devices.iter().map(|d| {
+                                            LegendItem::new(d.month.clone(), d.color(color))
+                                        }),
+                                    )
+                                    .active_index(self.active_pie_index)
+                                    .on_hover(cx.listener(Self::set_active_pie_index)),
+                            ),
+                        true,
+                        cx,
+                    ))
                     .child(chart_container(
                         "Pie Chart - Pad Angle",
                         PieChart::new(self.monthly_devices.clone())
@@ -395,5 +465,72 @@ impl Render for ChartStory {
                         cx,
                     )),
             )
+            .child(Divider::horizontal())
+            .child(
+                h_flex()
+                    .flex_wrap()
+                    .gap_4()
+                    .child(sparkline_card(
+                        "Desktop",
+                        "1,024",
+                        SparklineChart::new(self.daily_devices.clone()).y(|d| d.desktop),
+                        cx,
+                    ))
+                    .child(sparkline_card(
+                        "Mobile",
+                        "842",
+                        SparklineChart::new(self.daily_devices.clone())
+                            .y(|d| d.mobile)
+                            .area()
+                            .natural()
+                            .stroke(cx.theme().chart_1),
+                        cx,
+                    ))
+                    .child(sparkline_card(
+                        "Tablet",
+                        "310",
+                        SparklineChart::new(self.daily_devices.iter().take(30).cloned())
+                            .y(|d| d.tablet)
+                            .bar()
+                            .stroke(cx.theme().chart_3),
+                        cx,
+                    ))
+                    .child(sparkline_card(
+                        "Stock",
+                        "$182.5",
+                        SparklineChart::new(self.stock_prices.clone())
+                            .y(|d| d.close)
+                            .dot()
+                            .stroke(cx.theme().chart_4),
+                        cx,
+                    )),
+            )
+            .child(Divider::horizontal())
+            .child(
+                v_flex()
+                    .gap_2()
+                    .border_1()
+                    .border_color(cx.theme().border)
+                    .rounded(cx.theme().radius_lg)
+                    .p_4()
+                    .child(div().font_semibold().child("Activity Heatmap"))
+                    .child(
+                        ActivityHeatmap::new("activity-heatmap")
+                            .end_date(NaiveDate::from_ymd_opt(2025, 6, 30).unwrap())
+                            .weeks(26)
+                            .cell_size(px(12.))
+                            .values(self.daily_devices.iter().filter_map(|d| {
+                                let date = NaiveDate::parse_from_str(
+                                    &format!("{} 2025", d.date),
+                                    "%b %d %Y",
+                                )
+                                .ok()?;
+                                Some((date, d.desktop))
+                            }))
+                            .tooltip(|date, value| {
+                                format!("{} visitors on {}", value, date.format("%b %d")).into()
+                            }),
+                    ),
+            )
     }
 }
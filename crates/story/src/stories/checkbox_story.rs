@@ -4,7 +4,11 @@ use gpui::{
 };
 
 use gpui_component::{
-    ActiveTheme, Disableable as _, Sizable, checkbox::Checkbox, h_flex, text::markdown, v_flex,
+    ActiveTheme, Disableable as _, Sizable,
+    checkbox::{Checkbox, CheckboxGroup},
+    h_flex,
+    text::markdown,
+    v_flex,
 };
 
 use crate::section;
@@ -17,6 +21,9 @@ pub struct CheckboxStory {
     check4: bool,
     check5: bool,
     check6: bool,
+    items: [bool; 3],
+    plan: usize,
+    group_selected: Vec<usize>,
 }
 
 impl super::Story for CheckboxStory {
@@ -47,6 +54,9 @@ impl CheckboxStory {
             check4: false,
             check5: false,
             check6: false,
+            items: [true, false, false],
+            plan: 0,
+            group_selected: vec![0],
         }
     }
 }
@@ -172,6 +182,106 @@ impl Render for CheckboxStory {
                     ),
                 ),
             )
+            .child(
+                section("Description").max_w_md().child(
+                    Checkbox::new("description-checkbox")
+                        .checked(self.check1)
+                        .label("Enable notifications")
+                        .description("You can change this later in the settings.")
+                        .on_click(cx.listener(|this, checked: &bool, _, cx| {
+                            this.check1 = *checked;
+                            cx.notify();
+                        })),
+                ),
+            )
+            .child({
+                let all = self.items.iter().all(|checked| *checked);
+                let any = self.items.iter().any(|checked| *checked);
+
+                section("Indeterminate").max_w_md().child(
+                    v_flex()
+                        .gap_2()
+                        .child(
+                            Checkbox::new("select-all")
+                                .label("Select all")
+                                .checked(all)
+                                .indeterminate(any && !all)
+                                .on_click(cx.listener(|this, checked: &bool, _, cx| {
+                                    this.items = [*checked; 3];
+                                    cx.notify();
+                                })),
+                        )
+                        .children(self.items.iter().enumerate().map(|(ix, checked)| {
+                            Checkbox::new(("item", ix))
+                                .ml_6()
+                                .label(format!("Item {}", ix + 1))
+                                .checked(*checked)
+                                .on_click(cx.listener(move |this, checked: &bool, _, cx| {
+                                    this.items[ix] = *checked;
+                                    cx.notify();
+                                }))
+                        })),
+                )
+            })
+            .child(
+                section("Error").max_w_md().child(
+                    Checkbox::new("error-checkbox")
+                        .checked(self.check2)
+                        .error(!self.check2)
+                        .label("I agree to the terms and conditions")
+                        .description("You must accept the terms to continue.")
+                        .on_click(cx.listener(|this, checked: &bool, _, cx| {
+                            this.check2 = *checked;
+                            cx.notify();
+                        })),
+                ),
+            )
+            .child(
+                section("Card").max_w_xl().child(
+                    h_flex().gap_3().children(
+                        [
+                            ("Starter", "For individuals and small projects."),
+                            ("Pro", "For growing teams with advanced needs."),
+                        ]
+                        .into_iter()
+                        .enumerate()
+                        .map(|(ix, (label, description))| {
+                            Checkbox::new(("plan", ix))
+                                .card(true)
+                                .flex_1()
+                                .label(label)
+                                .description(description)
+                                .checked(self.plan == ix)
+                                .on_click(cx.listener(move |this, _, _, cx| {
+                                    this.plan = ix;
+                                    cx.notify();
+                                }))
+                        }),
+                    ),
+                ),
+            )
+            .child(
+                section("Checkbox Group").max_w_md().child(
+                    v_flex()
+                        .w_full()
+                        .gap_4()
+                        .child(
+                            CheckboxGroup::horizontal("checkbox-group")
+                                .children(["Apple", "Banana", "Orange"])
+                                .selected_indices(self.group_selected.clone())
+                                .on_click(cx.listener(|this, selected: &Vec<usize>, _, cx| {
+                                    this.group_selected = selected.clone();
+                                    cx.notify();
+                                })),
+                        )
+                        .child(
+                            CheckboxGroup::vertical("checkbox-group-disabled")
+                                .children(["Monday", "Tuesday"])
+                                .selected_indices([1])
+                                .disabled(true),
+                        ),
+                ),
+            )
             .child(
                 section("Rich description (Markdown)").child(
                     Checkbox::new("longlong-markdown-checkbox")
@@ -5,7 +5,7 @@ use gpui::{
 
 use gpui_component::{
     WindowExt,
-    clipboard::Clipboard,
+    clipboard::{Clipboard, ClipboardContent},
     h_flex,
     input::{Input, InputState},
     label::Label,
@@ -97,5 +97,19 @@ impl Render for ClipboardStory {
                     ),
                 ),
             )
+            .child(
+                section("Copy Markdown").max_w_md().child(
+                    h_flex().gap_2().child(
+                        Clipboard::new("copy-markdown")
+                            .label("Copy Markdown")
+                            .content(ClipboardContent::Markdown(
+                                "# Hello\n\nThis is **Markdown**.".into(),
+                            ))
+                            .on_copied(|value, window, cx| {
+                                window.push_notification(format!("Copied: {}", value), cx)
+                            }),
+                    ),
+                ),
+            )
     }
 }
@@ -0,0 +1,125 @@
+use gpui::{
+    App, AppContext, Context, Entity, Focusable, IntoElement, ParentElement, Render, Styled, Window,
+};
+
+use gpui_component::{
+    IconName, Sizable as _,
+    button::{Button, ButtonVariants as _},
+    code_block::CodeBlock,
+    v_flex,
+};
+
+use crate::section;
+
+const RUST_CODE: &str = r#"use gpui::{App, Application, Context, Render, Window, div, prelude::*};
+
+struct HelloWorld {
+    text: String,
+}
+
+impl Render for HelloWorld {
+    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+        div().child(format!("Hello, {}! This is a long line to show the soft wrap of the code block.", self.text))
+    }
+}
+
+fn main() {
+    Application::new().run(|cx: &mut App| {
+        cx.open_window(Default::default(), |_, cx| {
+            cx.new(|_| HelloWorld {
+                text: "World".into(),
+            })
+        })
+        .unwrap();
+    });
+}
+"#;
+
+const SHELL_CODE: &str = "cargo run --example hello_world";
+
+pub struct CodeBlockStory {
+    focus_handle: gpui::FocusHandle,
+}
+
+impl super::Story for CodeBlockStory {
+    fn title() -> &'static str {
+        "CodeBlock"
+    }
+
+    fn description() -> &'static str {
+        "A code block with syntax highlighting, line numbers and copy button."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render> {
+        Self::view(window, cx)
+    }
+}
+
+impl CodeBlockStory {
+    pub(crate) fn new(_: &mut Window, cx: &mut App) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+        }
+    }
+
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+}
+
+impl Focusable for CodeBlockStory {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for CodeBlockStory {
+    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_6()
+            .child(
+                section("Basic").child(CodeBlock::new("basic", SHELL_CODE).lang("bash").w_full()),
+            )
+            .child(
+                section("Line Numbers and Highlighted Lines").child(
+                    CodeBlock::new("line-numbers", RUST_CODE)
+                        .lang("rust")
+                        .line_numbers(true)
+                        .highlight_lines([7, 8, 9])
+                        .w_full(),
+                ),
+            )
+            .child(
+                section("No Soft Wrap").child(
+                    CodeBlock::new("no-wrap", RUST_CODE)
+                        .lang("rust")
+                        .soft_wrap(false)
+                        .w_full(),
+                ),
+            )
+            .child(
+                section("Max Lines").child(
+                    CodeBlock::new("max-lines", RUST_CODE)
+                        .lang("rust")
+                        .line_numbers(true)
+                        .max_lines(8)
+                        .w_full(),
+                ),
+            )
+            .child(
+                section("With Actions").child(
+                    CodeBlock::new("actions", SHELL_CODE)
+                        .lang("bash")
+                        .wrap_toggle(false)
+                        .actions(
+                            Button::new("run")
+                                .icon(IconName::SquareTerminal)
+                                .ghost()
+                                .xsmall()
+                                .tooltip("Run in Terminal"),
+                        )
+                        .w_full(),
+                ),
+            )
+    }
+}
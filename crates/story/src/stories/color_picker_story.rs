@@ -4,7 +4,7 @@ use gpui::{
 };
 use gpui_component::{
     ActiveTheme as _, Colorize, Sizable,
-    color_picker::{ColorPicker, ColorPickerEvent, ColorPickerState},
+    color_picker::{ColorFormat, ColorPicker, ColorPickerEvent, ColorPickerState},
     v_flex,
 };
 
@@ -12,6 +12,7 @@ use crate::section;
 
 pub struct ColorPickerStory {
     color: Entity<ColorPickerState>,
+    rgb_color: Entity<ColorPickerState>,
     selected_color: Option<Hsla>,
     _subscriptions: Vec<Subscription>,
 }
@@ -39,6 +40,14 @@ impl ColorPickerStory {
         let color =
             cx.new(|cx| ColorPickerState::new(window, cx).default_value(cx.theme().primary));
 
+        let rgb_color = cx.new(|cx| {
+            let mut state = ColorPickerState::new(window, cx)
+                .format(ColorFormat::Rgb)
+                .default_value(cx.theme().blue);
+            state.set_recent_colors([cx.theme().red, cx.theme().green, cx.theme().yellow], cx);
+            state
+        });
+
         let _subscriptions = vec![cx.subscribe(&color, |this, _, ev, _| match ev {
             ColorPickerEvent::Change(color) => {
                 this.selected_color = *color;
@@ -47,6 +56,7 @@ impl ColorPickerStory {
 
         Self {
             color,
+            rgb_color,
             selected_color: Some(cx.theme().primary),
             _subscriptions,
         }
@@ -61,13 +71,20 @@ impl Focusable for ColorPickerStory {
 
 impl Render for ColorPickerStory {
     fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
-        v_flex().gap_3().child(
-            section("Normal")
-                .max_w_md()
-                .child(ColorPicker::new(&self.color).small())
-                .when_some(self.selected_color, |this, color| {
-                    this.child(div().w_24().child(color.to_hex()))
-                }),
-        )
+        v_flex()
+            .gap_3()
+            .child(
+                section("Normal")
+                    .max_w_md()
+                    .child(ColorPicker::new(&self.color).small())
+                    .when_some(self.selected_color, |this, color| {
+                        this.child(div().w_24().child(color.to_hex()))
+                    }),
+            )
+            .child(
+                section("RGB Format with Recent Colors")
+                    .max_w_md()
+                    .child(ColorPicker::new(&self.rgb_color).label("Accent")),
+            )
     }
 }
@@ -0,0 +1,171 @@
+use gpui::{
+    Action, App, AppContext, Context, Entity, FocusHandle, Focusable, InteractiveElement as _,
+    IntoElement, KeyBinding, ParentElement as _, Render, SharedString, Styled as _, Subscription,
+    Window, actions,
+};
+use gpui_component::{
+    ActiveTheme as _, WindowExt as _,
+    button::Button,
+    command_palette::{
+        CommandPalette, CommandPaletteEvent, CommandPaletteItem, CommandPaletteState,
+    },
+    v_flex,
+};
+
+use crate::section;
+
+actions!(
+    command_palette_story,
+    [
+        OpenCommandPalette,
+        NewFile,
+        OpenFile,
+        SaveFile,
+        ToggleSidebar
+    ]
+);
+
+const CONTEXT: &str = "CommandPaletteStory";
+
+pub fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("secondary-shift-p", OpenCommandPalette, Some(CONTEXT)),
+        KeyBinding::new("secondary-n", NewFile, Some(CONTEXT)),
+        KeyBinding::new("secondary-o", OpenFile, Some(CONTEXT)),
+        KeyBinding::new("secondary-s", SaveFile, Some(CONTEXT)),
+        KeyBinding::new("secondary-b", ToggleSidebar, Some(CONTEXT)),
+    ])
+}
+
+pub struct CommandPaletteStory {
+    focus_handle: FocusHandle,
+    palette: Entity<CommandPaletteState>,
+    inline_palette: Entity<CommandPaletteState>,
+    last_command: Option<SharedString>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl super::Story for CommandPaletteStory {
+    fn title() -> &'static str {
+        "CommandPalette"
+    }
+
+    fn description() -> &'static str {
+        "Search and run commands and actions with fuzzy matching."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render> {
+        Self::view(window, cx)
+    }
+}
+
+impl CommandPaletteStory {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let palette = cx.new(|cx| {
+            CommandPaletteState::new(window, cx)
+                .include_actions(true)
+                .action_filter(|action| action.name().starts_with("command_palette_story::"))
+                .commands([
+                    CommandPaletteItem::new("Change Theme")
+                        .description("Switch between light and dark mode")
+                        .on_select(|window, cx| {
+                            window.push_notification("Change Theme selected.", cx)
+                        }),
+                    CommandPaletteItem::new("Reload Window"),
+                ])
+        });
+        let inline_palette = cx.new(|cx| {
+            CommandPaletteState::new(window, cx).commands([
+                CommandPaletteItem::new("Copy Path"),
+                CommandPaletteItem::new("Copy Relative Path"),
+                CommandPaletteItem::new("Reveal in Finder"),
+            ])
+        });
+
+        let _subscriptions = vec![cx.subscribe(&palette, |this, _, event, cx| match event {
+            CommandPaletteEvent::Confirm(id) => {
+                this.last_command = Some(id.clone());
+                cx.notify();
+            }
+        })];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            palette,
+            inline_palette,
+            last_command: None,
+            _subscriptions,
+        }
+    }
+
+    fn open_palette(
+        &mut self,
+        _: &OpenCommandPalette,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.palette
+            .update(cx, |palette, cx| palette.open(window, cx));
+    }
+
+    fn notify_action(&mut self, action: &dyn Action, window: &mut Window, cx: &mut Context<Self>) {
+        window.push_notification(format!("Dispatched action: {}", action.name()), cx);
+    }
+}
+
+impl Focusable for CommandPaletteStory {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for CommandPaletteStory {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::open_palette))
+            .on_action(cx.listener(|this, action: &NewFile, window, cx| {
+                this.notify_action(action, window, cx)
+            }))
+            .on_action(cx.listener(|this, action: &OpenFile, window, cx| {
+                this.notify_action(action, window, cx)
+            }))
+            .on_action(cx.listener(|this, action: &SaveFile, window, cx| {
+                this.notify_action(action, window, cx)
+            }))
+            .on_action(cx.listener(|this, action: &ToggleSidebar, window, cx| {
+                this.notify_action(action, window, cx)
+            }))
+            .gap_6()
+            .child(
+                section("Command Palette")
+                    .sub_title("Press `cmd-shift-p` (or `ctrl-shift-p`) to open.")
+                    .child(
+                        Button::new("open")
+                            .label("Open Command Palette")
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                this.open_palette(&OpenCommandPalette, window, cx)
+                            })),
+                    )
+                    .child(
+                        self.last_command
+                            .clone()
+                            .map(|id| format!("Last command: {}", id))
+                            .unwrap_or_default(),
+                    ),
+            )
+            .child(
+                section("Inline").max_w_md().child(
+                    CommandPalette::new(&self.inline_palette)
+                        .border_1()
+                        .border_color(cx.theme().border)
+                        .rounded(cx.theme().radius),
+                ),
+            )
+    }
+}
@@ -0,0 +1,89 @@
+use gpui::{
+    App, AppContext, Context, Entity, Focusable, IntoElement, ParentElement, Render, Styled, Window,
+};
+use gpui_component::{
+    ActiveTheme as _, IconName, Sizable as _,
+    button::{Button, ButtonVariants as _},
+    empty_state::EmptyState,
+    text::markdown,
+    v_flex,
+};
+
+use crate::section;
+
+pub struct EmptyStateStory {
+    focus_handle: gpui::FocusHandle,
+}
+
+impl super::Story for EmptyStateStory {
+    fn title() -> &'static str {
+        "EmptyState"
+    }
+
+    fn description() -> &'static str {
+        "A placeholder with icon, title, description and actions when there is no content."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render> {
+        Self::view(window, cx)
+    }
+}
+
+impl EmptyStateStory {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(_: &mut Window, cx: &mut Context<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+        }
+    }
+}
+
+impl Focusable for EmptyStateStory {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for EmptyStateStory {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_6()
+            .child(
+                section("Empty State").child(
+                    EmptyState::new()
+                        .title("No session selected")
+                        .description("Select a session from the sidebar, or start a new one.")
+                        .action(
+                            Button::new("new-session")
+                                .primary()
+                                .small()
+                                .icon(IconName::Plus)
+                                .label("New Session"),
+                        )
+                        .action(Button::new("import").outline().small().label("Import")),
+                ),
+            )
+            .child(
+                section("Custom Icon").child(
+                    EmptyState::new()
+                        .large()
+                        .icon(IconName::Search)
+                        .title("No results found")
+                        .description(markdown("Try to search with **different keywords**.")),
+                ),
+            )
+            .child(
+                section("Small").child(
+                    EmptyState::new()
+                        .small()
+                        .title("No files")
+                        .border_1()
+                        .border_color(cx.theme().border)
+                        .rounded(cx.theme().radius),
+                ),
+            )
+    }
+}
@@ -1,6 +1,9 @@
+use std::time::Duration;
+
 use gpui::{
     App, AppContext, Axis, Context, Entity, FocusHandle, Focusable, InteractiveElement,
-    IntoElement, ParentElement as _, Render, Styled, Window, div, prelude::FluentBuilder as _, px,
+    IntoElement, ParentElement as _, Render, SharedString, Styled, Subscription, Window, div,
+    prelude::FluentBuilder as _, px,
 };
 use gpui_component::{
     ActiveTheme, AxisExt, IndexPath, Selectable, Sizable, Size,
@@ -9,7 +12,7 @@ use gpui_component::{
     color_picker::{ColorPicker, ColorPickerState},
     date_picker::{DatePicker, DatePickerState},
     divider::Divider,
-    form::{field, v_form},
+    form::{FieldValue, FormEvent, FormState, field, v_form, validator},
     h_flex,
     input::{Input, InputState},
     select::{Select, SelectState},
@@ -29,6 +32,9 @@ pub struct FormStory {
     layout: Axis,
     size: Size,
     columns: usize,
+    form: Entity<FormState>,
+    submit_message: Option<SharedString>,
+    _subscriptions: Vec<Subscription>,
 }
 
 impl super::Story for FormStory {
@@ -82,6 +88,53 @@ impl FormStory {
         });
         let date = cx.new(|cx| DatePickerState::new(window, cx));
 
+        let form = cx.new(|cx| {
+            FormState::new(window, cx)
+                .field("name", {
+                    let input = name_input.clone();
+                    move |cx| input.read(cx).value().into()
+                })
+                .validator("name", validator::required("Name is required."))
+                .validator(
+                    "name",
+                    validator::max_length(20, "Name must be at most 20 characters."),
+                )
+                .field("email", {
+                    let input = email_input.clone();
+                    move |cx| input.read(cx).value().into()
+                })
+                .validator("email", validator::required("Email is required."))
+                .validator(
+                    "email",
+                    validator::pattern(
+                        regex::Regex::new(r"^[^@\s]+@[^@\s]+\.[^@\s]+$").unwrap(),
+                        "Please enter a valid email address.",
+                    ),
+                )
+                .async_validator("email", |value: FieldValue, cx: &mut App| {
+                    // Simulate to check the email on the server.
+                    let timer = cx.background_executor().timer(Duration::from_millis(500));
+                    cx.spawn(async move |_| {
+                        timer.await;
+                        if value.as_str() == Some("admin@example.com") {
+                            Err("This email is already taken.".into())
+                        } else {
+                            Ok(())
+                        }
+                    })
+                })
+        });
+
+        let _subscriptions = vec![cx.subscribe(&form, |this, _, event: &FormEvent, cx| {
+            this.submit_message = Some(match event {
+                FormEvent::Submit(values) => format!("Submitted {} fields.", values.len()).into(),
+                FormEvent::Invalid(errors) => {
+                    format!("{} fields are invalid.", errors.len()).into()
+                }
+            });
+            cx.notify();
+        })];
+
         Self {
             focus_handle: cx.focus_handle(),
             name_prefix_state,
@@ -94,6 +147,9 @@ impl FormStory {
             layout: Axis::Vertical,
             size: Size::default(),
             columns: 1,
+            form,
+            submit_message: None,
+            _subscriptions,
         }
     }
 }
@@ -184,12 +240,13 @@ impl Render for FormStory {
             .child(Divider::horizontal())
             .child(
                 v_form()
+                    .state(&self.form)
                     .layout(self.layout)
                     .with_size(self.size)
                     .columns(self.columns)
                     .label_width(px(if is_multi_column { 100. } else { 140. }))
                     .child(
-                        field().label_fn(|_, _| "Name").child(
+                        field().name("name").label_fn(|_, _| "Name").child(
                             h_flex()
                                 .gap_2()
                                 .border_1()
@@ -212,6 +269,7 @@ impl Render for FormStory {
                     )
                     .child(
                         field()
+                            .name("email")
                             .label("Email")
                             .child(Input::new(&self.email_input))
                             .required(true),
@@ -282,6 +340,32 @@ impl Render for FormStory {
                                         cx.notify();
                                     })),
                             ),
+                    )
+                    .child(
+                        field().label_indent(false).child(
+                            h_flex()
+                                .gap_3()
+                                .child(
+                                    Button::new("submit")
+                                        .primary()
+                                        .small()
+                                        .label("Submit")
+                                        .loading(self.form.read(cx).is_validating())
+                                        .on_click(cx.listener(|this, _, window, cx| {
+                                            this.form.update(cx, |form, cx| {
+                                                form.submit(window, cx);
+                                            });
+                                        })),
+                                )
+                                .when_some(self.submit_message.clone(), |this, message| {
+                                    this.child(
+                                        div()
+                                            .text_sm()
+                                            .text_color(cx.theme().muted_foreground)
+                                            .child(message),
+                                    )
+                                }),
+                        ),
                     ),
             )
     }
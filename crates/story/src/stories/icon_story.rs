@@ -78,6 +78,23 @@ impl Render for IconStory {
                             .text_color(cx.theme().red),
                     ),
             )
+            .child(
+                section("Animated Icon")
+                    .child(Icon::new(IconName::LoaderCircle).size_6().spin())
+                    .child(Icon::new(IconName::Loader).size_6().spin())
+                    .child(
+                        Icon::new(IconName::Bot)
+                            .size_6()
+                            .text_color(cx.theme().blue)
+                            .pulse(),
+                    )
+                    .child(
+                        Icon::new(IconName::Heart)
+                            .size_6()
+                            .text_color(cx.theme().red)
+                            .pulse(),
+                    ),
+            )
             .child(
                 section("Icon Button").child(
                     h_flex()
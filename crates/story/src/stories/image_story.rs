@@ -1,12 +1,38 @@
 use crate::section;
 use gpui::{
-    App, AppContext, Context, Entity, FocusHandle, Focusable, IntoElement, ParentElement as _,
-    Render, Styled, Window, img,
+    App, AppContext, Context, Entity, FocusHandle, Focusable, InteractiveElement as _, IntoElement,
+    ParentElement as _, Render, StatefulInteractiveElement as _, Styled, Window, div, img,
 };
-use gpui_component::{dock::PanelControl, v_flex};
+use gpui_component::{
+    ActiveTheme as _, WindowExt as _,
+    animated_image::AnimatedImage,
+    button::{Button, ButtonVariants as _},
+    dock::PanelControl,
+    image_viewer::{ImageViewer, ImageViewerState},
+    svg_image::SvgImage,
+    v_flex,
+};
+
+const SDK_IMAGE: &str = "https://pub.lbkrs.com/files/202503/vEnnmgUM6bo362ya/sdk.svg";
+const GALLERY_IMAGES: [&str; 4] = [
+    "https://avatars.githubusercontent.com/u/10757551?v=4",
+    "https://avatars.githubusercontent.com/u/28998859?v=4",
+    "https://avatars.githubusercontent.com/u/20092316?v=4",
+    SDK_IMAGE,
+];
+const THEMED_SVG: &str = r##"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 64 64">
+<rect x="4" y="4" width="56" height="56" rx="12" fill="none" stroke="currentColor"
+  stroke-width="4"/>
+<circle cx="24" cy="26" r="6" fill="#F59E0B"/>
+<path d="M12 50 L28 34 L38 44 L44 38 L54 50 Z" fill="currentColor"/>
+</svg>"##;
+const ANIMATED_IMAGE: &str =
+    "https://upload.wikimedia.org/wikipedia/commons/2/2c/Rotating_earth_%28large%29.gif";
 
 pub struct ImageStory {
     focus_handle: gpui::FocusHandle,
+    viewer: Entity<ImageViewerState>,
+    playing: bool,
 }
 
 impl super::Story for ImageStory {
@@ -15,7 +41,7 @@ impl super::Story for ImageStory {
     }
 
     fn description() -> &'static str {
-        "Image and SVG image supported."
+        "Image and SVG image supported, with an ImageViewer and Lightbox to zoom and pan."
     }
 
     fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render> {
@@ -28,9 +54,13 @@ impl super::Story for ImageStory {
 }
 
 impl ImageStory {
-    pub fn new(_: &mut Window, cx: &mut App) -> Self {
+    pub fn new(window: &mut Window, cx: &mut App) -> Self {
+        let viewer = cx.new(|cx| ImageViewerState::new(window, cx).source(SDK_IMAGE));
+
         Self {
             focus_handle: cx.focus_handle(),
+            viewer,
+            playing: true,
         }
     }
 
@@ -46,10 +76,56 @@ impl Focusable for ImageStory {
 }
 
 impl Render for ImageStory {
-    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
-        v_flex().gap_4().size_full().child(
-            section("SVG from URL")
-                .child(img("https://pub.lbkrs.com/files/202503/vEnnmgUM6bo362ya/sdk.svg").h_24()),
-        )
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_4()
+            .size_full()
+            .child(section("SVG from URL").child(img(SDK_IMAGE).h_24()))
+            .child(
+                section("SVG with currentColor")
+                    .child(SvgImage::new("themed-svg").data(THEMED_SVG).size_16())
+                    .child(
+                        SvgImage::new("themed-svg-primary")
+                            .data(THEMED_SVG)
+                            .color(cx.theme().primary)
+                            .size_16(),
+                    ),
+            )
+            .child(
+                section("Animated Image")
+                    .child(
+                        AnimatedImage::new("animated-image", ANIMATED_IMAGE)
+                            .size_32()
+                            .playing(self.playing),
+                    )
+                    .child(
+                        Button::new("toggle-playing")
+                            .outline()
+                            .label(if self.playing { "Pause" } else { "Play" })
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.playing = !this.playing;
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .child(
+                section("Image Viewer").child(ImageViewer::new(&self.viewer).h_80().rounded_md()),
+            )
+            .child(
+                section("Lightbox").children(GALLERY_IMAGES.iter().enumerate().map(|(ix, src)| {
+                    div()
+                        .id(ix)
+                        .size_20()
+                        .p_1()
+                        .border_1()
+                        .border_color(cx.theme().border)
+                        .rounded_md()
+                        .cursor_pointer()
+                        .child(img(*src).size_full())
+                        .on_click(move |_, window, cx| {
+                            window.open_lightbox(GALLERY_IMAGES, ix, cx);
+                        })
+                })),
+            )
     }
 }
@@ -1,3 +1,5 @@
+use std::rc::Rc;
+
 use gpui::{
     App, AppContext as _, ClickEvent, Context, Entity, InteractiveElement, IntoElement,
     ParentElement as _, Render, Styled, Subscription, Window, div,
@@ -30,6 +32,8 @@ pub struct InputStory {
     custom_menu_input: Entity<InputState>,
     code_input: Entity<InputState>,
     color_input: Entity<InputState>,
+    mention_input: Entity<InputState>,
+    command_input: Entity<InputState>,
 
     _subscriptions: Vec<Subscription>,
 }
@@ -127,10 +131,37 @@ impl InputStory {
                 .default_value("Right Aligned Text")
         });
 
+        let mention_input = cx.new(|cx| {
+            let mut state = InputState::new(window, cx);
+            state.set_value("Review ", window, cx);
+            state.insert_mention("@main.rs", "src/main.rs", window, cx);
+            state.insert(" please", window, cx);
+            state
+        });
+
+        let commands = CommandRegistry::new()
+            .command(
+                SlashCommand::new("model")
+                    .description("Switch the model")
+                    .arg("name"),
+            )
+            .command(
+                SlashCommand::new("rename")
+                    .description("Rename the session")
+                    .arg("title"),
+            )
+            .command(SlashCommand::new("clear").description("Clear the conversation"));
+        let command_input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder("Type / to see the commands, Enter to dispatch.")
+                .command_registry(Rc::new(commands))
+        });
+
         let _subscriptions = vec![
             cx.subscribe_in(&input1, window, Self::on_input_event),
             cx.subscribe_in(&input2, window, Self::on_input_event),
             cx.subscribe_in(&phone_input, window, Self::on_input_event),
+            cx.subscribe_in(&command_input, window, Self::on_input_event),
         ];
 
         Self {
@@ -156,6 +187,8 @@ impl InputStory {
             custom_menu_input,
             code_input,
             color_input,
+            mention_input,
+            command_input,
             input_text_centered,
             input_text_right,
             _subscriptions,
@@ -184,9 +217,18 @@ impl InputStory {
             InputEvent::PressEnter { secondary } => println!("PressEnter secondary: {}", secondary),
             InputEvent::Focus => println!("Focus"),
             InputEvent::Blur => println!("Blur"),
+            InputEvent::Command(command) => {
+                println!("Command: /{} {:?}", command.name, command.args)
+            }
         };
     }
 
+    fn on_click_mention(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.mention_input.update(cx, |state, cx| {
+            state.insert_mention("#session", "session-1", window, cx);
+        });
+    }
+
     fn on_click_reset(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
         self.code_input.update(cx, |input_state, cx| {
             input_state.set_value(CODE_EXAMPLE, window, cx);
@@ -325,6 +367,28 @@ impl Render for InputStory {
                     .max_w_md()
                     .child(Input::new(&self.color_input).text_color(cx.theme().info)),
             )
+            .child(
+                section("Mentions")
+                    .max_w_md()
+                    .child(
+                        Input::new(&self.mention_input).suffix(
+                            Button::new("insert-mention")
+                                .ghost()
+                                .label("#session")
+                                .xsmall()
+                                .on_click(cx.listener(Self::on_click_mention)),
+                        ),
+                    )
+                    .child(div().w_full().child(format!(
+                        "Segments: {:?}",
+                        self.mention_input.read(cx).segments()
+                    ))),
+            )
+            .child(
+                section("Slash Commands")
+                    .max_w_md()
+                    .child(Input::new(&self.command_input)),
+            )
             .child(
                 section("Single line code editor").max_w_md().child(
                     Input::new(&self.code_input).suffix(
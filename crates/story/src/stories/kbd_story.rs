@@ -1,11 +1,11 @@
 use gpui::{
-    App, AppContext, Context, Entity, Focusable, IntoElement, Keystroke, ParentElement, Render,
-    Styled, Window,
+    App, AppContext, Context, Entity, Focusable, IntoElement, KeyBinding, Keystroke, ParentElement,
+    Render, Styled, Window,
 };
 
 use gpui_component::{h_flex, kbd::Kbd, v_flex};
 
-use crate::section;
+use crate::{Quit, section};
 
 pub struct KbdStory {
     focus_handle: gpui::FocusHandle,
@@ -68,5 +68,27 @@ impl Render for KbdStory {
                         .child(Kbd::new(Keystroke::parse("enter").unwrap()).outline()),
                 ),
             )
+            .child(
+                section("Keystroke Sequence").child(
+                    h_flex()
+                        .gap_2()
+                        .child(Kbd::with_keystrokes(vec![
+                            Keystroke::parse("cmd-k").unwrap(),
+                            Keystroke::parse("cmd-s").unwrap(),
+                        ]))
+                        .child(Kbd::from_binding(&KeyBinding::new(
+                            "ctrl-x ctrl-c",
+                            Quit,
+                            None,
+                        )))
+                        .child(
+                            Kbd::with_keystrokes(vec![
+                                Keystroke::parse("g").unwrap(),
+                                Keystroke::parse("g").unwrap(),
+                            ])
+                            .outline(),
+                        ),
+                ),
+            )
     }
 }
@@ -176,6 +176,20 @@ impl Render for LabelStory {
                         ),
                     ),
             )
+            .child(
+                section("Selectable (drag to select, cmd-a to select all)")
+                    .max_w_md()
+                    .child(
+                        div().w(px(200.)).child(
+                            Label::new(
+                                "The selected text can be copied by cmd-c, \
+                                like the text in the browser.",
+                            )
+                            .secondary("(Selectable)")
+                            .selectable("selectable-label"),
+                        ),
+                    ),
+            )
             .child(
                 section("Masked Label").max_w_md().child(
                     v_flex()
@@ -8,12 +8,13 @@ use gpui::{
 };
 
 use gpui_component::{
-    ActiveTheme, Icon, IconName, IndexPath, Selectable, Sizable,
-    button::Button,
+    ActiveTheme, Icon, IconName, IndexPath, Selectable, Sizable, WindowExt as _,
+    button::{Button, ButtonVariants as _},
     checkbox::Checkbox,
+    drag_drop::move_item,
     h_flex,
     label::Label,
-    list::{List, ListDelegate, ListEvent, ListItem, ListState},
+    list::{List, ListDelegate, ListEvent, ListItem, ListState, SwipeRow},
     v_flex,
 };
 
@@ -45,6 +46,7 @@ impl Company {
 
 #[derive(IntoElement)]
 struct CompanyListItem {
+    id: ElementId,
     base: ListItem,
     company: Rc<Company>,
     selected: bool,
@@ -52,9 +54,11 @@ struct CompanyListItem {
 
 impl CompanyListItem {
     pub fn new(id: impl Into<ElementId>, company: Rc<Company>, selected: bool) -> Self {
+        let id = id.into();
         CompanyListItem {
             company,
-            base: ListItem::new(id).selected(selected),
+            base: ListItem::new(id.clone()).selected(selected),
+            id,
             selected,
         }
     }
@@ -73,6 +77,7 @@ impl Selectable for CompanyListItem {
 
 impl RenderOnce for CompanyListItem {
     fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+        let name = self.company.name.clone();
         let text_color = if self.selected {
             cx.theme().accent_foreground
         } else {
@@ -85,7 +90,8 @@ impl RenderOnce for CompanyListItem {
             _ => cx.theme().foreground,
         };
 
-        self.base
+        let item = self
+            .base
             .px_2()
             .py_1()
             .overflow_x_hidden()
@@ -130,7 +136,30 @@ impl RenderOnce for CompanyListItem {
                                 ),
                             ),
                     ),
+            );
+
+        // Swipe the item to reveal the actions.
+        SwipeRow::new(self.id)
+            .leading_action(
+                Button::new("star")
+                    .primary()
+                    .icon(IconName::Star)
+                    .on_click({
+                        let name = name.clone();
+                        move |_, window, cx| {
+                            window.push_notification(format!("Starred {}", name), cx)
+                        }
+                    }),
             )
+            .trailing_action(
+                Button::new("delete")
+                    .danger()
+                    .icon(IconName::Delete)
+                    .on_click(move |_, window, cx| {
+                        window.push_notification(format!("Deleted {}", name), cx)
+                    }),
+            )
+            .child(item)
     }
 }
 
@@ -238,6 +267,21 @@ impl ListDelegate for CompanyListDelegate {
         println!("right_clicked_index: {:?}", ix);
     }
 
+    fn can_move_item(&self, _: IndexPath, _: &App) -> bool {
+        // Only allow to reorder the items when not searching.
+        self.query.is_empty()
+    }
+
+    fn move_item(
+        &mut self,
+        from: IndexPath,
+        to: IndexPath,
+        _: &mut Window,
+        _: &mut Context<ListState<Self>>,
+    ) {
+        move_item(&mut self.matched_companies[from.section], from.row, to.row);
+    }
+
     fn render_section_header(
         &mut self,
         section: usize,
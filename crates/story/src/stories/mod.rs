@@ -12,8 +12,10 @@ mod calendar_story;
 mod chart_story;
 mod checkbox_story;
 mod clipboard_story;
+mod code_block_story;
 mod collapsible_story;
 mod color_picker_story;
+mod command_palette_story;
 mod data_table_story;
 mod date_picker_story;
 mod description_list_story;
@@ -21,6 +23,7 @@ mod dialog_story;
 mod divider_story;
 mod dropdown_button_story;
 mod editor_story;
+mod empty_state_story;
 mod form_story;
 mod group_box_story;
 mod hover_card_story;
@@ -44,17 +47,21 @@ mod scrollbar_story;
 mod select_story;
 mod settings_story;
 mod sheet_story;
+mod shortcut_cheatsheet_story;
 mod sidebar_story;
 mod skeleton_story;
 mod slider_story;
 mod spinner_story;
+mod status_bar_story;
 mod stepper_story;
 mod switch_story;
 mod table_story;
 mod tabs_story;
 mod tag_story;
 mod textarea_story;
+mod theme_editor_story;
 mod theme_story;
+mod time_picker_story;
 mod toggle_story;
 mod tooltip_story;
 mod tree_story;
@@ -72,8 +79,10 @@ pub use calendar_story::CalendarStory;
 pub use chart_story::ChartStory;
 pub use checkbox_story::CheckboxStory;
 pub use clipboard_story::ClipboardStory;
+pub use code_block_story::CodeBlockStory;
 pub use collapsible_story::CollapsibleStory;
 pub use color_picker_story::ColorPickerStory;
+pub use command_palette_story::CommandPaletteStory;
 pub use data_table_story::DataTableStory;
 pub use date_picker_story::DatePickerStory;
 pub use description_list_story::DescriptionListStory;
@@ -81,6 +90,7 @@ pub use dialog_story::DialogStory;
 pub use divider_story::DividerStory;
 pub use dropdown_button_story::DropdownButtonStory;
 pub use editor_story::EditorStory;
+pub use empty_state_story::EmptyStateStory;
 pub use form_story::FormStory;
 pub use group_box_story::GroupBoxStory;
 pub use hover_card_story::HoverCardStory;
@@ -104,17 +114,21 @@ pub use scrollbar_story::ScrollbarStory;
 pub use select_story::SelectStory;
 pub use settings_story::SettingsStory;
 pub use sheet_story::SheetStory;
+pub use shortcut_cheatsheet_story::ShortcutCheatsheetStory;
 pub use sidebar_story::SidebarStory;
 pub use skeleton_story::SkeletonStory;
 pub use slider_story::SliderStory;
 pub use spinner_story::SpinnerStory;
+pub use status_bar_story::StatusBarStory;
 pub use stepper_story::StepperStory;
 pub use switch_story::SwitchStory;
 pub use table_story::TableStory;
 pub use tabs_story::TabsStory;
 pub use tag_story::TagStory;
 pub use textarea_story::TextareaStory;
+pub use theme_editor_story::ThemeEditorStory;
 pub use theme_story::ThemeColorsStory;
+pub use time_picker_story::TimePickerStory;
 pub use toggle_story::ToggleStory;
 pub use tooltip_story::TooltipStory;
 pub use tree_story::TreeStory;
@@ -124,6 +138,7 @@ pub use welcome_story::WelcomeStory;
 
 pub(crate) fn init(cx: &mut App) {
     input_story::init(cx);
+    command_palette_story::init(cx);
     rating_story::init(cx);
     number_input_story::init(cx);
     textarea_story::init(cx);
@@ -133,6 +148,7 @@ pub(crate) fn init(cx: &mut App) {
     tooltip_story::init(cx);
     otp_input_story::init(cx);
     tree_story::init(cx);
+    shortcut_cheatsheet_story::init(cx);
 }
 
 pub trait Story: Render + Sized {
@@ -337,6 +337,23 @@ impl Render for PopoverStory {
                         .child("This popover is open by default when first rendered."),
                 ),
             )
+            .child(
+                section("Arrow")
+                    .sub_title("Flip to the opposite side if there is not enough space.")
+                    .child(
+                        Popover::new("popover-arrow")
+                            .arrow(true)
+                            .anchor(Anchor::TopCenter)
+                            .trigger(Button::new("arrow-btn").label("With Arrow").outline())
+                            .child("This popover has an arrow pointing to the trigger."),
+                    )
+                    .child(
+                        Popover::new("popover-no-flip")
+                            .auto_flip(false)
+                            .trigger(Button::new("no-flip-btn").label("No Flip").outline())
+                            .child("This popover never flips."),
+                    ),
+            )
             .child(
                 section("Popover Anchor")
                     .min_h(px(360.))
@@ -15,6 +15,7 @@ pub struct RatingStory {
     focus_handle: gpui::FocusHandle,
     size: Size,
     value: usize,
+    half_value: f32,
 }
 
 impl super::Story for RatingStory {
@@ -41,6 +42,7 @@ impl RatingStory {
             focus_handle: cx.focus_handle(),
             size: Size::default(),
             value: 3,
+            half_value: 3.5,
         }
     }
 }
@@ -143,6 +145,24 @@ impl Render for RatingStory {
                         ),
                 ),
             )
+            .child(
+                section("Half Star").max_w_md().child(
+                    v_flex()
+                        .gap_3()
+                        .items_center()
+                        .child(
+                            Rating::new("rating-half")
+                                .with_size(self.size)
+                                .half(true)
+                                .value_f32(self.half_value)
+                                .on_change(cx.listener(|this, value: &f32, _, cx| {
+                                    this.half_value = *value;
+                                    cx.notify();
+                                })),
+                        )
+                        .child(format!("Value: {}", self.half_value)),
+                ),
+            )
             .child(
                 section("Disabled").max_w_md().child(
                     Rating::new("rating-2")
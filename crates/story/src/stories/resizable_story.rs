@@ -116,6 +116,34 @@ impl Render for ResizableStory {
                             .child(panel_box("Right (Grow)", cx)),
                     ),
             )
+            // Drag the handle to less than half of the min size to collapse the panel,
+            // click the button on the handle to restore it, or double-click the handle
+            // to reset the panel to its default size.
+            .child(
+                div()
+                    .h(px(300.))
+                    .border_1()
+                    .border_color(cx.theme().border)
+                    .child(
+                        h_resizable("resizable-collapsible")
+                            .child(
+                                resizable_panel()
+                                    .size(px(240.))
+                                    .min_size(px(160.))
+                                    .max_size(px(400.))
+                                    .collapsible(true)
+                                    .child(panel_box("Collapsible (160px .. 400px)", cx)),
+                            )
+                            .child(panel_box("Center", cx))
+                            .child(
+                                resizable_panel()
+                                    .size(px(200.))
+                                    .min_size(px(120.))
+                                    .collapsible(true)
+                                    .child(panel_box("Collapsible", cx)),
+                            ),
+                    ),
+            )
             // Demonstrates `.flex_none()`. Toggle the left panel's
             // visibility while watching the right panel's width:
             //
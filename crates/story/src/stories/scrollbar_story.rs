@@ -1,14 +1,16 @@
 use std::rc::Rc;
 
 use gpui::{
-    App, AppContext, Context, Entity, FocusHandle, Focusable, IntoElement, ParentElement, Pixels,
-    Render, Size, Styled, UniformListScrollHandle, Window, div, px, size, uniform_list,
+    App, AppContext, Context, Entity, FocusHandle, Focusable, InteractiveElement as _, IntoElement,
+    ParentElement, Pixels, Render, ScrollStrategy, Size, Styled, StyledText, Subscription,
+    UniformListScrollHandle, Window, div, px, size, uniform_list,
 };
 use gpui_component::{
     ActiveTheme as _, Selectable,
-    button::{Button, ButtonGroup},
+    button::{Button, ButtonGroup, ButtonVariants as _},
+    find::{FindBar, FindEvent, FindableExt as _},
     h_flex,
-    scroll::ScrollableElement,
+    scroll::Scrollbar,
     v_flex,
 };
 
@@ -19,12 +21,14 @@ pub struct ScrollbarStory {
     test_width: Pixels,
     size_mode: usize,
     scroll_handle: UniformListScrollHandle,
+    find_bar: Entity<FindBar>,
+    _subscriptions: Vec<Subscription>,
 }
 
 const ITEM_HEIGHT: Pixels = px(50.);
 
 impl ScrollbarStory {
-    fn new(_: &mut Window, cx: &mut Context<Self>) -> Self {
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
         let items: Rc<Vec<String>> = Rc::new((0..5000).map(|i| format!("Item {}", i)).collect());
         let test_width = px(3000.);
         let item_sizes = items
@@ -32,6 +36,21 @@ impl ScrollbarStory {
             .map(|_| size(test_width, ITEM_HEIGHT))
             .collect::<Vec<_>>();
 
+        let find_bar = cx.new(|cx| FindBar::new(window, cx));
+        find_bar.update(cx, |bar, cx| bar.set_texts(items.iter().cloned(), cx));
+        let _subscriptions = vec![cx.subscribe_in(
+            &find_bar,
+            window,
+            |this: &mut Self, _, event: &FindEvent, window, cx| match event {
+                FindEvent::Select(m) => {
+                    this.scroll_handle
+                        .scroll_to_item(m.ix, ScrollStrategy::Center);
+                    cx.notify();
+                }
+                FindEvent::Close => this.focus_handle.focus(window, cx),
+            },
+        )];
+
         Self {
             focus_handle: cx.focus_handle(),
             items,
@@ -39,6 +58,8 @@ impl ScrollbarStory {
             test_width,
             size_mode: 0,
             scroll_handle: UniformListScrollHandle::new(),
+            find_bar,
+            _subscriptions,
         }
     }
 
@@ -68,48 +89,63 @@ impl ScrollbarStory {
             .map(|_| size(self.test_width, ITEM_HEIGHT))
             .collect::<Vec<_>>()
             .into();
+        let items = self.items.clone();
+        self.find_bar
+            .update(cx, |bar, cx| bar.set_texts(items.iter().cloned(), cx));
         cx.notify();
     }
 
     fn render_buttons(&mut self, cx: &mut Context<Self>) -> impl IntoElement {
-        h_flex().gap_2().justify_between().child(
-            h_flex().gap_2().child(
-                ButtonGroup::new("test-cases")
+        h_flex()
+            .gap_2()
+            .justify_between()
+            .child(
+                h_flex().gap_2().child(
+                    ButtonGroup::new("test-cases")
+                        .outline()
+                        .compact()
+                        .child(
+                            Button::new("test-0")
+                                .label("Size 0")
+                                .selected(self.size_mode == 0),
+                        )
+                        .child(
+                            Button::new("test-1")
+                                .label("Size 1")
+                                .selected(self.size_mode == 1),
+                        )
+                        .child(
+                            Button::new("test-2")
+                                .label("Size 2")
+                                .selected(self.size_mode == 2),
+                        )
+                        .child(
+                            Button::new("test-3")
+                                .label("Size 3")
+                                .selected(self.size_mode == 3),
+                        )
+                        .on_click(cx.listener(|view, clicks: &Vec<usize>, _, cx| {
+                            if clicks.contains(&0) {
+                                view.change_test_cases(0, cx)
+                            } else if clicks.contains(&1) {
+                                view.change_test_cases(1, cx)
+                            } else if clicks.contains(&2) {
+                                view.change_test_cases(2, cx)
+                            } else if clicks.contains(&3) {
+                                view.change_test_cases(3, cx)
+                            }
+                        })),
+                ),
+            )
+            .child(
+                Button::new("find")
                     .outline()
                     .compact()
-                    .child(
-                        Button::new("test-0")
-                            .label("Size 0")
-                            .selected(self.size_mode == 0),
-                    )
-                    .child(
-                        Button::new("test-1")
-                            .label("Size 1")
-                            .selected(self.size_mode == 1),
-                    )
-                    .child(
-                        Button::new("test-2")
-                            .label("Size 2")
-                            .selected(self.size_mode == 2),
-                    )
-                    .child(
-                        Button::new("test-3")
-                            .label("Size 3")
-                            .selected(self.size_mode == 3),
-                    )
-                    .on_click(cx.listener(|view, clicks: &Vec<usize>, _, cx| {
-                        if clicks.contains(&0) {
-                            view.change_test_cases(0, cx)
-                        } else if clicks.contains(&1) {
-                            view.change_test_cases(1, cx)
-                        } else if clicks.contains(&2) {
-                            view.change_test_cases(2, cx)
-                        } else if clicks.contains(&3) {
-                            view.change_test_cases(3, cx)
-                        }
+                    .label("Find (Cmd+F)")
+                    .on_click(cx.listener(|view, _, window, cx| {
+                        view.find_bar.update(cx, |bar, cx| bar.open(window, cx));
                     })),
-            ),
-        )
+            )
     }
 }
 
@@ -139,10 +175,15 @@ impl Render for ScrollbarStory {
         _: &mut gpui::Window,
         cx: &mut gpui::Context<Self>,
     ) -> impl gpui::IntoElement {
+        let markers = self.find_bar.read(cx).scrollbar_markers(cx);
+
         v_flex()
+            .track_focus(&self.focus_handle)
+            .findable(&self.find_bar)
             .size_full()
             .gap_4()
             .child(self.render_buttons(cx))
+            .child(self.find_bar.clone())
             .child({
                 div()
                     .relative()
@@ -152,10 +193,12 @@ impl Render for ScrollbarStory {
                     .child(
                         uniform_list("list", self.items.len(), {
                             let items = self.items.clone();
+                            let find_bar = self.find_bar.clone();
                             move |visible_range, _, cx| {
                                 let mut elements = Vec::with_capacity(visible_range.len());
                                 for ix in visible_range {
                                     let item = &items[ix];
+                                    let highlights = find_bar.read(cx).highlights(ix, cx);
                                     elements.push(
                                         div()
                                             .h(ITEM_HEIGHT)
@@ -164,10 +207,10 @@ impl Render for ScrollbarStory {
                                             .justify_center()
                                             .text_sm()
                                             .child(
-                                                div()
-                                                    .p_2()
-                                                    .bg(cx.theme().secondary)
-                                                    .child(item.to_string()),
+                                                div().p_2().bg(cx.theme().secondary).child(
+                                                    StyledText::new(item.clone())
+                                                        .with_highlights(highlights),
+                                                ),
                                             ),
                                     );
                                 }
@@ -179,7 +222,15 @@ impl Render for ScrollbarStory {
                         .size_full()
                         .track_scroll(&self.scroll_handle),
                     )
-                    .vertical_scrollbar(&self.scroll_handle)
+                    .child(
+                        div()
+                            .absolute()
+                            .top_0()
+                            .left_0()
+                            .right_0()
+                            .bottom_0()
+                            .child(Scrollbar::vertical(&self.scroll_handle).markers(markers)),
+                    )
             })
     }
 }
@@ -4,18 +4,19 @@ use gpui::{
 };
 
 use gpui_component::{
-    ActiveTheme, Icon, IconName, Sizable, Size, Theme, ThemeMode,
+    ActiveTheme, Density, Icon, IconName, Sizable, Size, Theme, ThemeMode,
     button::Button,
     group_box::GroupBoxVariant,
     h_flex,
     label::Label,
     setting::{
         NumberFieldOptions, RenderOptions, SettingField, SettingFieldElement, SettingGroup,
-        SettingItem, SettingPage, Settings,
+        SettingItem, SettingPage, Settings, SettingsStore,
     },
     text::markdown,
     v_flex,
 };
+use serde::{Deserialize, Serialize};
 
 struct AppSettings {
     auto_switch_theme: bool,
@@ -55,8 +56,27 @@ impl AppSettings {
     }
 }
 
+/// The settings bound to a [`SettingsStore`], it can be persisted as JSON by [`SettingsStore::load`].
+#[derive(Clone, Serialize, Deserialize)]
+struct EditorSettings {
+    tab_size: f64,
+    format_on_save: bool,
+    command_palette_key: SharedString,
+}
+
+impl Default for EditorSettings {
+    fn default() -> Self {
+        Self {
+            tab_size: 4.0,
+            format_on_save: true,
+            command_palette_key: "cmd-shift-p".into(),
+        }
+    }
+}
+
 pub struct SettingsStory {
     focus_handle: FocusHandle,
+    editor_settings: Entity<SettingsStore<EditorSettings>>,
     group_variant: GroupBoxVariant,
     size: Size,
 }
@@ -114,9 +134,13 @@ impl SettingsStory {
 
     fn new(_: &mut Window, cx: &mut Context<Self>) -> Self {
         cx.set_global::<AppSettings>(AppSettings::default());
+        let editor_settings = cx.new(|_| SettingsStore::new(EditorSettings::default()));
+        cx.observe(&editor_settings, |_, _, cx| cx.notify())
+            .detach();
 
         Self {
             focus_handle: cx.focus_handle(),
+            editor_settings,
             group_variant: GroupBoxVariant::Outline,
             size: Size::default(),
         }
@@ -125,6 +149,7 @@ impl SettingsStory {
     fn setting_pages(&self, _: &mut Window, cx: &mut Context<Self>) -> Vec<SettingPage> {
         let view = cx.entity();
         let default_settings = AppSettings::default();
+        let default_editor_settings = EditorSettings::default();
         let resettable = AppSettings::global(cx).resettable;
 
         vec![
@@ -151,6 +176,35 @@ impl SettingsStory {
                             .default_value(false),
                         )
                         .description("Switch between light and dark themes."),
+                        SettingItem::new(
+                            "Compact Mode",
+                            SettingField::switch(
+                                |cx: &App| cx.theme().density.is_compact(),
+                                |val: bool, cx: &mut App| {
+                                    Theme::global_mut(cx).density = if val {
+                                        Density::Compact
+                                    } else {
+                                        Density::Comfortable
+                                    };
+                                    cx.refresh_windows();
+                                },
+                            )
+                            .default_value(false),
+                        )
+                        .description(
+                            "Use smaller paddings and sizes for Button, Input, Table and List.",
+                        ),
+                        SettingItem::new(
+                            "Reduce Motion",
+                            SettingField::switch(
+                                |cx: &App| cx.theme().prefers_reduced_motion,
+                                |val: bool, cx: &mut App| {
+                                    Theme::set_prefers_reduced_motion(val, cx)
+                                },
+                            )
+                            .default_value(false),
+                        )
+                        .description("Disable the animations, e.g. Dialog, Sheet and Spinner."),
                         SettingItem::new(
                             "Auto Switch Theme",
                             SettingField::checkbox(
@@ -332,6 +386,50 @@ impl SettingsStory {
                         ),
                     ]),
                 ]),
+            SettingPage::new("Editor")
+                .resettable(resettable)
+                .icon(Icon::new(IconName::SquareTerminal))
+                .groups(vec![SettingGroup::new().title("Editor").items(vec![
+                    SettingItem::new(
+                        "Tab Size",
+                        SettingField::slider(
+                            NumberFieldOptions {
+                                min: 1.0,
+                                max: 8.0,
+                                step: 1.0,
+                            },
+                            SettingsStore::getter(&self.editor_settings, |s| s.tab_size),
+                            SettingsStore::setter(&self.editor_settings, |s, val| {
+                                s.tab_size = val
+                            }),
+                        )
+                        .default_value(default_editor_settings.tab_size),
+                    )
+                    .description("The number of spaces for a tab."),
+                    SettingItem::new(
+                        "Format on Save",
+                        SettingField::switch(
+                            SettingsStore::getter(&self.editor_settings, |s| s.format_on_save),
+                            SettingsStore::setter(&self.editor_settings, |s, val| {
+                                s.format_on_save = val
+                            }),
+                        )
+                        .default_value(default_editor_settings.format_on_save),
+                    ),
+                    SettingItem::new(
+                        "Command Palette",
+                        SettingField::keybinding(
+                            SettingsStore::getter(&self.editor_settings, |s| {
+                                s.command_palette_key.clone()
+                            }),
+                            SettingsStore::setter(&self.editor_settings, |s, val| {
+                                s.command_palette_key = val
+                            }),
+                        )
+                        .default_value(default_editor_settings.command_palette_key),
+                    )
+                    .description("Click and press the keys to change the shortcut."),
+                ])]),
             SettingPage::new("Software Update")
                 .resettable(resettable)
                 .icon(Icon::new(IconName::Cpu))
@@ -0,0 +1,114 @@
+use gpui::{
+    App, AppContext, Context, Entity, FocusHandle, Focusable, InteractiveElement as _, IntoElement,
+    KeyBinding, ParentElement as _, Render, Styled as _, Window, actions,
+};
+use gpui_component::{
+    ActiveTheme as _,
+    button::Button,
+    shortcut_cheatsheet::{ShortcutCheatsheet, ShortcutCheatsheetState},
+    v_flex,
+};
+
+use crate::section;
+
+actions!(
+    shortcut_cheatsheet_story,
+    [ShowShortcuts, NewFile, OpenFile, SaveFile, ToggleSidebar]
+);
+
+const CONTEXT: &str = "ShortcutCheatsheetStory";
+
+pub fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("shift-/", ShowShortcuts, Some(CONTEXT)),
+        KeyBinding::new("secondary-n", NewFile, Some(CONTEXT)),
+        KeyBinding::new("secondary-o", OpenFile, Some(CONTEXT)),
+        KeyBinding::new("secondary-s", SaveFile, Some(CONTEXT)),
+        KeyBinding::new("secondary-k secondary-b", ToggleSidebar, Some(CONTEXT)),
+    ])
+}
+
+pub struct ShortcutCheatsheetStory {
+    focus_handle: FocusHandle,
+    cheatsheet: Entity<ShortcutCheatsheetState>,
+    inline_cheatsheet: Entity<ShortcutCheatsheetState>,
+}
+
+impl super::Story for ShortcutCheatsheetStory {
+    fn title() -> &'static str {
+        "ShortcutCheatsheet"
+    }
+
+    fn description() -> &'static str {
+        "A searchable cheatsheet of the registered keyboard shortcuts, grouped by context."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render> {
+        Self::view(window, cx)
+    }
+}
+
+impl ShortcutCheatsheetStory {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let cheatsheet = cx.new(|cx| ShortcutCheatsheetState::new(window, cx));
+        let inline_cheatsheet = cx.new(|cx| {
+            ShortcutCheatsheetState::new(window, cx).binding_filter(|binding| {
+                binding
+                    .action()
+                    .name()
+                    .starts_with("shortcut_cheatsheet_story::")
+            })
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            cheatsheet,
+            inline_cheatsheet,
+        }
+    }
+
+    fn show_shortcuts(&mut self, _: &ShowShortcuts, window: &mut Window, cx: &mut Context<Self>) {
+        self.cheatsheet
+            .update(cx, |cheatsheet, cx| cheatsheet.open(window, cx));
+    }
+}
+
+impl Focusable for ShortcutCheatsheetStory {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for ShortcutCheatsheetStory {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::show_shortcuts))
+            .gap_6()
+            .child(
+                section("Shortcut Cheatsheet")
+                    .sub_title("Press `?` to open.")
+                    .child(
+                        Button::new("open")
+                            .label("Show Shortcuts")
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                this.show_shortcuts(&ShowShortcuts, window, cx)
+                            })),
+                    ),
+            )
+            .child(
+                section("Inline").max_w_md().child(
+                    ShortcutCheatsheet::new(&self.inline_cheatsheet)
+                        .p_3()
+                        .border_1()
+                        .border_color(cx.theme().border)
+                        .rounded(cx.theme().radius),
+                ),
+            )
+    }
+}
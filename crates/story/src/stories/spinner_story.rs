@@ -1,16 +1,35 @@
+use std::{ops::Range, time::Duration};
+
 use gpui::{
-    App, AppContext, Context, Entity, Focusable, IntoElement, ParentElement, Render, Styled,
-    Window, px,
+    App, AppContext, Context, Entity, Focusable, InteractiveElement as _, IntoElement,
+    ParentElement, Render, ScrollHandle, StatefulInteractiveElement as _, Styled, Window,
+    prelude::FluentBuilder as _, px,
+};
+use gpui_component::{
+    ActiveTheme as _, IconName, Sizable,
+    infinite_scroll::{
+        InfiniteScrollExt as _, InfiniteScrollState, LoadDirection, LoadMoreIndicator,
+    },
+    spinner::Spinner,
+    typing_indicator::TypingIndicator,
+    v_flex,
 };
-use gpui_component::{ActiveTheme as _, IconName, Sizable, spinner::Spinner, v_flex};
 
 use crate::section;
 
 pub struct SpinnerStory {
     focus_handle: gpui::FocusHandle,
     value: f32,
+    messages: Range<usize>,
+    scroll_handle: ScrollHandle,
+    infinite_scroll: Entity<InfiniteScrollState>,
 }
 
+/// The total number of messages to load in the infinite scroll.
+const TOTAL_MESSAGES: usize = 100;
+/// The number of messages to load at once.
+const PAGE_SIZE: usize = 10;
+
 impl super::Story for SpinnerStory {
     fn title() -> &'static str {
         "Spinner"
@@ -31,9 +50,42 @@ impl SpinnerStory {
     }
 
     fn new(_: &mut Window, cx: &mut Context<Self>) -> Self {
+        let view = cx.entity().downgrade();
+        let infinite_scroll = cx.new(|_| {
+            InfiniteScrollState::new(move |direction, window, cx| {
+                let view = view.clone();
+                window.spawn(cx, async move |cx| {
+                    // Simulate network request, delay 1s to load the messages.
+                    cx.background_executor().timer(Duration::from_secs(1)).await;
+
+                    view.update(cx, |this, cx| this.load_messages(direction, cx))
+                        .unwrap_or(false)
+                })
+            })
+            .load_start(true)
+        });
+
         Self {
             focus_handle: cx.focus_handle(),
             value: 50.,
+            messages: 40..60,
+            scroll_handle: ScrollHandle::new(),
+            infinite_scroll,
+        }
+    }
+
+    /// Load a page of messages in the `direction`, returns true if there are more messages.
+    fn load_messages(&mut self, direction: LoadDirection, cx: &mut Context<Self>) -> bool {
+        cx.notify();
+        match direction {
+            LoadDirection::Start => {
+                self.messages.start = self.messages.start.saturating_sub(PAGE_SIZE);
+                self.messages.start > 0
+            }
+            LoadDirection::End => {
+                self.messages.end = (self.messages.end + PAGE_SIZE).min(TOTAL_MESSAGES);
+                self.messages.end < TOTAL_MESSAGES
+            }
         }
     }
 
@@ -50,6 +102,10 @@ impl Focusable for SpinnerStory {
 
 impl Render for SpinnerStory {
     fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let infinite_scroll = self.infinite_scroll.read(cx);
+        let loading_start = infinite_scroll.is_loading(LoadDirection::Start);
+        let loading_end = infinite_scroll.is_loading(LoadDirection::End);
+
         v_flex()
             .w_full()
             .gap_3()
@@ -80,5 +136,50 @@ impl Render for SpinnerStory {
                             .color(cx.theme().cyan),
                     ),
             )
+            .child(
+                section("Circular Spinner")
+                    .gap_x_2()
+                    .child(Spinner::new().circular(true).with_size(px(64.)))
+                    .child(Spinner::new().circular(true).large())
+                    .child(Spinner::new().circular(true))
+                    .child(Spinner::new().circular(true).small())
+                    .child(Spinner::new().circular(true).xsmall())
+                    .child(
+                        Spinner::new()
+                            .circular(true)
+                            .large()
+                            .color(cx.theme().green),
+                    ),
+            )
+            .child(
+                section("Typing Indicator")
+                    .gap_x_4()
+                    .child(TypingIndicator::new().label("Thinking…"))
+                    .child(TypingIndicator::new().small())
+                    .child(TypingIndicator::new().large().color(cx.theme().primary)),
+            )
+            .child(
+                section("Infinite Scroll").child(
+                    v_flex()
+                        .id("infinite-scroll")
+                        .w_full()
+                        .h_64()
+                        .overflow_y_scroll()
+                        .track_scroll(&self.scroll_handle)
+                        .infinite_scroll(&self.infinite_scroll, &self.scroll_handle)
+                        .when(loading_start, |this| this.child(LoadMoreIndicator::new()))
+                        .children(self.messages.clone().map(|ix| {
+                            v_flex()
+                                .px_3()
+                                .py_2()
+                                .border_b_1()
+                                .border_color(cx.theme().border)
+                                .child(format!("Message {}", ix))
+                        }))
+                        .when(loading_end, |this| {
+                            this.child(LoadMoreIndicator::new().label("Loading..."))
+                        }),
+                ),
+            )
     }
 }
@@ -0,0 +1,189 @@
+use std::time::Duration;
+
+use gpui::{
+    App, AppContext, Context, Entity, Focusable, IntoElement, ParentElement, Render, Styled,
+    Window, div,
+};
+use gpui_component::{
+    ActiveTheme as _, IconName, StyledExt as _, WindowExt as _,
+    button::Button,
+    notification::Notification,
+    notification_center::NotificationCenter,
+    status_bar::{StatusBar, StatusBarItem},
+    task_center::TaskCenter,
+    v_flex,
+};
+
+use crate::section;
+
+pub struct StatusBarStory {
+    focus_handle: gpui::FocusHandle,
+    indexing: bool,
+    exports: usize,
+}
+
+impl super::Story for StatusBarStory {
+    fn title() -> &'static str {
+        "StatusBar"
+    }
+
+    fn description() -> &'static str {
+        "A status bar with left, center and right segments, the items can open a popover."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render> {
+        Self::view(window, cx)
+    }
+}
+
+impl StatusBarStory {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(_: &mut Window, cx: &mut Context<Self>) -> Self {
+        let center = TaskCenter::global(cx);
+        cx.observe(&center, |_, _, cx| cx.notify()).detach();
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            indexing: true,
+            exports: 0,
+        }
+    }
+
+    fn start_export(&mut self, cx: &mut Context<Self>) {
+        self.exports += 1;
+        let center = TaskCenter::global(cx);
+        let id = center.update(cx, |center, cx| {
+            center.start(format!("Exporting #{}", self.exports), cx)
+        });
+
+        let task = cx.spawn({
+            let center = center.clone();
+            async move |_, cx| {
+                for step in 1..=20 {
+                    cx.background_executor()
+                        .timer(Duration::from_millis(300))
+                        .await;
+                    _ = center.update(cx, |center, cx| {
+                        center.set_progress(id, Some(step as f32 * 5.), cx);
+                        center.set_message(id, format!("page-{}.pdf", step), cx);
+                    });
+                }
+            }
+        });
+        center.update(cx, |center, cx| center.set_task(id, task, cx));
+    }
+}
+
+impl Focusable for StatusBarStory {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for StatusBarStory {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_6()
+            .child(
+                section("Status Bar").child(
+                    v_flex()
+                        .w_full()
+                        .h_48()
+                        .border_1()
+                        .border_color(cx.theme().border)
+                        .rounded(cx.theme().radius)
+                        .overflow_hidden()
+                        .child(div().flex_1())
+                        .child(
+                            StatusBar::new()
+                                .left(
+                                    StatusBarItem::new("branch")
+                                        .icon(IconName::Github)
+                                        .label("main")
+                                        .popover(|_, _, _| {
+                                            v_flex()
+                                                .gap_1()
+                                                .text_sm()
+                                                .child("main")
+                                                .child("develop")
+                                                .child("feature/status-bar")
+                                        }),
+                                )
+                                .left(
+                                    StatusBarItem::new("problems")
+                                        .icon(IconName::Info)
+                                        .label("0 problems")
+                                        .on_click(|_, window, cx| {
+                                            window.push_notification("No problems found.", cx)
+                                        }),
+                                )
+                                .center(StatusBarItem::new("status").label("Connected"))
+                                .right(
+                                    StatusBarItem::new("tasks")
+                                        .icon(IconName::CircleCheck)
+                                        .loading(self.indexing)
+                                        .label(if self.indexing {
+                                            "Indexing…"
+                                        } else {
+                                            "Ready"
+                                        })
+                                        .tooltip("Click to toggle the background task")
+                                        .on_click(cx.listener(|this, _, _, cx| {
+                                            this.indexing = !this.indexing;
+                                            cx.notify();
+                                        })),
+                                )
+                                .right(NotificationCenter::status_item(window, cx)),
+                        ),
+                ),
+            )
+            .child(
+                section("Task Center")
+                    .v_flex()
+                    .child(
+                        Button::new("start-export")
+                            .label("Start Export")
+                            .on_click(cx.listener(|this, _, _, cx| this.start_export(cx))),
+                    )
+                    .child(
+                        v_flex()
+                            .w_full()
+                            .h_32()
+                            .border_1()
+                            .border_color(cx.theme().border)
+                            .rounded(cx.theme().radius)
+                            .overflow_hidden()
+                            .child(div().flex_1())
+                            .child(StatusBar::new().right(TaskCenter::status_item(cx))),
+                    ),
+            )
+            .child(
+                section("Notification Center")
+                    .sub_title(
+                        "The pushed notifications are kept in the history, open it from the status bar.",
+                    )
+                    .child(
+                        Button::new("push-info")
+                            .label("Push Info")
+                            .on_click(|_, window, cx| {
+                                window.push_notification(
+                                    Notification::info("The project has been saved."),
+                                    cx,
+                                )
+                            }),
+                    )
+                    .child(Button::new("push-error").label("Push Error").on_click(
+                        |_, window, cx| {
+                            window.push_notification(
+                                Notification::error("Failed to connect to the server.")
+                                    .title("Connection"),
+                                cx,
+                            )
+                        },
+                    )),
+            )
+    }
+}
@@ -1,12 +1,13 @@
 use gpui::{
     App, AppContext, Context, Entity, FocusHandle, Focusable, IntoElement, ParentElement, Render,
-    Styled, Window,
+    ScrollHandle, SharedString, Styled, Window,
 };
 
 use gpui_component::{
     ActiveTheme as _, IconName, Selectable as _, Sizable, Size,
     button::{Button, ButtonGroup, ButtonVariants},
     checkbox::Checkbox,
+    drag_drop::{ReorderEvent, move_item, moved_index},
     h_flex,
     tab::{Tab, TabBar},
     v_flex,
@@ -19,6 +20,9 @@ pub struct TabsStory {
     active_tab_ix: usize,
     size: Size,
     menu: bool,
+    reorderable_tabs: Vec<SharedString>,
+    reorderable_tab_ix: usize,
+    reorderable_scroll_handle: ScrollHandle,
 }
 
 impl super::Story for TabsStory {
@@ -46,6 +50,14 @@ impl TabsStory {
             active_tab_ix: 0,
             size: Size::default(),
             menu: false,
+            reorderable_tabs: [
+                "Home", "Inbox", "Drafts", "Sent", "Archive", "Spam", "Trash", "Settings",
+            ]
+            .into_iter()
+            .map(SharedString::from)
+            .collect(),
+            reorderable_tab_ix: 0,
+            reorderable_scroll_handle: ScrollHandle::new(),
         }
     }
 
@@ -54,6 +66,12 @@ impl TabsStory {
         cx.notify();
     }
 
+    fn reorder_tab(&mut self, event: &ReorderEvent, _: &mut Window, cx: &mut Context<Self>) {
+        move_item(&mut self.reorderable_tabs, event.from, event.to);
+        self.reorderable_tab_ix = moved_index(self.reorderable_tab_ix, event.from, event.to);
+        cx.notify();
+    }
+
     fn set_size(&mut self, size: Size, _: &mut Window, cx: &mut Context<Self>) {
         self.size = size;
         cx.notify();
@@ -248,6 +266,24 @@ impl Render for TabsStory {
                         .children(vec!["Appearance", "Settings", "About", "License"]),
                 ),
             )
+            .child(
+                section("Reorderable Tabs (Drag to reorder)")
+                    .max_w_md()
+                    .child(
+                        TabBar::new("reorderable")
+                            .w_full()
+                            .with_size(self.size)
+                            .menu(self.menu)
+                            .track_scroll(&self.reorderable_scroll_handle)
+                            .selected_index(self.reorderable_tab_ix)
+                            .on_click(cx.listener(|this, ix: &usize, _, cx| {
+                                this.reorderable_tab_ix = *ix;
+                                cx.notify();
+                            }))
+                            .on_reorder(cx.listener(Self::reorder_tab))
+                            .children(self.reorderable_tabs.clone()),
+                    ),
+            )
             .child(
                 section("Segmented Tabs (With filling space)")
                     .max_w_md()
@@ -1,14 +1,20 @@
 use gpui::{
-    App, AppContext, Context, Entity, FocusHandle, Focusable, IntoElement, ParentElement, Render,
-    Styled, Window, px,
+    App, AppContext, Axis, Context, Entity, FocusHandle, Focusable, InteractiveElement,
+    IntoElement, ParentElement, Render, Styled, Window, div, px,
 };
 
-use gpui_component::{ColorName, Sizable, h_flex, indigo_50, indigo_500, tag::Tag, v_flex};
+use gpui_component::{
+    ColorName, FocusGroup, FocusGroupState, FocusableExt as _, Sizable, h_flex, indigo_50,
+    indigo_500, tag::Tag, v_flex,
+};
 
 use crate::section;
 
+const TAG_GROUP_ITEMS: [&str; 5] = ["Rust", "GPUI", "Desktop", "Cross-platform", "UI"];
+
 pub struct TagStory {
     focus_handle: FocusHandle,
+    tag_group: Entity<FocusGroupState>,
 }
 
 impl super::Story for TagStory {
@@ -29,6 +35,7 @@ impl TagStory {
     pub(crate) fn new(_: &mut Window, cx: &mut App) -> Self {
         Self {
             focus_handle: cx.focus_handle(),
+            tag_group: cx.new(|cx| FocusGroupState::new(TAG_GROUP_ITEMS.len(), cx)),
         }
     }
 
@@ -42,7 +49,7 @@ impl Focusable for TagStory {
     }
 }
 impl Render for TagStory {
-    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         v_flex()
             .w_full()
             .gap_3()
@@ -138,5 +145,33 @@ impl Render for TagStory {
                     ),
                 ),
             )
+            .child(
+                section("Focus Group (use arrow keys)").child(
+                    FocusGroup::new("tag-group", &self.tag_group)
+                        .axis(Axis::Horizontal)
+                        .wrap(true)
+                        .flex()
+                        .gap_2()
+                        .children(
+                            TAG_GROUP_ITEMS
+                                .iter()
+                                .enumerate()
+                                .filter_map(|(ix, label)| {
+                                    let handle = self.tag_group.read(cx).handle(ix)?;
+                                    let is_focused = handle.is_focused(window);
+
+                                    Some(
+                                        div()
+                                            .id(ix)
+                                            .relative()
+                                            .rounded_md()
+                                            .track_focus(&handle)
+                                            .focus_ring(is_focused, px(0.), window, cx)
+                                            .child(Tag::secondary().child(*label)),
+                                    )
+                                }),
+                        ),
+                ),
+            )
     }
 }
@@ -1,3 +1,5 @@
+use std::rc::Rc;
+
 use gpui::{
     App, AppContext as _, ClickEvent, Context, Entity, Focusable, IntoElement, ParentElement as _,
     Render, Styled, Window, px,
@@ -8,7 +10,7 @@ use gpui_component::{
     Sizable,
     button::Button,
     h_flex,
-    input::{Input, InputState},
+    input::{Input, InputState, WordList},
     v_flex,
 };
 
@@ -19,8 +21,14 @@ pub struct TextareaStory {
     textarea_auto_grow: Entity<InputState>,
     textarea_no_wrap: Entity<InputState>,
     textarea_auto_grow_no_wrap: Entity<InputState>,
+    textarea_spell_check: Entity<InputState>,
+    textarea_max_length: Entity<InputState>,
 }
 
+/// A tiny dictionary for the spell check example, a real app would load a full word list.
+const WORDS: &str = "a an and are as be by can check click for from has have i in is it \
+    misspelled of on or right see spell test text that the this to underline with word words you";
+
 impl super::Story for TextareaStory {
     fn title() -> &'static str {
         "Textarea"
@@ -108,11 +116,27 @@ impl TextareaStory {
                 .default_value("Hello 世界，this is GPUI component.")
         });
 
+        let textarea_spell_check = cx.new(|cx| {
+            InputState::new(window, cx)
+                .auto_grow(3, 5)
+                .spell_checker(Rc::new(WordList::new(WORDS.split_whitespace())))
+                .default_value("This is a tset of the spel check, right click a mispelled word.")
+        });
+
+        let textarea_max_length = cx.new(|cx| {
+            InputState::new(window, cx)
+                .auto_grow(3, 5)
+                .max_length(100)
+                .placeholder("Up to 100 characters, the pasted text is truncated.")
+        });
+
         Self {
             textarea,
             textarea_auto_grow,
             textarea_no_wrap,
             textarea_auto_grow_no_wrap,
+            textarea_spell_check,
+            textarea_max_length,
         }
     }
 
@@ -202,5 +226,15 @@ impl Render for TextareaStory {
                     .max_w_md()
                     .child(Input::new(&self.textarea_auto_grow_no_wrap)),
             )
+            .child(
+                section("Spell Check")
+                    .max_w_md()
+                    .child(Input::new(&self.textarea_spell_check)),
+            )
+            .child(
+                section("Max Length")
+                    .max_w_md()
+                    .child(Input::new(&self.textarea_max_length).show_count(true)),
+            )
     }
 }
@@ -0,0 +1,83 @@
+use gpui::{
+    App, AppContext, Context, Entity, FocusHandle, Focusable, IntoElement, ParentElement as _,
+    Render, Styled as _, Window,
+};
+use gpui_component::{
+    ActiveTheme as _,
+    button::{Button, ButtonVariants as _},
+    h_flex,
+    theme_editor::{ThemeEditor, ThemeEditorState},
+    v_flex,
+};
+
+use crate::section;
+
+pub struct ThemeEditorStory {
+    focus_handle: FocusHandle,
+    editor: Entity<ThemeEditorState>,
+}
+
+impl super::Story for ThemeEditorStory {
+    fn title() -> &'static str {
+        "ThemeEditor"
+    }
+
+    fn description() -> &'static str {
+        "Edit the theme colors live and export the result to a theme file."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render> {
+        Self::view(window, cx)
+    }
+}
+
+impl ThemeEditorStory {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            editor: cx.new(|cx| ThemeEditorState::new(window, cx)),
+        }
+    }
+}
+
+impl Focusable for ThemeEditorStory {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for ThemeEditorStory {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        h_flex()
+            .size_full()
+            .gap_6()
+            .items_start()
+            .child(
+                ThemeEditor::new(&self.editor)
+                    .w_96()
+                    .h_full()
+                    .p_3()
+                    .border_1()
+                    .border_color(cx.theme().border)
+                    .rounded(cx.theme().radius),
+            )
+            .child(
+                v_flex().flex_1().gap_6().child(
+                    section("Preview")
+                        .sub_title("The changes are applied to the whole app immediately.")
+                        .child(Button::new("primary").primary().label("Primary"))
+                        .child(Button::new("secondary").label("Secondary"))
+                        .child(Button::new("danger").danger().label("Danger"))
+                        .child(Button::new("reload").ghost().label("Reload").on_click(
+                            cx.listener(|this, _, _, cx| {
+                                this.editor.update(cx, |editor, cx| editor.reload(cx));
+                            }),
+                        )),
+                ),
+            )
+    }
+}
@@ -0,0 +1,162 @@
+use std::time::Duration;
+
+use chrono::NaiveTime;
+use gpui::{
+    App, AppContext, Context, Entity, Focusable, IntoElement, ParentElement as _, Render,
+    Styled as _, Subscription, Window, px,
+};
+use gpui_component::{
+    Sizable as _,
+    duration_input::{DurationInput, DurationInputEvent, DurationInputState},
+    time_picker::{HourFormat, TimePicker, TimePickerEvent, TimePickerState},
+    v_flex,
+};
+
+use crate::section;
+
+pub struct TimePickerStory {
+    time_picker: Entity<TimePickerState>,
+    time_picker_12h: Entity<TimePickerState>,
+    time_picker_seconds: Entity<TimePickerState>,
+    time_picker_step: Entity<TimePickerState>,
+    time_picker_value: Option<NaiveTime>,
+    duration_input: Entity<DurationInputState>,
+    duration_input_minutes: Entity<DurationInputState>,
+    duration_value: Duration,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl super::Story for TimePickerStory {
+    fn title() -> &'static str {
+        "TimePicker"
+    }
+
+    fn description() -> &'static str {
+        "A time picker to select a time of the day, and a duration input."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render> {
+        Self::view(window, cx)
+    }
+}
+
+impl TimePickerStory {
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let now = chrono::Local::now().naive_local().time();
+        let time_picker = cx.new(|cx| {
+            let mut picker = TimePickerState::new(window, cx);
+            picker.set_time(now, window, cx);
+            picker
+        });
+        let time_picker_12h = cx.new(|cx| {
+            let mut picker = TimePickerState::new(window, cx).hour_format(HourFormat::Hour12);
+            picker.set_time(now, window, cx);
+            picker
+        });
+        let time_picker_seconds = cx.new(|cx| TimePickerState::new(window, cx).seconds(true));
+        let time_picker_step = cx.new(|cx| TimePickerState::new(window, cx).minute_step(15));
+
+        let duration_input = cx.new(|cx| {
+            let mut input = DurationInputState::new(window, cx);
+            input.set_duration(Duration::from_secs(90 * 60), window, cx);
+            input
+        });
+        let duration_input_minutes = cx.new(|cx| {
+            DurationInputState::new(window, cx)
+                .seconds(false)
+                .max(Duration::from_secs(24 * 3600))
+        });
+
+        let _subscriptions = vec![
+            cx.subscribe(&time_picker, |this, _, ev, cx| match ev {
+                TimePickerEvent::Change(time) => {
+                    this.time_picker_value = *time;
+                    cx.notify();
+                }
+            }),
+            cx.subscribe(&duration_input, |this, _, ev, cx| match ev {
+                DurationInputEvent::Change(duration) => {
+                    this.duration_value = *duration;
+                    cx.notify();
+                }
+            }),
+        ];
+
+        Self {
+            time_picker,
+            time_picker_12h,
+            time_picker_seconds,
+            time_picker_step,
+            time_picker_value: Some(now),
+            duration_input,
+            duration_input_minutes,
+            duration_value: Duration::from_secs(90 * 60),
+            _subscriptions,
+        }
+    }
+}
+
+impl Focusable for TimePickerStory {
+    fn focus_handle(&self, cx: &gpui::App) -> gpui::FocusHandle {
+        self.time_picker.focus_handle(cx)
+    }
+}
+
+impl Render for TimePickerStory {
+    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_3()
+            .child(
+                section("Normal")
+                    .max_w_128()
+                    .child(TimePicker::new(&self.time_picker).cleanable(true)),
+            )
+            .child(
+                section("12-hour")
+                    .max_w_128()
+                    .child(TimePicker::new(&self.time_picker_12h).w(px(180.))),
+            )
+            .child(
+                section("With seconds").max_w_128().child(
+                    TimePicker::new(&self.time_picker_seconds)
+                        .large()
+                        .placeholder("Select time with seconds"),
+                ),
+            )
+            .child(
+                section("15 minutes step")
+                    .max_w_128()
+                    .child(TimePicker::new(&self.time_picker_step).small()),
+            )
+            .child(
+                section("Time Picker Value").max_w_128().child(
+                    format!(
+                        "Time picker value: {:?}",
+                        self.time_picker_value
+                            .map(|t| t.format("%H:%M").to_string())
+                    )
+                    .into_element(),
+                ),
+            )
+            .child(
+                section("Duration Input").max_w_128().child(
+                    v_flex()
+                        .w_full()
+                        .gap_2()
+                        .child(DurationInput::new(&self.duration_input).w(px(180.)))
+                        .child(format!("Duration: {}s", self.duration_value.as_secs())),
+                ),
+            )
+            .child(
+                section("Duration without seconds").max_w_128().child(
+                    DurationInput::new(&self.duration_input_minutes)
+                        .w(px(120.))
+                        .small(),
+                ),
+            )
+    }
+}
@@ -4,9 +4,10 @@ use gpui::{
 };
 
 use gpui_component::{
-    IconName, Sizable, StyledExt,
-    button::{Toggle, ToggleGroup, ToggleVariants},
-    v_flex,
+    IconName, Selectable as _, Sizable, StyledExt,
+    badge::Badge,
+    button::{Button, ButtonVariants as _, Toggle, ToggleGroup, ToggleVariants},
+    h_flex, v_flex,
 };
 
 use crate::section;
@@ -15,6 +16,8 @@ pub struct ToggleStory {
     focus_handle: FocusHandle,
     single_toggle: usize,
     checked: Vec<bool>,
+    show_thinking: bool,
+    notifications: bool,
 }
 
 impl ToggleStory {
@@ -23,6 +26,8 @@ impl ToggleStory {
             focus_handle: cx.focus_handle(),
             single_toggle: 0,
             checked: vec![false; 20],
+            show_thinking: true,
+            notifications: false,
         })
     }
 }
@@ -95,6 +100,46 @@ impl Render for ToggleStory {
                             })),
                     ),
             )
+            .child(
+                section("Icon Toggle with Badge").child(
+                    h_flex()
+                        .gap_4()
+                        .child(
+                            Toggle::new("show-thinking")
+                                .icon(IconName::Bot)
+                                .tooltip("Show Thinking")
+                                .checked(self.show_thinking)
+                                .on_click(cx.listener(|view, checked, _, cx| {
+                                    view.show_thinking = *checked;
+                                    cx.notify();
+                                })),
+                        )
+                        .child(
+                            Toggle::new("notifications")
+                                .outline()
+                                .icon(IconName::Bell)
+                                .badge(Badge::new().dot())
+                                .checked(self.notifications)
+                                .on_click(cx.listener(|view, checked, _, cx| {
+                                    view.notifications = *checked;
+                                    cx.notify();
+                                })),
+                        )
+                        .child(
+                            Button::new("inbox")
+                                .ghost()
+                                .icon(IconName::Inbox)
+                                .badge(Badge::new().count(12)),
+                        )
+                        .child(
+                            Button::new("inbox-selected")
+                                .ghost()
+                                .icon(IconName::Inbox)
+                                .selected(true)
+                                .badge(Badge::new().count(120)),
+                        ),
+                ),
+            )
             .child(
                 section("Toggle Group with Ghost Style")
                     .v_flex()
@@ -1,10 +1,12 @@
+use std::time::Duration;
+
 use gpui::{
     App, AppContext, Context, Entity, Focusable, InteractiveElement, KeyBinding, ParentElement,
     Render, StatefulInteractiveElement as _, Styled, Window, actions, div,
 };
 
 use gpui_component::{
-    IconName,
+    ActiveTheme as _, IconName, Placement,
     button::{Button, ButtonVariant, ButtonVariants, Toggle},
     checkbox::Checkbox,
     clipboard::Clipboard,
@@ -12,7 +14,7 @@ use gpui_component::{
     h_flex,
     radio::Radio,
     switch::Switch,
-    tooltip::Tooltip,
+    tooltip::{Tooltip, TooltipExt as _, TooltipOptions},
     v_flex,
 };
 
@@ -26,6 +28,7 @@ pub fn init(cx: &mut App) {
 
 pub struct TooltipStory {
     focus_handle: gpui::FocusHandle,
+    trigger_focus_handle: gpui::FocusHandle,
 }
 
 impl TooltipStory {
@@ -36,6 +39,7 @@ impl TooltipStory {
     fn new(_: &mut Window, cx: &mut Context<Self>) -> Self {
         Self {
             focus_handle: cx.focus_handle(),
+            trigger_focus_handle: cx.focus_handle().tab_stop(true),
         }
     }
 }
@@ -68,7 +72,7 @@ impl Render for TooltipStory {
     fn render(
         &mut self,
         _: &mut gpui::Window,
-        _cx: &mut gpui::Context<Self>,
+        cx: &mut gpui::Context<Self>,
     ) -> impl gpui::IntoElement {
         v_flex()
             .w_full()
@@ -143,5 +147,70 @@ impl Render for TooltipStory {
                     },
                 )),
             )
+            .child(
+                section("Placement")
+                    .sub_title("Flip to the opposite side if there is not enough space.")
+                    .children(
+                        [
+                            Placement::Top,
+                            Placement::Bottom,
+                            Placement::Left,
+                            Placement::Right,
+                        ]
+                        .into_iter()
+                        .map(|placement| {
+                            div()
+                                .id(format!("placement-{}", placement))
+                                .px_3()
+                                .py_1()
+                                .border_1()
+                                .border_color(cx.theme().border)
+                                .rounded(cx.theme().radius)
+                                .child(placement.to_string())
+                                .tooltip_with(
+                                    TooltipOptions::new().placement(placement),
+                                    move |window, cx| {
+                                        Tooltip::new(format!("Tooltip on {}", placement))
+                                            .build(window, cx)
+                                    },
+                                )
+                        }),
+                    ),
+            )
+            .child(
+                section("Managed Tooltip")
+                    .child(
+                        div()
+                            .id("tooltip-text")
+                            .child("Hover me")
+                            .tooltip_text("Text tooltip"),
+                    )
+                    .child(
+                        div()
+                            .id("tooltip-element")
+                            .child("Rich content")
+                            .tooltip_element(|_, cx| {
+                                h_flex().gap_1().child(IconName::Info).child(
+                                    div()
+                                        .child("Muted text")
+                                        .text_color(cx.theme().muted_foreground),
+                                )
+                            }),
+                    )
+                    .child(
+                        div()
+                            .id("tooltip-focus")
+                            .track_focus(&self.trigger_focus_handle)
+                            .child("Focus me with Tab")
+                            .tooltip_with(
+                                TooltipOptions::new()
+                                    .delay(Duration::from_millis(100))
+                                    .track_focus(&self.trigger_focus_handle),
+                                |window, cx| {
+                                    Tooltip::new("Shown on keyboard focus").build(window, cx)
+                                },
+                            ),
+                    ),
+            )
     }
 }
@@ -87,7 +87,7 @@ impl TreeStory {
     }
 
     fn new(_: &mut Window, cx: &mut Context<Self>) -> Self {
-        let tree_state = cx.new(|cx| TreeState::new(cx));
+        let tree_state = cx.new(|cx| TreeState::new(cx).reorderable(true));
 
         Self::load_files(tree_state.clone(), PathBuf::from("./"), cx);
 
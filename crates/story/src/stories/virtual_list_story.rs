@@ -1,15 +1,18 @@
-use std::{ops::Range, rc::Rc};
+use std::{ops::Range, rc::Rc, time::Duration};
 
 use gpui::{
     App, AppContext, Context, Div, Entity, FocusHandle, Focusable, InteractiveElement, IntoElement,
-    ParentElement, Pixels, Render, ScrollStrategy, Size, Styled, Window, div, px, size,
+    MouseButton, MouseDownEvent, ParentElement, Pixels, Render, ScrollStrategy, Size, Styled,
+    Subscription, Window, div, prelude::FluentBuilder as _, px, size,
 };
 use gpui_component::{
-    ActiveTheme as _, Selectable, Sizable, VirtualListScrollHandle,
+    ActiveTheme as _, Selectable, Sizable, StyledExt as _, VirtualListScrollHandle,
+    animation::Transition,
     button::{Button, ButtonGroup},
     divider::Divider,
     h_flex,
     scroll::{ScrollableElement, ScrollbarAxis},
+    selection::{SelectionEvent, SelectionExt as _, SelectionMode, SelectionState},
     v_flex, v_virtual_list,
 };
 
@@ -22,15 +25,33 @@ pub struct VirtualListStory {
     axis: ScrollbarAxis,
     size_mode: usize,
     visible_range: Range<usize>,
+    sticky_headers: bool,
+    selection: Entity<SelectionState>,
+    _subscriptions: Vec<Subscription>,
 }
 
 const ITEM_SIZE: Size<Pixels> = size(px(100.), px(30.));
+const GROUP_SIZE: usize = 20;
 
 impl VirtualListStory {
-    fn new(_: &mut Window, cx: &mut Context<Self>) -> Self {
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
         let items = (0..5000).map(|i| format!("Item {}", i)).collect::<Vec<_>>();
         let item_sizes = items.iter().map(|_| ITEM_SIZE).collect::<Vec<_>>();
 
+        let selection = cx.new(|_| SelectionState::new(SelectionMode::Multiple, items.len()));
+        let _subscriptions = vec![cx.subscribe_in(
+            &selection,
+            window,
+            |this: &mut Self, selection, event: &SelectionEvent, _, cx| {
+                if let SelectionEvent::Change = event
+                    && let Some(ix) = selection.read(cx).active_index()
+                {
+                    this.scroll_handle.scroll_to_item(ix, ScrollStrategy::Top);
+                }
+                cx.notify();
+            },
+        )];
+
         Self {
             focus_handle: cx.focus_handle(),
             scroll_handle: VirtualListScrollHandle::new(),
@@ -40,6 +61,9 @@ impl VirtualListStory {
             axis: ScrollbarAxis::Both,
             size_mode: 0,
             visible_range: (0..0),
+            sticky_headers: false,
+            selection,
+            _subscriptions,
         }
     }
 
@@ -66,9 +90,35 @@ impl VirtualListStory {
         }
 
         self.item_sizes = Rc::new(self.items.iter().map(|_| ITEM_SIZE).collect());
+        let len = self.items.len();
+        self.selection
+            .update(cx, |selection, cx| selection.set_len(len, cx));
+        cx.notify();
+    }
+
+    fn append_item(&mut self, cx: &mut Context<Self>) {
+        self.items.push(format!("Item {}", self.items.len()));
+        self.item_sizes = Rc::new(self.items.iter().map(|_| ITEM_SIZE).collect());
+        self.scroll_handle.scroll_to_bottom();
+        let len = self.items.len();
+        self.selection
+            .update(cx, |selection, cx| selection.set_len(len, cx));
         cx.notify();
     }
 
+    fn select_row(
+        &mut self,
+        ix: usize,
+        event: &MouseDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.focus_handle.focus(window, cx);
+        self.selection.update(cx, |selection, cx| {
+            selection.click(ix, &event.modifiers, cx);
+        });
+    }
+
     pub fn change_axis(&mut self, axis: ScrollbarAxis, cx: &mut Context<Self>) {
         self.axis = axis;
         cx.notify();
@@ -149,9 +199,25 @@ impl VirtualListStory {
                                             view.change_axis(ScrollbarAxis::Horizontal, cx)
                                         }
                                     })),
+                            )
+                            .child(Divider::vertical().px_2())
+                            .child(
+                                Button::new("sticky-headers")
+                                    .outline()
+                                    .compact()
+                                    .label("Sticky Headers")
+                                    .selected(self.sticky_headers)
+                                    .on_click(cx.listener(|view, _, _, cx| {
+                                        view.sticky_headers = !view.sticky_headers;
+                                        cx.notify();
+                                    })),
                             ),
                     )
-                    .child(format!("visible_range: {:?}", self.visible_range)),
+                    .child(format!(
+                        "visible_range: {:?}, selected: {}",
+                        self.visible_range,
+                        self.selection.read(cx).selected_count()
+                    )),
             )
             .child(
                 h_flex()
@@ -196,6 +262,15 @@ impl VirtualListStory {
                                 this.scroll_handle.scroll_to_bottom();
                                 cx.notify();
                             })),
+                    )
+                    .child(
+                        Button::new("append-item")
+                            .small()
+                            .outline()
+                            .label("Append Item")
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.append_item(cx);
+                            })),
                     ),
             )
     }
@@ -229,6 +304,12 @@ impl Render for VirtualListStory {
         cx: &mut gpui::Context<Self>,
     ) -> impl gpui::IntoElement {
         let columns_count = self.columns_count;
+        let sticky_headers = self.sticky_headers;
+        let header_ixs: Rc<Vec<usize>> = if sticky_headers {
+            Rc::new((0..self.items.len()).step_by(GROUP_SIZE).collect())
+        } else {
+            Rc::new(vec![])
+        };
 
         fn render_item(cx: &App) -> Div {
             div()
@@ -243,6 +324,8 @@ impl Render for VirtualListStory {
         }
 
         v_flex()
+            .track_focus(&self.focus_handle)
+            .selection(&self.selection)
             .size_full()
             .gap_4()
             .child(self.render_buttons(cx))
@@ -263,19 +346,58 @@ impl Render for VirtualListStory {
 
                                         visible_range
                                             .map(|ix| {
-                                                h_flex().gap_1().items_center().children(
-                                                    (0..columns_count).map(|i| {
-                                                        render_item(cx).child(if i == 0 {
-                                                            format!("row: {}", ix)
-                                                        } else {
-                                                            format!("{}", i)
-                                                        })
-                                                    }),
-                                                )
+                                                if sticky_headers && ix % GROUP_SIZE == 0 {
+                                                    return h_flex()
+                                                        .size_full()
+                                                        .px_2()
+                                                        .items_center()
+                                                        .text_sm()
+                                                        .font_semibold()
+                                                        .bg(cx.theme().background)
+                                                        .border_b_1()
+                                                        .border_color(cx.theme().border)
+                                                        .child(format!(
+                                                            "Group {}",
+                                                            ix / GROUP_SIZE
+                                                        ));
+                                                }
+
+                                                let selected =
+                                                    story.selection.read(cx).is_selected(ix);
+                                                h_flex()
+                                                    .gap_1()
+                                                    .items_center()
+                                                    .on_mouse_down(
+                                                        MouseButton::Left,
+                                                        cx.listener(move |this, e, window, cx| {
+                                                            this.select_row(ix, e, window, cx)
+                                                        }),
+                                                    )
+                                                    .children((0..columns_count).map(|i| {
+                                                        render_item(cx)
+                                                            .when(i == 0 && selected, |this| {
+                                                                this.bg(cx.theme().primary)
+                                                                    .text_color(
+                                                                        cx.theme()
+                                                                            .primary_foreground,
+                                                                    )
+                                                            })
+                                                            .child(if i == 0 {
+                                                                format!("row: {}", ix)
+                                                            } else {
+                                                                format!("{}", i)
+                                                            })
+                                                    }))
                                             })
                                             .collect()
                                     },
                                 )
+                                .sticky_headers(header_ixs)
+                                .appear_transition(
+                                    Transition::new(Duration::from_millis(200))
+                                        .fade(0., 1.)
+                                        .slide_y(px(8.), px(0.)),
+                                )
                                 .track_scroll(&self.scroll_handle)
                                 .p_4()
                                 .border_1()
@@ -14,6 +14,7 @@ const STATE_FILE: &str = "target/state.json";
 struct State {
     theme: SharedString,
     scrollbar_show: Option<ScrollbarShow>,
+    ui_scale: Option<f32>,
 }
 
 impl Default for State {
@@ -21,6 +22,7 @@ impl Default for State {
         Self {
             theme: "Default Light".into(),
             scrollbar_show: None,
+            ui_scale: None,
         }
     }
 }
@@ -58,6 +60,14 @@ pub fn init(cx: &mut App) {
             {
                 Theme::global_mut(cx).apply_config(&theme);
             }
+
+            // Tweak the theme live, e.g.: `GPUI_THEME_FILE=themes/brand.json cargo run`.
+            #[cfg(debug_assertions)]
+            if let Ok(path) = std::env::var("GPUI_THEME_FILE") {
+                if let Err(err) = Theme::watch_file(path, cx) {
+                    tracing::error!("Failed to watch theme file: {}", err);
+                }
+            }
         })
     {
         tracing::error!("Failed to watch themes directory: {}", err);
@@ -66,6 +76,9 @@ pub fn init(cx: &mut App) {
     if let Some(scrollbar_show) = state.scrollbar_show {
         Theme::global_mut(cx).scrollbar_show = scrollbar_show;
     }
+    if let Some(ui_scale) = state.ui_scale {
+        Theme::set_ui_scale(ui_scale, cx);
+    }
     cx.refresh_windows();
 
     #[cfg(not(target_family = "wasm"))]
@@ -73,6 +86,7 @@ pub fn init(cx: &mut App) {
         let state = State {
             theme: cx.theme().theme_name().clone(),
             scrollbar_show: Some(cx.theme().scrollbar_show),
+            ui_scale: Some(cx.theme().ui_scale),
         };
 
         if let Ok(json) = serde_json::to_string_pretty(&state) {
@@ -85,7 +99,7 @@ pub fn init(cx: &mut App) {
     cx.on_action(|switch: &SwitchTheme, cx| {
         let theme_name = switch.0.clone();
         if let Some(theme_config) = ThemeRegistry::global(cx).themes().get(&theme_name).cloned() {
-            Theme::global_mut(cx).apply_config(&theme_config);
+            Theme::set(theme_config, None, cx);
         }
         cx.refresh_windows();
     });
@@ -0,0 +1,237 @@
+//! An image element to play the animated GIF and WebP images, e.g.: the attachments in a chat.
+//!
+//! The frames are decoded by the image loader of GPUI, the [`AnimatedImage`] schedules the
+//! frames by their delays, and pauses when the image is scrolled out of the view.
+use std::sync::Arc;
+
+use gpui::{
+    App, Corners, ElementId, ImageSource, ImgResourceLoader, IntoElement, ObjectFit,
+    ParentElement as _, Pixels, RenderImage, RenderOnce, Size, StyleRefinement, Styled, Window,
+    canvas, div, prelude::FluentBuilder as _, px, size,
+};
+use instant::{Duration, Instant};
+
+use crate::{StyledExt as _, profiler::Profiler};
+
+/// The frames with a shorter delay are played at the [`DEFAULT_FRAME_DELAY`], the same as the
+/// browsers.
+const MIN_FRAME_DELAY: Duration = Duration::from_millis(20);
+/// The delay to use for the frames shorter than [`MIN_FRAME_DELAY`], or without a valid delay.
+const DEFAULT_FRAME_DELAY: Duration = Duration::from_millis(100);
+
+#[derive(Default)]
+struct AnimatedImageState {
+    frame_ix: usize,
+    /// The time elapsed in the current frame.
+    elapsed: Duration,
+    /// The time of the last painted frame, `None` if paused.
+    last_tick: Option<Instant>,
+}
+
+/// An image element to play the animated GIF and WebP images.
+///
+/// Unlike the `img` element, the animation can be paused, and it is paused when the image
+/// is out of the visible area of the scroll container, to save the CPU in a long list.
+///
+/// ```ignore
+/// AnimatedImage::new(("attachment", ix), "https://example.com/image.gif")
+///     .w_64()
+///     .playing(self.playing)
+/// ```
+#[derive(IntoElement)]
+pub struct AnimatedImage {
+    id: ElementId,
+    source: ImageSource,
+    style: StyleRefinement,
+    object_fit: ObjectFit,
+    playing: bool,
+    pause_offscreen: bool,
+}
+
+impl AnimatedImage {
+    /// Create a new animated image, the `id` is used to keep the current frame.
+    pub fn new(id: impl Into<ElementId>, source: impl Into<ImageSource>) -> Self {
+        Self {
+            id: id.into(),
+            source: source.into(),
+            style: StyleRefinement::default(),
+            object_fit: ObjectFit::Contain,
+            playing: true,
+            pause_offscreen: true,
+        }
+    }
+
+    /// Set to play the animation, default: true.
+    ///
+    /// When paused, the current frame is kept.
+    pub fn playing(mut self, playing: bool) -> Self {
+        self.playing = playing;
+        self
+    }
+
+    /// Set to pause the animation when the image is not visible, default: true.
+    pub fn pause_offscreen(mut self, pause_offscreen: bool) -> Self {
+        self.pause_offscreen = pause_offscreen;
+        self
+    }
+
+    /// Set the object fit of the image, default: [`ObjectFit::Contain`].
+    pub fn object_fit(mut self, object_fit: ObjectFit) -> Self {
+        self.object_fit = object_fit;
+        self
+    }
+}
+
+impl Styled for AnimatedImage {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+/// Returns the loaded image of the `source`, or `None` if it is loading or failed to load.
+fn load_image(source: &ImageSource, window: &mut Window, cx: &mut App) -> Option<Arc<RenderImage>> {
+    match source {
+        ImageSource::Render(image) => Some(image.clone()),
+        ImageSource::Resource(resource) => {
+            window.use_asset::<ImgResourceLoader>(resource, cx)?.ok()
+        }
+        _ => None,
+    }
+}
+
+/// Returns the delay of the frame in `(numerator, denominator)` milliseconds.
+fn frame_delay((numer, denom): (u32, u32)) -> Duration {
+    if denom == 0 {
+        return DEFAULT_FRAME_DELAY;
+    }
+
+    let delay = Duration::from_micros(numer as u64 * 1000 / denom as u64);
+    if delay < MIN_FRAME_DELAY {
+        DEFAULT_FRAME_DELAY
+    } else {
+        delay
+    }
+}
+
+/// Advance the frames by the `elapsed` time, returns the new frame index and the time elapsed
+/// in that frame.
+fn advance_frame(
+    mut frame_ix: usize,
+    mut elapsed: Duration,
+    frame_count: usize,
+    delay: impl Fn(usize) -> Duration,
+) -> (usize, Duration) {
+    if frame_count <= 1 {
+        return (0, Duration::ZERO);
+    }
+
+    // Skip the whole loops, e.g.: after the window is inactive for a long time.
+    let total: Duration = (0..frame_count).map(&delay).sum();
+    if !total.is_zero() && elapsed >= total {
+        elapsed = Duration::from_nanos((elapsed.as_nanos() % total.as_nanos()) as u64);
+    }
+
+    loop {
+        let delay = delay(frame_ix);
+        if elapsed < delay || delay.is_zero() {
+            return (frame_ix, elapsed);
+        }
+        elapsed -= delay;
+        frame_ix = (frame_ix + 1) % frame_count;
+    }
+}
+
+impl RenderOnce for AnimatedImage {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let state = window.use_keyed_state(self.id, cx, |_, _| AnimatedImageState::default());
+        let image = load_image(&self.source, window, cx);
+        let natural_size: Option<Size<Pixels>> = image.as_ref().map(|image| {
+            let image_size = image.size(0);
+            size(
+                px(image_size.width.0 as f32),
+                px(image_size.height.0 as f32),
+            )
+        });
+        let object_fit = self.object_fit;
+        let playing = self.playing;
+        let pause_offscreen = self.pause_offscreen;
+
+        div()
+            .relative()
+            .when_some(natural_size, |this, natural_size| {
+                this.w(natural_size.width).h(natural_size.height)
+            })
+            .refine_style(&self.style)
+            .when_some(image, |this, image| {
+                this.child(
+                    canvas(
+                        |_, _, _| {},
+                        move |bounds, _, window, cx| {
+                            let frame_count = image.frame_count();
+                            let frame_ix =
+                                state.read(cx).frame_ix.min(frame_count.saturating_sub(1));
+                            let image_bounds = object_fit.get_bounds(bounds, image.size(frame_ix));
+                            _ = window.paint_image(
+                                image_bounds,
+                                Corners::default(),
+                                image.clone(),
+                                frame_ix,
+                                false,
+                            );
+                            Profiler::count("animated_image.frames", 1, window, cx);
+
+                            let visible = window.content_mask().bounds.intersects(&bounds);
+                            if !playing || frame_count <= 1 || (pause_offscreen && !visible) {
+                                state.update(cx, |state, _| state.last_tick = None);
+                                return;
+                            }
+
+                            let now = Instant::now();
+                            state.update(cx, |state, _| {
+                                let elapsed = state.elapsed
+                                    + state.last_tick.map_or(Duration::ZERO, |last| now - last);
+                                (state.frame_ix, state.elapsed) =
+                                    advance_frame(frame_ix, elapsed, frame_count, |ix| {
+                                        frame_delay(image.delay(ix).numer_denom_ms())
+                                    });
+                                state.last_tick = Some(now);
+                            });
+                            window.request_animation_frame();
+                        },
+                    )
+                    .absolute()
+                    .size_full(),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use instant::Duration;
+
+    use super::{DEFAULT_FRAME_DELAY, advance_frame, frame_delay};
+
+    #[test]
+    fn test_frame_delay() {
+        assert_eq!(frame_delay((100, 1)), Duration::from_millis(100));
+        assert_eq!(frame_delay((50, 1)), Duration::from_millis(50));
+        assert_eq!(frame_delay((0, 1)), DEFAULT_FRAME_DELAY);
+        assert_eq!(frame_delay((10, 1)), DEFAULT_FRAME_DELAY);
+        assert_eq!(frame_delay((1, 0)), DEFAULT_FRAME_DELAY);
+    }
+
+    #[test]
+    fn test_advance_frame() {
+        let delay = |ix: usize| Duration::from_millis(if ix == 1 { 200 } else { 100 });
+        let ms = Duration::from_millis;
+
+        assert_eq!(advance_frame(0, ms(50), 3, delay), (0, ms(50)));
+        assert_eq!(advance_frame(0, ms(100), 3, delay), (1, ms(0)));
+        assert_eq!(advance_frame(0, ms(350), 3, delay), (2, ms(50)));
+        assert_eq!(advance_frame(2, ms(120), 3, delay), (0, ms(20)));
+        // Skip the whole loops.
+        assert_eq!(advance_frame(0, ms(4000 + 150), 3, delay), (1, ms(50)));
+        assert_eq!(advance_frame(5, ms(1000), 1, delay), (0, ms(0)));
+    }
+}
@@ -1,11 +1,14 @@
 use std::{rc::Rc, time::Duration};
 
 use gpui::{
-    Animation, AnimationExt, ElementId, IntoElement, Pixels, Point, Styled, point,
-    prelude::FluentBuilder, px,
+    Animation, AnimationExt, App, ElementId, Global, IntoElement, Pixels, Point, Styled, Window,
+    point, prelude::FluentBuilder, px,
 };
+use instant::Instant;
 use smallvec::SmallVec;
 
+use crate::Theme;
+
 /// A cubic bezier function like CSS `cubic-bezier`.
 ///
 /// Builder:
@@ -26,6 +29,106 @@ pub fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> impl Fn(f32) -> f32 {
     }
 }
 
+// ── Test mode ───────────────────────────────────────────────────────────────
+
+/// The duration used for animations in test mode.
+///
+/// GPUI divides the elapsed time by the animation duration, so a zero duration
+/// is not safe, use the smallest one instead to finish in the first frame.
+pub(crate) const INSTANT: Duration = Duration::from_nanos(1);
+
+/// A global toggle to make the UI deterministic in interaction tests.
+///
+/// When enabled:
+///
+/// - All animations complete instantly, repeating animations (e.g. [`Spinner`], [`Skeleton`])
+///   are rendered in their static state.
+/// - Debounce and delay timers (e.g. tooltip show delay, hover card delays, list search)
+///   act synchronously, they are resolved in the next `run_until_parked`.
+/// - Long running timers (e.g. notification auto hide) are kept, they are driven by the
+///   test executor clock, use `cx.executor().advance_clock(duration)` to control them.
+///
+/// ```ignore
+/// #[gpui::test]
+/// fn test_dialog(cx: &mut TestAppContext) {
+///     cx.update(|cx| {
+///         gpui_component::init(cx);
+///         UiTestMode::set_enabled(true, cx);
+///     });
+/// }
+/// ```
+///
+/// [`Spinner`]: crate::spinner::Spinner
+/// [`Skeleton`]: crate::skeleton::Skeleton
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UiTestMode {
+    enabled: bool,
+}
+
+impl Global for UiTestMode {}
+
+impl UiTestMode {
+    /// Enable or disable the test mode.
+    pub fn set_enabled(enabled: bool, cx: &mut App) {
+        cx.set_global(Self { enabled });
+    }
+
+    /// Returns true if the test mode is enabled.
+    pub fn is_enabled(cx: &App) -> bool {
+        cx.try_global::<Self>().is_some_and(|mode| mode.enabled)
+    }
+
+    /// Returns the duration to use for an [`Animation`].
+    ///
+    /// In test mode the animation completes in the first frame.
+    pub fn animation_duration(duration: Duration, cx: &App) -> Duration {
+        if Self::is_enabled(cx) {
+            INSTANT
+        } else {
+            duration
+        }
+    }
+
+    /// Returns the delay to use for a debounce, throttle or delay timer.
+    ///
+    /// In test mode the delay is zero, so the timer is resolved immediately.
+    pub fn delay(delay: Duration, cx: &App) -> Duration {
+        if Self::is_enabled(cx) {
+            Duration::ZERO
+        } else {
+            delay
+        }
+    }
+}
+
+// ── Reduced motion ──────────────────────────────────────────────────────────
+
+/// Returns true if the animations should be skipped, when the
+/// [`Theme::prefers_reduced_motion`] is enabled or in [`UiTestMode`].
+///
+/// The transitions complete instantly, and the repeating animations (e.g. [`Spinner`],
+/// [`Skeleton`]) are rendered in their static state.
+///
+/// [`Spinner`]: crate::spinner::Spinner
+/// [`Skeleton`]: crate::skeleton::Skeleton
+pub fn is_motion_reduced(cx: &App) -> bool {
+    UiTestMode::is_enabled(cx)
+        || cx
+            .try_global::<Theme>()
+            .is_some_and(|theme| theme.prefers_reduced_motion)
+}
+
+/// Returns the duration to use for an [`Animation`].
+///
+/// The animation completes in the first frame if [`is_motion_reduced`].
+pub fn motion_duration(duration: Duration, cx: &App) -> Duration {
+    if is_motion_reduced(cx) {
+        INSTANT
+    } else {
+        duration
+    }
+}
+
 // ── Easing presets ──────────────────────────────────────────────────────────
 
 /// Cubic ease-out — fast start, slow end. Good for enter animations.
@@ -91,7 +194,7 @@ impl Lerp for Point<Pixels> {
 ///     .ease(ease_out_cubic)
 ///     .slide_y(px(-4.), px(0.))
 ///     .fade(0.0, 1.0)
-///     .apply(element, "enter-anim")
+///     .apply(element, "enter-anim", cx)
 /// ```
 #[derive(Clone)]
 pub struct Transition {
@@ -154,41 +257,224 @@ impl Transition {
         self
     }
 
+    /// Returns the duration to animate, it is instant if [`is_motion_reduced`],
+    /// see [`motion_duration`].
+    pub fn motion_duration(&self, cx: &App) -> Duration {
+        motion_duration(self.duration, cx)
+    }
+
     /// Apply this transition to a Styled element, returning an AnimationElement.
+    ///
+    /// The duration is resolved by [`Transition::motion_duration`], so the transition completes
+    /// instantly if [`is_motion_reduced`].
     pub fn apply<E: IntoElement + Styled + 'static>(
         self,
         element: E,
         id: impl Into<ElementId>,
+        cx: &App,
     ) -> gpui::AnimationElement<E> {
-        let animation = Animation::new(self.duration).with_easing({
+        let animation = Animation::new(self.motion_duration(cx)).with_easing({
             let easing = self.easing.clone();
             move |t| easing(t)
         });
         let effects = self.effects;
         element.with_animation(id, animation, move |el, delta| {
-            let mut el = el;
-            for effect in &effects {
-                match effect {
-                    TransitionEffect::SlideY(from, to) => {
-                        el = el.top(Lerp::lerp(from, to, delta));
-                    }
-                    TransitionEffect::SlideX(from, to) => {
-                        el = el.left(Lerp::lerp(from, to, delta));
-                    }
-                    TransitionEffect::Fade(from, to) => {
-                        el = el.opacity(Lerp::lerp(from, to, delta));
-                    }
-                    TransitionEffect::Width(from, to) => {
-                        el = el.w(Lerp::lerp(from, to, delta));
-                    }
-                    TransitionEffect::Height(from, to) => {
-                        el = el.h(Lerp::lerp(from, to, delta));
-                    }
-                }
-            }
-            el
+            apply_effects(&effects, el, delta)
         })
     }
+
+    /// Apply this transition to a Styled element at the `progress` (0.0 to 1.0) of the duration,
+    /// for the elements driving the animation frames themselves.
+    pub(crate) fn apply_at<E: Styled>(&self, element: E, progress: f32) -> E {
+        apply_effects(
+            &self.effects,
+            element,
+            (self.easing)(progress.clamp(0., 1.)),
+        )
+    }
+}
+
+fn apply_effects<E: Styled>(effects: &[TransitionEffect], mut el: E, delta: f32) -> E {
+    for effect in effects {
+        match effect {
+            TransitionEffect::SlideY(from, to) => {
+                el = el.top(Lerp::lerp(from, to, delta));
+            }
+            TransitionEffect::SlideX(from, to) => {
+                el = el.left(Lerp::lerp(from, to, delta));
+            }
+            TransitionEffect::Fade(from, to) => {
+                el = el.opacity(Lerp::lerp(from, to, delta));
+            }
+            TransitionEffect::Width(from, to) => {
+                el = el.w(Lerp::lerp(from, to, delta));
+            }
+            TransitionEffect::Height(from, to) => {
+                el = el.h(Lerp::lerp(from, to, delta));
+            }
+        }
+    }
+    el
 }
 
 impl FluentBuilder for Transition {}
+
+// ── Animated value ──────────────────────────────────────────────────────────
+
+/// A value that animates to the target over time, driven by the animation frames.
+///
+/// Keep it in the state and read it by [`AnimatedValue::value`] in `render`, it requests the
+/// next animation frame until the animation is finished.
+///
+/// # Example
+///
+/// ```ignore
+/// struct Progress {
+///     width: AnimatedValue<f32>,
+/// }
+///
+/// // Change the target, e.g.: in an event handler.
+/// self.width.set(0.8);
+/// cx.notify();
+///
+/// // In render.
+/// let width = self.width.value(window, cx);
+/// div().w(relative(width))
+/// ```
+#[derive(Clone)]
+pub struct AnimatedValue<T: Lerp> {
+    from: T,
+    to: T,
+    started_at: Option<Instant>,
+    duration: Duration,
+    easing: Rc<dyn Fn(f32) -> f32>,
+}
+
+impl<T: Lerp> AnimatedValue<T> {
+    /// Create a new animated value with the initial `value` and the animation `duration`.
+    pub fn new(value: T, duration: Duration) -> Self {
+        Self {
+            from: value.clone(),
+            to: value,
+            started_at: None,
+            duration,
+            easing: Rc::new(ease_out_cubic),
+        }
+    }
+
+    /// Set the easing function, default: [`ease_out_cubic`].
+    pub fn ease(mut self, easing: impl Fn(f32) -> f32 + 'static) -> Self {
+        self.easing = Rc::new(easing);
+        self
+    }
+
+    /// Returns the target value.
+    pub fn target(&self) -> &T {
+        &self.to
+    }
+
+    /// Animate from the current value to the `target`.
+    pub fn set(&mut self, target: T) {
+        self.set_at(target, Instant::now());
+    }
+
+    /// Set the value immediately without animation.
+    pub fn set_immediate(&mut self, value: T) {
+        self.from = value.clone();
+        self.to = value;
+        self.started_at = None;
+    }
+
+    /// Returns true if the animation is not finished.
+    pub fn is_animating(&self) -> bool {
+        self.progress_at(Instant::now()) < 1.
+    }
+
+    /// Returns the current value, and request the next animation frame if it is animating.
+    ///
+    /// The target value is returned immediately if [`is_motion_reduced`].
+    pub fn value(&self, window: &mut Window, cx: &App) -> T {
+        if is_motion_reduced(cx) {
+            return self.to.clone();
+        }
+
+        let now = Instant::now();
+        if self.progress_at(now) < 1. {
+            window.request_animation_frame();
+        }
+        self.value_at(now)
+    }
+
+    fn set_at(&mut self, target: T, now: Instant) {
+        self.from = self.value_at(now);
+        self.to = target;
+        self.started_at = Some(now);
+    }
+
+    fn progress_at(&self, now: Instant) -> f32 {
+        let Some(started_at) = self.started_at else {
+            return 1.;
+        };
+        if self.duration.is_zero() {
+            return 1.;
+        }
+
+        let elapsed = now.saturating_duration_since(started_at);
+        (elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.)
+    }
+
+    fn value_at(&self, now: Instant) -> T {
+        let progress = self.progress_at(now);
+        if progress >= 1. {
+            return self.to.clone();
+        }
+
+        self.from.lerp(&self.to, (self.easing)(progress))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_animated_value() {
+        let now = Instant::now();
+        let mut value = AnimatedValue::new(0_f32, Duration::from_secs(1)).ease(|t| t);
+        assert_eq!(value.value_at(now), 0.);
+        assert_eq!(value.progress_at(now), 1.);
+
+        value.set_at(10., now);
+        assert_eq!(*value.target(), 10.);
+        assert_eq!(value.value_at(now), 0.);
+        assert_eq!(value.value_at(now + Duration::from_millis(500)), 5.);
+        assert_eq!(value.value_at(now + Duration::from_secs(2)), 10.);
+
+        // Retarget in the middle, start from the current value.
+        value.set_at(0., now + Duration::from_millis(500));
+        assert_eq!(value.value_at(now + Duration::from_millis(500)), 5.);
+        assert_eq!(value.value_at(now + Duration::from_secs(1)), 2.5);
+
+        value.set_immediate(3.);
+        assert_eq!(value.value_at(now), 3.);
+    }
+
+    #[gpui::test]
+    fn test_ui_test_mode(cx: &mut gpui::TestAppContext) {
+        let duration = Duration::from_millis(250);
+
+        cx.update(|cx| {
+            assert!(!UiTestMode::is_enabled(cx));
+            assert_eq!(UiTestMode::animation_duration(duration, cx), duration);
+            assert_eq!(UiTestMode::delay(duration, cx), duration);
+
+            UiTestMode::set_enabled(true, cx);
+            assert!(UiTestMode::is_enabled(cx));
+            assert_eq!(UiTestMode::animation_duration(duration, cx), INSTANT);
+            assert_eq!(UiTestMode::delay(duration, cx), Duration::ZERO);
+
+            UiTestMode::set_enabled(false, cx);
+            assert!(!UiTestMode::is_enabled(cx));
+        });
+    }
+}
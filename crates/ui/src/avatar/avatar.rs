@@ -1,17 +1,25 @@
 use gpui::{
-    App, Div, Hsla, ImageSource, InteractiveElement, Interactivity, IntoElement,
-    ParentElement as _, RenderOnce, SharedString, StyleRefinement, Styled, Window, div, img,
-    prelude::FluentBuilder,
+    AnyElement, App, Div, Hsla, ImageSource, InteractiveElement, Interactivity, IntoElement,
+    ParentElement as _, RenderOnce, SharedString, StyleRefinement, Styled, StyledImage as _,
+    Window, div, img, prelude::FluentBuilder,
 };
 
 use crate::{
     ActiveTheme, Colorize, Icon, IconName, Sizable, Size, StyledExt,
     avatar::{AvatarSized as _, avatar_size},
+    skeleton::Skeleton,
 };
 
+/// The number of hues used for the initials background, in 15 degree steps.
+const COLOR_COUNT: u64 = 360 / 15;
+const BG_OPACITY: f32 = 0.2;
+
 /// User avatar element.
 ///
 /// We can use [`Sizable`] trait to set the size of the avatar (see also: [`avatar_size`] about the size in pixels).
+///
+/// When the image is missing or fails to load, the avatar falls back to the initials of the
+/// `name` on a color derived from the name, and then to the `placeholder` icon.
 #[derive(IntoElement)]
 pub struct Avatar {
     base: Div,
@@ -21,6 +29,7 @@ pub struct Avatar {
     short_name: SharedString,
     placeholder: Icon,
     size: Size,
+    loading: bool,
 }
 
 impl Avatar {
@@ -33,6 +42,7 @@ impl Avatar {
             short_name: SharedString::default(),
             placeholder: Icon::new(IconName::User),
             size: Size::Medium,
+            loading: false,
         }
     }
 
@@ -57,6 +67,15 @@ impl Avatar {
         self.placeholder = icon.into();
         self
     }
+
+    /// Set to show a loading shimmer, default: false
+    ///
+    /// If `src` is set, the shimmer is shown while the image is loading,
+    /// otherwise it is always shown, e.g. when the user data is still fetching.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
 }
 
 impl Sizable for Avatar {
@@ -84,13 +103,35 @@ impl RenderOnce for Avatar {
         let mut inner_style = StyleRefinement::default();
         inner_style.corner_radii = corner_radii;
 
-        const COLOR_COUNT: u64 = 360 / 15;
-        fn default_color(ix: u64, cx: &mut App) -> Hsla {
-            let h = (ix * 15).clamp(0, 360) as f32;
-            cx.theme().blue.hue(h / 360.0)
-        }
-
-        const BG_OPACITY: f32 = 0.2;
+        let size = self.size;
+        let short_name = self.short_name;
+        let color = self
+            .name
+            .as_ref()
+            .map(|_| name_color(&short_name, cx.theme().blue));
+        let placeholder = self.placeholder;
+        let fallback = move || -> AnyElement {
+            match color {
+                Some(color) => div()
+                    .size_full()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .bg(color.opacity(BG_OPACITY))
+                    .text_color(color)
+                    .child(div().avatar_text_size(size).child(short_name.clone()))
+                    .into_any_element(),
+                None => div()
+                    .size_full()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .text_size(avatar_size(size) * 0.6)
+                    .child(placeholder.clone())
+                    .into_any_element(),
+            }
+        };
+        let shimmer = || Skeleton::new().size_full().into_any_element();
 
         self.base
             .avatar_size(self.size)
@@ -104,30 +145,33 @@ impl RenderOnce for Avatar {
             .text_color(cx.theme().background)
             .border_1()
             .border_color(cx.theme().border)
-            .when(self.name.is_none() && self.src.is_none(), |this| {
-                this.text_size(avatar_size(self.size) * 0.6)
-                    .child(self.placeholder)
-            })
             .map(|this| match self.src {
-                None => this.when(self.name.is_some(), |this| {
-                    let color_ix = gpui::hash(&self.short_name) % COLOR_COUNT;
-                    let color = default_color(color_ix, cx);
-
-                    this.bg(color.opacity(BG_OPACITY))
-                        .text_color(color)
-                        .child(div().avatar_text_size(self.size).child(self.short_name))
-                }),
+                None if self.loading => this.child(shimmer()),
+                None => this.child(fallback()),
                 Some(src) => this.child(
                     img(src)
                         .avatar_size(self.size)
                         .rounded_full()
-                        .refine_style(&inner_style),
+                        .refine_style(&inner_style)
+                        .when(self.loading, |this| this.with_loading(shimmer))
+                        .with_fallback(fallback),
                 ),
             })
             .refine_style(&self.style)
     }
 }
 
+/// Returns a deterministic color for the initials of the name, the same initials always get
+/// the same hue.
+fn name_color(short_name: &SharedString, base: Hsla) -> Hsla {
+    let h = (name_color_index(short_name) * 15) as f32;
+    base.hue(h / 360.0)
+}
+
+fn name_color_index(short_name: &SharedString) -> u64 {
+    gpui::hash(short_name) % COLOR_COUNT
+}
+
 fn extract_text_initials(text: &str) -> String {
     let mut result = text
         .split(" ")
@@ -154,13 +198,25 @@ mod tests {
         assert_eq!(extract_text_initials(&"huacnlee"), "HU".to_string());
     }
 
+    #[test]
+    fn test_name_color_index() {
+        // The color is hashed from the initials, e.g.: "Jason Lee" and "Jim Lane" are the same.
+        let jason = SharedString::from(extract_text_initials("Jason Lee"));
+        let jim = SharedString::from(extract_text_initials("Jim Lane"));
+        assert_eq!(name_color_index(&jason), name_color_index(&jim));
+        assert!(name_color_index(&jason) < COLOR_COUNT);
+        assert!(name_color_index(&SharedString::default()) < COLOR_COUNT);
+    }
+
     #[gpui::test]
     fn test_avatar_builder(_cx: &mut gpui::TestAppContext) {
         let avatar = Avatar::new()
             .name("Jason Lee")
             .placeholder(Icon::new(IconName::User))
+            .loading(true)
             .large();
 
+        assert!(avatar.loading);
         assert_eq!(avatar.name, Some(SharedString::from("Jason Lee")));
         assert_eq!(avatar.short_name, SharedString::from("JL"));
         assert_eq!(avatar.size, Size::Large);
@@ -91,6 +91,12 @@ impl ParentElement for Badge {
     }
 }
 
+impl Styled for Badge {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
 impl Sizable for Badge {
     fn with_size(mut self, size: impl Into<Size>) -> Self {
         self.size = size.into();
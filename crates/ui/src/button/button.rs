@@ -2,16 +2,18 @@ use std::rc::Rc;
 
 use crate::{
     ActiveTheme, Colorize as _, Disableable, FocusableExt as _, Icon, IconName, Selectable,
-    Sizable, Size, StyleSized, StyledExt,
+    Sizable, Size, StyleSized, StyledExt, WCAG_AA,
+    badge::Badge,
     button::ButtonIcon,
-    h_flex,
+    check_contrast, h_flex,
+    spinner::Spinner,
     tooltip::{ManagedTooltipExt as _, Tooltip},
 };
 use gpui::{
-    AnyElement, App, ClickEvent, Corners, Div, Edges, ElementId, Hsla, InteractiveElement,
-    Interactivity, IntoElement, MouseButton, ParentElement, Pixels, RenderOnce, SharedString,
-    Stateful, StatefulInteractiveElement as _, StyleRefinement, Styled, Window, div,
-    prelude::FluentBuilder as _, px, relative, transparent_white,
+    AnyElement, App, ClickEvent, Corners, Div, Edges, ElementId, FocusHandle, Hsla,
+    InteractiveElement, Interactivity, IntoElement, MouseButton, ParentElement, Pixels, RenderOnce,
+    SharedString, Stateful, StatefulInteractiveElement as _, StyleRefinement, Styled, Task, Window,
+    div, prelude::FluentBuilder as _, px, relative, transparent_white,
 };
 
 #[derive(Default, Clone, Copy)]
@@ -196,6 +198,7 @@ pub struct Button {
     border_corners: Corners<bool>,
     border_edges: Edges<bool>,
     dropdown_caret: bool,
+    badge: Option<Badge>,
     size: Size,
     compact: bool,
     tooltip: Option<(
@@ -204,6 +207,7 @@ pub struct Button {
     )>,
     tooltip_builder: Option<Rc<dyn Fn(&mut Window, &mut App) -> gpui::AnyView>>,
     on_click: Option<Rc<dyn Fn(&ClickEvent, &mut Window, &mut App)>>,
+    on_click_task: Option<Rc<dyn Fn(&ClickEvent, &mut Window, &mut App) -> Task<()>>>,
     on_hover: Option<Rc<dyn Fn(&bool, &mut Window, &mut App)>>,
     loading: bool,
     loading_icon: Option<Icon>,
@@ -245,6 +249,7 @@ impl Button {
             tooltip: None,
             tooltip_builder: None,
             on_click: None,
+            on_click_task: None,
             on_hover: None,
             loading: false,
             compact: false,
@@ -252,6 +257,7 @@ impl Button {
             children: Vec::new(),
             loading_icon: None,
             dropdown_caret: false,
+            badge: None,
             tab_index: 0,
             tab_stop: true,
         }
@@ -337,6 +343,30 @@ impl Button {
         self
     }
 
+    /// Add an async click handler, the returned task is kept until it completes.
+    ///
+    /// The button shows the loading state and can't be clicked again while the task is running,
+    /// then it will be restored when the task completes (or is dropped).
+    ///
+    /// The handler of [`Button::on_click`] is still called before starting the task.
+    ///
+    /// ```ignore
+    /// Button::new("save")
+    ///     .label("Save")
+    ///     .on_click_task(|_, _, cx| {
+    ///         cx.spawn(async move |_| {
+    ///             // Do some async work.
+    ///         })
+    ///     })
+    /// ```
+    pub fn on_click_task(
+        mut self,
+        handler: impl Fn(&ClickEvent, &mut Window, &mut App) -> Task<()> + 'static,
+    ) -> Self {
+        self.on_click_task = Some(Rc::new(handler));
+        self
+    }
+
     /// Add hover handler, the bool parameter indicates whether the mouse is hovering.
     pub fn on_hover(mut self, handler: impl Fn(&bool, &mut Window, &mut App) + 'static) -> Self {
         self.on_hover = Some(Rc::new(handler));
@@ -373,9 +403,24 @@ impl Button {
         self
     }
 
+    /// Set a [`Badge`] to show a count or dot at the top-right corner of the button,
+    /// e.g.: the unread count of an icon button.
+    ///
+    /// ```ignore
+    /// Button::new("inbox")
+    ///     .ghost()
+    ///     .icon(IconName::Inbox)
+    ///     .badge(Badge::new().count(3))
+    /// ```
+    pub fn badge(mut self, badge: Badge) -> Self {
+        self.badge = Some(badge);
+        self
+    }
+
     #[inline]
     fn clickable(&self) -> bool {
-        !(self.disabled || self.loading) && self.on_click.is_some()
+        !(self.disabled || self.loading)
+            && (self.on_click.is_some() || self.on_click_task.is_some())
     }
 
     #[inline]
@@ -434,22 +479,62 @@ impl InteractiveElement for Button {
     }
 }
 
+struct ButtonState {
+    focus_handle: FocusHandle,
+    /// The running task of the `on_click_task`.
+    click_task: Option<Task<()>>,
+}
+
 impl RenderOnce for Button {
-    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+    fn render(mut self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        self.size = self.size.with_density(cx.theme().density);
+        let state = window.use_keyed_state(self.id.clone(), cx, |_, cx| ButtonState {
+            focus_handle: cx.focus_handle(),
+            click_task: None,
+        });
+        if state.read(cx).click_task.is_some() {
+            self.loading = true;
+        }
+        if let Some(on_click_task) = self.on_click_task.take() {
+            let state = state.clone();
+            let on_click = self.on_click.take();
+            self.on_click = Some(Rc::new(move |event, window, cx| {
+                if let Some(on_click) = &on_click {
+                    on_click(event, window, cx);
+                }
+
+                let task = on_click_task(event, window, cx);
+                let click_task = cx.spawn({
+                    let state = state.clone();
+                    async move |cx| {
+                        task.await;
+                        _ = state.update(cx, |state, cx| {
+                            state.click_task = None;
+                            cx.notify();
+                        });
+                    }
+                });
+                state.update(cx, |state, cx| {
+                    state.click_task = Some(click_task);
+                    cx.notify();
+                });
+            }));
+        }
+
         let style: ButtonVariant = self.variant;
         let clickable = self.clickable();
         let is_disabled = self.disabled;
         let hoverable = self.hoverable();
         let normal_style = style.normal(self.outline, cx);
+        if !is_disabled && self.label.is_some() {
+            check_contrast("Button", normal_style.fg, normal_style.bg, WCAG_AA, cx);
+        }
         let icon_size = match self.size {
             Size::Size(v) => Size::Size(v * 0.75),
             _ => self.size,
         };
 
-        let focus_handle = window
-            .use_keyed_state(self.id.clone(), cx, |_, cx| cx.focus_handle())
-            .read(cx)
-            .clone();
+        let focus_handle = state.read(cx).focus_handle.clone();
         let is_focused = focus_handle.is_focused(window);
 
         let rounding = match self.rounded {
@@ -552,6 +637,7 @@ impl RenderOnce for Button {
                     .border_color(disabled_style.border)
                     .shadow_none()
             })
+            .when(self.badge.is_some(), |this| this.relative())
             .refine_style(&self.style)
             .on_mouse_down(MouseButton::Left, move |_, window, cx| {
                 // Stop handle any click event when disabled.
@@ -593,12 +679,19 @@ impl RenderOnce for Button {
                         Size::Small => this.gap_1(),
                         _ => this.gap_2(),
                     })
-                    .when_some(self.icon, |this, icon| {
-                        this.child(
+                    .map(|this| match self.icon {
+                        Some(icon) => this.child(
                             icon.loading_icon(self.loading_icon)
                                 .loading(self.loading)
                                 .with_size(icon_size),
-                        )
+                        ),
+                        // Show a spinner before the label if there is no icon to replace.
+                        None if self.loading => this.child(
+                            Spinner::new()
+                                .when_some(self.loading_icon, |this, icon| this.icon(icon))
+                                .with_size(icon_size),
+                        ),
+                        None => this,
                     })
                     .when_some(self.label, |this, label| {
                         this.child(div().flex_none().line_height(relative(1.)).child(label))
@@ -615,6 +708,9 @@ impl RenderOnce for Button {
                         )
                     })
             })
+            .when_some(self.badge, |this, badge| {
+                this.child(badge.absolute().top_0().left_0().size_full())
+            })
             .when(self.loading && !self.disabled, |this| {
                 this.bg(normal_style.bg.opacity(0.8))
                     .border_color(normal_style.border.opacity(0.8))
@@ -962,7 +1058,12 @@ impl ButtonVariant {
 
 #[cfg(test)]
 mod tests {
+    use std::cell::Cell;
+
+    use gpui::{Context, Render};
+
     use super::*;
+    use crate::test::TestWindow;
 
     #[gpui::test]
     fn test_button_builder(_cx: &mut gpui::TestAppContext) {
@@ -980,6 +1081,7 @@ mod tests {
             .tab_stop(true)
             .dropdown_caret(false)
             .rounded(ButtonRounded::Medium)
+            .badge(Badge::new().count(3))
             .on_click(|_, _, _| {});
 
         assert_eq!(button.label, Some("Save Changes".into()));
@@ -994,6 +1096,7 @@ mod tests {
         assert_eq!(button.tab_index, 1);
         assert!(button.tab_stop);
         assert!(!button.dropdown_caret);
+        assert!(button.badge.is_some());
         assert!(matches!(button.rounded, ButtonRounded::Medium));
     }
 
@@ -1010,6 +1113,67 @@ mod tests {
         // Loading button should not be clickable
         let loading = Button::new("test").loading(true).on_click(|_, _, _| {});
         assert!(!loading.clickable());
+
+        // Button with async click handler should be clickable
+        let task = Button::new("test").on_click_task(|_, _, _| Task::ready(()));
+        assert!(task.clickable());
+        let task = Button::new("test")
+            .loading(true)
+            .on_click_task(|_, _, _| Task::ready(()));
+        assert!(!task.clickable());
+    }
+
+    struct ClickTaskView {
+        clicks: Rc<Cell<usize>>,
+        tasks: Rc<Cell<usize>>,
+        done: smol::channel::Receiver<()>,
+    }
+
+    impl Render for ClickTaskView {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            let clicks = self.clicks.clone();
+            let tasks = self.tasks.clone();
+            let done = self.done.clone();
+            Button::new("save")
+                .label("Save")
+                .debug_selector(|| "save".into())
+                .on_click(move |_, _, _| clicks.set(clicks.get() + 1))
+                .on_click_task(move |_, _, cx| {
+                    tasks.set(tasks.get() + 1);
+                    let done = done.clone();
+                    cx.background_spawn(async move {
+                        _ = done.recv().await;
+                    })
+                })
+        }
+    }
+
+    #[gpui::test]
+    fn test_button_click_task(cx: &mut gpui::TestAppContext) {
+        let clicks = Rc::new(Cell::new(0));
+        let tasks = Rc::new(Cell::new(0));
+        let (tx, rx) = smol::channel::unbounded();
+        let mut window = TestWindow::new(cx, {
+            let clicks = clicks.clone();
+            let tasks = tasks.clone();
+            move |_, _| ClickTaskView {
+                clicks,
+                tasks,
+                done: rx,
+            }
+        });
+
+        window.click_on("save");
+        assert_eq!((clicks.get(), tasks.get()), (1, 1));
+
+        // Loading while the task is running, the clicks are ignored.
+        window.click_on("save");
+        assert_eq!((clicks.get(), tasks.get()), (1, 1));
+
+        tx.try_send(()).unwrap();
+        window.run_until_parked();
+        window.click_on("save");
+        assert_eq!((clicks.get(), tasks.get()), (2, 2));
     }
 
     #[gpui::test]
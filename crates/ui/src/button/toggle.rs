@@ -8,7 +8,8 @@ use gpui::{
 use smallvec::{SmallVec, smallvec};
 
 use crate::{
-    ActiveTheme, Disableable, Icon, Sizable, Size, StyledExt, h_flex, tooltip::ComponentTooltip,
+    ActiveTheme, Disableable, Icon, Sizable, Size, StyledExt, badge::Badge, h_flex,
+    tooltip::ComponentTooltip,
 };
 
 #[derive(Default, Copy, Debug, Clone, PartialEq, Eq, Hash)]
@@ -42,6 +43,7 @@ pub struct Toggle {
     border_corners: Corners<bool>,
     border_edges: Edges<bool>,
     children: SmallVec<[AnyElement; 1]>,
+    badge: Option<Badge>,
     on_click: Option<Box<dyn Fn(&bool, &mut Window, &mut App) + 'static>>,
     tooltip: ComponentTooltip,
 }
@@ -64,6 +66,7 @@ impl Toggle {
             },
             border_edges: Edges::all(true),
             children: smallvec![],
+            badge: None,
             on_click: None,
             tooltip: ComponentTooltip::default(),
         }
@@ -95,6 +98,12 @@ impl Toggle {
         self
     }
 
+    /// Set a [`Badge`] to show a count or dot at the top-right corner of the toggle.
+    pub fn badge(mut self, badge: Badge) -> Self {
+        self.badge = Some(badge);
+        self
+    }
+
     /// Set the callback to be called when the toggle is clicked.
     ///
     /// The `&bool` parameter represents the new checked state of the toggle.
@@ -197,8 +206,12 @@ impl RenderOnce for Toggle {
                 this.bg(cx.theme().accent)
                     .text_color(cx.theme().accent_foreground)
             })
+            .when(self.badge.is_some(), |this| this.relative())
             .refine_style(&self.style)
             .children(self.children)
+            .when_some(self.badge, |this, badge| {
+                this.child(badge.absolute().top_0().left_0().size_full())
+            })
             .when(!disabled, |this| {
                 this.when_some(self.on_click, |this, on_click| {
                     this.on_click(move |_, window, cx| on_click(&!checked, window, cx))
@@ -0,0 +1,276 @@
+use std::{borrow::Cow, collections::HashMap, rc::Rc};
+
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use gpui::{
+    App, ElementId, Hsla, InteractiveElement as _, IntoElement, ParentElement, Pixels, RenderOnce,
+    SharedString, StatefulInteractiveElement as _, StyleRefinement, Styled, Window, div,
+    prelude::FluentBuilder as _, px,
+};
+use rust_i18n::t;
+
+use crate::{ActiveTheme as _, StyledExt as _, h_flex, tooltip::Tooltip, v_flex};
+
+/// The number of the color levels, the level 0 is for no activity.
+const LEVELS: usize = 5;
+
+/// A GitHub-style activity heatmap, each cell is a day and each column is a week.
+///
+/// The value of each day is mapped to 5 color levels by the max value.
+///
+/// ```ignore
+/// ActivityHeatmap::new("usage")
+///     .values(daily_usage.iter().map(|d| (d.date, d.requests as f64)))
+///     .tooltip(|date, value| format!("{} requests on {}", value, date).into())
+/// ```
+#[derive(IntoElement)]
+pub struct ActivityHeatmap {
+    id: ElementId,
+    style: StyleRefinement,
+    values: HashMap<NaiveDate, f64>,
+    end_date: Option<NaiveDate>,
+    weeks: usize,
+    cell_size: Pixels,
+    color: Option<Hsla>,
+    tooltip: Option<Rc<dyn Fn(NaiveDate, f64) -> SharedString>>,
+}
+
+impl ActivityHeatmap {
+    /// Create a new activity heatmap.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            style: StyleRefinement::default(),
+            values: HashMap::new(),
+            end_date: None,
+            weeks: 53,
+            cell_size: px(10.),
+            color: None,
+            tooltip: None,
+        }
+    }
+
+    /// Set the values of the days, the values of the same day are summed.
+    pub fn values(mut self, values: impl IntoIterator<Item = (NaiveDate, f64)>) -> Self {
+        for (date, value) in values {
+            *self.values.entry(date).or_default() += value;
+        }
+        self
+    }
+
+    /// Set the last day to show, default is today.
+    pub fn end_date(mut self, end_date: NaiveDate) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Set the number of weeks to show, default is 53 (a year).
+    pub fn weeks(mut self, weeks: usize) -> Self {
+        self.weeks = weeks.max(1);
+        self
+    }
+
+    /// Set the size of each cell, default is 10px.
+    pub fn cell_size(mut self, cell_size: impl Into<Pixels>) -> Self {
+        self.cell_size = cell_size.into();
+        self
+    }
+
+    /// Set the color of the max level, default is the `chart_2` color of the theme.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Set the tooltip text of each day.
+    pub fn tooltip(mut self, tooltip: impl Fn(NaiveDate, f64) -> SharedString + 'static) -> Self {
+        self.tooltip = Some(Rc::new(tooltip));
+        self
+    }
+}
+
+impl Styled for ActivityHeatmap {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+/// Returns the first day (Sunday) of the heatmap, to show `weeks` weeks until the `end_date`.
+fn start_date(end_date: NaiveDate, weeks: usize) -> NaiveDate {
+    let days_from_sunday = end_date.weekday().num_days_from_sunday() as i64;
+    end_date - Duration::days(days_from_sunday + (weeks as i64 - 1) * 7)
+}
+
+/// Returns the color level of the `value` in `0..LEVELS`, by the `max` value.
+fn level(value: f64, max: f64) -> usize {
+    if value <= 0. || max <= 0. {
+        return 0;
+    }
+
+    let ratio = (value / max).min(1.);
+    ((ratio * (LEVELS - 1) as f64).ceil() as usize).clamp(1, LEVELS - 1)
+}
+
+/// Returns the columns and the months to show the month labels,
+/// a label is shown at the first week that starts in that month.
+fn month_columns(start_date: NaiveDate, weeks: usize) -> Vec<(usize, u32)> {
+    let mut columns = vec![];
+    let mut last_month = None;
+    for col in 0..weeks {
+        let date = start_date + Duration::days(col as i64 * 7);
+        if last_month != Some(date.month()) {
+            // Skip the first partial month, if there is no space for the label.
+            if !(col == 0 && date.day() > 7 * 2) {
+                columns.push((col, date.month()));
+            }
+            last_month = Some(date.month());
+        }
+    }
+    columns
+}
+
+/// Returns the short name of the month, e.g.: "Jan".
+fn short_month_name(month: u32) -> SharedString {
+    let name = match month {
+        1 => t!("Calendar.month.January"),
+        2 => t!("Calendar.month.February"),
+        3 => t!("Calendar.month.March"),
+        4 => t!("Calendar.month.April"),
+        5 => t!("Calendar.month.May"),
+        6 => t!("Calendar.month.June"),
+        7 => t!("Calendar.month.July"),
+        8 => t!("Calendar.month.August"),
+        9 => t!("Calendar.month.September"),
+        10 => t!("Calendar.month.October"),
+        11 => t!("Calendar.month.November"),
+        12 => t!("Calendar.month.December"),
+        _ => Cow::Borrowed(""),
+    };
+    name.chars().take(3).collect::<String>().into()
+}
+
+impl RenderOnce for ActivityHeatmap {
+    fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+        let end_date = self.end_date.unwrap_or_else(|| Local::now().date_naive());
+        let start_date = start_date(end_date, self.weeks);
+        let max = self.values.values().copied().fold(0., f64::max);
+        let color = self.color.unwrap_or(cx.theme().chart_2);
+        let empty_color = cx.theme().muted;
+        let cell_size = self.cell_size;
+        let gap = (cell_size * 0.3).round();
+        let column_width = cell_size + gap;
+
+        let label = |text: SharedString| {
+            div()
+                .h(cell_size)
+                .line_height(cell_size)
+                .text_size(cell_size)
+                .child(text)
+        };
+
+        v_flex()
+            .id(self.id)
+            .gap(gap)
+            .text_color(cx.theme().muted_foreground)
+            .refine_style(&self.style)
+            // Month labels
+            .child(
+                div().relative().h(cell_size).ml(cell_size * 3.).children(
+                    month_columns(start_date, self.weeks)
+                        .into_iter()
+                        .map(|(col, month)| {
+                            label(short_month_name(month))
+                                .absolute()
+                                .left(column_width * col as f32)
+                                .whitespace_nowrap()
+                        }),
+                ),
+            )
+            .child(
+                h_flex()
+                    .items_start()
+                    .gap(gap)
+                    // Week labels
+                    .child(
+                        v_flex()
+                            .w(cell_size * 3. - gap)
+                            .gap(gap)
+                            .children((0..7).map(|day| {
+                                label(match day {
+                                    1 => t!("Calendar.week.1").into(),
+                                    3 => t!("Calendar.week.3").into(),
+                                    5 => t!("Calendar.week.5").into(),
+                                    _ => SharedString::default(),
+                                })
+                            })),
+                    )
+                    .children((0..self.weeks).map(|col| {
+                        v_flex().gap(gap).children((0..7).map(|day| {
+                            let date = start_date + Duration::days((col * 7 + day) as i64);
+                            let value = self.values.get(&date).copied().unwrap_or_default();
+                            let bg = match level(value, max) {
+                                0 => empty_color,
+                                level => color.opacity(level as f32 / (LEVELS - 1) as f32),
+                            };
+
+                            div()
+                                .id(col * 7 + day)
+                                .size(cell_size)
+                                .rounded(px(2.))
+                                .when(date <= end_date, |this| {
+                                    let tooltip = self.tooltip.clone();
+                                    this.bg(bg).tooltip(move |window, cx| {
+                                        let text = match tooltip.as_ref() {
+                                            Some(tooltip) => tooltip(date, value),
+                                            None => format!("{}: {}", date, value).into(),
+                                        };
+                                        Tooltip::new(text).build(window, cx)
+                                    })
+                                })
+                        }))
+                    })),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::{level, month_columns, start_date};
+
+    #[test]
+    fn test_start_date() {
+        // 2025-06-18 is Wednesday.
+        let end = NaiveDate::from_ymd_opt(2025, 6, 18).unwrap();
+        assert_eq!(
+            start_date(end, 1),
+            NaiveDate::from_ymd_opt(2025, 6, 15).unwrap()
+        );
+        assert_eq!(
+            start_date(end, 3),
+            NaiveDate::from_ymd_opt(2025, 6, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_level() {
+        assert_eq!(level(0., 10.), 0);
+        assert_eq!(level(5., 0.), 0);
+        assert_eq!(level(0.1, 10.), 1);
+        assert_eq!(level(5., 10.), 2);
+        assert_eq!(level(6., 10.), 3);
+        assert_eq!(level(10., 10.), 4);
+        assert_eq!(level(20., 10.), 4);
+    }
+
+    #[test]
+    fn test_month_columns() {
+        // 2025-06-01 is Sunday.
+        let start = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+        assert_eq!(month_columns(start, 6), vec![(0, 6), (5, 7)]);
+
+        // Skip the partial month at the start.
+        let start = NaiveDate::from_ymd_opt(2025, 5, 25).unwrap();
+        assert_eq!(month_columns(start, 3), vec![(1, 6)]);
+    }
+}
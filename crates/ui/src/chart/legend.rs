@@ -0,0 +1,148 @@
+use std::rc::Rc;
+
+use gpui::{
+    App, Axis, ElementId, Hsla, InteractiveElement as _, IntoElement, ParentElement, RenderOnce,
+    SharedString, StatefulInteractiveElement as _, StyleRefinement, Styled, Window, div,
+    prelude::FluentBuilder as _, px,
+};
+
+use crate::{ActiveTheme as _, StyledExt as _, h_flex};
+
+/// An item of the [`Legend`].
+#[derive(Clone)]
+pub struct LegendItem {
+    label: SharedString,
+    color: Hsla,
+}
+
+impl LegendItem {
+    /// Create a new legend item with the `label` and the `color` of the series.
+    pub fn new(label: impl Into<SharedString>, color: impl Into<Hsla>) -> Self {
+        Self {
+            label: label.into(),
+            color: color.into(),
+        }
+    }
+}
+
+/// A legend to show the label and color of the series or items in a chart.
+///
+/// It can be used with any chart, and the hovered item can be shared with the chart,
+/// e.g.: [`super::PieChart::active_index`].
+///
+/// ```ignore
+/// Legend::new("devices")
+///     .items(devices.iter().map(|d| LegendItem::new(d.name.clone(), d.color)))
+///     .active_index(self.active_ix)
+///     .on_hover(cx.listener(|this, ix: &Option<usize>, _, cx| {
+///         this.active_ix = *ix;
+///         cx.notify();
+///     }))
+/// ```
+#[derive(IntoElement)]
+pub struct Legend {
+    id: ElementId,
+    style: StyleRefinement,
+    items: Vec<LegendItem>,
+    axis: Axis,
+    active_index: Option<usize>,
+    on_hover: Option<Rc<dyn Fn(&Option<usize>, &mut Window, &mut App)>>,
+    on_click: Option<Rc<dyn Fn(&usize, &mut Window, &mut App)>>,
+}
+
+impl Legend {
+    /// Create a new legend.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            style: StyleRefinement::default(),
+            items: vec![],
+            axis: Axis::Horizontal,
+            active_index: None,
+            on_hover: None,
+            on_click: None,
+        }
+    }
+
+    /// Add an item to the legend.
+    pub fn item(mut self, item: LegendItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Add items to the legend.
+    pub fn items(mut self, items: impl IntoIterator<Item = LegendItem>) -> Self {
+        self.items.extend(items);
+        self
+    }
+
+    /// Layout the items vertically, default is horizontal.
+    pub fn vertical(mut self) -> Self {
+        self.axis = Axis::Vertical;
+        self
+    }
+
+    /// Set the index of the active item, the other items are dimmed.
+    pub fn active_index(mut self, active_index: Option<usize>) -> Self {
+        self.active_index = active_index;
+        self
+    }
+
+    /// Set the callback when the hovered item changed, `None` if no item is hovered.
+    pub fn on_hover(
+        mut self,
+        on_hover: impl Fn(&Option<usize>, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_hover = Some(Rc::new(on_hover));
+        self
+    }
+
+    /// Set the callback when an item is clicked, e.g.: to toggle the series.
+    pub fn on_click(mut self, on_click: impl Fn(&usize, &mut Window, &mut App) + 'static) -> Self {
+        self.on_click = Some(Rc::new(on_click));
+        self
+    }
+}
+
+impl Styled for Legend {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl RenderOnce for Legend {
+    fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+        let active_index = self.active_index;
+
+        h_flex()
+            .id(self.id)
+            .flex_wrap()
+            .gap_x_4()
+            .gap_y_1()
+            .when(self.axis == Axis::Vertical, |this| {
+                this.flex_col().items_start()
+            })
+            .text_sm()
+            .text_color(cx.theme().muted_foreground)
+            .refine_style(&self.style)
+            .children(self.items.into_iter().enumerate().map(|(ix, item)| {
+                let dimmed = active_index.is_some_and(|active| active != ix);
+
+                h_flex()
+                    .id(ix)
+                    .gap_1p5()
+                    .when(dimmed, |this| this.opacity(0.5))
+                    .child(div().size(px(10.)).rounded(px(2.)).bg(item.color))
+                    .child(item.label)
+                    .when_some(self.on_hover.clone(), |this, on_hover| {
+                        this.on_hover(move |hovered, window, cx| {
+                            on_hover(&hovered.then_some(ix), window, cx);
+                        })
+                    })
+                    .when_some(self.on_click.clone(), |this, on_click| {
+                        this.cursor_pointer()
+                            .on_click(move |_, window, cx| on_click(&ix, window, cx))
+                    })
+            }))
+    }
+}
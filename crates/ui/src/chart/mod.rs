@@ -1,14 +1,20 @@
 mod area_chart;
 mod bar_chart;
 mod candlestick_chart;
+mod heatmap;
+mod legend;
 mod line_chart;
 mod pie_chart;
+mod sparkline_chart;
 
 pub use area_chart::AreaChart;
 pub use bar_chart::BarChart;
 pub use candlestick_chart::CandlestickChart;
+pub use heatmap::ActivityHeatmap;
+pub use legend::{Legend, LegendItem};
 pub use line_chart::LineChart;
 pub use pie_chart::PieChart;
+pub use sparkline_chart::SparklineChart;
 
 use gpui::{Hsla, SharedString, TextAlign};
 
@@ -1,6 +1,9 @@
-use std::rc::Rc;
+use std::{
+    f32::consts::{FRAC_PI_2, TAU},
+    rc::Rc,
+};
 
-use gpui::{App, Bounds, Hsla, Pixels, Window};
+use gpui::{App, Bounds, Hsla, MouseMoveEvent, Pixels, Point, Window};
 use gpui_component_macros::IntoPlot;
 use num_traits::Zero;
 
@@ -12,6 +15,9 @@ use crate::{
     },
 };
 
+/// The distance to expand the outer radius of the active arc.
+const ACTIVE_OFFSET: f32 = 6.;
+
 #[derive(IntoPlot)]
 pub struct PieChart<T: 'static> {
     data: Vec<T>,
@@ -22,6 +28,8 @@ pub struct PieChart<T: 'static> {
     pad_angle: f32,
     value: Option<Rc<dyn Fn(&T) -> f32>>,
     color: Option<Rc<dyn Fn(&T) -> Hsla>>,
+    active_index: Option<usize>,
+    on_hover: Option<Rc<dyn Fn(&Option<usize>, &mut Window, &mut App)>>,
 }
 
 impl<T> PieChart<T> {
@@ -38,6 +46,8 @@ impl<T> PieChart<T> {
             pad_angle: 0.,
             value: None,
             color: None,
+            active_index: None,
+            on_hover: None,
         }
     }
 
@@ -106,6 +116,50 @@ impl<T> PieChart<T> {
         self.color = Some(Rc::new(move |t| color(t).into()));
         self
     }
+
+    /// Set the index of the active (highlighted) item in the data, the arc is expanded.
+    pub fn active_index(mut self, active_index: Option<usize>) -> Self {
+        self.active_index = active_index;
+        self
+    }
+
+    /// Set the callback when the hovered arc changed, the argument is the index of the item
+    /// in the data, or `None` if no arc is hovered.
+    ///
+    /// Use this with [`PieChart::active_index`] to highlight the hovered arc.
+    pub fn on_hover(
+        mut self,
+        on_hover: impl Fn(&Option<usize>, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_hover = Some(Rc::new(on_hover));
+        self
+    }
+}
+
+/// The angles and radii of an arc, to find the arc at a position.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ArcBounds {
+    index: usize,
+    start_angle: f32,
+    end_angle: f32,
+    inner_radius: f32,
+    outer_radius: f32,
+}
+
+/// Returns the index of the arc at the `position` relative to the center of the pie.
+fn arc_index_at(arcs: &[ArcBounds], position: Point<f32>) -> Option<usize> {
+    let radius = (position.x * position.x + position.y * position.y).sqrt();
+    // The arcs start from 12 o'clock and go clockwise.
+    let angle = (position.y.atan2(position.x) + FRAC_PI_2).rem_euclid(TAU);
+
+    arcs.iter()
+        .find(|arc| {
+            radius >= arc.inner_radius
+                && radius <= arc.outer_radius
+                && angle >= arc.start_angle
+                && angle < arc.end_angle
+        })
+        .map(|arc| arc.index)
 }
 
 impl<T> Plot for PieChart<T> {
@@ -128,9 +182,25 @@ impl<T> Plot for PieChart<T> {
         pie = pie.pad_angle(self.pad_angle);
         let arcs = pie.arcs(&self.data);
 
+        let mut arc_bounds = Vec::with_capacity(arcs.len());
         for a in &arcs {
             let inner_radius = self.get_inner_radius(a);
-            let outer_radius = self.get_outer_radius(a);
+            let mut arc_outer_radius = if self.outer_radius_fn.is_some() {
+                self.get_outer_radius(a)
+            } else {
+                outer_radius
+            };
+            if self.active_index == Some(a.index) {
+                arc_outer_radius += ACTIVE_OFFSET;
+            }
+            arc_bounds.push(ArcBounds {
+                index: a.index,
+                start_angle: a.start_angle,
+                end_angle: a.end_angle,
+                inner_radius,
+                outer_radius: arc_outer_radius,
+            });
+
             arc.paint(
                 a,
                 if let Some(color_fn) = self.color.as_ref() {
@@ -139,10 +209,72 @@ impl<T> Plot for PieChart<T> {
                     cx.theme().chart_2
                 },
                 Some(inner_radius),
-                Some(outer_radius),
+                Some(arc_outer_radius),
                 &bounds,
                 window,
             );
         }
+
+        if let Some(on_hover) = self.on_hover.clone() {
+            let active_index = self.active_index;
+            window.on_mouse_event(move |event: &MouseMoveEvent, phase, window, cx| {
+                if !phase.bubble() {
+                    return;
+                }
+
+                let index = if bounds.contains(&event.position) {
+                    let center = bounds.center();
+                    let position = event.position - center;
+                    arc_index_at(
+                        &arc_bounds,
+                        Point::new(position.x.as_f32(), position.y.as_f32()),
+                    )
+                } else {
+                    None
+                };
+
+                if index != active_index {
+                    on_hover(&index, window, cx);
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::PI;
+
+    use gpui::point;
+
+    use super::{ArcBounds, arc_index_at};
+
+    #[test]
+    fn test_arc_index_at() {
+        let arcs = [
+            ArcBounds {
+                index: 0,
+                start_angle: 0.,
+                end_angle: PI,
+                inner_radius: 10.,
+                outer_radius: 100.,
+            },
+            ArcBounds {
+                index: 2,
+                start_angle: PI,
+                end_angle: PI * 2.,
+                inner_radius: 10.,
+                outer_radius: 100.,
+            },
+        ];
+
+        // Right side, clockwise from 12 o'clock.
+        assert_eq!(arc_index_at(&arcs, point(50., 0.)), Some(0));
+        assert_eq!(arc_index_at(&arcs, point(0., 50.)), Some(2));
+        assert_eq!(arc_index_at(&arcs, point(-50., -10.)), Some(2));
+        assert_eq!(arc_index_at(&arcs, point(1., -50.)), Some(0));
+        // Inside the inner radius, or outside the outer radius.
+        assert_eq!(arc_index_at(&arcs, point(5., 0.)), None);
+        assert_eq!(arc_index_at(&arcs, point(200., 0.)), None);
     }
 }
@@ -0,0 +1,232 @@
+use std::rc::Rc;
+
+use gpui::{App, Background, Bounds, Hsla, Pixels, Window};
+use gpui_component_macros::IntoPlot;
+use num_traits::{Num, ToPrimitive};
+
+use crate::{
+    ActiveTheme,
+    plot::{
+        Plot, StrokeStyle,
+        scale::{Scale, ScaleBand, ScaleLinear, ScalePoint, Sealed},
+        shape::{Area, Bar, Line},
+    },
+};
+
+/// The vertical padding to keep the stroke inside the bounds.
+const PADDING: f32 = 2.;
+
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+enum SparklineKind {
+    #[default]
+    Line,
+    Area,
+    Bar,
+}
+
+/// A tiny chart without axis and grid to show the trend of the values, e.g.: in a stats card.
+///
+/// Unlike the other charts, the y scale is from the min to the max value, to show the trend.
+///
+/// It has no axis ticks and tooltip, use [`super::LineChart`], [`super::AreaChart`] or
+/// [`super::BarChart`] for the axis ticks, or wrap it in an element with a tooltip to show
+/// the values.
+///
+/// ```ignore
+/// SparklineChart::new(daily_tokens).y(|d| d.count).area().h_8().w_24()
+/// ```
+#[derive(IntoPlot)]
+pub struct SparklineChart<T, Y>
+where
+    T: 'static,
+    Y: Copy + PartialOrd + Num + ToPrimitive + Sealed + 'static,
+{
+    data: Vec<T>,
+    y: Option<Rc<dyn Fn(&T) -> Y>>,
+    kind: SparklineKind,
+    stroke: Option<Hsla>,
+    fill: Option<Background>,
+    stroke_style: StrokeStyle,
+    dot: bool,
+}
+
+impl<T, Y> SparklineChart<T, Y>
+where
+    Y: Copy + PartialOrd + Num + ToPrimitive + Sealed + 'static,
+{
+    /// Create a sparkline chart with the `data`, call [`SparklineChart::y`] to set the values.
+    pub fn new<I>(data: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            data: data.into_iter().collect(),
+            y: None,
+            kind: SparklineKind::default(),
+            stroke: None,
+            fill: None,
+            stroke_style: StrokeStyle::Linear,
+            dot: false,
+        }
+    }
+
+    /// Set the value of each item, nothing is drawn without it.
+    pub fn y(mut self, y: impl Fn(&T) -> Y + 'static) -> Self {
+        self.y = Some(Rc::new(y));
+        self
+    }
+
+    /// Draw the values as a line, this is the default.
+    pub fn line(mut self) -> Self {
+        self.kind = SparklineKind::Line;
+        self
+    }
+
+    /// Draw the values as a line with the area filled.
+    pub fn area(mut self) -> Self {
+        self.kind = SparklineKind::Area;
+        self
+    }
+
+    /// Draw the values as bars.
+    pub fn bar(mut self) -> Self {
+        self.kind = SparklineKind::Bar;
+        self
+    }
+
+    /// Set the color of the line and bars, default is the `chart_2` color of the theme.
+    pub fn stroke(mut self, stroke: impl Into<Hsla>) -> Self {
+        self.stroke = Some(stroke.into());
+        self
+    }
+
+    /// Set the fill of the area, default is the stroke color with opacity.
+    pub fn fill(mut self, fill: impl Into<Background>) -> Self {
+        self.fill = Some(fill.into());
+        self
+    }
+
+    /// Draw the line and area as a smooth curve, default is the straight segments.
+    pub fn natural(mut self) -> Self {
+        self.stroke_style = StrokeStyle::Natural;
+        self
+    }
+
+    /// Show a dot on the last value.
+    pub fn dot(mut self) -> Self {
+        self.dot = true;
+        self
+    }
+}
+
+/// Returns the domain of the y scale, the zero is added if all the values are the same,
+/// so the line is still drawn.
+fn y_domain<Y>(values: &[Y]) -> Vec<Y>
+where
+    Y: Copy + PartialOrd + Num,
+{
+    let mut domain = values.to_vec();
+    if let Some(first) = values.first() {
+        if values.iter().all(|v| v == first) {
+            domain.push(Y::zero());
+            if first.is_zero() {
+                domain.push(Y::one());
+            }
+        }
+    }
+    domain
+}
+
+impl<T, Y> Plot for SparklineChart<T, Y>
+where
+    Y: Copy + PartialOrd + Num + ToPrimitive + Sealed + 'static,
+{
+    fn paint(&mut self, bounds: Bounds<Pixels>, window: &mut Window, cx: &mut App) {
+        let Some(y_fn) = self.y.as_ref() else {
+            return;
+        };
+        if self.data.is_empty() {
+            return;
+        }
+
+        let width = bounds.size.width.as_f32();
+        let height = bounds.size.height.as_f32();
+        let values = self.data.iter().map(|d| y_fn(d)).collect::<Vec<_>>();
+        let points = values.iter().copied().enumerate().collect::<Vec<_>>();
+        let stroke = self.stroke.unwrap_or(cx.theme().chart_2);
+
+        if self.kind == SparklineKind::Bar {
+            // The bars start from 0.
+            let domain = values.iter().copied().chain(Some(Y::zero())).collect();
+            let y = ScaleLinear::new(domain, vec![height, 0.]);
+            let x = ScaleBand::new((0..points.len()).collect(), vec![0., width])
+                .padding_inner(0.2)
+                .padding_outer(0.);
+            let band_width = x.band_width();
+
+            Bar::new()
+                .data(&points)
+                .band_width(band_width)
+                .x(move |(ix, _)| x.tick(ix))
+                .y0(move |_| height)
+                .y1(move |(_, v)| y.tick(v))
+                .fill(move |_| stroke)
+                .paint(&bounds, window, cx);
+            return;
+        }
+
+        let y = ScaleLinear::new(y_domain(&values), vec![height - PADDING, PADDING]);
+        let x = ScalePoint::new((0..points.len()).collect(), vec![0., width]);
+
+        if self.kind == SparklineKind::Area {
+            let x = x.clone();
+            let y = y.clone();
+            Area::new()
+                .data(&points)
+                .x(move |(ix, _)| x.tick(ix))
+                .y0(height)
+                .y1(move |(_, v)| y.tick(v))
+                .stroke(stroke)
+                .stroke_style(self.stroke_style)
+                .fill(self.fill.unwrap_or(stroke.opacity(0.2).into()))
+                .paint(&bounds, window);
+        } else {
+            let x = x.clone();
+            let y = y.clone();
+            Line::new()
+                .data(&points)
+                .x(move |(ix, _)| x.tick(ix))
+                .y(move |(_, v)| y.tick(v))
+                .stroke(stroke)
+                .stroke_style(self.stroke_style)
+                .stroke_width(1.5)
+                .paint(&bounds, window);
+        }
+
+        if self.dot {
+            let last = points.last().copied();
+            Line::new()
+                .data(last)
+                .x(move |(ix, _)| x.tick(ix))
+                .y(move |(_, v)| y.tick(v))
+                .stroke(stroke)
+                .dot()
+                .dot_size(4.)
+                .dot_fill_color(stroke)
+                .paint(&bounds, window);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::y_domain;
+
+    #[test]
+    fn test_y_domain() {
+        assert_eq!(y_domain(&[1., 3., 2.]), vec![1., 3., 2.]);
+        assert_eq!(y_domain(&[5, 5]), vec![5, 5, 0]);
+        assert_eq!(y_domain(&[0, 0]), vec![0, 0, 0, 1]);
+        assert_eq!(y_domain::<f64>(&[]), Vec::<f64>::new());
+    }
+}
@@ -1,13 +1,19 @@
 use std::{rc::Rc, time::Duration};
 
 use crate::{
-    ActiveTheme, Disableable, FocusableExt, IconName, Selectable, Sizable, Size, StyledExt as _,
-    icon::IconNamed, text::Text, tooltip::ComponentTooltip, v_flex,
+    ActiveTheme, AxisExt as _, Disableable, FocusableExt, IconName, Selectable, Sizable, Size,
+    StyledExt as _,
+    animation::{UiTestMode, motion_duration},
+    h_flex,
+    icon::IconNamed,
+    text::Text,
+    tooltip::ComponentTooltip,
+    v_flex,
 };
 use gpui::{
-    Animation, AnimationExt, AnyElement, App, Div, ElementId, InteractiveElement, IntoElement,
-    ParentElement, RenderOnce, SharedString, StatefulInteractiveElement, StyleRefinement, Styled,
-    Window, div, prelude::FluentBuilder as _, px, relative, rems, svg,
+    Animation, AnimationExt, AnyElement, App, Axis, Div, ElementId, InteractiveElement,
+    IntoElement, ParentElement, RenderOnce, SharedString, StatefulInteractiveElement,
+    StyleRefinement, Styled, Window, div, prelude::FluentBuilder as _, px, relative, rems, svg,
 };
 
 /// A Checkbox element.
@@ -17,9 +23,13 @@ pub struct Checkbox {
     base: Div,
     style: StyleRefinement,
     label: Option<Text>,
+    description: Option<Text>,
     children: Vec<AnyElement>,
     checked: bool,
+    indeterminate: bool,
     disabled: bool,
+    error: bool,
+    card: bool,
     size: Size,
     tab_stop: bool,
     tab_index: isize,
@@ -35,9 +45,13 @@ impl Checkbox {
             base: div(),
             style: StyleRefinement::default(),
             label: None,
+            description: None,
             children: Vec::new(),
             checked: false,
+            indeterminate: false,
             disabled: false,
+            error: false,
+            card: false,
             size: Size::default(),
             on_click: None,
             tab_stop: true,
@@ -58,12 +72,44 @@ impl Checkbox {
         self
     }
 
+    /// Set the description for the checkbox, displayed below the label.
+    pub fn description(mut self, description: impl Into<Text>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
     /// Set the checked state for the checkbox.
     pub fn checked(mut self, checked: bool) -> Self {
         self.checked = checked;
         self
     }
 
+    /// Set the indeterminate state for the checkbox, default is false.
+    ///
+    /// The indeterminate state is displayed as a dash, it is used for a parent checkbox
+    /// that some of its children are checked. Clicking it will check the checkbox.
+    pub fn indeterminate(mut self, indeterminate: bool) -> Self {
+        self.indeterminate = indeterminate;
+        self
+    }
+
+    /// Set the error state for the checkbox, default is false.
+    ///
+    /// The border and the description will be displayed in danger color.
+    pub fn error(mut self, error: bool) -> Self {
+        self.error = error;
+        self
+    }
+
+    /// Set to use the card variant, default is false.
+    ///
+    /// The card variant wraps the checkbox, label and description in a bordered card,
+    /// the whole card is clickable, this is useful for option grids.
+    pub fn card(mut self, card: bool) -> Self {
+        self.card = card;
+        self
+    }
+
     /// Set the click handler for the checkbox.
     ///
     /// The `&bool` parameter indicates the new checked state after the click.
@@ -87,10 +133,11 @@ impl Checkbox {
     fn handle_click(
         on_click: &Option<Rc<dyn Fn(&bool, &mut Window, &mut App) + 'static>>,
         checked: bool,
+        indeterminate: bool,
         window: &mut Window,
         cx: &mut App,
     ) {
-        let new_checked = !checked;
+        let new_checked = indeterminate || !checked;
         if let Some(f) = on_click {
             (f)(&new_checked, window, cx);
         }
@@ -147,6 +194,18 @@ pub(crate) fn checkbox_check_icon(
     disabled: bool,
     window: &mut Window,
     cx: &mut App,
+) -> impl IntoElement {
+    checkbox_icon(id, size, IconName::Check, checked, disabled, window, cx)
+}
+
+fn checkbox_icon(
+    id: ElementId,
+    size: Size,
+    icon: IconName,
+    checked: bool,
+    disabled: bool,
+    window: &mut Window,
+    cx: &mut App,
 ) -> impl IntoElement {
     let toggle_state = window.use_keyed_state(id, cx, |_, _| checked);
     let color = if disabled {
@@ -168,16 +227,17 @@ pub(crate) fn checkbox_check_icon(
         })
         .text_color(color)
         .map(|this| match checked {
-            true => this.path(IconName::Check.path()),
+            true => this.path(icon.path()),
             _ => this,
         })
         .map(|this| {
             if !disabled && checked != *toggle_state.read(cx) {
                 let duration = Duration::from_secs_f64(0.25);
+                let delay = UiTestMode::delay(duration, cx);
                 cx.spawn({
                     let toggle_state = toggle_state.clone();
                     async move |cx| {
-                        cx.background_executor().timer(duration).await;
+                        cx.background_executor().timer(delay).await;
                         _ = toggle_state.update(cx, |this, _| *this = checked);
                     }
                 })
@@ -185,7 +245,7 @@ pub(crate) fn checkbox_check_icon(
 
                 this.with_animation(
                     ElementId::NamedInteger("toggle".into(), checked as u64),
-                    Animation::new(Duration::from_secs_f64(0.25)),
+                    Animation::new(motion_duration(duration, cx)),
                     move |this, delta| {
                         this.opacity(if checked { 1.0 * delta } else { 1.0 - delta })
                     },
@@ -200,6 +260,8 @@ pub(crate) fn checkbox_check_icon(
 impl RenderOnce for Checkbox {
     fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
         let checked = self.checked;
+        let indeterminate = self.indeterminate;
+        let filled = checked || indeterminate;
 
         let focus_handle = window
             .use_keyed_state(self.id.clone(), cx, |_, cx| cx.focus_handle())
@@ -207,8 +269,10 @@ impl RenderOnce for Checkbox {
             .clone();
         let is_focused = focus_handle.is_focused(window);
 
-        let border_color = if checked {
+        let border_color = if filled {
             cx.theme().primary
+        } else if self.error {
+            cx.theme().danger
         } else {
             cx.theme().input
         };
@@ -245,6 +309,27 @@ impl RenderOnce for Checkbox {
                     this.text_color(cx.theme().muted_foreground)
                 })
                 .rounded(cx.theme().radius * 0.5)
+                .when(self.card, |this| {
+                    let card_border = if filled && !self.disabled {
+                        cx.theme().primary
+                    } else if self.error {
+                        cx.theme().danger
+                    } else {
+                        cx.theme().border
+                    };
+                    let hover_bg = cx.theme().accent;
+
+                    this.p_3()
+                        .border_1()
+                        .border_color(card_border)
+                        .rounded(cx.theme().radius)
+                        .when(filled && !self.disabled, |this| {
+                            this.bg(cx.theme().primary.opacity(0.05))
+                        })
+                        .when(!filled && !self.disabled, |this| {
+                            this.hover(|this| this.bg(hover_bg))
+                        })
+                })
                 .focus_ring(is_focused, px(2.), window, cx)
                 .refine_style(&self.style)
                 .child(
@@ -262,45 +347,68 @@ impl RenderOnce for Checkbox {
                         .border_color(color)
                         .rounded(radius)
                         .when(cx.theme().shadow && !self.disabled, |this| this.shadow_xs())
-                        .map(|this| match checked {
+                        .map(|this| match filled {
                             false => this.bg(cx.theme().input_background()),
                             _ => this.bg(color),
                         })
-                        .child(checkbox_check_icon(
+                        .child(checkbox_icon(
                             self.id,
                             self.size,
-                            checked,
+                            if indeterminate {
+                                IconName::Minus
+                            } else {
+                                IconName::Check
+                            },
+                            filled,
                             self.disabled,
                             window,
                             cx,
                         )),
                 )
-                .when(self.label.is_some() || !self.children.is_empty(), |this| {
-                    this.child(
-                        v_flex()
-                            .flex_1()
-                            .overflow_hidden()
-                            .line_height(relative(1.2))
-                            .gap_1()
-                            .map(|this| {
-                                if let Some(label) = self.label {
+                .when(
+                    self.label.is_some() || self.description.is_some() || !self.children.is_empty(),
+                    |this| {
+                        this.child(
+                            v_flex()
+                                .flex_1()
+                                .overflow_hidden()
+                                .line_height(relative(1.2))
+                                .gap_1()
+                                .map(|this| {
+                                    if let Some(label) = self.label {
+                                        this.child(
+                                            div()
+                                                .size_full()
+                                                .text_color(cx.theme().foreground)
+                                                .when(self.disabled, |this| {
+                                                    this.text_color(cx.theme().muted_foreground)
+                                                })
+                                                .line_height(relative(1.))
+                                                .child(label),
+                                        )
+                                    } else {
+                                        this
+                                    }
+                                })
+                                .when_some(self.description, |this, description| {
                                     this.child(
                                         div()
-                                            .size_full()
-                                            .text_color(cx.theme().foreground)
-                                            .when(self.disabled, |this| {
-                                                this.text_color(cx.theme().muted_foreground)
+                                            .map(|this| match self.size {
+                                                Size::XSmall | Size::Small => this.text_xs(),
+                                                _ => this.text_sm(),
                                             })
-                                            .line_height(relative(1.))
-                                            .child(label),
+                                            .text_color(if self.error {
+                                                cx.theme().danger
+                                            } else {
+                                                cx.theme().muted_foreground
+                                            })
+                                            .child(description),
                                     )
-                                } else {
-                                    this
-                                }
-                            })
-                            .children(self.children),
-                    )
-                })
+                                })
+                                .children(self.children),
+                        )
+                    },
+                )
                 .on_mouse_down(gpui::MouseButton::Left, |_, window, _| {
                     // Avoid focus on mouse down.
                     window.prevent_default();
@@ -310,7 +418,7 @@ impl RenderOnce for Checkbox {
                         let on_click = self.on_click.clone();
                         move |_, window, cx| {
                             window.prevent_default();
-                            Self::handle_click(&on_click, checked, window, cx);
+                            Self::handle_click(&on_click, checked, indeterminate, window, cx);
                         }
                     })
                 })
@@ -318,3 +426,194 @@ impl RenderOnce for Checkbox {
         )
     }
 }
+
+/// A Checkbox group element, to manage a group of checkboxes with the selected indices.
+#[derive(IntoElement)]
+pub struct CheckboxGroup {
+    id: ElementId,
+    style: StyleRefinement,
+    checkboxes: Vec<Checkbox>,
+    layout: Axis,
+    selected_indices: Vec<usize>,
+    disabled: bool,
+    on_click: Option<Rc<dyn Fn(&Vec<usize>, &mut Window, &mut App) + 'static>>,
+}
+
+impl CheckboxGroup {
+    fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            style: StyleRefinement::default().flex_1(),
+            checkboxes: vec![],
+            layout: Axis::Vertical,
+            selected_indices: vec![],
+            disabled: false,
+            on_click: None,
+        }
+    }
+
+    /// Create a new Checkbox group with default Vertical layout.
+    pub fn vertical(id: impl Into<ElementId>) -> Self {
+        Self::new(id)
+    }
+
+    /// Create a new Checkbox group with Horizontal layout.
+    pub fn horizontal(id: impl Into<ElementId>) -> Self {
+        Self::new(id).layout(Axis::Horizontal)
+    }
+
+    /// Set the layout of the Checkbox group. Default is `Axis::Vertical`.
+    pub fn layout(mut self, layout: Axis) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Add on_click handler when the selected indices change.
+    ///
+    /// The `&Vec<usize>` parameter is the new selected indices in ascending order.
+    pub fn on_click(
+        mut self,
+        handler: impl Fn(&Vec<usize>, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_click = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the selected indices.
+    pub fn selected_indices(mut self, indices: impl IntoIterator<Item = usize>) -> Self {
+        self.selected_indices = indices.into_iter().collect();
+        self
+    }
+
+    /// Set the disabled state.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Add a child Checkbox element.
+    pub fn child(mut self, child: impl Into<Checkbox>) -> Self {
+        self.checkboxes.push(child.into());
+        self
+    }
+
+    /// Add multiple child Checkbox elements.
+    pub fn children(mut self, children: impl IntoIterator<Item = impl Into<Checkbox>>) -> Self {
+        self.checkboxes.extend(children.into_iter().map(Into::into));
+        self
+    }
+}
+
+impl Styled for CheckboxGroup {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl From<&'static str> for Checkbox {
+    fn from(label: &'static str) -> Self {
+        Self::new(label).label(label)
+    }
+}
+
+impl From<SharedString> for Checkbox {
+    fn from(label: SharedString) -> Self {
+        Self::new(label.clone()).label(label)
+    }
+}
+
+impl From<String> for Checkbox {
+    fn from(label: String) -> Self {
+        Self::new(SharedString::from(label.clone())).label(SharedString::from(label))
+    }
+}
+
+/// Returns the new selected indices after the checkbox at `ix` changed to `checked`.
+fn toggle_selected_index(selected: &[usize], ix: usize, checked: bool) -> Vec<usize> {
+    let mut indices: Vec<usize> = selected.iter().copied().filter(|i| *i != ix).collect();
+    if checked {
+        indices.push(ix);
+    }
+    indices.sort_unstable();
+    indices
+}
+
+impl RenderOnce for CheckboxGroup {
+    fn render(self, _: &mut Window, _: &mut App) -> impl IntoElement {
+        let on_click = self.on_click;
+        let disabled = self.disabled;
+        let selected_indices: Rc<[usize]> = self.selected_indices.into();
+
+        let base = if self.layout.is_vertical() {
+            v_flex()
+        } else {
+            h_flex().w_full().flex_wrap()
+        };
+
+        let mut container = div().id(self.id);
+        *container.style() = self.style;
+
+        container.child(
+            base.gap_3()
+                .children(
+                    self.checkboxes
+                        .into_iter()
+                        .enumerate()
+                        .map(|(ix, mut checkbox)| {
+                            let checked = selected_indices.contains(&ix);
+                            let disabled = disabled || checkbox.disabled;
+
+                            checkbox.id = ix.into();
+                            checkbox.disabled(disabled).checked(checked).when_some(
+                                on_click.clone(),
+                                |this, on_click| {
+                                    let selected_indices = selected_indices.clone();
+                                    this.on_click(move |checked, window, cx| {
+                                        let indices =
+                                            toggle_selected_index(&selected_indices, ix, *checked);
+                                        on_click(&indices, window, cx);
+                                    })
+                                },
+                            )
+                        }),
+                ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checkbox_builder() {
+        let checkbox = Checkbox::new("checkbox")
+            .label("Accept terms")
+            .description("You agree to our Terms of Service.")
+            .checked(true)
+            .indeterminate(true)
+            .error(true)
+            .card(true)
+            .disabled(true)
+            .small()
+            .on_click(|_, _, _| {});
+
+        assert!(checkbox.label.is_some());
+        assert!(checkbox.description.is_some());
+        assert!(checkbox.checked);
+        assert!(checkbox.indeterminate);
+        assert!(checkbox.error);
+        assert!(checkbox.card);
+        assert!(checkbox.disabled);
+        assert_eq!(checkbox.size, Size::Small);
+        assert!(checkbox.on_click.is_some());
+    }
+
+    #[test]
+    fn test_toggle_selected_index() {
+        assert_eq!(toggle_selected_index(&[0, 2], 1, true), vec![0, 1, 2]);
+        assert_eq!(toggle_selected_index(&[0, 1, 2], 1, false), vec![0, 2]);
+        assert_eq!(toggle_selected_index(&[0], 0, true), vec![0]);
+        assert_eq!(toggle_selected_index(&[], 3, false), Vec::<usize>::new());
+    }
+}
@@ -1,23 +1,135 @@
-use std::{rc::Rc, time::Duration};
+use std::{rc::Rc, sync::Arc, time::Duration};
 
 use gpui::{
-    prelude::FluentBuilder, App, ClipboardItem, ElementId, IntoElement, RenderOnce, SharedString,
-    Window,
+    prelude::FluentBuilder, App, ClipboardEntry, ClipboardItem, ElementId, Image, ImageFormat,
+    IntoElement, RenderOnce, SharedString, Window,
 };
+use rust_i18n::t;
 
 use crate::{
     button::{Button, ButtonVariants as _},
-    IconName, Sizable as _,
+    IconName, Sizable, Size,
 };
 
-/// An element that provides clipboard copy functionality.
+/// The duration to show the copied feedback.
+const COPIED_DURATION: Duration = Duration::from_secs(2);
+
+/// The JSON metadata of the clipboard text to mark it as Markdown.
+const MARKDOWN_METADATA: &str = "markdown";
+
+/// Copy the plain `text` to the clipboard.
+pub fn copy_text(text: impl Into<SharedString>, cx: &mut App) {
+    ClipboardContent::Text(text.into()).write(cx);
+}
+
+/// Copy the Markdown `source` to the clipboard, see [`ClipboardContent::Markdown`].
+pub fn copy_markdown(source: impl Into<SharedString>, cx: &mut App) {
+    ClipboardContent::Markdown(source.into()).write(cx);
+}
+
+/// Copy the image `bytes` in the `format` (e.g.: PNG) to the clipboard.
+pub fn copy_image(format: ImageFormat, bytes: Vec<u8>, cx: &mut App) {
+    ClipboardContent::image(format, bytes).write(cx);
+}
+
+/// The content to copy to the clipboard.
+#[derive(Clone)]
+pub enum ClipboardContent {
+    /// The plain text.
+    Text(SharedString),
+    /// The Markdown source.
+    ///
+    /// It is written as text, so it can be pasted to other apps, with a metadata
+    /// to be read back as Markdown by [`ClipboardContent::read`].
+    Markdown(SharedString),
+    /// The image, e.g.: a PNG screenshot.
+    Image(Arc<Image>),
+}
+
+impl ClipboardContent {
+    /// Create an image content with the `bytes` in the `format`.
+    pub fn image(format: ImageFormat, bytes: Vec<u8>) -> Self {
+        Self::Image(Arc::new(Image::from_bytes(format, bytes)))
+    }
+
+    /// Returns the text of the content, `None` for the image.
+    pub fn text(&self) -> Option<&SharedString> {
+        match self {
+            Self::Text(text) | Self::Markdown(text) => Some(text),
+            Self::Image(_) => None,
+        }
+    }
+
+    /// Write the content to the clipboard.
+    pub fn write(&self, cx: &mut App) {
+        let item = match self {
+            Self::Text(text) => ClipboardItem::new_string(text.to_string()),
+            Self::Markdown(source) => {
+                ClipboardItem::new_string_with_json_metadata(source.to_string(), MARKDOWN_METADATA)
+            }
+            Self::Image(image) => ClipboardItem::new_image(image),
+        };
+        cx.write_to_clipboard(item);
+    }
+
+    /// Read the content from the clipboard, `None` if the clipboard is empty or not supported.
+    pub fn read(cx: &App) -> Option<Self> {
+        let item = cx.read_from_clipboard()?;
+        item.entries().iter().find_map(|entry| match entry {
+            ClipboardEntry::String(string) => {
+                let text = SharedString::from(string.text().clone());
+                if string.metadata_json::<String>().as_deref() == Some(MARKDOWN_METADATA) {
+                    Some(Self::Markdown(text))
+                } else {
+                    Some(Self::Text(text))
+                }
+            }
+            ClipboardEntry::Image(image) => Some(Self::Image(Arc::new(image.clone()))),
+        })
+    }
+}
+
+impl From<&'static str> for ClipboardContent {
+    fn from(text: &'static str) -> Self {
+        Self::Text(text.into())
+    }
+}
+
+impl From<String> for ClipboardContent {
+    fn from(text: String) -> Self {
+        Self::Text(text.into())
+    }
+}
+
+impl From<SharedString> for ClipboardContent {
+    fn from(text: SharedString) -> Self {
+        Self::Text(text)
+    }
+}
+
+/// A button to copy the [`ClipboardContent`] to the clipboard, with the "Copied!" feedback,
+/// e.g.: for input fields, code blocks, diff hunks and chat messages.
+///
+/// After copied, the icon is changed to a check mark, and the label (or the tooltip if no label)
+/// is changed to "Copied!" for 2 seconds.
+///
+/// ```ignore
+/// Clipboard::new("copy-url").value(url.clone())
+///
+/// Clipboard::new("copy-message")
+///     .label("Copy")
+///     .content(ClipboardContent::Markdown(message.clone()))
+/// ```
 #[derive(IntoElement)]
 pub struct Clipboard {
     id: ElementId,
-    value: SharedString,
-    value_fn: Option<Rc<dyn Fn(&mut Window, &mut App) -> SharedString>>,
+    content: ClipboardContent,
+    content_fn: Option<Rc<dyn Fn(&mut Window, &mut App) -> ClipboardContent>>,
     on_copied: Option<Rc<dyn Fn(SharedString, &mut Window, &mut App)>>,
+    label: Option<SharedString>,
+    copied_label: Option<SharedString>,
     tooltip_text: Option<SharedString>,
+    size: Size,
 }
 
 impl Clipboard {
@@ -25,10 +137,13 @@ impl Clipboard {
     pub fn new(id: impl Into<ElementId>) -> Self {
         Self {
             id: id.into(),
-            value: SharedString::default(),
-            value_fn: None,
+            content: ClipboardContent::Text(SharedString::default()),
+            content_fn: None,
             on_copied: None,
+            label: None,
+            copied_label: None,
             tooltip_text: None,
+            size: Size::XSmall,
         }
     }
 
@@ -38,9 +153,21 @@ impl Clipboard {
         self
     }
 
+    /// Set the label of the button, default is `None` to show the icon only.
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set the feedback text after copied, default is "Copied!".
+    pub fn copied_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.copied_label = Some(label.into());
+        self
+    }
+
     /// Set the value for copying to the clipboard. Default is an empty string.
     pub fn value(mut self, value: impl Into<SharedString>) -> Self {
-        self.value = value.into();
+        self.content = ClipboardContent::Text(value.into());
         self
     }
 
@@ -51,11 +178,32 @@ impl Clipboard {
         mut self,
         value: impl Fn(&mut Window, &mut App) -> SharedString + 'static,
     ) -> Self {
-        self.value_fn = Some(Rc::new(value));
+        self.content_fn = Some(Rc::new(move |window: &mut Window, cx: &mut App| {
+            ClipboardContent::Text(value(window, cx))
+        }));
+        self
+    }
+
+    /// Set the content to copy, e.g.: a text, Markdown or image.
+    pub fn content(mut self, content: impl Into<ClipboardContent>) -> Self {
+        self.content = content.into();
+        self
+    }
+
+    /// Set the content to copy by the result of the function when clicked.
+    ///
+    /// This is useful when the content is expensive to compute, e.g.: a long chat message.
+    pub fn content_fn(
+        mut self,
+        content: impl Fn(&mut Window, &mut App) -> ClipboardContent + 'static,
+    ) -> Self {
+        self.content_fn = Some(Rc::new(content));
         self
     }
 
     /// Set a callback to be invoked when the content is copied to the clipboard.
+    ///
+    /// The callback receives the copied text, it is empty for the image.
     pub fn on_copied<F>(mut self, handler: F) -> Self
     where
         F: Fn(SharedString, &mut Window, &mut App) + 'static,
@@ -65,35 +213,52 @@ impl Clipboard {
     }
 }
 
+impl Sizable for Clipboard {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
 impl RenderOnce for Clipboard {
     fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
         let state = window.use_keyed_state(self.id.clone(), cx, |_, _| ClipboardState::default());
-
-        let value = self.value.clone();
-        let clipboard_id = self.id.clone();
         let copied = state.read(cx).copied;
-        let value_fn = self.value_fn.clone();
+        let copied_label = self
+            .copied_label
+            .unwrap_or_else(|| t!("Clipboard.copied").into());
+        let has_label = self.label.is_some();
+        let tooltip = if copied && !has_label {
+            Some(copied_label.clone())
+        } else {
+            self.tooltip_text
+        };
 
-        Button::new(clipboard_id)
+        Button::new(self.id)
             .icon(if copied {
                 IconName::Check
             } else {
                 IconName::Copy
             })
             .ghost()
-            .xsmall()
-            .when_some(self.tooltip_text, |this, text| this.tooltip(text))
+            .with_size(self.size)
+            .when_some(self.label, |this, label| {
+                this.label(if copied { copied_label } else { label })
+            })
+            .when_some(tooltip, |this, text| this.tooltip(text))
             .when(!copied, |this| {
                 this.on_click({
-                    let state = state.clone();
-                    let on_copied = self.on_copied.clone();
+                    let content = self.content;
+                    let content_fn = self.content_fn;
+                    let on_copied = self.on_copied;
                     move |_, window, cx| {
                         cx.stop_propagation();
-                        let value = value_fn
+                        let content = content_fn
                             .as_ref()
                             .map(|f| f(window, cx))
-                            .unwrap_or_else(|| value.clone());
-                        cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+                            .unwrap_or_else(|| content.clone());
+
+                        content.write(cx);
                         state.update(cx, |state, cx| {
                             state.copied = true;
                             cx.notify();
@@ -101,7 +266,7 @@ impl RenderOnce for Clipboard {
 
                         let state = state.clone();
                         cx.spawn(async move |cx| {
-                            cx.background_executor().timer(Duration::from_secs(2)).await;
+                            cx.background_executor().timer(COPIED_DURATION).await;
                             _ = state.update(cx, |state, cx| {
                                 state.copied = false;
                                 cx.notify();
@@ -110,7 +275,7 @@ impl RenderOnce for Clipboard {
                         .detach();
 
                         if let Some(on_copied) = &on_copied {
-                            on_copied(value.clone(), window, cx);
+                            on_copied(content.text().cloned().unwrap_or_default(), window, cx);
                         }
                     }
                 })
@@ -123,3 +288,50 @@ impl RenderOnce for Clipboard {
 struct ClipboardState {
     copied: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use gpui::{ImageFormat, SharedString, TestAppContext};
+
+    use super::{copy_markdown, copy_text, ClipboardContent};
+
+    #[test]
+    fn test_clipboard_content_from() {
+        let hello = SharedString::from("Hello");
+        for content in [
+            ClipboardContent::from("Hello"),
+            ClipboardContent::from(String::from("Hello")),
+            ClipboardContent::from(hello.clone()),
+        ] {
+            assert!(matches!(content, ClipboardContent::Text(_)));
+            assert_eq!(content.text(), Some(&hello));
+        }
+    }
+
+    #[test]
+    fn test_clipboard_content_text() {
+        assert_eq!(
+            ClipboardContent::Markdown("**Hello**".into()).text(),
+            Some(&SharedString::from("**Hello**"))
+        );
+        assert_eq!(
+            ClipboardContent::image(ImageFormat::Png, vec![1, 2, 3]).text(),
+            None
+        );
+    }
+
+    #[gpui::test]
+    fn test_clipboard_content_read(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            copy_text("Hello", cx);
+            let content = ClipboardContent::read(cx).unwrap();
+            assert!(matches!(content, ClipboardContent::Text(_)));
+            assert_eq!(content.text(), Some(&SharedString::from("Hello")));
+
+            copy_markdown("**Hello**", cx);
+            let content = ClipboardContent::read(cx).unwrap();
+            assert!(matches!(content, ClipboardContent::Markdown(_)));
+            assert_eq!(content.text(), Some(&SharedString::from("**Hello**")));
+        });
+    }
+}
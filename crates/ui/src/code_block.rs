@@ -0,0 +1,377 @@
+//! A standalone code block with syntax highlighting, e.g.: in the Markdown, the tool call
+//! output or the diff preview.
+use std::{ops::Range, sync::Arc};
+
+use gpui::{
+    AnyElement, App, ElementId, HighlightStyle, InteractiveElement as _, IntoElement,
+    ParentElement, RenderOnce, SharedString, StatefulInteractiveElement as _, StyleRefinement,
+    Styled, StyledText, Window, div, prelude::FluentBuilder as _, px, rems,
+};
+use ropey::Rope;
+use rust_i18n::t;
+
+use crate::{
+    ActiveTheme as _, IconName, Selectable as _, StyledExt as _,
+    button::{Button, ButtonVariants as _},
+    clipboard::Clipboard,
+    h_flex,
+    highlighter::{HighlightTheme, SyntaxHighlighter},
+    v_flex,
+};
+
+#[derive(Default)]
+struct CodeBlockState {
+    /// The code, language and theme of the cached styles.
+    key: Option<(SharedString, Option<SharedString>, Arc<HighlightTheme>)>,
+    styles: Vec<(Range<usize>, HighlightStyle)>,
+    /// The soft wrap toggled by the user, `None` to use the default.
+    soft_wrap: Option<bool>,
+    expanded: bool,
+}
+
+/// A code block with syntax highlighting, line numbers, soft wrap toggle and copy button.
+///
+/// ```ignore
+/// CodeBlock::new("example", code)
+///     .lang("rust")
+///     .line_numbers(true)
+///     .highlight_lines([3, 4])
+///     .max_lines(20)
+/// ```
+#[derive(IntoElement)]
+pub struct CodeBlock {
+    id: ElementId,
+    style: StyleRefinement,
+    code: SharedString,
+    lang: Option<SharedString>,
+    line_numbers: bool,
+    highlight_lines: Vec<usize>,
+    soft_wrap: bool,
+    wrap_toggle: bool,
+    copyable: bool,
+    max_lines: Option<usize>,
+    actions: Option<AnyElement>,
+    /// The custom content to render instead of the lines, e.g.: the selectable text of TextView.
+    content: Option<AnyElement>,
+}
+
+impl CodeBlock {
+    /// Create a new code block, the `id` is used to keep the soft wrap and expanded state.
+    pub fn new(id: impl Into<ElementId>, code: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            style: StyleRefinement::default(),
+            code: code.into(),
+            lang: None,
+            line_numbers: false,
+            highlight_lines: vec![],
+            soft_wrap: true,
+            wrap_toggle: true,
+            copyable: true,
+            max_lines: None,
+            actions: None,
+            content: None,
+        }
+    }
+
+    /// Set the language to highlight the code, e.g.: "rust", default is plain text.
+    pub fn lang(mut self, lang: impl Into<SharedString>) -> Self {
+        self.lang = Some(lang.into());
+        self
+    }
+
+    /// Set to show the line numbers, default: false.
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Set the lines to highlight, the line numbers are 1-based.
+    pub fn highlight_lines(mut self, lines: impl IntoIterator<Item = usize>) -> Self {
+        self.highlight_lines = lines.into_iter().collect();
+        self
+    }
+
+    /// Set to soft wrap the long lines by default, default: true.
+    ///
+    /// If false, the code block can be scrolled horizontally.
+    pub fn soft_wrap(mut self, soft_wrap: bool) -> Self {
+        self.soft_wrap = soft_wrap;
+        self
+    }
+
+    /// Set to show the button to toggle the soft wrap, default: true.
+    pub fn wrap_toggle(mut self, wrap_toggle: bool) -> Self {
+        self.wrap_toggle = wrap_toggle;
+        self
+    }
+
+    /// Set to show the copy button, default: true.
+    pub fn copyable(mut self, copyable: bool) -> Self {
+        self.copyable = copyable;
+        self
+    }
+
+    /// Set the max lines to show, the rest lines are collapsed with a button to expand.
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = Some(max_lines);
+        self
+    }
+
+    /// Set the extra actions to show before the copy button, e.g.: a run button.
+    pub fn actions(mut self, actions: impl IntoElement) -> Self {
+        self.actions = Some(actions.into_any_element());
+        self
+    }
+
+    /// Render the `content` instead of the highlighted lines.
+    pub(crate) fn content(mut self, content: impl IntoElement) -> Self {
+        self.content = Some(content.into_any_element());
+        self
+    }
+}
+
+impl Styled for CodeBlock {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+/// Returns the byte ranges of the lines in the `code`, without the line endings.
+///
+/// The last empty line after the trailing line ending is ignored.
+fn line_ranges(code: &str) -> Vec<Range<usize>> {
+    let mut ranges = vec![];
+    let mut start = 0;
+    for (ix, _) in code.match_indices('\n') {
+        let end = if code[..ix].ends_with('\r') {
+            ix - 1
+        } else {
+            ix
+        };
+        ranges.push(start..end);
+        start = ix + 1;
+    }
+    if start < code.len() || ranges.is_empty() {
+        ranges.push(start..code.len());
+    }
+    ranges
+}
+
+/// Returns the `styles` in the `line` range, relative to the start of the line.
+fn line_styles(
+    styles: &[(Range<usize>, HighlightStyle)],
+    line: &Range<usize>,
+) -> Vec<(Range<usize>, HighlightStyle)> {
+    styles
+        .iter()
+        .filter(|(range, _)| range.start < line.end && range.end > line.start)
+        .map(|(range, style)| {
+            let start = range.start.max(line.start) - line.start;
+            let end = range.end.min(line.end) - line.start;
+            (start..end, *style)
+        })
+        .collect()
+}
+
+fn highlight(
+    code: &str,
+    lang: &str,
+    theme: &HighlightTheme,
+) -> Vec<(Range<usize>, HighlightStyle)> {
+    let mut highlighter = SyntaxHighlighter::new(lang);
+    highlighter.update(None, &Rope::from_str(code), None);
+    highlighter.styles(&(0..code.len()), theme)
+}
+
+impl RenderOnce for CodeBlock {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let state = window.use_keyed_state(self.id.clone(), cx, |_, _| CodeBlockState::default());
+        let soft_wrap = state.read(cx).soft_wrap.unwrap_or(self.soft_wrap);
+        let expanded = state.read(cx).expanded;
+
+        let code = self.code.clone();
+        let lines = match self.content {
+            Some(content) => content,
+            None => {
+                let theme = cx.theme().highlight_theme.clone();
+                let key = (code.clone(), self.lang.clone(), theme);
+                let changed = state
+                    .read(cx)
+                    .key
+                    .as_ref()
+                    .is_none_or(|(code, lang, theme)| {
+                        code != &key.0 || lang != &key.1 || !Arc::ptr_eq(theme, &key.2)
+                    });
+                if changed {
+                    let styles = match &key.1 {
+                        Some(lang) => highlight(&key.0, lang, &key.2),
+                        None => vec![],
+                    };
+                    state.update(cx, |state, _| {
+                        state.key = Some(key);
+                        state.styles = styles;
+                    });
+                }
+
+                let ranges = line_ranges(&code);
+                let total_lines = ranges.len();
+                let visible_lines = match self.max_lines {
+                    Some(max_lines) if !expanded => max_lines.min(total_lines),
+                    _ => total_lines,
+                };
+                let number_width = rems(0.6 * total_lines.to_string().len() as f32);
+                let styles = &state.read(cx).styles;
+
+                v_flex()
+                    .children(ranges.into_iter().take(visible_lines).enumerate().map(
+                        |(ix, range)| {
+                            let line_number = ix + 1;
+                            let highlighted = self.highlight_lines.contains(&line_number);
+                            let line_styles = line_styles(styles, &range);
+                            let text: SharedString = if range.is_empty() {
+                                " ".into()
+                            } else {
+                                code[range].to_string().into()
+                            };
+
+                            h_flex()
+                                .items_start()
+                                .when(highlighted, |this| {
+                                    this.bg(cx.theme().accent).rounded(px(2.))
+                                })
+                                .when(self.line_numbers, |this| {
+                                    this.child(
+                                        div()
+                                            .flex_none()
+                                            .min_w(number_width)
+                                            .mr_3()
+                                            .text_right()
+                                            .text_color(cx.theme().muted_foreground)
+                                            .child(line_number.to_string()),
+                                    )
+                                })
+                                .child(
+                                    div()
+                                        .map(|this| {
+                                            if soft_wrap {
+                                                this.flex_1().min_w_0()
+                                            } else {
+                                                this.flex_none().whitespace_nowrap()
+                                            }
+                                        })
+                                        .child(StyledText::new(text).with_highlights(line_styles)),
+                                )
+                        },
+                    ))
+                    .when(visible_lines < total_lines || expanded, |this| {
+                        this.child(
+                            h_flex().justify_center().pt_2().child(
+                                Button::new("expand")
+                                    .ghost()
+                                    .xsmall()
+                                    .icon(if expanded {
+                                        IconName::ChevronUp
+                                    } else {
+                                        IconName::ChevronDown
+                                    })
+                                    .label(if expanded {
+                                        t!("CodeBlock.show_less")
+                                    } else {
+                                        t!("CodeBlock.show_more")
+                                    })
+                                    .on_click({
+                                        let state = state.clone();
+                                        move |_, _, cx| {
+                                            state.update(cx, |state, cx| {
+                                                state.expanded = !state.expanded;
+                                                cx.notify();
+                                            });
+                                        }
+                                    }),
+                            ),
+                        )
+                    })
+                    .into_any_element()
+            }
+        };
+
+        let has_toolbar = self.actions.is_some() || self.wrap_toggle || self.copyable;
+
+        div()
+            .id(self.id)
+            .relative()
+            .p_3()
+            .rounded(cx.theme().radius)
+            .bg(cx.theme().muted)
+            .font_family(cx.theme().mono_font_family.clone())
+            .text_size(cx.theme().mono_font_size)
+            .when(!soft_wrap, |this| this.overflow_x_scroll())
+            .refine_style(&self.style)
+            .child(lines)
+            .when(has_toolbar, |this| {
+                this.child(
+                    h_flex()
+                        .id("actions")
+                        .absolute()
+                        .top_2()
+                        .right_2()
+                        .gap_1()
+                        .bg(cx.theme().muted)
+                        .rounded(cx.theme().radius)
+                        .children(self.actions)
+                        .when(self.wrap_toggle, |this| {
+                            this.child(
+                                Button::new("soft-wrap")
+                                    .icon(IconName::TextWrap)
+                                    .ghost()
+                                    .xsmall()
+                                    .selected(soft_wrap)
+                                    .tooltip(t!("CodeBlock.soft_wrap"))
+                                    .on_click(move |_, _, cx| {
+                                        cx.stop_propagation();
+                                        state.update(cx, |state, cx| {
+                                            state.soft_wrap = Some(!soft_wrap);
+                                            cx.notify();
+                                        });
+                                    }),
+                            )
+                        })
+                        .when(self.copyable, |this| {
+                            this.child(
+                                Clipboard::new("copy")
+                                    .tooltip(t!("Clipboard.copy"))
+                                    .value(code),
+                            )
+                        }),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{HighlightStyle, black};
+
+    use super::{line_ranges, line_styles};
+
+    #[test]
+    fn test_line_ranges() {
+        assert_eq!(line_ranges(""), vec![0..0]);
+        assert_eq!(line_ranges("a"), vec![0..1]);
+        assert_eq!(line_ranges("a\nbc\n"), vec![0..1, 2..4]);
+        assert_eq!(line_ranges("a\r\n\nbc"), vec![0..1, 3..3, 4..6]);
+    }
+
+    #[test]
+    fn test_line_styles() {
+        let style = HighlightStyle::color(black());
+        let styles = vec![(0..3, style), (3..8, style), (9..10, style)];
+        assert_eq!(line_styles(&styles, &(4..9)), vec![(0..4, style)]);
+        assert_eq!(
+            line_styles(&styles, &(2..10)),
+            vec![(0..1, style), (1..6, style), (7..8, style)]
+        );
+        assert_eq!(line_styles(&styles, &(8..9)), vec![]);
+    }
+}
@@ -1,14 +1,17 @@
 use gpui::{
-    Anchor, App, AppContext, Context, Div, ElementId, Entity, EventEmitter, FocusHandle, Focusable,
-    Hsla, InteractiveElement as _, IntoElement, KeyBinding, ParentElement, Render, RenderOnce,
-    SharedString, Stateful, StatefulInteractiveElement as _, StyleRefinement, Styled, Subscription,
-    TextAlign, Window, div, hsla, linear_color_stop, linear_gradient, prelude::FluentBuilder as _,
+    Anchor, App, AppContext, Bounds, Context, Div, DragMoveEvent, ElementId, Empty, Entity,
+    EntityId, EventEmitter, FocusHandle, Focusable, Hsla, InteractiveElement as _, IntoElement,
+    KeyBinding, MouseButton, MouseDownEvent, ParentElement, Pixels, Point, Render, RenderOnce,
+    Rgba, SharedString, Stateful, StatefulInteractiveElement as _, StyleRefinement, Styled,
+    Subscription, TextAlign, Window, div, hsla, linear_color_stop, linear_gradient,
+    prelude::FluentBuilder as _, px, relative,
 };
 use rust_i18n::t;
 
 use crate::{
-    ActiveTheme as _, Colorize as _, Icon, Selectable, Sizable, Size, StyleSized,
+    ActiveTheme as _, Colorize as _, ElementExt as _, Icon, Selectable, Sizable, Size, StyleSized,
     actions::Confirm,
+    button::{Button, ButtonVariants as _},
     divider::Divider,
     h_flex,
     input::{Input, InputEvent, InputState},
@@ -28,12 +31,167 @@ pub(crate) fn init(cx: &mut App) {
     )])
 }
 
+/// The max number of recent colors to keep.
+const MAX_RECENT_COLORS: usize = 10;
+
 /// Events emitted by the [`ColorPicker`].
 #[derive(Clone)]
 pub enum ColorPickerEvent {
     Change(Option<Hsla>),
 }
 
+/// The text format of the color value in the [`ColorPicker`] input.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColorFormat {
+    /// e.g.: `#3B82F6`, `#3B82F680`
+    #[default]
+    Hex,
+    /// e.g.: `rgb(59, 130, 246)`, `rgba(59, 130, 246, 0.5)`
+    Rgb,
+    /// e.g.: `hsl(217, 91%, 60%)`, `hsla(217, 91%, 60%, 0.5)`
+    Hsl,
+}
+
+impl ColorFormat {
+    fn label(&self) -> &'static str {
+        match self {
+            ColorFormat::Hex => "HEX",
+            ColorFormat::Rgb => "RGB",
+            ColorFormat::Hsl => "HSL",
+        }
+    }
+
+    fn next(&self) -> Self {
+        match self {
+            ColorFormat::Hex => ColorFormat::Rgb,
+            ColorFormat::Rgb => ColorFormat::Hsl,
+            ColorFormat::Hsl => ColorFormat::Hex,
+        }
+    }
+
+    fn pattern(&self) -> regex::Regex {
+        match self {
+            ColorFormat::Hex => regex::Regex::new(r"^#[0-9a-fA-F]{0,8}$").unwrap(),
+            _ => regex::Regex::new(r"^[a-zA-Z]*\(?[0-9.,%\s]*\)?$").unwrap(),
+        }
+    }
+}
+
+/// Format the color as text in the given format.
+fn format_color(color: Hsla, format: ColorFormat) -> String {
+    match format {
+        ColorFormat::Hex => color.to_hex(),
+        ColorFormat::Rgb => {
+            let rgb = color.to_rgb();
+            let (r, g, b) = (
+                (rgb.r * 255.).round(),
+                (rgb.g * 255.).round(),
+                (rgb.b * 255.).round(),
+            );
+            if color.a < 1. {
+                format!("rgba({}, {}, {}, {:.2})", r, g, b, color.a)
+            } else {
+                format!("rgb({}, {}, {})", r, g, b)
+            }
+        }
+        ColorFormat::Hsl => {
+            let (h, s, l) = (
+                (color.h * 360.).round(),
+                (color.s * 100.).round(),
+                (color.l * 100.).round(),
+            );
+            if color.a < 1. {
+                format!("hsla({}, {}%, {}%, {:.2})", h, s, l, color.a)
+            } else {
+                format!("hsl({}, {}%, {}%)", h, s, l)
+            }
+        }
+    }
+}
+
+/// Parse the color from text.
+///
+/// The `rgb(..)`, `hsl(..)` and `#` prefixes take precedence over the given format,
+/// so `rgb(255, 0, 0)` can be parsed in any format, but `255, 0, 0` only in [`ColorFormat::Rgb`].
+fn parse_color(text: &str, format: ColorFormat) -> Option<Hsla> {
+    let text = text.trim().to_lowercase();
+    let (format, args) = if text.starts_with('#') {
+        (ColorFormat::Hex, text.as_str())
+    } else if let Some(args) = text
+        .strip_prefix("rgba")
+        .or_else(|| text.strip_prefix("rgb"))
+    {
+        (ColorFormat::Rgb, args)
+    } else if let Some(args) = text
+        .strip_prefix("hsla")
+        .or_else(|| text.strip_prefix("hsl"))
+    {
+        (ColorFormat::Hsl, args)
+    } else {
+        (format, text.as_str())
+    };
+
+    if format == ColorFormat::Hex {
+        return Hsla::parse_hex(args).ok();
+    }
+
+    let args = args.trim().trim_start_matches('(').trim_end_matches(')');
+    let values = args
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|part| !part.is_empty())
+        .map(|part| part.trim_end_matches('%').parse::<f32>().ok())
+        .collect::<Option<Vec<_>>>()?;
+    if values.len() != 3 && values.len() != 4 {
+        return None;
+    }
+    let a = values.get(3).copied().unwrap_or(1.).clamp(0., 1.);
+
+    match format {
+        ColorFormat::Rgb => Some(
+            Rgba {
+                r: values[0].clamp(0., 255.) / 255.,
+                g: values[1].clamp(0., 255.) / 255.,
+                b: values[2].clamp(0., 255.) / 255.,
+                a,
+            }
+            .into(),
+        ),
+        _ => Some(hsla(
+            values[0].rem_euclid(360.) / 360.,
+            values[1].clamp(0., 100.) / 100.,
+            values[2].clamp(0., 100.) / 100.,
+            a,
+        )),
+    }
+}
+
+/// Convert HSL saturation and lightness to HSV saturation and value.
+fn hsl_to_hsv(s: f32, l: f32) -> (f32, f32) {
+    let v = l + s * l.min(1. - l);
+    let s = if v == 0. { 0. } else { 2. * (1. - l / v) };
+    (s, v)
+}
+
+/// Convert HSV saturation and value to HSL saturation and lightness.
+fn hsv_to_hsl(s: f32, v: f32) -> (f32, f32) {
+    let l = v * (1. - s / 2.);
+    let s = if l == 0. || l == 1. {
+        0.
+    } else {
+        (v - l) / l.min(1. - l)
+    };
+    (s, l)
+}
+
+#[derive(Clone)]
+struct DragSaturation(EntityId);
+
+impl Render for DragSaturation {
+    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+        Empty
+    }
+}
+
 fn color_palettes() -> Vec<Vec<Hsla>> {
     use crate::theme::DEFAULT_COLORS;
     use itertools::Itertools as _;
@@ -140,15 +298,18 @@ pub struct ColorPickerState {
     suppress_input_change: bool,
     active_tab: usize,
     open: bool,
+    format: ColorFormat,
+    recent_colors: Vec<Hsla>,
+    /// The bounds of the saturation square after rendered.
+    saturation_bounds: Bounds<Pixels>,
     _subscriptions: Vec<Subscription>,
 }
 
 impl ColorPickerState {
     /// Create a new [`ColorPickerState`].
     pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
-        let state = cx.new(|cx| {
-            InputState::new(window, cx).pattern(regex::Regex::new(r"^#[0-9a-fA-F]{0,8}$").unwrap())
-        });
+        let state =
+            cx.new(|cx| InputState::new(window, cx).pattern(ColorFormat::default().pattern()));
         let hsla_sliders = HslaSliders::new(cx);
 
         let mut _subscriptions = vec![
@@ -162,16 +323,16 @@ impl ColorPickerState {
                             return;
                         }
                         let value = state.read(cx).value();
-                        if let Ok(color) = Hsla::parse_hex(value.as_str()) {
+                        if let Some(color) = parse_color(value.as_str(), this.format) {
                             this.hovered_color = Some(color);
                             this.sync_sliders(Some(color), window, cx);
                         }
                     }
                     InputEvent::PressEnter { .. } => {
                         let val = this.state.read(cx).value();
-                        if let Ok(color) = Hsla::parse_hex(&val) {
-                            this.open = false;
+                        if let Some(color) = parse_color(&val, this.format) {
                             this.update_value(Some(color), true, window, cx);
+                            this.set_open(false, cx);
                         }
                     }
                     _ => {}
@@ -221,6 +382,9 @@ impl ColorPickerState {
             suppress_input_change: false,
             active_tab: 0,
             open: false,
+            format: ColorFormat::default(),
+            recent_colors: vec![],
+            saturation_bounds: Bounds::default(),
             _subscriptions,
         }
     }
@@ -249,6 +413,84 @@ impl ColorPickerState {
         self.value
     }
 
+    /// Set the text format of the color input, default: [`ColorFormat::Hex`].
+    pub fn format(mut self, format: ColorFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Get the recent colors, the most recent first.
+    pub fn recent_colors(&self) -> &[Hsla] {
+        &self.recent_colors
+    }
+
+    /// Set the recent colors, the most recent first.
+    ///
+    /// This can be used to restore the recent colors from the user's settings.
+    pub fn set_recent_colors(
+        &mut self,
+        colors: impl IntoIterator<Item = Hsla>,
+        cx: &mut Context<Self>,
+    ) {
+        self.recent_colors = colors.into_iter().take(MAX_RECENT_COLORS).collect();
+        cx.notify();
+    }
+
+    fn push_recent_color(&mut self, color: Hsla) {
+        self.recent_colors.retain(|c| c.to_hex() != color.to_hex());
+        self.recent_colors.insert(0, color);
+        self.recent_colors.truncate(MAX_RECENT_COLORS);
+    }
+
+    /// Set the open state of the popover, the current value is added
+    /// to the recent colors when the popover is closed.
+    fn set_open(&mut self, open: bool, cx: &mut Context<Self>) {
+        if self.open && !open {
+            if let Some(value) = self.value {
+                self.push_recent_color(value);
+            }
+        }
+        self.open = open;
+        cx.notify();
+    }
+
+    fn set_format(&mut self, format: ColorFormat, window: &mut Window, cx: &mut Context<Self>) {
+        self.format = format;
+        let color = self.hovered_color.or(self.value);
+        self.suppress_input_change = color.is_some();
+        self.state.update(cx, |input, cx| {
+            input.set_pattern(format.pattern(), window, cx);
+            if let Some(color) = color {
+                input.set_value(format_color(color, format), window, cx);
+            }
+        });
+        cx.notify();
+    }
+
+    fn update_value_by_position(
+        &mut self,
+        position: Point<Pixels>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let bounds = self.saturation_bounds;
+        if bounds.size.width <= Pixels::ZERO || bounds.size.height <= Pixels::ZERO {
+            return;
+        }
+
+        let s = ((position.x - bounds.left()) / bounds.size.width).clamp(0., 1.);
+        let v = 1. - ((position.y - bounds.top()) / bounds.size.height).clamp(0., 1.);
+        let (s, l) = hsv_to_hsl(s, v);
+        let color = self
+            .hovered_color
+            .or(self.value)
+            .unwrap_or_else(|| hsla(0., 0., 0., 1.));
+
+        let color = hsla(color.h, s, l, color.a);
+        self.sync_sliders(Some(color), window, cx);
+        self.update_value_from_slider(color, true, window, cx);
+    }
+
     fn on_confirm(&mut self, _: &Confirm, _: &mut Window, cx: &mut Context<Self>) {
         self.open = !self.open;
         cx.notify();
@@ -269,7 +511,7 @@ impl ColorPickerState {
         self.suppress_input_change = true;
         self.state.update(cx, |view, cx| {
             if let Some(value) = value {
-                view.set_value(value.to_hex(), window, cx);
+                view.set_value(format_color(value, self.format), window, cx);
             } else {
                 view.set_value("", window, cx);
             }
@@ -296,8 +538,9 @@ impl ColorPickerState {
         // Keep the hex input in sync with the slider, but suppress the resulting
         // InputEvent::Change to avoid the Hsla→hex→Hsla precision loss loop.
         self.suppress_input_change = true;
+        let format = self.format;
         self.state.update(cx, |view, cx| {
-            view.set_value(value.to_hex(), window, cx);
+            view.set_value(format_color(value, format), window, cx);
         });
         if emit {
             cx.emit(ColorPickerEvent::Change(Some(value)));
@@ -412,17 +655,17 @@ impl ColorPicker {
                 .active(|this| this.border_color(color.darken(0.5)).bg(color.darken(0.2)))
                 .on_mouse_move(window.listener_for(&state, move |state, _, window, cx| {
                     state.hovered_color = Some(color);
+                    let format = state.format;
                     state.state.update(cx, |input, cx| {
-                        input.set_value(color.to_hex(), window, cx);
+                        input.set_value(format_color(color, format), window, cx);
                     });
                     cx.notify();
                 }))
                 .on_click(window.listener_for(
                     &state,
                     move |state, _, window, cx| {
-                        state.open = false;
                         state.update_value(Some(color), true, window, cx);
-                        cx.notify();
+                        state.set_open(false, cx);
                     },
                 ))
             })
@@ -437,6 +680,8 @@ impl ColorPicker {
         });
 
         let active_tab = self.state.read(cx).active_tab;
+        let format = self.state.read(cx).format;
+        let recent_colors = self.state.read(cx).recent_colors.clone();
 
         let (slider_color, hovered_color) = {
             let state = self.state.read(cx);
@@ -466,9 +711,28 @@ impl ColorPicker {
             .child(match active_tab {
                 0 => self.render_palette_panel(window, cx).into_any_element(),
                 _ => self
-                    .render_slider_tab_panel(slider_color, cx)
+                    .render_slider_tab_panel(slider_color, window, cx)
                     .into_any_element(),
             })
+            .when(!recent_colors.is_empty(), |this| {
+                this.child(
+                    v_flex()
+                        .gap_1()
+                        .child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(t!("ColorPicker.Recent")),
+                        )
+                        .child(
+                            h_flex().gap_1().children(
+                                recent_colors
+                                    .iter()
+                                    .map(|color| self.render_item(*color, true, window, cx)),
+                            ),
+                        ),
+                )
+            })
             .when_some(hovered_color, |this, hovered_color| {
                 this.child(Divider::horizontal()).child(
                     h_flex()
@@ -483,7 +747,20 @@ impl ColorPicker {
                                 .size_5()
                                 .rounded(cx.theme().radius),
                         )
-                        .child(Input::new(&self.state.read(cx).state).small()),
+                        .child(Input::new(&self.state.read(cx).state).small())
+                        .child(
+                            Button::new("format")
+                                .ghost()
+                                .xsmall()
+                                .tab_stop(false)
+                                .label(format.label())
+                                .on_click(window.listener_for(
+                                    &self.state,
+                                    move |state, _, window, cx| {
+                                        state.set_format(format.next(), window, cx);
+                                    },
+                                )),
+                        ),
                 )
             })
     }
@@ -528,7 +805,89 @@ impl ColorPicker {
             )
     }
 
-    fn render_slider_tab_panel(&self, slider_color: Hsla, cx: &mut App) -> impl IntoElement {
+    fn render_saturation_square(
+        &self,
+        color: Hsla,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> impl IntoElement {
+        let entity_id = self.state.entity_id();
+        let (s, v) = hsl_to_hsv(color.s, color.l);
+
+        div()
+            .id("saturation")
+            .relative()
+            .w_full()
+            .h_32()
+            .rounded(cx.theme().radius)
+            .bg(hsla(color.h, 1., 0.5, 1.))
+            .cursor_crosshair()
+            .child(
+                div()
+                    .absolute()
+                    .size_full()
+                    .rounded(cx.theme().radius)
+                    .bg(linear_gradient(
+                        90.,
+                        linear_color_stop(hsla(0., 0., 1., 1.), 0.),
+                        linear_color_stop(hsla(0., 0., 1., 0.), 1.),
+                    )),
+            )
+            .child(
+                div()
+                    .absolute()
+                    .size_full()
+                    .rounded(cx.theme().radius)
+                    .bg(linear_gradient(
+                        180.,
+                        linear_color_stop(hsla(0., 0., 0., 0.), 0.),
+                        linear_color_stop(hsla(0., 0., 0., 1.), 1.),
+                    )),
+            )
+            .child(
+                div()
+                    .absolute()
+                    .left(relative(s))
+                    .top(relative(1. - v))
+                    .size_3()
+                    .ml(px(-6.))
+                    .mt(px(-6.))
+                    .rounded_full()
+                    .border_2()
+                    .border_color(hsla(0., 0., 1., 1.))
+                    .shadow_sm(),
+            )
+            .on_mouse_down(
+                MouseButton::Left,
+                window.listener_for(&self.state, |state, e: &MouseDownEvent, window, cx| {
+                    state.update_value_by_position(e.position, window, cx);
+                }),
+            )
+            .on_drag(DragSaturation(entity_id), |drag, _, _, cx| {
+                cx.stop_propagation();
+                cx.new(|_| drag.clone())
+            })
+            .on_drag_move(window.listener_for(
+                &self.state,
+                move |state, e: &DragMoveEvent<DragSaturation>, window, cx| {
+                    if e.drag(cx).0 != entity_id {
+                        return;
+                    }
+                    state.update_value_by_position(e.event.position, window, cx);
+                },
+            ))
+            .on_prepaint({
+                let state = self.state.clone();
+                move |bounds, _, cx| state.update(cx, |r, _| r.saturation_bounds = bounds)
+            })
+    }
+
+    fn render_slider_tab_panel(
+        &self,
+        slider_color: Hsla,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> impl IntoElement {
         let hsla_sliders = self.state.read(cx).hsla_sliders.clone();
         let steps = 96usize;
         let hue_colors = (0..steps)
@@ -552,6 +911,7 @@ impl ColorPicker {
 
         v_flex()
             .gap_2()
+            .child(self.render_saturation_square(slider_color, window, cx))
             .child(
                 h_flex()
                     .gap_2()
@@ -765,8 +1125,7 @@ impl RenderOnce for ColorPicker {
                     .w_72()
                     .on_open_change(
                         window.listener_for(&self.state, |this, open: &bool, _, cx| {
-                            this.open = *open;
-                            cx.notify();
+                            this.set_open(*open, cx);
                         }),
                     )
                     .trigger(ColorPickerButton {
@@ -849,3 +1208,70 @@ impl RenderOnce for ColorPickerButton {
             .when_some(self.label, |this, label| this.child(label))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gpui::hsla;
+
+    use super::{ColorFormat, format_color, hsl_to_hsv, hsv_to_hsl, parse_color};
+    use crate::Colorize as _;
+
+    #[test]
+    fn test_format_color() {
+        let color = parse_color("#3B82F6", ColorFormat::Hex).unwrap();
+        assert_eq!(format_color(color, ColorFormat::Rgb), "rgb(59, 130, 246)");
+        assert_eq!(format_color(color, ColorFormat::Hsl), "hsl(217, 91%, 60%)");
+
+        let color = hsla(0., 1., 0.5, 0.5);
+        assert_eq!(
+            format_color(color, ColorFormat::Rgb),
+            "rgba(255, 0, 0, 0.50)"
+        );
+        assert_eq!(
+            format_color(color, ColorFormat::Hsl),
+            "hsla(0, 100%, 50%, 0.50)"
+        );
+    }
+
+    #[test]
+    fn test_parse_color() {
+        let red = "#FF0000";
+        for (text, format) in [
+            ("#ff0000", ColorFormat::Rgb),
+            ("rgb(255, 0, 0)", ColorFormat::Hex),
+            ("255, 0, 0", ColorFormat::Rgb),
+            ("255 0 0", ColorFormat::Rgb),
+            ("hsl(0, 100%, 50%)", ColorFormat::Hex),
+            ("hsl(360 100% 50%)", ColorFormat::Hsl),
+            ("0, 100, 50", ColorFormat::Hsl),
+        ] {
+            assert_eq!(
+                parse_color(text, format).map(|c| c.to_hex()).as_deref(),
+                Some(red),
+                "{}",
+                text
+            );
+        }
+
+        let color = parse_color("rgba(255, 0, 0, 0.5)", ColorFormat::Rgb).unwrap();
+        assert_eq!(color.a, 0.5);
+
+        assert_eq!(parse_color("255, 0", ColorFormat::Rgb), None);
+        assert_eq!(parse_color("255, 0, 0", ColorFormat::Hex), None);
+        assert_eq!(parse_color("rgb(a, b, c)", ColorFormat::Rgb), None);
+    }
+
+    #[test]
+    fn test_hsv_roundtrip() {
+        assert_eq!(hsl_to_hsv(1., 0.5), (1., 1.));
+        assert_eq!(hsl_to_hsv(0., 1.), (0., 1.));
+        assert_eq!(hsl_to_hsv(0., 0.), (0., 0.));
+        assert_eq!(hsv_to_hsl(1., 1.), (1., 0.5));
+        assert_eq!(hsv_to_hsl(0., 1.), (0., 1.));
+
+        let (s, v) = hsl_to_hsv(0.6, 0.3);
+        let (s, l) = hsv_to_hsl(s, v);
+        assert!((s - 0.6).abs() < 0.0001);
+        assert!((l - 0.3).abs() < 0.0001);
+    }
+}
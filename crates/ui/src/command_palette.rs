@@ -0,0 +1,688 @@
+use std::{collections::VecDeque, ops::Range, rc::Rc};
+
+use gpui::{
+    Action, App, AppContext as _, Context, Entity, EventEmitter, FocusHandle, Focusable,
+    HighlightStyle, IntoElement, ParentElement as _, RenderOnce, SharedString, StyleRefinement,
+    Styled, StyledText, Task, WeakEntity, Window, div, prelude::FluentBuilder as _, px,
+};
+use rust_i18n::t;
+
+use crate::{
+    ActiveTheme, IndexPath, StyledExt, WindowExt as _,
+    kbd::Kbd,
+    list::{List, ListDelegate, ListItem, ListState},
+    v_flex,
+};
+
+/// The max number of recent commands to remember.
+const MAX_RECENT: usize = 10;
+
+/// A command to display in the [`CommandPalette`].
+///
+/// A command can dispatch an [`Action`], run a custom handler, or both.
+#[derive(Clone)]
+pub struct CommandPaletteItem {
+    id: SharedString,
+    title: SharedString,
+    description: Option<SharedString>,
+    action: Option<Rc<dyn Action>>,
+    handler: Option<Rc<dyn Fn(&mut Window, &mut App)>>,
+}
+
+impl CommandPaletteItem {
+    /// Create a new command with the given title.
+    ///
+    /// The title is also used as the id of the command, see [`Self::id`].
+    pub fn new(title: impl Into<SharedString>) -> Self {
+        let title: SharedString = title.into();
+        Self {
+            id: title.clone(),
+            title,
+            description: None,
+            action: None,
+            handler: None,
+        }
+    }
+
+    /// Create a command from a gpui [`Action`], the title is humanized from the action name.
+    ///
+    /// For example: `editor::MoveUp` will be displayed as `Editor: Move Up`.
+    pub fn from_action(action: Box<dyn Action>) -> Self {
+        Self {
+            id: action.name().into(),
+            title: humanize_action_name(action.name()).into(),
+            description: None,
+            action: Some(Rc::from(action)),
+            handler: None,
+        }
+    }
+
+    /// Set the unique id of the command, used to remember the recent commands.
+    pub fn id(mut self, id: impl Into<SharedString>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Set the description of the command, displayed below the title.
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the action to dispatch when the command is confirmed.
+    ///
+    /// The keybinding of the action will be displayed as a hint.
+    pub fn action(mut self, action: impl Action) -> Self {
+        self.action = Some(Rc::new(action));
+        self
+    }
+
+    /// Set the handler to call when the command is confirmed.
+    pub fn on_select(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.handler = Some(Rc::new(handler));
+        self
+    }
+}
+
+/// Returns a human readable name for the action name.
+///
+/// `editor::MoveUp` -> `Editor: Move Up`
+pub(crate) fn humanize_action_name(name: &str) -> String {
+    let mut result = String::with_capacity(name.len() + 8);
+    let mut parts = name.split("::").peekable();
+    while let Some(part) = parts.next() {
+        let mut prev: Option<char> = None;
+        for c in part.chars() {
+            match c {
+                '_' => {
+                    result.push(' ');
+                    prev = Some(' ');
+                    continue;
+                }
+                c if c.is_uppercase() => {
+                    if prev.is_some_and(|p| p.is_lowercase() || p.is_ascii_digit()) {
+                        result.push(' ');
+                    }
+                }
+                _ => {}
+            }
+
+            if prev.is_none() || prev == Some(' ') {
+                result.extend(c.to_uppercase());
+            } else {
+                result.push(c);
+            }
+            prev = Some(c);
+        }
+
+        if parts.peek().is_some() {
+            result.push_str(": ");
+        }
+    }
+
+    result
+}
+
+/// The result of a fuzzy match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FuzzyMatch {
+    pub(crate) score: i32,
+    /// The matched byte ranges in the text.
+    pub(crate) ranges: Vec<Range<usize>>,
+}
+
+#[inline]
+fn is_word_boundary(prev: Option<char>, c: char) -> bool {
+    match prev {
+        None => true,
+        Some(prev) => !prev.is_alphanumeric() || (prev.is_lowercase() && c.is_uppercase()),
+    }
+}
+
+/// Match the `query` as a case-insensitive subsequence of `text`.
+///
+/// Returns `None` if the text does not contain all the characters of the query (in order).
+/// Whitespaces in the query are ignored.
+///
+/// The score is higher for consecutive matches and matches at word boundaries.
+pub(crate) fn fuzzy_match(query: &str, text: &str) -> Option<FuzzyMatch> {
+    let mut query_chars = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(|c| c.to_lowercase())
+        .peekable();
+
+    let mut score = 0;
+    let mut ranges: Vec<Range<usize>> = vec![];
+    let mut prev: Option<char> = None;
+    let mut last_matched_end: Option<usize> = None;
+    let mut gap = 0;
+
+    for (ix, c) in text.char_indices() {
+        let Some(q) = query_chars.peek() else {
+            break;
+        };
+
+        if c.to_lowercase().eq(std::iter::once(*q)) {
+            query_chars.next();
+            score += 1;
+
+            if last_matched_end == Some(ix) {
+                score += 5;
+            }
+            if is_word_boundary(prev, c) {
+                score += 8;
+            }
+            score -= gap.min(3);
+            gap = 0;
+
+            let end = ix + c.len_utf8();
+            match ranges.last_mut() {
+                Some(range) if range.end == ix => range.end = end,
+                _ => ranges.push(ix..end),
+            }
+            last_matched_end = Some(end);
+        } else if last_matched_end.is_some() {
+            gap += 1;
+        }
+
+        prev = Some(c);
+    }
+
+    if query_chars.peek().is_some() {
+        return None;
+    }
+
+    Some(FuzzyMatch { score, ranges })
+}
+
+#[derive(Debug, Clone)]
+struct CommandMatch {
+    item_ix: usize,
+    ranges: Vec<Range<usize>>,
+}
+
+/// Filter and sort the items by the query.
+///
+/// The items are sorted by score, and then by the recent order, the recent used items first.
+fn match_items(
+    items: &[CommandPaletteItem],
+    recent: &VecDeque<SharedString>,
+    query: &str,
+) -> Vec<CommandMatch> {
+    let query = query.trim();
+    let recent_rank = |item: &CommandPaletteItem| {
+        recent
+            .iter()
+            .position(|id| id == &item.id)
+            .unwrap_or(usize::MAX)
+    };
+
+    let mut matches = items
+        .iter()
+        .enumerate()
+        .filter_map(|(item_ix, item)| {
+            if query.is_empty() {
+                return Some((
+                    0,
+                    recent_rank(item),
+                    CommandMatch {
+                        item_ix,
+                        ranges: vec![],
+                    },
+                ));
+            }
+
+            let m = fuzzy_match(query, &item.title)?;
+            Some((
+                m.score,
+                recent_rank(item),
+                CommandMatch {
+                    item_ix,
+                    ranges: m.ranges,
+                },
+            ))
+        })
+        .collect::<Vec<_>>();
+
+    matches.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    matches.into_iter().map(|(_, _, m)| m).collect()
+}
+
+struct CommandPaletteDelegate {
+    state: WeakEntity<CommandPaletteState>,
+    items: Vec<CommandPaletteItem>,
+    matches: Vec<CommandMatch>,
+    recent: VecDeque<SharedString>,
+    selected_index: Option<IndexPath>,
+    /// The focus handle before the palette opened, used to find keybindings and dispatch actions.
+    action_context: Option<FocusHandle>,
+}
+
+impl CommandPaletteDelegate {
+    fn update_matches(&mut self, query: &str) {
+        self.matches = match_items(&self.items, &self.recent, query);
+    }
+
+    fn selected_item(&self) -> Option<&CommandPaletteItem> {
+        let ix = self.selected_index?;
+        let m = self.matches.get(ix.row)?;
+        self.items.get(m.item_ix)
+    }
+
+    /// Close the Dialog if the palette is opened by [`CommandPaletteState::open`].
+    fn close_if_opened(state: &WeakEntity<CommandPaletteState>, window: &mut Window, cx: &mut App) {
+        let opened = state
+            .update(cx, |state, _| std::mem::take(&mut state.open))
+            .unwrap_or(false);
+        if opened {
+            window.close_dialog(cx);
+        }
+    }
+
+    fn push_recent(&mut self, id: SharedString) {
+        self.recent.retain(|recent_id| recent_id != &id);
+        self.recent.push_front(id);
+        self.recent.truncate(MAX_RECENT);
+    }
+}
+
+impl ListDelegate for CommandPaletteDelegate {
+    type Item = ListItem;
+
+    fn items_count(&self, _: usize, _: &App) -> usize {
+        self.matches.len()
+    }
+
+    fn perform_search(
+        &mut self,
+        query: &str,
+        _: &mut Window,
+        _: &mut Context<ListState<Self>>,
+    ) -> Task<()> {
+        self.update_matches(query);
+        Task::ready(())
+    }
+
+    fn render_item(
+        &mut self,
+        ix: IndexPath,
+        window: &mut Window,
+        cx: &mut Context<ListState<Self>>,
+    ) -> Option<Self::Item> {
+        let m = self.matches.get(ix.row)?;
+        let item = self.items.get(m.item_ix)?;
+
+        let kbd = item
+            .action
+            .as_ref()
+            .and_then(|action| match &self.action_context {
+                Some(focus_handle) => {
+                    Kbd::binding_for_action_in(action.as_ref(), focus_handle, window)
+                }
+                None => Kbd::binding_for_action(action.as_ref(), None, window),
+            });
+
+        let highlights = m
+            .ranges
+            .iter()
+            .map(|range| {
+                (
+                    range.clone(),
+                    HighlightStyle {
+                        color: Some(cx.theme().blue),
+                        ..Default::default()
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+
+        Some(
+            ListItem::new(ix.row)
+                .gap_x_3()
+                .child(
+                    v_flex()
+                        .flex_1()
+                        .overflow_hidden()
+                        .child(
+                            div().truncate().child(
+                                StyledText::new(item.title.clone()).with_highlights(highlights),
+                            ),
+                        )
+                        .when_some(item.description.clone(), |this, description| {
+                            this.child(
+                                div()
+                                    .truncate()
+                                    .text_xs()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(description),
+                            )
+                        }),
+                )
+                .children(kbd),
+        )
+    }
+
+    fn set_selected_index(
+        &mut self,
+        ix: Option<IndexPath>,
+        _: &mut Window,
+        _: &mut Context<ListState<Self>>,
+    ) {
+        self.selected_index = ix;
+    }
+
+    fn confirm(&mut self, _: bool, window: &mut Window, cx: &mut Context<ListState<Self>>) {
+        let Some(item) = self.selected_item().cloned() else {
+            return;
+        };
+        self.push_recent(item.id.clone());
+
+        let state = self.state.clone();
+        cx.defer_in(window, move |_, window, cx| {
+            Self::close_if_opened(&state, window, cx);
+            if let Some(handler) = item.handler.as_ref() {
+                handler(window, cx);
+            }
+            if let Some(action) = item.action.as_ref() {
+                window.dispatch_action(action.boxed_clone(), cx);
+            }
+            _ = state.update(cx, |_, cx| {
+                cx.emit(CommandPaletteEvent::Confirm(item.id.clone()));
+            });
+        });
+    }
+
+    fn cancel(&mut self, window: &mut Window, cx: &mut Context<ListState<Self>>) {
+        let state = self.state.clone();
+        cx.defer_in(window, move |_, window, cx| {
+            Self::close_if_opened(&state, window, cx);
+        });
+    }
+}
+
+/// Events emitted by the [`CommandPaletteState`].
+#[derive(Debug, Clone)]
+pub enum CommandPaletteEvent {
+    /// A command has been confirmed, with the id of the command.
+    Confirm(SharedString),
+}
+
+/// State of the [`CommandPalette`].
+///
+/// The state keeps the recent commands, so it should be created once and reused.
+pub struct CommandPaletteState {
+    list: Entity<ListState<CommandPaletteDelegate>>,
+    commands: Vec<CommandPaletteItem>,
+    include_actions: bool,
+    action_filter: Option<Rc<dyn Fn(&dyn Action) -> bool>>,
+    /// Whether the items need to be reloaded before next render.
+    dirty: bool,
+    /// Whether the palette is opened in a Dialog by [`Self::open`].
+    open: bool,
+}
+
+impl EventEmitter<CommandPaletteEvent> for CommandPaletteState {}
+
+impl CommandPaletteState {
+    /// Create a new command palette state.
+    pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let delegate = CommandPaletteDelegate {
+            state: cx.entity().downgrade(),
+            items: vec![],
+            matches: vec![],
+            recent: VecDeque::new(),
+            selected_index: None,
+            action_context: None,
+        };
+        let list = cx.new(|cx| ListState::new(delegate, window, cx).searchable(true));
+
+        Self {
+            list,
+            commands: vec![],
+            include_actions: false,
+            action_filter: None,
+            dirty: true,
+            open: false,
+        }
+    }
+
+    /// Set the custom commands.
+    pub fn commands(mut self, commands: impl IntoIterator<Item = CommandPaletteItem>) -> Self {
+        self.commands = commands.into_iter().collect();
+        self.dirty = true;
+        self
+    }
+
+    /// Set the custom commands.
+    pub fn set_commands(
+        &mut self,
+        commands: impl IntoIterator<Item = CommandPaletteItem>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.commands = commands.into_iter().collect();
+        self.dirty = true;
+        cx.notify();
+    }
+
+    /// Set whether to include the gpui actions available in the focused element, default is `false`.
+    pub fn include_actions(mut self, include_actions: bool) -> Self {
+        self.include_actions = include_actions;
+        self.dirty = true;
+        self
+    }
+
+    /// Set a filter to decide which actions are listed, only used when [`Self::include_actions`] is `true`.
+    pub fn action_filter(mut self, filter: impl Fn(&dyn Action) -> bool + 'static) -> Self {
+        self.action_filter = Some(Rc::new(filter));
+        self.dirty = true;
+        self
+    }
+
+    /// Returns the ids of the recent confirmed commands, the most recent first.
+    pub fn recent(&self, cx: &App) -> Vec<SharedString> {
+        self.list
+            .read(cx)
+            .delegate()
+            .recent
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Reload the commands and the actions available in the `action_context`.
+    fn reload(
+        &mut self,
+        action_context: Option<FocusHandle>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.dirty = false;
+
+        let mut items = self.commands.clone();
+        if self.include_actions {
+            for action in window.available_actions(cx) {
+                if self
+                    .action_filter
+                    .as_ref()
+                    .is_some_and(|filter| !filter(action.as_ref()))
+                {
+                    continue;
+                }
+                if items.iter().any(|item| item.id.as_ref() == action.name()) {
+                    continue;
+                }
+
+                items.push(CommandPaletteItem::from_action(action));
+            }
+        }
+
+        self.list.update(cx, |list, cx| {
+            let query = list.query_input.read(cx).value().to_string();
+            let delegate = list.delegate_mut();
+            delegate.items = items;
+            delegate.action_context = action_context;
+            delegate.update_matches(&query);
+            list._set_selected_index(Some(IndexPath::default()), window, cx);
+        });
+    }
+
+    /// Open the command palette in a Dialog.
+    ///
+    /// The actions and keybindings are collected from the focused element before opening.
+    pub fn open(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let action_context = window.focused(cx);
+        self.list
+            .update(cx, |list, cx| list.set_query("", window, cx));
+        self.reload(action_context, window, cx);
+        self.open = true;
+
+        let state = cx.entity();
+        window.open_dialog(cx, move |dialog, _, _| {
+            dialog
+                .w(px(560.))
+                .p_0()
+                .close_button(false)
+                .child(CommandPalette::new(&state))
+        });
+        self.list.update(cx, |list, cx| list.focus(window, cx));
+    }
+}
+
+impl Focusable for CommandPaletteState {
+    fn focus_handle(&self, cx: &App) -> FocusHandle {
+        self.list.focus_handle(cx)
+    }
+}
+
+/// A command palette to search and run commands (and actions) by fuzzy matching.
+///
+/// Use [`CommandPaletteState::open`] to open it in a Dialog, or render it inline.
+#[derive(IntoElement)]
+pub struct CommandPalette {
+    state: Entity<CommandPaletteState>,
+    style: StyleRefinement,
+}
+
+impl CommandPalette {
+    /// Create a new CommandPalette with the given state.
+    pub fn new(state: &Entity<CommandPaletteState>) -> Self {
+        Self {
+            state: state.clone(),
+            style: StyleRefinement::default(),
+        }
+    }
+}
+
+impl Styled for CommandPalette {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl RenderOnce for CommandPalette {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let list = self.state.update(cx, |state, cx| {
+            if state.dirty {
+                let action_context = window.focused(cx);
+                state.reload(action_context, window, cx);
+            }
+            state.list.clone()
+        });
+
+        v_flex().w_full().refine_style(&self.style).child(
+            List::new(&list)
+                .search_placeholder(t!("CommandPalette.placeholder"))
+                .max_h(px(360.))
+                .p_1(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_humanize_action_name() {
+        assert_eq!(humanize_action_name("editor::MoveUp"), "Editor: Move Up");
+        assert_eq!(humanize_action_name("ui::Cancel"), "Ui: Cancel");
+        assert_eq!(
+            humanize_action_name("workspace::toggle_left_dock"),
+            "Workspace: Toggle Left Dock"
+        );
+        assert_eq!(humanize_action_name("OpenFile"), "Open File");
+        assert_eq!(humanize_action_name("story::Tab2"), "Story: Tab2");
+    }
+
+    #[test]
+    fn test_fuzzy_match() {
+        assert_eq!(fuzzy_match("xyz", "Open File"), None);
+        assert_eq!(fuzzy_match("fo", "Open File"), None);
+
+        let m = fuzzy_match("of", "Open File").unwrap();
+        assert_eq!(m.ranges, vec![0..1, 5..6]);
+
+        let m = fuzzy_match("open", "Open File").unwrap();
+        assert_eq!(m.ranges, vec![0..4]);
+
+        // Whitespace is ignored, and match is case insensitive.
+        let m = fuzzy_match("OPEN f", "Open File").unwrap();
+        assert_eq!(m.ranges, vec![0..4, 5..6]);
+
+        // Multi-byte chars
+        let m = fuzzy_match("文件", "打开文件").unwrap();
+        assert_eq!(m.ranges, vec![6..12]);
+
+        // Consecutive and word boundary matches have higher score.
+        let a = fuzzy_match("tog", "Toggle Sidebar").unwrap();
+        let b = fuzzy_match("tog", "Set Top Gap").unwrap();
+        assert!(a.score > b.score);
+    }
+
+    #[test]
+    fn test_match_items_order() {
+        let items = vec![
+            CommandPaletteItem::new("Open File"),
+            CommandPaletteItem::new("Close File"),
+            CommandPaletteItem::new("Toggle Sidebar"),
+        ];
+
+        let titles = |matches: Vec<CommandMatch>| {
+            matches
+                .into_iter()
+                .map(|m| items[m.item_ix].title.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        // Empty query keeps the original order.
+        let recent = VecDeque::new();
+        assert_eq!(
+            titles(match_items(&items, &recent, "")),
+            vec!["Open File", "Close File", "Toggle Sidebar"]
+        );
+
+        // Recent items come first with empty query.
+        let recent = VecDeque::from(vec![
+            SharedString::from("Toggle Sidebar"),
+            SharedString::from("Close File"),
+        ]);
+        assert_eq!(
+            titles(match_items(&items, &recent, "  ")),
+            vec!["Toggle Sidebar", "Close File", "Open File"]
+        );
+
+        // Same score, the recent one first.
+        assert_eq!(
+            titles(match_items(&items, &recent, "file")),
+            vec!["Close File", "Open File"]
+        );
+
+        // Better score wins over recent.
+        assert_eq!(
+            titles(match_items(&items, &recent, "ope")),
+            vec!["Open File"]
+        );
+    }
+}
@@ -11,7 +11,7 @@ use rust_i18n::t;
 use crate::{
     ActiveTheme as _, FocusTrapElement as _, IconName, Root, Sizable as _, StyledExt,
     TITLE_BAR_HEIGHT, WindowExt as _,
-    animation::cubic_bezier,
+    animation::{cubic_bezier, motion_duration},
     button::{Button, ButtonVariant, ButtonVariants as _},
     dialog::{DialogContent, DialogTitle},
     scroll::ScrollableElement as _,
@@ -477,8 +477,8 @@ impl RenderOnce for Dialog {
             paddings.bottom = pb.to_pixels(base_size, rem_size);
         }
 
-        let animation =
-            Animation::new(*ANIMATION_DURATION).with_easing(cubic_bezier(0.32, 0.72, 0., 1.));
+        let animation = Animation::new(motion_duration(*ANIMATION_DURATION, cx))
+            .with_easing(cubic_bezier(0.32, 0.72, 0., 1.));
 
         anchored()
             .position(point(window_paddings.left, window_paddings.top))
@@ -0,0 +1,275 @@
+//! A generic drag and drop to reorder the items, e.g.: sessions, tabs or slides.
+//!
+//! - [`SortableExt::sortable`] makes an element both the drag source and the drop target.
+//! - [`DragItem`] is the drag value, and renders the drag preview.
+//! - [`SortableExt::auto_scroll_on_drag`] scrolls the container when dragging near its edges.
+//! - [`move_item`] applies a [`ReorderEvent`] to a `Vec`, and [`moved_index`] keeps the selection.
+use gpui::{
+    App, AppContext as _, Axis, Bounds, Context, DragMoveEvent, IntoElement, ParentElement, Pixels,
+    Point, Render, ScrollHandle, SharedString, StatefulInteractiveElement, Styled, Window, div,
+    point, prelude::FluentBuilder as _, px,
+};
+
+use crate::{ActiveTheme as _, AxisExt as _};
+
+/// The distance from the edges of the container to start auto scrolling.
+const AUTO_SCROLL_EDGE: Pixels = px(40.);
+/// The max distance to scroll for each drag move.
+const AUTO_SCROLL_STEP: Pixels = px(12.);
+
+/// The drag value of a sortable item, also renders the drag preview.
+#[derive(Clone)]
+pub struct DragItem {
+    /// The group of the item, only the items in the same group can be reordered.
+    pub group: SharedString,
+    /// The index of the item in the group.
+    pub ix: usize,
+    /// The label to show in the drag preview.
+    pub label: Option<SharedString>,
+}
+
+impl DragItem {
+    /// Create a new drag item in the `group` at index `ix`.
+    pub fn new(group: impl Into<SharedString>, ix: usize) -> Self {
+        Self {
+            group: group.into(),
+            ix,
+            label: None,
+        }
+    }
+
+    /// Set the label to show in the drag preview.
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+impl Render for DragItem {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .id("drag-item")
+            .cursor_grabbing()
+            .h_7()
+            .min_w_16()
+            .max_w_64()
+            .py_1()
+            .px_3()
+            .overflow_hidden()
+            .whitespace_nowrap()
+            .text_ellipsis()
+            .text_sm()
+            .border_1()
+            .border_color(cx.theme().drag_border)
+            .rounded(cx.theme().radius)
+            .text_color(cx.theme().popover_foreground)
+            .bg(cx.theme().popover)
+            .shadow_md()
+            .opacity(0.85)
+            .when_some(self.label.clone(), |this, label| this.child(label))
+    }
+}
+
+/// The event to move the item `from` index to `to` index in the same group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReorderEvent {
+    /// The index of the dragged item.
+    pub from: usize,
+    /// The index of the drop target, the dragged item should be moved to this index.
+    pub to: usize,
+}
+
+/// Move the item `from` index to `to` index, the items between are shifted.
+///
+/// Nothing happens if any index is out of bounds.
+pub fn move_item<T>(items: &mut Vec<T>, from: usize, to: usize) {
+    if from == to || from >= items.len() || to >= items.len() {
+        return;
+    }
+
+    let item = items.remove(from);
+    items.insert(to, item);
+}
+
+/// Returns the new index of the item at `ix` after moving the item `from` index to `to` index.
+///
+/// This is useful to keep the selection after reordering.
+pub fn moved_index(ix: usize, from: usize, to: usize) -> usize {
+    if ix == from {
+        to
+    } else if from < ix && ix <= to {
+        ix - 1
+    } else if to <= ix && ix < from {
+        ix + 1
+    } else {
+        ix
+    }
+}
+
+/// An extension trait to make the elements reorderable by drag and drop.
+pub trait SortableExt: StatefulInteractiveElement + Styled + Sized {
+    /// Make the element a drag source with the `item`, and a drop target for the other items
+    /// in the same group.
+    ///
+    /// The `axis` is the layout direction of the items, to show the drop indicator on the side
+    /// the dragged item will be placed.
+    ///
+    /// ```ignore
+    /// div()
+    ///     .id(ix)
+    ///     .sortable(
+    ///         DragItem::new("sessions", ix).label(session.title.clone()),
+    ///         Axis::Vertical,
+    ///         cx.listener(|this, event: &ReorderEvent, _, cx| {
+    ///             move_item(&mut this.sessions, event.from, event.to);
+    ///             cx.notify();
+    ///         }),
+    ///     )
+    /// ```
+    fn sortable(
+        self,
+        item: DragItem,
+        axis: Axis,
+        on_reorder: impl Fn(&ReorderEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        let group = item.group.clone();
+        let to = item.ix;
+
+        self.on_drag(item, |drag, _, _, cx| cx.new(|_| drag.clone()))
+            .drag_over::<DragItem>({
+                let group = group.clone();
+                move |this, drag, _, cx| {
+                    if drag.group != group || drag.ix == to {
+                        return this;
+                    }
+
+                    let this = this.border_color(cx.theme().drag_border);
+                    match (axis.is_vertical(), drag.ix < to) {
+                        (true, true) => this.border_b_2(),
+                        (true, false) => this.border_t_2(),
+                        (false, true) => this.border_r_2(),
+                        (false, false) => this.border_l_2(),
+                    }
+                }
+            })
+            .on_drop(move |drag: &DragItem, window, cx| {
+                if drag.group != group || drag.ix == to {
+                    return;
+                }
+
+                cx.stop_propagation();
+                on_reorder(&ReorderEvent { from: drag.ix, to }, window, cx);
+            })
+    }
+
+    /// Scroll the `scroll_handle` when dragging a [`DragItem`] near the edges of this element.
+    ///
+    /// The element should be the scroll container tracked by the `scroll_handle`.
+    fn auto_scroll_on_drag(self, scroll_handle: &ScrollHandle, axis: Axis) -> Self {
+        let scroll_handle = scroll_handle.clone();
+        self.on_drag_move(move |event: &DragMoveEvent<DragItem>, window, _| {
+            if auto_scroll(&scroll_handle, event.bounds, event.event.position, axis) {
+                window.refresh();
+            }
+        })
+    }
+}
+
+impl<T: StatefulInteractiveElement + Styled + Sized> SortableExt for T {}
+
+/// Scroll the `scroll_handle` if the `position` is near the edges of the `bounds`.
+///
+/// Returns true if scrolled.
+pub fn auto_scroll(
+    scroll_handle: &ScrollHandle,
+    bounds: Bounds<Pixels>,
+    position: Point<Pixels>,
+    axis: Axis,
+) -> bool {
+    if !bounds.contains(&position) {
+        return false;
+    }
+
+    let offset = scroll_handle.offset();
+    let max_offset = scroll_handle.max_offset();
+    let new_offset = if axis.is_vertical() {
+        let delta = auto_scroll_delta(bounds.top(), bounds.bottom(), position.y);
+        point(offset.x, (offset.y - delta).clamp(-max_offset.y, px(0.)))
+    } else {
+        let delta = auto_scroll_delta(bounds.left(), bounds.right(), position.x);
+        point((offset.x - delta).clamp(-max_offset.x, px(0.)), offset.y)
+    };
+
+    if new_offset == offset {
+        return false;
+    }
+
+    scroll_handle.set_offset(new_offset);
+    true
+}
+
+/// Returns the distance to scroll, negative to scroll to the start, and faster near the edge.
+fn auto_scroll_delta(start: Pixels, end: Pixels, position: Pixels) -> Pixels {
+    let edge = AUTO_SCROLL_EDGE.min((end - start) / 2.);
+    if edge <= px(0.) {
+        return px(0.);
+    }
+
+    if position < start + edge {
+        -AUTO_SCROLL_STEP * ((start + edge - position) / edge).min(1.)
+    } else if position > end - edge {
+        AUTO_SCROLL_STEP * ((position - (end - edge)) / edge).min(1.)
+    } else {
+        px(0.)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::px;
+
+    use super::{AUTO_SCROLL_STEP, auto_scroll_delta, move_item, moved_index};
+
+    #[test]
+    fn test_move_item() {
+        let mut items = vec![0, 1, 2, 3, 4];
+        move_item(&mut items, 0, 3);
+        assert_eq!(items, vec![1, 2, 3, 0, 4]);
+        move_item(&mut items, 4, 0);
+        assert_eq!(items, vec![4, 1, 2, 3, 0]);
+        move_item(&mut items, 2, 2);
+        assert_eq!(items, vec![4, 1, 2, 3, 0]);
+        move_item(&mut items, 1, 10);
+        assert_eq!(items, vec![4, 1, 2, 3, 0]);
+    }
+
+    #[test]
+    fn test_moved_index() {
+        let items = vec![0, 1, 2, 3, 4];
+        for (from, to) in [(0, 3), (3, 0), (4, 1), (2, 2)] {
+            let mut moved = items.clone();
+            move_item(&mut moved, from, to);
+            for ix in 0..items.len() {
+                assert_eq!(moved[moved_index(ix, from, to)], items[ix]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_auto_scroll_delta() {
+        assert_eq!(auto_scroll_delta(px(0.), px(400.), px(200.)), px(0.));
+        assert_eq!(
+            auto_scroll_delta(px(0.), px(400.), px(0.)),
+            -AUTO_SCROLL_STEP
+        );
+        assert_eq!(
+            auto_scroll_delta(px(0.), px(400.), px(20.)),
+            -AUTO_SCROLL_STEP / 2.
+        );
+        assert_eq!(
+            auto_scroll_delta(px(0.), px(400.), px(400.)),
+            AUTO_SCROLL_STEP
+        );
+        assert_eq!(auto_scroll_delta(px(0.), px(0.), px(0.)), px(0.));
+    }
+}
@@ -0,0 +1,153 @@
+use gpui::{
+    AnyElement, App, IntoElement, ParentElement, RenderOnce, SharedString, StyleRefinement, Styled,
+    Window, div, prelude::FluentBuilder as _, px,
+};
+
+use crate::{
+    ActiveTheme as _, Icon, IconName, Sizable, Size, StyledExt, h_flex, text::Text, v_flex,
+};
+
+/// A placeholder to display when there is no content, e.g.: "No session selected".
+///
+/// It contains an icon, a title, a description and the actions.
+#[derive(IntoElement)]
+pub struct EmptyState {
+    style: StyleRefinement,
+    icon: Option<Icon>,
+    title: Option<SharedString>,
+    description: Option<Text>,
+    actions: Vec<AnyElement>,
+    size: Size,
+}
+
+impl EmptyState {
+    /// Create a new EmptyState with the default [`IconName::Inbox`] icon.
+    pub fn new() -> Self {
+        Self {
+            style: StyleRefinement::default(),
+            icon: Some(Icon::new(IconName::Inbox)),
+            title: None,
+            description: None,
+            actions: Vec::new(),
+            size: Size::default(),
+        }
+    }
+
+    /// Set the icon, default: [`IconName::Inbox`]
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Hide the icon.
+    pub fn no_icon(mut self) -> Self {
+        self.icon = None;
+        self
+    }
+
+    /// Set the title.
+    pub fn title(mut self, title: impl Into<SharedString>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the description, it supports [`Text`] to use Markdown.
+    pub fn description(mut self, description: impl Into<Text>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Add an action element, e.g.: a primary [`Button`](crate::button::Button).
+    pub fn action(mut self, action: impl IntoElement) -> Self {
+        self.actions.push(action.into_any_element());
+        self
+    }
+
+    /// Add multiple action elements.
+    pub fn actions(mut self, actions: impl IntoIterator<Item = impl IntoElement>) -> Self {
+        self.actions
+            .extend(actions.into_iter().map(|action| action.into_any_element()));
+        self
+    }
+}
+
+impl Styled for EmptyState {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl Sizable for EmptyState {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl RenderOnce for EmptyState {
+    fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+        let (icon_size, gap) = match self.size {
+            Size::XSmall | Size::Small => (px(32.), px(8.)),
+            Size::Large => (px(64.), px(16.)),
+            _ => (px(48.), px(12.)),
+        };
+
+        v_flex()
+            .size_full()
+            .items_center()
+            .justify_center()
+            .gap(gap)
+            .p_4()
+            .text_center()
+            .refine_style(&self.style)
+            .when_some(self.icon, |this, icon| {
+                this.child(
+                    div()
+                        .text_color(cx.theme().muted_foreground.opacity(0.6))
+                        .child(icon.size(icon_size)),
+                )
+            })
+            .when(self.title.is_some() || self.description.is_some(), |this| {
+                this.child(
+                    v_flex()
+                        .items_center()
+                        .gap_1()
+                        .max_w_96()
+                        .when_some(self.title, |this, title| {
+                            this.child(
+                                div()
+                                    .map(|this| match self.size {
+                                        Size::XSmall | Size::Small => this.text_sm(),
+                                        Size::Large => this.text_lg(),
+                                        _ => this.text_base(),
+                                    })
+                                    .font_semibold()
+                                    .text_color(cx.theme().foreground)
+                                    .child(title),
+                            )
+                        })
+                        .when_some(self.description, |this, description| {
+                            this.child(
+                                div()
+                                    .map(|this| match self.size {
+                                        Size::XSmall | Size::Small => this.text_xs(),
+                                        _ => this.text_sm(),
+                                    })
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(description),
+                            )
+                        }),
+                )
+            })
+            .when(!self.actions.is_empty(), |this| {
+                this.child(
+                    h_flex()
+                        .mt_1()
+                        .gap_2()
+                        .flex_wrap()
+                        .justify_center()
+                        .children(self.actions),
+                )
+            })
+    }
+}
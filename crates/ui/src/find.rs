@@ -0,0 +1,552 @@
+//! A find-in-page bar to search the content of a scrollable view, e.g.: chat history, diffs
+//! or markdown documents.
+//!
+//! The host provides the text index (one text per item, can be virtualized) by
+//! [`FindBar::set_texts`], and scrolls to the item of the [`FindEvent::Select`] match.
+//!
+//! - [`FindBar::highlights`] returns the highlights of the matches in an item.
+//! - [`FindBar::scrollbar_markers`] returns the markers of the matches for the scrollbar.
+//! - [`FindableExt::findable`] opens the find bar with `cmd-f` (`ctrl-f` on Linux and Windows).
+use std::ops::Range;
+
+use aho_corasick::AhoCorasick;
+use gpui::{
+    App, AppContext as _, Context, Empty, Entity, EventEmitter, FocusHandle, Focusable, Half,
+    HighlightStyle, InteractiveElement, IntoElement, KeyBinding, ParentElement as _, Render,
+    SharedString, Styled, Subscription, Window, div, prelude::FluentBuilder as _,
+};
+use rust_i18n::t;
+
+use crate::{
+    ActiveTheme as _, Colorize as _, Disableable as _, IconName, Selectable as _, Sizable as _,
+    actions::SelectUp,
+    button::{Button, ButtonVariants as _},
+    h_flex,
+    input::{Enter, Escape, Input, InputEvent, InputState, Search, SelectAll},
+    label::Label,
+    scroll::ScrollbarMarker,
+};
+
+const CONTEXT: &str = "FindBar";
+const FINDABLE_CONTEXT: &str = "Findable";
+
+pub(crate) fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("shift-enter", SelectUp, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-f", Search, Some(FINDABLE_CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-f", Search, Some(FINDABLE_CONTEXT)),
+    ]);
+}
+
+/// A match of the find query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FindMatch {
+    /// The index of the item in the texts.
+    pub ix: usize,
+    /// The byte range of the match in the text of the item.
+    pub range: Range<usize>,
+}
+
+/// The events emitted by the [`FindBar`].
+#[derive(Debug, Clone)]
+pub enum FindEvent {
+    /// The match is selected, the host should scroll to the item of the match.
+    Select(FindMatch),
+    /// The find bar is closed.
+    Close,
+}
+
+/// Find all the matches of the `query` in the `texts`, the matches are ordered by the item index.
+///
+/// The case insensitive match compares the Unicode lowercase of the chars, e.g.: `Ä` matches `ä`.
+pub fn find_matches(texts: &[SharedString], query: &str, case_sensitive: bool) -> Vec<FindMatch> {
+    if query.is_empty() {
+        return vec![];
+    }
+
+    let query = if case_sensitive {
+        query.to_string()
+    } else {
+        lowercase_with_offsets(query).0
+    };
+    let Ok(matcher) = AhoCorasick::new([query]) else {
+        return vec![];
+    };
+
+    texts
+        .iter()
+        .enumerate()
+        .flat_map(|(ix, text)| {
+            if case_sensitive {
+                return matcher
+                    .find_iter(text.as_str())
+                    .map(|m| FindMatch {
+                        ix,
+                        range: m.range(),
+                    })
+                    .collect::<Vec<_>>();
+            }
+
+            let (lowercase, offsets) = lowercase_with_offsets(text);
+            matcher
+                .find_iter(&lowercase)
+                .map(|m| FindMatch {
+                    ix,
+                    range: offsets[m.start()]..offsets[m.end()],
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns the lowercase of the `text`, and the byte offsets in the `text` of each byte of
+/// the lowercase (and the end), the lowercase of a char may have a different length.
+fn lowercase_with_offsets(text: &str) -> (String, Vec<usize>) {
+    let mut lowercase = String::with_capacity(text.len());
+    let mut offsets = Vec::with_capacity(text.len() + 1);
+    for (ix, c) in text.char_indices() {
+        lowercase.extend(c.to_lowercase());
+        offsets.resize(lowercase.len(), ix);
+    }
+    offsets.push(text.len());
+    (lowercase, offsets)
+}
+
+/// A find bar to search the texts provided by the host, and move between the matches.
+///
+/// ```ignore
+/// let find_bar = cx.new(|cx| FindBar::new(window, cx));
+/// find_bar.update(cx, |bar, cx| bar.set_texts(messages.iter().map(|m| m.text.clone()), cx));
+/// cx.subscribe_in(&find_bar, window, |this, _, event: &FindEvent, _, cx| {
+///     if let FindEvent::Select(m) = event {
+///         this.scroll_handle.scroll_to_item(m.ix, ScrollStrategy::Center);
+///         cx.notify();
+///     }
+/// });
+///
+/// v_flex()
+///     .findable(&find_bar)
+///     .child(find_bar.clone())
+///     .child(messages)
+/// ```
+pub struct FindBar {
+    query_input: Entity<InputState>,
+    texts: Vec<SharedString>,
+    case_sensitive: bool,
+    matches: Vec<FindMatch>,
+    current_ix: usize,
+    open: bool,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl EventEmitter<FindEvent> for FindBar {}
+
+impl FindBar {
+    /// Create a new find bar, it is closed by default.
+    pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let query_input =
+            cx.new(|cx| InputState::new(window, cx).placeholder(t!("Find.placeholder")));
+
+        let _subscriptions = vec![cx.subscribe_in(
+            &query_input,
+            window,
+            |this, _, event: &InputEvent, window, cx| {
+                if let InputEvent::Change = event {
+                    this.update_matches(window, cx);
+                }
+            },
+        )];
+
+        Self {
+            query_input,
+            texts: vec![],
+            case_sensitive: false,
+            matches: vec![],
+            current_ix: 0,
+            open: false,
+            _subscriptions,
+        }
+    }
+
+    /// Set the texts to search, one text for each item of the host.
+    ///
+    /// The current match is kept if it is still in the matches.
+    pub fn set_texts(
+        &mut self,
+        texts: impl IntoIterator<Item = impl Into<SharedString>>,
+        cx: &mut Context<Self>,
+    ) {
+        self.texts = texts.into_iter().map(Into::into).collect();
+
+        let current = self.current_match().cloned();
+        self.matches = find_matches(&self.texts, &self.query(cx), self.case_sensitive);
+        self.current_ix = current
+            .and_then(|current| self.matches.iter().position(|m| *m == current))
+            .unwrap_or(0);
+        cx.notify();
+    }
+
+    /// Open the find bar and focus the query input, the query is selected for typing a new one.
+    pub fn open(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.open = true;
+        self.query_input.update(cx, |input, cx| {
+            input.focus(window, cx);
+            input.select_all(&SelectAll, window, cx);
+        });
+        cx.notify();
+    }
+
+    /// Close the find bar and emit [`FindEvent::Close`].
+    pub fn close(&mut self, _: &mut Window, cx: &mut Context<Self>) {
+        if !self.open {
+            return;
+        }
+
+        self.open = false;
+        cx.emit(FindEvent::Close);
+        cx.notify();
+    }
+
+    /// Returns true if the find bar is open.
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Returns the current query.
+    pub fn query(&self, cx: &App) -> SharedString {
+        self.query_input.read(cx).value()
+    }
+
+    /// Returns all the matches, empty if the find bar is closed.
+    pub fn matches(&self) -> &[FindMatch] {
+        if !self.open {
+            return &[];
+        }
+
+        &self.matches
+    }
+
+    /// Returns the current match.
+    pub fn current_match(&self) -> Option<&FindMatch> {
+        self.matches().get(self.current_ix)
+    }
+
+    /// Select the next match, wrap to the first match at the end.
+    pub fn select_next(&mut self, _: &mut Window, cx: &mut Context<Self>) {
+        if self.matches.is_empty() {
+            return;
+        }
+
+        self.current_ix = (self.current_ix + 1) % self.matches.len();
+        self.emit_select(cx);
+    }
+
+    /// Select the previous match, wrap to the last match at the start.
+    pub fn select_prev(&mut self, _: &mut Window, cx: &mut Context<Self>) {
+        if self.matches.is_empty() {
+            return;
+        }
+
+        self.current_ix = self
+            .current_ix
+            .checked_sub(1)
+            .unwrap_or(self.matches.len() - 1);
+        self.emit_select(cx);
+    }
+
+    /// Returns the highlights of the matches in the item at `ix`, to render the text with
+    /// [`StyledText::with_highlights`](gpui::StyledText::with_highlights).
+    pub fn highlights(&self, ix: usize, cx: &App) -> Vec<(Range<usize>, HighlightStyle)> {
+        let matches = self.matches();
+        let start = matches.partition_point(|m| m.ix < ix);
+        let current = self.current_match();
+
+        matches[start..]
+            .iter()
+            .take_while(|m| m.ix == ix)
+            .map(|m| {
+                let background = if Some(m) == current {
+                    cx.theme().selection
+                } else {
+                    cx.theme().selection.saturation(0.1)
+                };
+
+                (
+                    m.range.clone(),
+                    HighlightStyle {
+                        background_color: Some(background),
+                        ..Default::default()
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the markers of the matched items to show on the scrollbar.
+    ///
+    /// See also [`Scrollbar::markers`](crate::scroll::Scrollbar::markers).
+    ///
+    /// The position of the marker assumes the items have the same height.
+    pub fn scrollbar_markers(&self, cx: &App) -> Vec<ScrollbarMarker> {
+        let len = self.texts.len();
+        if len == 0 {
+            return vec![];
+        }
+
+        let current_ix = self.current_match().map(|m| m.ix);
+        let mut markers: Vec<ScrollbarMarker> = vec![];
+        let mut last_ix = None;
+        for m in self.matches() {
+            if last_ix == Some(m.ix) {
+                continue;
+            }
+            last_ix = Some(m.ix);
+
+            let color = if Some(m.ix) == current_ix {
+                cx.theme().selection
+            } else {
+                cx.theme().selection.opacity(0.5)
+            };
+            markers.push(ScrollbarMarker::new(
+                (m.ix as f32 + 0.5) / len as f32,
+                color,
+            ));
+        }
+        markers
+    }
+
+    fn update_matches(&mut self, _: &mut Window, cx: &mut Context<Self>) {
+        self.matches = find_matches(&self.texts, &self.query(cx), self.case_sensitive);
+        self.current_ix = 0;
+        self.emit_select(cx);
+    }
+
+    fn emit_select(&mut self, cx: &mut Context<Self>) {
+        if let Some(m) = self.current_match().cloned() {
+            cx.emit(FindEvent::Select(m));
+        }
+        cx.notify();
+    }
+
+    fn label(&self) -> String {
+        if self.matches.is_empty() {
+            return "0/0".to_string();
+        }
+        format!("{}/{}", self.current_ix + 1, self.matches.len())
+    }
+
+    fn on_action_prev(&mut self, _: &SelectUp, window: &mut Window, cx: &mut Context<Self>) {
+        self.select_prev(window, cx);
+    }
+
+    fn on_action_next(&mut self, _: &Enter, window: &mut Window, cx: &mut Context<Self>) {
+        // The `shift-enter` is bound to `Enter` by the query input, it selects the previous match.
+        if window.modifiers().shift {
+            self.select_prev(window, cx);
+        } else {
+            self.select_next(window, cx);
+        }
+    }
+
+    fn on_action_escape(&mut self, _: &Escape, window: &mut Window, cx: &mut Context<Self>) {
+        self.close(window, cx);
+    }
+}
+
+impl Focusable for FindBar {
+    fn focus_handle(&self, cx: &App) -> FocusHandle {
+        self.query_input.focus_handle(cx)
+    }
+}
+
+impl Render for FindBar {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        if !self.open {
+            return Empty.into_any_element();
+        }
+
+        let has_matches = !self.matches.is_empty();
+
+        h_flex()
+            .id("find-bar")
+            .occlude()
+            .key_context(CONTEXT)
+            .on_action(cx.listener(Self::on_action_prev))
+            .on_action(cx.listener(Self::on_action_next))
+            .on_action(cx.listener(Self::on_action_escape))
+            .w_full()
+            .gap_2()
+            .py_2()
+            .px_3()
+            .bg(cx.theme().popover)
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .rounded(cx.theme().radius.half())
+            .child(
+                div().flex_1().child(
+                    Input::new(&self.query_input)
+                        .focus_bordered(false)
+                        .suffix(
+                            Button::new("case-sensitive")
+                                .selected(self.case_sensitive)
+                                .xsmall()
+                                .compact()
+                                .ghost()
+                                .icon(IconName::CaseSensitive)
+                                .on_click(cx.listener(|this, _, window, cx| {
+                                    this.case_sensitive = !this.case_sensitive;
+                                    this.update_matches(window, cx);
+                                })),
+                        )
+                        .small()
+                        .w_full()
+                        .shadow_none(),
+                ),
+            )
+            .child(
+                Button::new("prev")
+                    .xsmall()
+                    .ghost()
+                    .icon(IconName::ChevronLeft)
+                    .disabled(!has_matches)
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.select_prev(window, cx);
+                    })),
+            )
+            .child(
+                Button::new("next")
+                    .xsmall()
+                    .ghost()
+                    .icon(IconName::ChevronRight)
+                    .disabled(!has_matches)
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.select_next(window, cx);
+                    })),
+            )
+            .child(
+                Label::new(self.label())
+                    .when(!has_matches, |this| {
+                        this.text_color(cx.theme().muted_foreground)
+                    })
+                    .text_left()
+                    .min_w_16(),
+            )
+            .child(
+                Button::new("close")
+                    .xsmall()
+                    .ghost()
+                    .icon(IconName::Close)
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.close(window, cx);
+                    })),
+            )
+            .into_any_element()
+    }
+}
+
+/// An extension trait to open the [`FindBar`] by `cmd-f` (`ctrl-f` on Linux and Windows).
+pub trait FindableExt: InteractiveElement + Sized {
+    /// Open the `find_bar` when `cmd-f` is pressed in this element.
+    ///
+    /// NOTE: This sets the key context of the element to `Findable`.
+    fn findable(self, find_bar: &Entity<FindBar>) -> Self {
+        let find_bar = find_bar.clone();
+        self.key_context(FINDABLE_CONTEXT)
+            .on_action(move |_: &Search, window, cx| {
+                find_bar.update(cx, |bar, cx| bar.open(window, cx));
+            })
+    }
+}
+
+impl<T: InteractiveElement + Sized> FindableExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{SharedString, TestAppContext};
+
+    use super::{FindBar, FindMatch, find_matches};
+    use crate::test::TestWindow;
+
+    #[test]
+    fn test_find_matches_unicode_case() {
+        let texts: Vec<SharedString> = vec!["Ärger ÄRGER ärger".into()];
+        assert_eq!(
+            find_matches(&texts, "äRGER", false),
+            vec![
+                FindMatch { ix: 0, range: 0..6 },
+                FindMatch {
+                    ix: 0,
+                    range: 7..13
+                },
+                FindMatch {
+                    ix: 0,
+                    range: 14..20
+                },
+            ]
+        );
+        assert_eq!(
+            find_matches(&texts, "Ärger", true),
+            vec![FindMatch { ix: 0, range: 0..6 }]
+        );
+    }
+
+    #[gpui::test]
+    fn test_enter_and_shift_enter(cx: &mut TestAppContext) {
+        let mut window = TestWindow::new(cx, |window, cx| FindBar::new(window, cx));
+        window.update(|bar, window, cx| {
+            bar.set_texts(["foo", "foo", "foo"], cx);
+            bar.open(window, cx);
+        });
+        window.type_text("foo");
+        let current_ix = |window: &mut TestWindow<FindBar>| window.read(|bar, _| bar.current_ix);
+        assert_eq!(current_ix(&mut window), 0);
+
+        window.keystrokes("enter");
+        assert_eq!(current_ix(&mut window), 1);
+        window.keystrokes("shift-enter");
+        assert_eq!(current_ix(&mut window), 0);
+        // Wrap to the last match.
+        window.keystrokes("shift-enter");
+        assert_eq!(current_ix(&mut window), 2);
+    }
+
+    #[test]
+    fn test_find_matches() {
+        let texts: Vec<SharedString> = vec![
+            "Hello world".into(),
+            "".into(),
+            "hello, hello".into(),
+            "你好 Hello".into(),
+        ];
+
+        assert_eq!(find_matches(&texts, "", false), vec![]);
+        assert_eq!(
+            find_matches(&texts, "hello", false),
+            vec![
+                FindMatch { ix: 0, range: 0..5 },
+                FindMatch { ix: 2, range: 0..5 },
+                FindMatch {
+                    ix: 2,
+                    range: 7..12
+                },
+                FindMatch {
+                    ix: 3,
+                    range: 7..12
+                },
+            ]
+        );
+        assert_eq!(
+            find_matches(&texts, "hello", true),
+            vec![
+                FindMatch { ix: 2, range: 0..5 },
+                FindMatch {
+                    ix: 2,
+                    range: 7..12
+                },
+            ]
+        );
+        assert_eq!(find_matches(&texts, "foo", false), vec![]);
+    }
+}
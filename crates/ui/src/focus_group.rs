@@ -0,0 +1,298 @@
+use gpui::{
+    AnyElement, App, Axis, Div, ElementId, Entity, FocusHandle, InteractiveElement, IntoElement,
+    KeyBinding, ParentElement, RenderOnce, StyleRefinement, Styled, Window, div,
+    prelude::FluentBuilder as _,
+};
+
+use crate::{
+    AxisExt as _, StyledExt as _,
+    actions::{SelectDown, SelectFirst, SelectLast, SelectLeft, SelectRight, SelectUp},
+};
+
+const CONTEXT: &str = "FocusGroup";
+pub(crate) fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("up", SelectUp, Some(CONTEXT)),
+        KeyBinding::new("down", SelectDown, Some(CONTEXT)),
+        KeyBinding::new("left", SelectLeft, Some(CONTEXT)),
+        KeyBinding::new("right", SelectRight, Some(CONTEXT)),
+        KeyBinding::new("home", SelectFirst, Some(CONTEXT)),
+        KeyBinding::new("end", SelectLast, Some(CONTEXT)),
+    ]);
+}
+
+/// The state of a [`FocusGroup`], holds the focus handles of the items.
+///
+/// Only the active item is a tab stop (roving tabindex), so `tab` moves the focus into
+/// and out of the group in one step, and the arrow keys move the focus between the items.
+pub struct FocusGroupState {
+    handles: Vec<FocusHandle>,
+    active_ix: usize,
+}
+
+impl FocusGroupState {
+    /// Create a new focus group state with `count` items.
+    pub fn new(count: usize, cx: &mut App) -> Self {
+        let mut this = Self {
+            handles: vec![],
+            active_ix: 0,
+        };
+        this.set_count(count, cx);
+        this
+    }
+
+    /// Set the number of the items, the focus handles of the existing items are kept.
+    pub fn set_count(&mut self, count: usize, cx: &mut App) {
+        self.handles.truncate(count);
+        while self.handles.len() < count {
+            self.handles.push(cx.focus_handle());
+        }
+        self.active_ix = self.active_ix.min(count.saturating_sub(1));
+    }
+
+    /// Returns the number of the items.
+    pub fn len(&self) -> usize {
+        self.handles.len()
+    }
+
+    /// Returns true if there is no item.
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+
+    /// Returns the index of the active item, the one can be focused by `tab`.
+    pub fn active_index(&self) -> usize {
+        self.active_ix
+    }
+
+    /// Returns the focus handle of the item at `ix` to track focus on the item element,
+    /// `None` if the `ix` is out of the items, call [`FocusGroupState::set_count`] first.
+    ///
+    /// ```ignore
+    /// div().when_some(state.read(cx).handle(ix), |this, handle| this.track_focus(&handle))
+    /// ```
+    pub fn handle(&self, ix: usize) -> Option<FocusHandle> {
+        self.handles
+            .get(ix)
+            .map(|handle| handle.clone().tab_stop(ix == self.active_ix))
+    }
+
+    /// Returns the index of the focused item.
+    pub fn focused_index(&self, window: &Window) -> Option<usize> {
+        self.handles
+            .iter()
+            .position(|handle| handle.is_focused(window))
+    }
+
+    /// Focus the item at `ix` and make it active, the `ix` is clamped to the items.
+    pub fn focus(&mut self, ix: usize, window: &mut Window, cx: &mut App) {
+        if self.handles.is_empty() {
+            return;
+        }
+
+        self.active_ix = ix.min(self.handles.len() - 1);
+        window.focus(&self.handles[self.active_ix], cx);
+    }
+
+    /// Focus the next item, wrap to the first item if `wrap` is true.
+    pub fn focus_next(&mut self, wrap: bool, window: &mut Window, cx: &mut App) {
+        let current = self.focused_index(window).unwrap_or(self.active_ix);
+        if let Some(ix) = next_index(current, self.len(), true, wrap) {
+            self.focus(ix, window, cx);
+        }
+    }
+
+    /// Focus the previous item, wrap to the last item if `wrap` is true.
+    pub fn focus_prev(&mut self, wrap: bool, window: &mut Window, cx: &mut App) {
+        let current = self.focused_index(window).unwrap_or(self.active_ix);
+        if let Some(ix) = next_index(current, self.len(), false, wrap) {
+            self.focus(ix, window, cx);
+        }
+    }
+
+    /// Focus the first item.
+    pub fn focus_first(&mut self, window: &mut Window, cx: &mut App) {
+        self.focus(0, window, cx);
+    }
+
+    /// Focus the last item.
+    pub fn focus_last(&mut self, window: &mut Window, cx: &mut App) {
+        self.focus(self.len().saturating_sub(1), window, cx);
+    }
+}
+
+/// Returns the index of the next (or previous) item, `None` if there is no item to move to.
+fn next_index(current: usize, len: usize, forward: bool, wrap: bool) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+
+    let current = current.min(len - 1);
+    match (forward, wrap) {
+        (true, _) if current + 1 < len => Some(current + 1),
+        (true, true) => Some(0),
+        (false, _) if current > 0 => Some(current - 1),
+        (false, true) => Some(len - 1),
+        _ => None,
+    }
+}
+
+/// A container to make a group of items keyboard navigable, e.g.: a session list, a menu, or tags.
+///
+/// - `up` / `down` (or `left` / `right` for [`Axis::Horizontal`]) to move the focus.
+/// - `home` / `end` to focus the first / last item.
+///
+/// The items use [`FocusGroupState::handle`] to track focus:
+///
+/// ```ignore
+/// let state = cx.new(|cx| FocusGroupState::new(items.len(), cx));
+///
+/// FocusGroup::new("sessions", &state).children(items.iter().enumerate().filter_map(|(ix, item)| {
+///     let handle = state.read(cx).handle(ix)?;
+///     Some(
+///         div()
+///             .track_focus(&handle)
+///             .focus_ring(handle.is_focused(window), px(0.), window, cx)
+///             .child(item.title.clone()),
+///     )
+/// }))
+/// ```
+#[derive(IntoElement)]
+pub struct FocusGroup {
+    id: ElementId,
+    state: Entity<FocusGroupState>,
+    axis: Axis,
+    wrap: bool,
+    base: Div,
+    style: StyleRefinement,
+}
+
+impl FocusGroup {
+    /// Create a new focus group with the [`FocusGroupState`].
+    pub fn new(id: impl Into<ElementId>, state: &Entity<FocusGroupState>) -> Self {
+        Self {
+            id: id.into(),
+            state: state.clone(),
+            axis: Axis::Vertical,
+            wrap: false,
+            base: div(),
+            style: StyleRefinement::default(),
+        }
+    }
+
+    /// Set the axis of the arrow keys to move the focus, default: [`Axis::Vertical`].
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Set to wrap around when moving the focus out of the first or last item, default: false.
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+}
+
+impl ParentElement for FocusGroup {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.base.extend(elements);
+    }
+}
+
+impl Styled for FocusGroup {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl RenderOnce for FocusGroup {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let state = self.state;
+        let wrap = self.wrap;
+
+        // Keep the active item in sync with the focus changed by the mouse or `tab`.
+        if let Some(ix) = state.read(cx).focused_index(window)
+            && ix != state.read(cx).active_ix
+        {
+            state.update(cx, |state, _| state.active_ix = ix);
+        }
+
+        self.base
+            .id(self.id)
+            .key_context(CONTEXT)
+            .map(|this| {
+                if self.axis.is_horizontal() {
+                    this.on_action(window.listener_for(
+                        &state,
+                        move |state, _: &SelectLeft, window, cx| {
+                            state.focus_prev(wrap, window, cx);
+                        },
+                    ))
+                    .on_action(window.listener_for(
+                        &state,
+                        move |state, _: &SelectRight, window, cx| {
+                            state.focus_next(wrap, window, cx);
+                        },
+                    ))
+                } else {
+                    this.on_action(window.listener_for(
+                        &state,
+                        move |state, _: &SelectUp, window, cx| {
+                            state.focus_prev(wrap, window, cx);
+                        },
+                    ))
+                    .on_action(window.listener_for(
+                        &state,
+                        move |state, _: &SelectDown, window, cx| {
+                            state.focus_next(wrap, window, cx);
+                        },
+                    ))
+                }
+            })
+            .on_action(
+                window.listener_for(&state, |state, _: &SelectFirst, window, cx| {
+                    state.focus_first(window, cx);
+                }),
+            )
+            .on_action(
+                window.listener_for(&state, |state, _: &SelectLast, window, cx| {
+                    state.focus_last(window, cx);
+                }),
+            )
+            .refine_style(&self.style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::TestAppContext;
+
+    use super::{FocusGroupState, next_index};
+
+    #[gpui::test]
+    fn test_handle(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            let mut state = FocusGroupState::new(2, cx);
+            assert!(state.handle(1).is_some());
+            assert!(state.handle(2).is_none());
+
+            state.set_count(3, cx);
+            assert!(state.handle(2).is_some());
+            state.set_count(0, cx);
+            assert!(state.handle(0).is_none());
+        });
+    }
+
+    #[test]
+    fn test_next_index() {
+        assert_eq!(next_index(0, 0, true, true), None);
+        assert_eq!(next_index(0, 3, true, false), Some(1));
+        assert_eq!(next_index(2, 3, true, false), None);
+        assert_eq!(next_index(2, 3, true, true), Some(0));
+        assert_eq!(next_index(1, 3, false, false), Some(0));
+        assert_eq!(next_index(0, 3, false, false), None);
+        assert_eq!(next_index(0, 3, false, true), Some(2));
+        // The out of range index is clamped to the last item.
+        assert_eq!(next_index(5, 3, false, false), Some(1));
+    }
+}
@@ -80,11 +80,13 @@ impl From<SharedString> for FieldBuilder {
 #[derive(IntoElement)]
 pub struct Field {
     id: ElementId,
+    pub(super) name: Option<SharedString>,
     props: FieldProps,
     style: StyleRefinement,
     label: Option<FieldBuilder>,
     label_indent: bool,
     description: Option<FieldBuilder>,
+    pub(super) error: Option<SharedString>,
     /// Used to render the actual form field, e.g.: Input, Switch...
     children: Vec<AnyElement>,
     visible: bool,
@@ -100,10 +102,12 @@ impl Field {
     pub fn new() -> Self {
         Self {
             id: 0.into(),
+            name: None,
             props: FieldProps::default(),
             style: StyleRefinement::default(),
             label: None,
             description: None,
+            error: None,
             children: Vec::new(),
             visible: true,
             required: false,
@@ -115,6 +119,12 @@ impl Field {
         }
     }
 
+    /// Sets the name of the form field, it is used to show the error from the [`FormState`](super::FormState).
+    pub fn name(mut self, name: impl Into<SharedString>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
     /// Sets the label for the form field.
     pub fn label(mut self, label: impl Into<FieldBuilder>) -> Self {
         self.label = Some(label.into());
@@ -161,6 +171,12 @@ impl Field {
         self
     }
 
+    /// Sets the error message for the form field, it will be shown instead of the description.
+    pub fn error(mut self, error: impl Into<SharedString>) -> Self {
+        self.error = Some(error.into());
+        self
+    }
+
     /// Set the visibility of the form field, default is `true`.
     pub fn visible(mut self, visible: bool) -> Self {
         self.visible = visible;
@@ -337,13 +353,17 @@ impl RenderOnce for Field {
                             wrap_label(label_width),
                         )
                     })
-                    .when_some(self.description, |this, builder| {
-                        this.child(
+                    .map(|this| match (self.error, self.description) {
+                        (Some(error), _) => {
+                            this.child(div().text_xs().text_color(cx.theme().danger).child(error))
+                        }
+                        (None, Some(builder)) => this.child(
                             div()
                                 .text_xs()
                                 .text_color(cx.theme().muted_foreground)
                                 .child(builder.render(window, cx)),
-                        )
+                        ),
+                        (None, None) => this,
                     }),
             )
     }
@@ -1,11 +1,11 @@
 use gpui::{
-    App, Axis, IntoElement, ParentElement, Pixels, Rems, RenderOnce, StyleRefinement, Styled,
-    Window, div, px,
+    App, Axis, Entity, IntoElement, ParentElement, Pixels, Rems, RenderOnce, StyleRefinement,
+    Styled, Window, div, px,
 };
 
 use crate::{
     Sizable, Size,
-    form::{Field, FieldProps},
+    form::{Field, FieldProps, FormState},
     v_flex,
 };
 
@@ -15,6 +15,7 @@ pub struct Form {
     style: StyleRefinement,
     fields: Vec<Field>,
     props: FieldProps,
+    state: Option<Entity<FormState>>,
 }
 
 impl Form {
@@ -23,6 +24,7 @@ impl Form {
             style: StyleRefinement::default(),
             props: FieldProps::default(),
             fields: Vec::new(),
+            state: None,
         }
     }
 
@@ -66,6 +68,12 @@ impl Form {
         self
     }
 
+    /// Bind the form with a [`FormState`], the errors of the state will be shown in the fields by their `name`.
+    pub fn state(mut self, state: &Entity<FormState>) -> Self {
+        self.state = Some(state.clone());
+        self
+    }
+
     /// Set the column count for the form.
     ///
     /// Default is 1.
@@ -89,8 +97,9 @@ impl Sizable for Form {
 }
 
 impl RenderOnce for Form {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
         let props = self.props;
+        let state = self.state.as_ref().map(|state| state.read(cx));
 
         let gap = match props.size {
             Size::XSmall | Size::Small => px(6.),
@@ -106,12 +115,15 @@ impl RenderOnce for Form {
                 .gap_y(gap)
                 .grid()
                 .grid_cols(props.columns as u16)
-                .children(
-                    self.fields
-                        .into_iter()
-                        .enumerate()
-                        .map(|(ix, field)| field.props(ix, props)),
-                ),
+                .children(self.fields.into_iter().enumerate().map(|(ix, mut field)| {
+                    if field.error.is_none()
+                        && let (Some(state), Some(name)) = (state, &field.name)
+                    {
+                        field.error = state.error(name).cloned();
+                    }
+
+                    field.props(ix, props)
+                })),
         )
     }
 }
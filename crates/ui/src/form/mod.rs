@@ -1,8 +1,11 @@
 mod field;
 mod form;
+mod state;
+pub mod validator;
 
 pub use field::*;
 pub use form::*;
+pub use state::*;
 
 /// Create a new [`Form`] with a vertical layout.
 pub fn v_form() -> Form {
@@ -0,0 +1,533 @@
+use std::{collections::HashMap, rc::Rc};
+
+use gpui::{App, Context, EventEmitter, SharedString, Task, Window};
+
+/// The value of a form field, collected by [`FormState`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue {
+    Empty,
+    Text(SharedString),
+    Bool(bool),
+    Number(f64),
+    List(Vec<SharedString>),
+}
+
+impl FieldValue {
+    /// Returns true if the value is empty, e.g.: `Empty`, blank text or empty list.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            FieldValue::Empty => true,
+            FieldValue::Text(text) => text.trim().is_empty(),
+            FieldValue::List(items) => items.is_empty(),
+            FieldValue::Bool(_) | FieldValue::Number(_) => false,
+        }
+    }
+
+    /// Returns the text if the value is `Text`.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            FieldValue::Text(text) => Some(text.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Returns the bool if the value is `Bool`.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            FieldValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the number if the value is `Number`.
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            FieldValue::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+impl From<&str> for FieldValue {
+    fn from(value: &str) -> Self {
+        Self::Text(SharedString::from(value.to_string()))
+    }
+}
+
+impl From<String> for FieldValue {
+    fn from(value: String) -> Self {
+        Self::Text(value.into())
+    }
+}
+
+impl From<SharedString> for FieldValue {
+    fn from(value: SharedString) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<bool> for FieldValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<f64> for FieldValue {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl<T: Into<FieldValue>> From<Option<T>> for FieldValue {
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or(FieldValue::Empty)
+    }
+}
+
+/// The result of a field validator, the `Err` is the error message.
+pub type ValidateResult = Result<(), SharedString>;
+
+type ValueFn = Rc<dyn Fn(&App) -> FieldValue>;
+type Validator = Rc<dyn Fn(&FieldValue) -> ValidateResult>;
+type AsyncValidator = Rc<dyn Fn(FieldValue, &mut App) -> Task<ValidateResult>>;
+
+struct FormFieldState {
+    name: SharedString,
+    value: ValueFn,
+    validators: Vec<Validator>,
+    async_validators: Vec<AsyncValidator>,
+}
+
+impl FormFieldState {
+    /// Run the sync validators, return the first error.
+    fn validate(&self, cx: &App) -> ValidateResult {
+        let value = (self.value)(cx);
+        self.validators
+            .iter()
+            .try_for_each(|validator| validator(&value))
+    }
+}
+
+/// Events emitted by the [`FormState`].
+#[derive(Clone)]
+pub enum FormEvent {
+    /// All fields are valid, with the values of the fields.
+    Submit(HashMap<SharedString, FieldValue>),
+    /// Some fields are invalid, with the `(name, error)` pairs in the fields order.
+    Invalid(Vec<(SharedString, SharedString)>),
+}
+
+/// The state of a [`Form`](super::Form) to collect values and validate the fields.
+///
+/// Each field is registered by a unique name with a function to read its value,
+/// then the validators can be added to the field.
+///
+/// ```ignore
+/// let form = cx.new(|cx| {
+///     FormState::new(window, cx)
+///         .field("email", {
+///             let input = email_input.clone();
+///             move |cx| input.read(cx).value().into()
+///         })
+///         .validator("email", validator::required("Email is required."))
+/// });
+/// ```
+pub struct FormState {
+    fields: Vec<FormFieldState>,
+    errors: HashMap<SharedString, SharedString>,
+    /// The async validators of [`FormState::validate_field`] are running.
+    validating: bool,
+    /// The async validators of [`FormState::submit`] are running.
+    submitting: bool,
+    _validate_task: Option<Task<()>>,
+    /// Kept apart from the `_validate_task`, so validating a field does not cancel the submit.
+    _submit_task: Option<Task<()>>,
+}
+
+impl EventEmitter<FormEvent> for FormState {}
+
+impl FormState {
+    /// Create a new form state.
+    pub fn new(_: &mut Window, _: &mut Context<Self>) -> Self {
+        Self {
+            fields: vec![],
+            errors: HashMap::new(),
+            validating: false,
+            submitting: false,
+            _validate_task: None,
+            _submit_task: None,
+        }
+    }
+
+    /// Register a field with the `name` and the function to read the value.
+    ///
+    /// If the field is already registered, the value function will be replaced.
+    pub fn field(
+        mut self,
+        name: impl Into<SharedString>,
+        value: impl Fn(&App) -> FieldValue + 'static,
+    ) -> Self {
+        let name = name.into();
+        if let Some(field) = self.fields.iter_mut().find(|field| field.name == name) {
+            field.value = Rc::new(value);
+        } else {
+            self.fields.push(FormFieldState {
+                name,
+                value: Rc::new(value),
+                validators: vec![],
+                async_validators: vec![],
+            });
+        }
+        self
+    }
+
+    /// Add a sync validator to the field, the validators run in the order they are added.
+    ///
+    /// This is no effect if the field is not registered.
+    pub fn validator(
+        mut self,
+        name: impl Into<SharedString>,
+        validator: impl Fn(&FieldValue) -> ValidateResult + 'static,
+    ) -> Self {
+        if let Some(field) = self.field_mut(&name.into()) {
+            field.validators.push(Rc::new(validator));
+        }
+        self
+    }
+
+    /// Add an async validator to the field, e.g.: check the username is available by a request.
+    ///
+    /// The async validators only run after all the sync validators of the field passed.
+    pub fn async_validator(
+        mut self,
+        name: impl Into<SharedString>,
+        validator: impl Fn(FieldValue, &mut App) -> Task<ValidateResult> + 'static,
+    ) -> Self {
+        if let Some(field) = self.field_mut(&name.into()) {
+            field.async_validators.push(Rc::new(validator));
+        }
+        self
+    }
+
+    fn field_mut(&mut self, name: &SharedString) -> Option<&mut FormFieldState> {
+        self.fields.iter_mut().find(|field| &field.name == name)
+    }
+
+    /// Returns the value of the field by name.
+    pub fn value(&self, name: &str, cx: &App) -> Option<FieldValue> {
+        self.fields
+            .iter()
+            .find(|field| field.name.as_ref() == name)
+            .map(|field| (field.value)(cx))
+    }
+
+    /// Returns the values of all fields.
+    pub fn values(&self, cx: &App) -> HashMap<SharedString, FieldValue> {
+        self.fields
+            .iter()
+            .map(|field| (field.name.clone(), (field.value)(cx)))
+            .collect()
+    }
+
+    /// Returns the error of the field by name.
+    pub fn error(&self, name: &str) -> Option<&SharedString> {
+        self.errors.get(name)
+    }
+
+    /// Returns the `(name, error)` pairs of the invalid fields, in the fields order.
+    pub fn errors(&self) -> Vec<(SharedString, SharedString)> {
+        self.fields
+            .iter()
+            .filter_map(|field| {
+                self.errors
+                    .get(&field.name)
+                    .map(|error| (field.name.clone(), error.clone()))
+            })
+            .collect()
+    }
+
+    /// Returns true if there is no error.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns true if the async validators are running.
+    pub fn is_validating(&self) -> bool {
+        self.validating || self.submitting
+    }
+
+    /// Set an error to the field, e.g.: the error returned by the server.
+    pub fn set_error(
+        &mut self,
+        name: impl Into<SharedString>,
+        error: impl Into<SharedString>,
+        cx: &mut Context<Self>,
+    ) {
+        self.errors.insert(name.into(), error.into());
+        cx.notify();
+    }
+
+    /// Clear all the errors.
+    pub fn clear_errors(&mut self, cx: &mut Context<Self>) {
+        self.errors.clear();
+        cx.notify();
+    }
+
+    /// Validate a single field by name, e.g.: when the input is blurred.
+    pub fn validate_field(&mut self, name: &str, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(ix) = self
+            .fields
+            .iter()
+            .position(|field| field.name.as_ref() == name)
+        else {
+            return;
+        };
+
+        self.run_validators(vec![ix], false, window, cx);
+    }
+
+    /// Validate all the fields, then emit [`FormEvent::Submit`] if all of them are valid,
+    /// otherwise emit [`FormEvent::Invalid`] with the errors.
+    pub fn submit(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let indices = (0..self.fields.len()).collect();
+        self.run_validators(indices, true, window, cx);
+    }
+
+    fn run_validators(
+        &mut self,
+        indices: Vec<usize>,
+        submit: bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let mut tasks = vec![];
+        for ix in indices {
+            let field = &self.fields[ix];
+            let name = field.name.clone();
+            match field.validate(cx) {
+                Err(error) => {
+                    self.errors.insert(name, error);
+                }
+                Ok(()) => {
+                    self.errors.remove(&name);
+                    if !field.async_validators.is_empty() {
+                        let value = (field.value)(cx);
+                        let validators = field.async_validators.clone();
+                        for validator in validators {
+                            tasks.push((name.clone(), validator(value.clone(), cx)));
+                        }
+                    }
+                }
+            }
+        }
+
+        if tasks.is_empty() {
+            if submit {
+                self.submitting = false;
+                self._submit_task = None;
+                self.emit_submit(cx);
+            } else {
+                self.validating = false;
+                self._validate_task = None;
+            }
+            cx.notify();
+            return;
+        }
+
+        let task = cx.spawn_in(window, async move |this, cx| {
+            let mut errors: Vec<(SharedString, SharedString)> = vec![];
+            for (name, task) in tasks {
+                if let Err(error) = task.await {
+                    if !errors.iter().any(|(n, _)| n == &name) {
+                        errors.push((name, error));
+                    }
+                }
+            }
+
+            _ = this.update_in(cx, |this, _, cx| {
+                this.errors.extend(errors);
+                if submit {
+                    this.submitting = false;
+                    this.emit_submit(cx);
+                } else {
+                    this.validating = false;
+                }
+                cx.notify();
+            });
+        });
+
+        if submit {
+            self.submitting = true;
+            self._submit_task = Some(task);
+        } else {
+            self.validating = true;
+            self._validate_task = Some(task);
+        }
+        cx.notify();
+    }
+
+    fn emit_submit(&mut self, cx: &mut Context<Self>) {
+        if self.is_valid() {
+            cx.emit(FormEvent::Submit(self.values(cx)));
+        } else {
+            cx.emit(FormEvent::Invalid(self.errors()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc, time::Duration};
+
+    use gpui::{
+        AppContext as _, Entity, SharedString, Subscription, TestAppContext, VisualTestContext,
+    };
+
+    use super::{FieldValue, FormEvent, FormState};
+    use crate::{animation::UiTestMode, form::validator};
+
+    const CHECK_DELAY: Duration = Duration::from_millis(100);
+
+    /// A form with a required `username`, which is checked to be not `taken` asynchronously.
+    fn build_form(
+        username: &str,
+        cx: &mut VisualTestContext,
+    ) -> (Entity<FormState>, Rc<RefCell<Vec<FormEvent>>>, Subscription) {
+        let username = SharedString::from(username.to_string());
+        let form = cx.update(|window, cx| {
+            cx.new(|cx| {
+                FormState::new(window, cx)
+                    .field("username", move |_| username.clone().into())
+                    .validator("username", validator::required("Username is required."))
+                    .async_validator("username", |value, cx| {
+                        let delay = UiTestMode::delay(CHECK_DELAY, cx);
+                        let timer = cx.background_executor().timer(delay);
+                        cx.background_spawn(async move {
+                            timer.await;
+                            if value.as_str() == Some("taken") {
+                                Err("Username is taken.".into())
+                            } else {
+                                Ok(())
+                            }
+                        })
+                    })
+            })
+        });
+
+        let events = Rc::new(RefCell::new(vec![]));
+        let subscription = cx.update(|_, cx| {
+            let events = events.clone();
+            cx.subscribe(&form, move |_, event: &FormEvent, _| {
+                events.borrow_mut().push(event.clone());
+            })
+        });
+
+        (form, events, subscription)
+    }
+
+    fn submit(form: &Entity<FormState>, cx: &mut VisualTestContext) {
+        cx.update(|window, cx| form.update(cx, |form, cx| form.submit(window, cx)));
+    }
+
+    fn errors(event: &FormEvent) -> Vec<(SharedString, SharedString)> {
+        match event {
+            FormEvent::Invalid(errors) => errors.clone(),
+            FormEvent::Submit(_) => vec![],
+        }
+    }
+
+    #[gpui::test]
+    fn test_submit_sync_invalid(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+        let (form, events, _subscription) = build_form(" ", cx);
+
+        submit(&form, cx);
+        assert!(!form.read_with(cx, |form, _| form.is_validating()));
+        let events = events.borrow();
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            errors(&events[0]),
+            vec![("username".into(), "Username is required.".into())]
+        );
+    }
+
+    #[gpui::test]
+    fn test_submit_async_invalid(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+        let (form, events, _subscription) = build_form("taken", cx);
+
+        submit(&form, cx);
+        cx.run_until_parked();
+        assert!(form.read_with(cx, |form, _| form.is_validating()));
+        assert!(events.borrow().is_empty());
+
+        cx.executor().advance_clock(CHECK_DELAY);
+        cx.run_until_parked();
+        assert!(!form.read_with(cx, |form, _| form.is_validating()));
+        assert_eq!(
+            form.read_with(cx, |form, _| form.error("username").cloned()),
+            Some("Username is taken.".into())
+        );
+        let events = events.borrow();
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            errors(&events[0]),
+            vec![("username".into(), "Username is taken.".into())]
+        );
+    }
+
+    #[gpui::test]
+    fn test_submit_valid(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+        let (form, events, _subscription) = build_form("alice", cx);
+
+        submit(&form, cx);
+        // Validating a field must not cancel the pending submit.
+        cx.update(|window, cx| {
+            form.update(cx, |form, cx| form.validate_field("username", window, cx))
+        });
+        cx.run_until_parked();
+        assert!(form.read_with(cx, |form, _| form.is_validating()));
+
+        cx.executor().advance_clock(CHECK_DELAY);
+        cx.run_until_parked();
+        assert!(!form.read_with(cx, |form, _| form.is_validating()));
+        let events = events.borrow();
+        assert_eq!(events.len(), 1);
+        let FormEvent::Submit(values) = &events[0] else {
+            panic!("expected the submit event");
+        };
+        assert_eq!(values.get("username"), Some(&FieldValue::from("alice")));
+    }
+
+    #[gpui::test]
+    fn test_submit_in_test_mode(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+        cx.update(|_, cx| UiTestMode::set_enabled(true, cx));
+        let (form, events, _subscription) = build_form("taken", cx);
+
+        // The check delay is resolved without advancing the clock.
+        submit(&form, cx);
+        cx.run_until_parked();
+        assert!(!form.read_with(cx, |form, _| form.is_validating()));
+        let events = events.borrow();
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            errors(&events[0]),
+            vec![("username".into(), "Username is taken.".into())]
+        );
+    }
+
+    #[test]
+    fn test_field_value() {
+        assert!(FieldValue::Empty.is_empty());
+        assert!(FieldValue::from("  ").is_empty());
+        assert!(!FieldValue::from("foo").is_empty());
+        assert!(FieldValue::List(vec![]).is_empty());
+        assert!(!FieldValue::from(false).is_empty());
+        assert_eq!(FieldValue::from(None::<bool>), FieldValue::Empty);
+        assert_eq!(FieldValue::from(Some(1.5)).as_number(), Some(1.5));
+        assert_eq!(FieldValue::from("foo").as_str(), Some("foo"));
+        assert_eq!(FieldValue::from(true).as_bool(), Some(true));
+    }
+}
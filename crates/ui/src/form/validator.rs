@@ -0,0 +1,99 @@
+//! Built-in validators for the [`FormState`](super::FormState).
+use gpui::SharedString;
+use regex::Regex;
+
+use super::{FieldValue, ValidateResult};
+
+/// The value must not be empty.
+pub fn required(
+    message: impl Into<SharedString>,
+) -> impl Fn(&FieldValue) -> ValidateResult + 'static {
+    let message = message.into();
+    move |value| {
+        if value.is_empty() {
+            Err(message.clone())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// The text must have at least `min` chars, empty text is skipped (use [`required`] for it).
+pub fn min_length(
+    min: usize,
+    message: impl Into<SharedString>,
+) -> impl Fn(&FieldValue) -> ValidateResult + 'static {
+    let message = message.into();
+    move |value| match value.as_str() {
+        Some(text) if !text.is_empty() && text.chars().count() < min => Err(message.clone()),
+        _ => Ok(()),
+    }
+}
+
+/// The text must have at most `max` chars.
+pub fn max_length(
+    max: usize,
+    message: impl Into<SharedString>,
+) -> impl Fn(&FieldValue) -> ValidateResult + 'static {
+    let message = message.into();
+    move |value| match value.as_str() {
+        Some(text) if text.chars().count() > max => Err(message.clone()),
+        _ => Ok(()),
+    }
+}
+
+/// The text must match the `pattern`, empty text is skipped (use [`required`] for it).
+pub fn pattern(
+    pattern: Regex,
+    message: impl Into<SharedString>,
+) -> impl Fn(&FieldValue) -> ValidateResult + 'static {
+    let message = message.into();
+    move |value| match value.as_str() {
+        Some(text) if !text.is_empty() && !pattern.is_match(text) => Err(message.clone()),
+        _ => Ok(()),
+    }
+}
+
+/// The number must be in the `min..=max` range.
+pub fn range(
+    min: f64,
+    max: f64,
+    message: impl Into<SharedString>,
+) -> impl Fn(&FieldValue) -> ValidateResult + 'static {
+    let message = message.into();
+    move |value| match value.as_number() {
+        Some(number) if number < min || number > max => Err(message.clone()),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use regex::Regex;
+
+    use super::*;
+
+    #[test]
+    fn test_validators() {
+        let validate = required("required");
+        assert!(validate(&FieldValue::from("")).is_err());
+        assert!(validate(&FieldValue::from("a")).is_ok());
+
+        let validate = min_length(3, "too short");
+        assert_eq!(validate(&FieldValue::from("ab")), Err("too short".into()));
+        assert!(validate(&FieldValue::from("")).is_ok());
+        assert!(validate(&FieldValue::from("你好吗")).is_ok());
+
+        let validate = max_length(3, "too long");
+        assert!(validate(&FieldValue::from("abcd")).is_err());
+        assert!(validate(&FieldValue::from("abc")).is_ok());
+
+        let validate = pattern(Regex::new(r"^\d+$").unwrap(), "digits only");
+        assert!(validate(&FieldValue::from("12a")).is_err());
+        assert!(validate(&FieldValue::from("123")).is_ok());
+
+        let validate = range(1., 10., "out of range");
+        assert!(validate(&FieldValue::from(11.)).is_err());
+        assert!(validate(&FieldValue::from(5.)).is_ok());
+    }
+}
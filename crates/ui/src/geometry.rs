@@ -48,6 +48,17 @@ impl Placement {
         }
     }
 
+    /// Returns the placement on the opposite side.
+    #[inline]
+    pub fn opposite(&self) -> Self {
+        match self {
+            Placement::Top => Placement::Bottom,
+            Placement::Bottom => Placement::Top,
+            Placement::Left => Placement::Right,
+            Placement::Right => Placement::Left,
+        }
+    }
+
     #[inline]
     pub fn axis(&self) -> Axis {
         match self {
@@ -6,7 +6,7 @@ use gpui::{
 use instant::Duration;
 use std::{cell::Cell, rc::Rc};
 
-use crate::{ElementExt, StyledExt as _, popover::Popover};
+use crate::{ElementExt, StyledExt as _, animation::UiTestMode, popover::Popover};
 
 /// A hover card element that displays content when hovering over a trigger element.
 ///
@@ -162,7 +162,7 @@ impl HoverCardState {
     fn schedule_open(&mut self, cx: &mut Context<Self>) {
         self.cancel_tasks();
         let epoch = self.next_epoch();
-        let delay = self.open_delay;
+        let delay = UiTestMode::delay(self.open_delay, cx);
 
         self.open_task = Some(cx.spawn(async move |this, cx| {
             cx.background_executor().timer(delay).await;
@@ -179,7 +179,7 @@ impl HoverCardState {
     fn schedule_close(&mut self, cx: &mut Context<Self>) {
         self.cancel_tasks();
         let epoch = self.next_epoch();
-        let delay = self.close_delay;
+        let delay = UiTestMode::delay(self.close_delay, cx);
 
         self.close_task = Some(cx.spawn(async move |this, cx| {
             cx.background_executor().timer(delay).await;
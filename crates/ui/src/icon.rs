@@ -1,11 +1,19 @@
-use crate::{ActiveTheme, Sizable, Size};
+use std::collections::HashMap;
+
+use instant::Duration;
+
+use crate::{ActiveTheme, Sizable, Size, animation::is_motion_reduced};
 use gpui::{
-    AnyElement, App, AppContext, Context, Entity, Hsla, IntoElement, Radians, Render, RenderOnce,
-    SharedString, StyleRefinement, Styled, Svg, Transformation, Window,
-    prelude::FluentBuilder as _, svg,
+    Animation, AnimationExt as _, AnyElement, App, AppContext, Context, ElementId, Entity, Global,
+    Hsla, IntoElement, Radians, Render, RenderOnce, SharedString, StyleRefinement, Styled, Svg,
+    Transformation, Window, ease_in_out, percentage, prelude::FluentBuilder as _, svg,
 };
 use gpui_component_macros::icon_named;
 
+pub(crate) fn init(cx: &mut App) {
+    cx.set_global(IconRegistry::default());
+}
+
 /// Types implementing this trait can automatically be converted to [`Icon`].
 ///
 /// This allows you to implement a custom version of [`IconName`] that functions as a drop-in
@@ -23,6 +31,75 @@ impl<T: IconNamed> From<T> for Icon {
 
 icon_named!(IconName, "../assets/assets/icons");
 
+/// A registry of the custom SVG icons, to use them by name with [`Icon::named`].
+///
+/// The path is the SVG path in the Assets bundle of the application, e.g.:
+///
+/// ```ignore
+/// IconRegistry::global_mut(cx)
+///     .register("brand-openai", "icons/brand/openai.svg")
+///     .register("brand-claude", "icons/brand/claude.svg");
+///
+/// Icon::named("brand-openai")
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct IconRegistry {
+    icons: HashMap<SharedString, SharedString>,
+}
+
+impl Global for IconRegistry {}
+
+impl IconRegistry {
+    /// Returns the global icon registry.
+    pub fn global(cx: &App) -> &Self {
+        cx.global::<Self>()
+    }
+
+    /// Returns the global icon registry mutable reference.
+    pub fn global_mut(cx: &mut App) -> &mut Self {
+        cx.global_mut::<Self>()
+    }
+
+    /// Register an icon with the `name` and the SVG `path`, the icon with the same name will be replaced.
+    pub fn register(
+        &mut self,
+        name: impl Into<SharedString>,
+        path: impl Into<SharedString>,
+    ) -> &mut Self {
+        self.icons.insert(name.into(), path.into());
+        self
+    }
+
+    /// Register multiple icons with `(name, path)` pairs.
+    pub fn register_all<N, P>(&mut self, icons: impl IntoIterator<Item = (N, P)>) -> &mut Self
+    where
+        N: Into<SharedString>,
+        P: Into<SharedString>,
+    {
+        self.icons.extend(
+            icons
+                .into_iter()
+                .map(|(name, path)| (name.into(), path.into())),
+        );
+        self
+    }
+
+    /// Remove the icon by name.
+    pub fn unregister(&mut self, name: &str) {
+        self.icons.remove(name);
+    }
+
+    /// Returns the SVG path of the icon by name.
+    pub fn path(&self, name: &str) -> Option<&SharedString> {
+        self.icons.get(name)
+    }
+
+    /// Returns true if the icon is registered.
+    pub fn contains(&self, name: &str) -> bool {
+        self.icons.contains_key(name)
+    }
+}
+
 impl IconName {
     /// Return the icon as a Entity<Icon>
     pub fn view(self, cx: &mut App) -> Entity<Icon> {
@@ -42,14 +119,52 @@ impl RenderOnce for IconName {
     }
 }
 
+/// The built-in animations of the [`Icon`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IconAnimation {
+    /// Rotate the icon continuously, with the duration of one turn.
+    Spin(Duration),
+    /// Fade the icon out and in continuously, with the duration of one cycle.
+    Pulse(Duration),
+}
+
+impl IconAnimation {
+    /// Apply the animation to the svg element.
+    fn apply(self, id: impl Into<ElementId>, svg: Svg) -> AnyElement {
+        match self {
+            IconAnimation::Spin(duration) => svg
+                .with_animation(id, Animation::new(duration).repeat(), |this, delta| {
+                    this.with_transformation(Transformation::rotate(percentage(delta)))
+                })
+                .into_any_element(),
+            IconAnimation::Pulse(duration) => svg
+                .with_animation(
+                    id,
+                    Animation::new(duration).repeat().with_easing(ease_in_out),
+                    |this, delta| this.opacity(pulse_opacity(delta)),
+                )
+                .into_any_element(),
+        }
+    }
+}
+
+/// Returns the opacity of the pulse animation, from `1.0` to `0.4` and back to `1.0`.
+fn pulse_opacity(delta: f32) -> f32 {
+    let t = 1. - (delta * 2. - 1.).abs();
+    1. - 0.6 * t
+}
+
 #[derive(IntoElement)]
 pub struct Icon {
     base: Svg,
     style: StyleRefinement,
     path: SharedString,
+    /// The name of the icon in the [`IconRegistry`], resolved when rendering.
+    name: Option<SharedString>,
     text_color: Option<Hsla>,
     size: Option<Size>,
     rotation: Option<Radians>,
+    animation: Option<IconAnimation>,
 }
 
 impl Default for Icon {
@@ -58,9 +173,11 @@ impl Default for Icon {
             base: svg().flex_none().size_4(),
             style: StyleRefinement::default(),
             path: "".into(),
+            name: None,
             text_color: None,
             size: None,
             rotation: None,
+            animation: None,
         }
     }
 }
@@ -68,8 +185,10 @@ impl Default for Icon {
 impl Clone for Icon {
     fn clone(&self) -> Self {
         let mut this = Self::default().path(self.path.clone());
+        this.name = self.name.clone();
         this.style = self.style.clone();
         this.rotation = self.rotation;
+        this.animation = self.animation;
         this.size = self.size;
         this.text_color = self.text_color;
         this
@@ -85,14 +204,41 @@ impl Icon {
         Self::default().path(name.path())
     }
 
+    /// Create an icon from the SVG path of the Assets bundle, e.g.: `icons/brand/openai.svg`.
+    pub fn from_svg_path(path: impl Into<SharedString>) -> Self {
+        Self::default().path(path)
+    }
+
+    /// Create an icon by the name registered in the [`IconRegistry`].
+    ///
+    /// Nothing will be rendered if the name is not registered.
+    pub fn named(name: impl Into<SharedString>) -> Self {
+        let mut this = Self::default();
+        this.name = Some(name.into());
+        this
+    }
+
     /// Set the icon path of the Assets bundle
     ///
     /// For example: `icons/foo.svg`
     pub fn path(mut self, path: impl Into<SharedString>) -> Self {
         self.path = path.into();
+        self.name = None;
         self
     }
 
+    /// Returns the SVG path to render, resolve the name by the [`IconRegistry`] if present.
+    fn resolved_path(&self, cx: &App) -> SharedString {
+        match &self.name {
+            Some(name) => cx
+                .try_global::<IconRegistry>()
+                .and_then(|registry| registry.path(name))
+                .cloned()
+                .unwrap_or_default(),
+            None => self.path.clone(),
+        }
+    }
+
     /// Create a new view for the icon
     pub fn view(self, cx: &mut App) -> Entity<Icon> {
         cx.new(|_| self)
@@ -114,6 +260,29 @@ impl Icon {
             .with_transformation(Transformation::rotate(radians));
         self
     }
+
+    /// Rotate the icon continuously, e.g.: for a running tool call.
+    pub fn spin(self) -> Self {
+        self.animation(IconAnimation::Spin(Duration::from_secs(1)))
+    }
+
+    /// Fade the icon out and in continuously, e.g.: for a waiting state.
+    pub fn pulse(self) -> Self {
+        self.animation(IconAnimation::Pulse(Duration::from_millis(1500)))
+    }
+
+    /// Set the animation of the icon.
+    ///
+    /// The animation is not played if [`is_motion_reduced`].
+    pub fn animation(mut self, animation: IconAnimation) -> Self {
+        self.animation = Some(animation);
+        self
+    }
+
+    /// Returns the animation to play, `None` if there is no animation or the motion is reduced.
+    fn active_animation(&self, cx: &App) -> Option<IconAnimation> {
+        self.animation.filter(|_| !is_motion_reduced(cx))
+    }
 }
 
 impl Styled for Icon {
@@ -135,15 +304,18 @@ impl Sizable for Icon {
 }
 
 impl RenderOnce for Icon {
-    fn render(self, window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let path = self.resolved_path(cx);
         let text_color = self.text_color.unwrap_or_else(|| window.text_style().color);
         let text_size = window.text_style().font_size.to_pixels(window.rem_size());
         let has_base_size = self.style.size.width.is_some() || self.style.size.height.is_some();
 
+        let animation = self.active_animation(cx);
         let mut base = self.base;
         *base.style() = self.style;
 
-        base.flex_shrink_0()
+        let base = base
+            .flex_shrink_0()
             .text_color(text_color)
             .when(!has_base_size, |this| this.size(text_size))
             .when_some(self.size, |this, size| match size {
@@ -153,7 +325,12 @@ impl RenderOnce for Icon {
                 Size::Medium => this.size_4(),
                 Size::Large => this.size_6(),
             })
-            .path(self.path)
+            .path(path.clone());
+
+        match animation {
+            Some(animation) => animation.apply(("icon-animation", path), base),
+            None => base.into_any_element(),
+        }
     }
 }
 
@@ -169,10 +346,13 @@ impl Render for Icon {
         let text_size = window.text_style().font_size.to_pixels(window.rem_size());
         let has_base_size = self.style.size.width.is_some() || self.style.size.height.is_some();
 
+        let path = self.resolved_path(cx);
+        let animation = self.active_animation(cx);
         let mut base = svg().flex_none();
         *base.style() = self.style.clone();
 
-        base.flex_shrink_0()
+        let base = base
+            .flex_shrink_0()
             .text_color(text_color)
             .when(!has_base_size, |this| this.size(text_size))
             .when_some(self.size, |this, size| match size {
@@ -182,9 +362,50 @@ impl Render for Icon {
                 Size::Medium => this.size_4(),
                 Size::Large => this.size_6(),
             })
-            .path(self.path.clone())
+            .path(path.clone())
             .when_some(self.rotation, |this, rotation| {
                 this.with_transformation(Transformation::rotate(rotation))
-            })
+            });
+
+        match animation {
+            Some(animation) => animation.apply(("icon-animation", path), base),
+            None => base.into_any_element(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IconRegistry, pulse_opacity};
+
+    #[test]
+    fn test_pulse_opacity() {
+        assert_eq!(pulse_opacity(0.), 1.);
+        assert_eq!(pulse_opacity(0.5), 0.4);
+        assert_eq!(pulse_opacity(1.), 1.);
+    }
+
+    #[test]
+    fn test_icon_registry() {
+        let mut registry = IconRegistry::default();
+        registry
+            .register("brand-openai", "icons/brand/openai.svg")
+            .register_all([("foo", "icons/foo.svg"), ("bar", "icons/bar.svg")]);
+
+        assert_eq!(
+            registry.path("brand-openai").map(|path| path.as_ref()),
+            Some("icons/brand/openai.svg")
+        );
+        assert!(registry.contains("foo"));
+
+        registry.register("foo", "icons/foo2.svg");
+        assert_eq!(
+            registry.path("foo").map(|path| path.as_ref()),
+            Some("icons/foo2.svg")
+        );
+
+        registry.unregister("bar");
+        assert!(!registry.contains("bar"));
+        assert_eq!(registry.path("unknown"), None);
     }
 }
@@ -0,0 +1,515 @@
+use gpui::{
+    App, AppContext as _, Bounds, Context, ElementId, Entity, FocusHandle, Focusable, ImageSource,
+    ImgResourceLoader, InteractiveElement as _, IntoElement, KeyBinding, KeyDownEvent, MouseButton,
+    MouseDownEvent, MouseMoveEvent, ObjectFit, ParentElement as _, Pixels, Point, RenderOnce,
+    ScrollWheelEvent, SharedString, Size, StatefulInteractiveElement as _, StyleRefinement, Styled,
+    StyledImage as _, Window, actions, div, img, prelude::FluentBuilder as _, px, size,
+};
+
+use crate::{
+    ActiveTheme as _, ElementExt as _, IconName, Selectable as _, Sizable as _, StyledExt as _,
+    button::{Button, ButtonVariants as _},
+    h_flex,
+};
+
+actions!(image_viewer, [ZoomIn, ZoomOut, ZoomReset, ActualSize]);
+
+const CONTEXT: &str = "ImageViewer";
+pub(crate) fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("=", ZoomIn, Some(CONTEXT)),
+        KeyBinding::new("+", ZoomIn, Some(CONTEXT)),
+        KeyBinding::new("secondary-=", ZoomIn, Some(CONTEXT)),
+        KeyBinding::new("-", ZoomOut, Some(CONTEXT)),
+        KeyBinding::new("secondary--", ZoomOut, Some(CONTEXT)),
+        KeyBinding::new("0", ZoomReset, Some(CONTEXT)),
+        KeyBinding::new("secondary-0", ZoomReset, Some(CONTEXT)),
+        KeyBinding::new("1", ActualSize, Some(CONTEXT)),
+    ]);
+}
+
+const MIN_ZOOM: f32 = 0.1;
+const MAX_ZOOM: f32 = 10.;
+const ZOOM_STEP: f32 = 1.25;
+/// The distance in pixels to pan the image by arrow keys.
+const PAN_STEP: Pixels = px(40.);
+
+/// The fit mode of the image in the [`ImageViewer`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFit {
+    /// Scale the image to fit inside the viewer, keep the aspect ratio.
+    #[default]
+    Fit,
+    /// Scale the image to fill the viewer, keep the aspect ratio, the image may be cropped.
+    Fill,
+    /// Display the image in the actual size.
+    ActualSize,
+}
+
+/// Returns the scale of the image in the `viewport` by the fit mode, without zoom.
+fn fit_scale(fit: ImageFit, image: Size<Pixels>, viewport: Size<Pixels>) -> f32 {
+    if image.width <= px(0.) || image.height <= px(0.) {
+        return 1.;
+    }
+
+    let scale_x = viewport.width / image.width;
+    let scale_y = viewport.height / image.height;
+    match fit {
+        ImageFit::Fit => scale_x.min(scale_y),
+        ImageFit::Fill => scale_x.max(scale_y),
+        ImageFit::ActualSize => 1.,
+    }
+}
+
+/// Returns the new offset to keep the `anchor` (relative to the viewport center) at the same
+/// point of the image, when the scale changed from `old_scale` to `new_scale`.
+fn zoom_offset(
+    offset: Point<Pixels>,
+    anchor: Point<Pixels>,
+    old_scale: f32,
+    new_scale: f32,
+) -> Point<Pixels> {
+    if old_scale <= 0. {
+        return offset;
+    }
+
+    let ratio = new_scale / old_scale;
+    anchor - (anchor - offset) * ratio
+}
+
+/// Returns the natural size of the image, if it is loaded.
+fn image_size(source: &ImageSource, window: &mut Window, cx: &mut App) -> Option<Size<Pixels>> {
+    let image = match source {
+        ImageSource::Render(image) => image.clone(),
+        ImageSource::Resource(resource) => {
+            window.use_asset::<ImgResourceLoader>(resource, cx)?.ok()?
+        }
+        _ => return None,
+    };
+
+    let image_size = image.size(0);
+    Some(size(
+        px(image_size.width.0 as f32),
+        px(image_size.height.0 as f32),
+    ))
+}
+
+/// State of the [`ImageViewer`].
+pub struct ImageViewerState {
+    focus_handle: FocusHandle,
+    source: Option<ImageSource>,
+    fit: ImageFit,
+    /// The zoom factor based on the fit scale.
+    zoom: f32,
+    /// The offset of the image center from the viewport center.
+    offset: Point<Pixels>,
+    image_size: Option<Size<Pixels>>,
+    bounds: Bounds<Pixels>,
+    last_drag_position: Option<Point<Pixels>>,
+}
+
+impl Focusable for ImageViewerState {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl ImageViewerState {
+    /// Create a new image viewer state.
+    pub fn new(_: &mut Window, cx: &mut Context<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            source: None,
+            fit: ImageFit::default(),
+            zoom: 1.,
+            offset: Point::default(),
+            image_size: None,
+            bounds: Bounds::default(),
+            last_drag_position: None,
+        }
+    }
+
+    /// Set the image source.
+    pub fn source(mut self, source: impl Into<ImageSource>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Set the default fit mode, default: [`ImageFit::Fit`]
+    pub fn fit(mut self, fit: ImageFit) -> Self {
+        self.fit = fit;
+        self
+    }
+
+    /// Set the image source, this will reset the zoom and position.
+    pub fn set_source(&mut self, source: impl Into<ImageSource>, cx: &mut Context<Self>) {
+        self.source = Some(source.into());
+        self.image_size = None;
+        self.reset(cx);
+    }
+
+    /// Returns the fit mode.
+    pub fn fit_mode(&self) -> ImageFit {
+        self.fit
+    }
+
+    /// Set the fit mode, this will reset the zoom and position.
+    pub fn set_fit(&mut self, fit: ImageFit, cx: &mut Context<Self>) {
+        self.fit = fit;
+        self.reset(cx);
+    }
+
+    /// Returns the zoom factor based on the fit mode, `1.0` means no zoom.
+    pub fn zoom(&self) -> f32 {
+        self.zoom
+    }
+
+    /// Returns the scale of the image to its actual size, `1.0` means the actual size.
+    ///
+    /// Returns `None` if the image is not loaded.
+    pub fn scale(&self) -> Option<f32> {
+        self.image_size
+            .map(|image_size| fit_scale(self.fit, image_size, self.bounds.size) * self.zoom)
+    }
+
+    /// Set the zoom factor, it is clamped to `0.1..=10.0`.
+    pub fn set_zoom(&mut self, zoom: f32, cx: &mut Context<Self>) {
+        self.zoom_at(zoom, Point::default(), cx);
+    }
+
+    /// Zoom in by a step.
+    pub fn zoom_in(&mut self, cx: &mut Context<Self>) {
+        self.set_zoom(self.zoom * ZOOM_STEP, cx);
+    }
+
+    /// Zoom out by a step.
+    pub fn zoom_out(&mut self, cx: &mut Context<Self>) {
+        self.set_zoom(self.zoom / ZOOM_STEP, cx);
+    }
+
+    /// Reset the zoom and position.
+    pub fn reset(&mut self, cx: &mut Context<Self>) {
+        self.zoom = 1.;
+        self.offset = Point::default();
+        cx.notify();
+    }
+
+    /// Zoom with the `anchor` point (relative to the viewport center) fixed.
+    fn zoom_at(&mut self, zoom: f32, anchor: Point<Pixels>, cx: &mut Context<Self>) {
+        let zoom = zoom.clamp(MIN_ZOOM, MAX_ZOOM);
+        if zoom == self.zoom {
+            return;
+        }
+
+        self.offset = zoom_offset(self.offset, anchor, self.zoom, zoom);
+        self.zoom = zoom;
+        cx.notify();
+    }
+
+    fn pan(&mut self, delta: Point<Pixels>, cx: &mut Context<Self>) {
+        self.offset = self.offset + delta;
+        cx.notify();
+    }
+
+    fn on_zoom_in(&mut self, _: &ZoomIn, _: &mut Window, cx: &mut Context<Self>) {
+        self.zoom_in(cx);
+    }
+
+    fn on_zoom_out(&mut self, _: &ZoomOut, _: &mut Window, cx: &mut Context<Self>) {
+        self.zoom_out(cx);
+    }
+
+    fn on_zoom_reset(&mut self, _: &ZoomReset, _: &mut Window, cx: &mut Context<Self>) {
+        self.reset(cx);
+    }
+
+    fn on_actual_size(&mut self, _: &ActualSize, _: &mut Window, cx: &mut Context<Self>) {
+        self.set_fit(ImageFit::ActualSize, cx);
+    }
+
+    fn on_scroll_wheel(
+        &mut self,
+        event: &ScrollWheelEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let delta = event.delta.pixel_delta(window.line_height());
+        if delta.y == px(0.) {
+            return;
+        }
+
+        let anchor = event.position - self.bounds.center();
+        let factor = (1. + delta.y / px(200.)).clamp(0.5, 2.);
+        self.zoom_at(self.zoom * factor, anchor, cx);
+        cx.stop_propagation();
+    }
+
+    fn on_mouse_down(&mut self, event: &MouseDownEvent, _: &mut Window, _: &mut Context<Self>) {
+        self.last_drag_position = Some(event.position);
+    }
+
+    fn on_mouse_move(&mut self, event: &MouseMoveEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let Some(last_position) = self.last_drag_position else {
+            return;
+        };
+        if !event.dragging() {
+            self.last_drag_position = None;
+            return;
+        }
+
+        self.last_drag_position = Some(event.position);
+        self.pan(event.position - last_position, cx);
+    }
+
+    fn on_key_down(&mut self, event: &KeyDownEvent, _: &mut Window, cx: &mut Context<Self>) {
+        if event.keystroke.modifiers.modified() {
+            return;
+        }
+
+        // Only pan when the image is larger than the viewport, otherwise let the parent handle
+        // the arrow keys, e.g.: the Lightbox uses them to switch images.
+        let (Some(image_size), Some(scale)) = (self.image_size, self.scale()) else {
+            return;
+        };
+        let viewport = self.bounds.size;
+        let can_pan_x = image_size.width * scale > viewport.width;
+        let can_pan_y = image_size.height * scale > viewport.height;
+
+        let delta = match event.keystroke.key.as_str() {
+            "left" if can_pan_x => Point::new(PAN_STEP, px(0.)),
+            "right" if can_pan_x => Point::new(-PAN_STEP, px(0.)),
+            "up" if can_pan_y => Point::new(px(0.), PAN_STEP),
+            "down" if can_pan_y => Point::new(px(0.), -PAN_STEP),
+            _ => return,
+        };
+        cx.stop_propagation();
+        self.pan(delta, cx);
+    }
+}
+
+/// An image viewer element to zoom and pan the image.
+///
+/// - Use mouse wheel to zoom, and drag to pan the image.
+/// - Use `+` / `-` to zoom, `0` to reset, `1` for actual size and arrow keys to pan.
+#[derive(IntoElement)]
+pub struct ImageViewer {
+    id: ElementId,
+    style: StyleRefinement,
+    state: Entity<ImageViewerState>,
+    toolbar: bool,
+}
+
+impl ImageViewer {
+    /// Create a new ImageViewer with the given [`ImageViewerState`].
+    pub fn new(state: &Entity<ImageViewerState>) -> Self {
+        Self {
+            id: ("image-viewer", state.entity_id()).into(),
+            style: StyleRefinement::default(),
+            state: state.clone(),
+            toolbar: true,
+        }
+    }
+
+    /// Set whether to show the toolbar with the zoom and fit buttons, default: true
+    pub fn toolbar(mut self, toolbar: bool) -> Self {
+        self.toolbar = toolbar;
+        self
+    }
+
+    fn render_toolbar(&self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let state = self.state.read(cx);
+        let fit = state.fit;
+        let percent: SharedString = match state.scale() {
+            Some(scale) => format!("{:.0}%", scale * 100.).into(),
+            None => format!("{:.0}%", state.zoom * 100.).into(),
+        };
+
+        h_flex()
+            .absolute()
+            .bottom_3()
+            .left_0()
+            .right_0()
+            .justify_center()
+            .child(
+                h_flex()
+                    .gap_1()
+                    .p_1()
+                    .bg(cx.theme().popover)
+                    .border_1()
+                    .border_color(cx.theme().border)
+                    .rounded(cx.theme().radius)
+                    .shadow_md()
+                    .child(
+                        Button::new("zoom-out")
+                            .ghost()
+                            .xsmall()
+                            .icon(IconName::ZoomOut)
+                            .on_click(window.listener_for(&self.state, |state, _, _, cx| {
+                                state.zoom_out(cx);
+                            })),
+                    )
+                    .child(
+                        div()
+                            .min_w_12()
+                            .text_center()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(percent),
+                    )
+                    .child(
+                        Button::new("zoom-in")
+                            .ghost()
+                            .xsmall()
+                            .icon(IconName::ZoomIn)
+                            .on_click(window.listener_for(&self.state, |state, _, _, cx| {
+                                state.zoom_in(cx);
+                            })),
+                    )
+                    .children(
+                        [
+                            ("fit", "Fit", ImageFit::Fit),
+                            ("fill", "Fill", ImageFit::Fill),
+                            ("actual-size", "1:1", ImageFit::ActualSize),
+                        ]
+                        .into_iter()
+                        .map(|(id, label, mode)| {
+                            Button::new(id)
+                                .ghost()
+                                .xsmall()
+                                .label(label)
+                                .selected(fit == mode)
+                                .on_click(window.listener_for(
+                                    &self.state,
+                                    move |state, _, _, cx| {
+                                        state.set_fit(mode, cx);
+                                    },
+                                ))
+                        }),
+                    ),
+            )
+    }
+}
+
+impl Styled for ImageViewer {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl Focusable for ImageViewer {
+    fn focus_handle(&self, cx: &App) -> FocusHandle {
+        self.state.focus_handle(cx)
+    }
+}
+
+impl RenderOnce for ImageViewer {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let source = self.state.read(cx).source.clone();
+        let image_size = source
+            .as_ref()
+            .and_then(|source| image_size(source, window, cx));
+        self.state
+            .update(cx, |state, _| state.image_size = image_size);
+
+        let state = self.state.read(cx);
+        let viewport = state.bounds.size;
+        let offset = state.offset;
+        let fit = state.fit;
+        let zoom = state.zoom;
+        let is_dragging = state.last_drag_position.is_some();
+
+        div()
+            .id(self.id.clone())
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle(cx).tab_stop(true))
+            .on_action(window.listener_for(&self.state, ImageViewerState::on_zoom_in))
+            .on_action(window.listener_for(&self.state, ImageViewerState::on_zoom_out))
+            .on_action(window.listener_for(&self.state, ImageViewerState::on_zoom_reset))
+            .on_action(window.listener_for(&self.state, ImageViewerState::on_actual_size))
+            .on_key_down(window.listener_for(&self.state, ImageViewerState::on_key_down))
+            .relative()
+            .size_full()
+            .overflow_hidden()
+            .bg(cx.theme().muted)
+            .when(is_dragging, |this| this.cursor_grabbing())
+            .when(!is_dragging, |this| this.cursor_grab())
+            .refine_style(&self.style)
+            .on_scroll_wheel(window.listener_for(&self.state, ImageViewerState::on_scroll_wheel))
+            .on_mouse_down(
+                MouseButton::Left,
+                window.listener_for(&self.state, ImageViewerState::on_mouse_down),
+            )
+            .on_mouse_move(window.listener_for(&self.state, ImageViewerState::on_mouse_move))
+            .on_mouse_up(
+                MouseButton::Left,
+                window.listener_for(&self.state, |state, _, _, cx| {
+                    state.last_drag_position = None;
+                    cx.notify();
+                }),
+            )
+            .on_mouse_up_out(
+                MouseButton::Left,
+                window.listener_for(&self.state, |state, _, _, cx| {
+                    state.last_drag_position = None;
+                    cx.notify();
+                }),
+            )
+            .on_prepaint({
+                let state = self.state.clone();
+                move |bounds, _, cx| state.update(cx, |state, _| state.bounds = bounds)
+            })
+            .when_some(source, |this, source| {
+                this.child(match image_size {
+                    Some(image_size) => {
+                        let scale = fit_scale(fit, image_size, viewport) * zoom;
+                        let display_size = image_size.map(|v| v * scale);
+
+                        img(source)
+                            .absolute()
+                            .left((viewport.width - display_size.width) / 2. + offset.x)
+                            .top((viewport.height - display_size.height) / 2. + offset.y)
+                            .w(display_size.width)
+                            .h(display_size.height)
+                            .object_fit(ObjectFit::Fill)
+                    }
+                    // Not loaded yet, the img will show the loading state.
+                    None => img(source).size_full().object_fit(ObjectFit::Contain),
+                })
+            })
+            .when(self.toolbar, |this| {
+                this.child(self.render_toolbar(window, cx))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{Point, px, size};
+
+    use super::{ImageFit, fit_scale, zoom_offset};
+
+    #[test]
+    fn test_fit_scale() {
+        let image = size(px(200.), px(100.));
+        let viewport = size(px(100.), px(100.));
+
+        assert_eq!(fit_scale(ImageFit::Fit, image, viewport), 0.5);
+        assert_eq!(fit_scale(ImageFit::Fill, image, viewport), 1.);
+        assert_eq!(fit_scale(ImageFit::ActualSize, image, viewport), 1.);
+        assert_eq!(fit_scale(ImageFit::Fit, size(px(0.), px(0.)), viewport), 1.);
+    }
+
+    #[test]
+    fn test_zoom_offset() {
+        // Zoom at the center, the offset is scaled.
+        assert_eq!(
+            zoom_offset(Point::new(px(10.), px(0.)), Point::default(), 1., 2.),
+            Point::new(px(20.), px(0.))
+        );
+        // Zoom at the image point under the anchor, the anchor point keeps.
+        assert_eq!(
+            zoom_offset(Point::default(), Point::new(px(50.), px(50.)), 1., 2.),
+            Point::new(px(-50.), px(-50.))
+        );
+    }
+}
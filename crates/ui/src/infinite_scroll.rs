@@ -0,0 +1,340 @@
+//! A helper to load more items when scrolling near the start or the end of a list,
+//! e.g.: the older messages of a chat history, or the next page of the sessions.
+//!
+//! - [`InfiniteScrollState`] invokes the async loader when scrolled within the threshold.
+//! - [`InfiniteScrollExt::infinite_scroll`] checks the scroll position on each frame.
+//! - [`LoadMoreIndicator`] is the spinner row to show while loading.
+//!
+//! The scroll position is kept when the items are prepended, so the content does not jump.
+use std::rc::Rc;
+
+use gpui::{
+    App, Context, Entity, IntoElement, ParentElement, Pixels, RenderOnce, ScrollHandle,
+    SharedString, Styled, Task, Window, point, prelude::FluentBuilder as _, px,
+};
+
+use crate::{ActiveTheme as _, ElementExt as _, Sizable as _, h_flex, spinner::Spinner};
+
+/// The default distance to the edges to start loading.
+const DEFAULT_THRESHOLD: Pixels = px(200.);
+
+/// The direction to load more items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadDirection {
+    /// Load the items before the first item, e.g.: the older messages.
+    Start,
+    /// Load the items after the last item, e.g.: the next page.
+    End,
+}
+
+impl LoadDirection {
+    /// Returns true if the direction is [`LoadDirection::Start`].
+    #[inline]
+    pub fn is_start(&self) -> bool {
+        matches!(self, Self::Start)
+    }
+}
+
+/// The async loader to load more items in the direction, returns `true` if there are more items.
+type Loader = Rc<dyn Fn(LoadDirection, &mut Window, &mut App) -> Task<bool>>;
+
+/// The state of the infinite scroll.
+///
+/// ```ignore
+/// let view = cx.entity().downgrade();
+/// let infinite_scroll = cx.new(|_| {
+///     InfiniteScrollState::new(move |direction, window, cx| {
+///         let view = view.clone();
+///         window.spawn(cx, async move |cx| {
+///             let messages = fetch_messages(direction).await;
+///             view.update(cx, |this, cx| this.insert_messages(direction, messages, cx))
+///                 .unwrap_or(false)
+///         })
+///     })
+///     .load_start(true)
+/// });
+/// ```
+pub struct InfiniteScrollState {
+    loader: Loader,
+    threshold: Pixels,
+    has_more_start: bool,
+    has_more_end: bool,
+    loading: Option<LoadDirection>,
+    /// The content height before loading the items at the start, to keep the scroll position.
+    anchor_height: Option<Pixels>,
+    _load_task: Option<Task<()>>,
+}
+
+impl InfiniteScrollState {
+    /// Create a new state with the async `loader`, only loads at the end by default.
+    ///
+    /// The `loader` should insert the items into the list, and return `true` if there are more
+    /// items in the direction.
+    pub fn new(
+        loader: impl Fn(LoadDirection, &mut Window, &mut App) -> Task<bool> + 'static,
+    ) -> Self {
+        Self {
+            loader: Rc::new(loader),
+            threshold: DEFAULT_THRESHOLD,
+            has_more_start: false,
+            has_more_end: true,
+            loading: None,
+            anchor_height: None,
+            _load_task: None,
+        }
+    }
+
+    /// Set the distance to the edges to start loading, default: 200px.
+    pub fn threshold(mut self, threshold: impl Into<Pixels>) -> Self {
+        self.threshold = threshold.into();
+        self
+    }
+
+    /// Set to load more items at the start, default: false.
+    pub fn load_start(mut self, load_start: bool) -> Self {
+        self.has_more_start = load_start;
+        self
+    }
+
+    /// Set to load more items at the end, default: true.
+    pub fn load_end(mut self, load_end: bool) -> Self {
+        self.has_more_end = load_end;
+        self
+    }
+
+    /// Set if there are more items in the `direction`, e.g.: reset after refreshing the list.
+    pub fn set_has_more(
+        &mut self,
+        direction: LoadDirection,
+        has_more: bool,
+        cx: &mut Context<Self>,
+    ) {
+        match direction {
+            LoadDirection::Start => self.has_more_start = has_more,
+            LoadDirection::End => self.has_more_end = has_more,
+        }
+        cx.notify();
+    }
+
+    /// Returns true if there are more items in the `direction`.
+    pub fn has_more(&self, direction: LoadDirection) -> bool {
+        match direction {
+            LoadDirection::Start => self.has_more_start,
+            LoadDirection::End => self.has_more_end,
+        }
+    }
+
+    /// Returns true if loading the items in the `direction`.
+    pub fn is_loading(&self, direction: LoadDirection) -> bool {
+        self.loading == Some(direction)
+    }
+
+    /// Check the scroll position of the `scroll_handle`, and load more items if needed.
+    ///
+    /// This is called by [`InfiniteScrollExt::infinite_scroll`] on each frame.
+    pub fn check(
+        &mut self,
+        scroll_handle: &ScrollHandle,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let viewport_height = scroll_handle.bounds().size.height;
+        let max_offset = scroll_handle.max_offset().y;
+        let offset = scroll_handle.offset();
+        let content_height = max_offset + viewport_height;
+
+        if let Some(direction) = self.loading {
+            // Track the content height with the loading indicator, until the items are prepended.
+            if direction.is_start() {
+                self.anchor_height = Some(content_height);
+            }
+            return;
+        }
+
+        // Keep the scroll position after the items are prepended.
+        if let Some(anchor_height) = self.anchor_height.take() {
+            let delta = content_height - anchor_height;
+            if delta != px(0.) {
+                let y = (offset.y - delta).clamp(-max_offset, px(0.));
+                scroll_handle.set_offset(point(offset.x, y));
+                window.refresh();
+                return;
+            }
+        }
+
+        if let Some(direction) = load_direction(
+            -offset.y,
+            max_offset,
+            self.threshold,
+            self.has_more_start,
+            self.has_more_end,
+        ) {
+            self.load(direction, window, cx);
+        }
+    }
+
+    /// Load more items in the `direction`, do nothing if already loading.
+    pub fn load(&mut self, direction: LoadDirection, window: &mut Window, cx: &mut Context<Self>) {
+        if self.loading.is_some() {
+            return;
+        }
+
+        self.loading = Some(direction);
+        let task = (self.loader)(direction, window, cx);
+        self._load_task = Some(cx.spawn_in(window, async move |this, cx| {
+            let has_more = task.await;
+            _ = this.update(cx, |this, cx| {
+                this.loading = None;
+                this.set_has_more(direction, has_more, cx);
+            });
+        }));
+        cx.notify();
+    }
+}
+
+/// Returns the direction to load, if the `scroll_top` is within the `threshold` to the edges.
+///
+/// The end is checked first, to fill the viewport when the content is not scrollable.
+fn load_direction(
+    scroll_top: Pixels,
+    max_offset: Pixels,
+    threshold: Pixels,
+    has_more_start: bool,
+    has_more_end: bool,
+) -> Option<LoadDirection> {
+    if has_more_end && max_offset - scroll_top <= threshold {
+        Some(LoadDirection::End)
+    } else if has_more_start && scroll_top <= threshold {
+        Some(LoadDirection::Start)
+    } else {
+        None
+    }
+}
+
+/// An extension trait to load more items when scrolling near the edges.
+pub trait InfiniteScrollExt: ParentElement + Sized {
+    /// Check the scroll position of the `scroll_handle` on each frame to load more items.
+    ///
+    /// This should be added to the scroll container or a parent of it.
+    fn infinite_scroll(
+        self,
+        state: &Entity<InfiniteScrollState>,
+        scroll_handle: &ScrollHandle,
+    ) -> Self {
+        let state = state.clone();
+        let scroll_handle = scroll_handle.clone();
+        self.on_prepaint(move |_, window, cx| {
+            state.update(cx, |state, cx| state.check(&scroll_handle, window, cx));
+        })
+    }
+}
+
+impl<T: ParentElement + Sized> InfiniteScrollExt for T {}
+
+/// A row with a spinner to show while loading more items.
+#[derive(IntoElement)]
+pub struct LoadMoreIndicator {
+    label: Option<SharedString>,
+}
+
+impl LoadMoreIndicator {
+    /// Create a new load more indicator.
+    pub fn new() -> Self {
+        Self { label: None }
+    }
+
+    /// Set the label to show after the spinner.
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+impl RenderOnce for LoadMoreIndicator {
+    fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+        h_flex()
+            .w_full()
+            .py_2()
+            .gap_2()
+            .justify_center()
+            .text_sm()
+            .text_color(cx.theme().muted_foreground)
+            .child(Spinner::new().small())
+            .when_some(self.label, |this, label| this.child(label))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use gpui::{AppContext as _, TestAppContext, px};
+
+    use super::{InfiniteScrollState, LoadDirection, load_direction};
+
+    #[gpui::test]
+    fn test_load_while_loading(cx: &mut TestAppContext) {
+        let calls = Rc::new(Cell::new(0));
+        let (tx, rx) = smol::channel::bounded::<bool>(1);
+        let cx = cx.add_empty_window();
+        let state = cx.new(|_| {
+            let calls = calls.clone();
+            InfiniteScrollState::new(move |_, _, cx| {
+                calls.set(calls.get() + 1);
+                let rx = rx.clone();
+                cx.background_spawn(async move { rx.recv().await.unwrap_or(false) })
+            })
+        });
+
+        cx.update(|window, cx| {
+            state.update(cx, |state, cx| {
+                state.load(LoadDirection::End, window, cx);
+                state.load(LoadDirection::End, window, cx);
+                state.load(LoadDirection::Start, window, cx);
+            })
+        });
+        assert_eq!(calls.get(), 1);
+        assert!(state.read_with(cx, |state, _| state.is_loading(LoadDirection::End)));
+
+        tx.try_send(false).unwrap();
+        cx.run_until_parked();
+        state.read_with(cx, |state, _| {
+            assert!(!state.is_loading(LoadDirection::End));
+            assert!(!state.has_more(LoadDirection::End));
+        });
+    }
+
+    #[test]
+    fn test_load_direction() {
+        let threshold = px(200.);
+        assert_eq!(
+            load_direction(px(500.), px(1000.), threshold, true, true),
+            None
+        );
+        assert_eq!(
+            load_direction(px(900.), px(1000.), threshold, true, true),
+            Some(LoadDirection::End)
+        );
+        assert_eq!(
+            load_direction(px(900.), px(1000.), threshold, true, false),
+            None
+        );
+        assert_eq!(
+            load_direction(px(100.), px(1000.), threshold, true, true),
+            Some(LoadDirection::Start)
+        );
+        assert_eq!(
+            load_direction(px(100.), px(1000.), threshold, false, true),
+            None
+        );
+        // Not scrollable, load the end first.
+        assert_eq!(
+            load_direction(px(0.), px(0.), threshold, true, true),
+            Some(LoadDirection::End)
+        );
+        assert_eq!(
+            load_direction(px(0.), px(0.), threshold, true, false),
+            Some(LoadDirection::Start)
+        );
+    }
+}
@@ -0,0 +1,309 @@
+use std::rc::Rc;
+
+use anyhow::Result;
+use gpui::{Context, SharedString, Task, Window};
+use lsp_types::{
+    CompletionContext, CompletionItem, CompletionItemKind, CompletionResponse, CompletionTextEdit,
+    Documentation, TextEdit,
+};
+use ropey::Rope;
+
+use super::{CompletionProvider, InputState, RopeExt as _};
+
+/// A slash command of the [`CommandRegistry`], e.g.: `/model <name>`.
+#[derive(Clone)]
+pub struct SlashCommand {
+    name: SharedString,
+    description: Option<SharedString>,
+    args: Vec<SharedString>,
+}
+
+impl SlashCommand {
+    /// Create a new command with the `name`, without the leading `/`.
+    pub fn new(name: impl Into<SharedString>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            args: vec![],
+        }
+    }
+
+    /// Set the description shown in the completion menu.
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Add an argument, the `placeholder` is shown as the inline hint after the command name,
+    /// e.g.: `name` is shown as `<name>`.
+    ///
+    /// The last argument takes the rest of the text, including the whitespaces.
+    pub fn arg(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.args.push(placeholder.into());
+        self
+    }
+
+    /// Returns the name of the command, without the leading `/`.
+    pub fn name(&self) -> &SharedString {
+        &self.name
+    }
+
+    fn placeholders(&self, skip: usize) -> String {
+        self.args
+            .iter()
+            .skip(skip)
+            .map(|arg| format!("<{}>", arg))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// A slash command parsed from the input text, see [`super::InputEvent::Command`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandInvocation {
+    /// The name of the command, without the leading `/`.
+    pub name: SharedString,
+    /// The arguments, at most the number of the arguments of the command.
+    pub args: Vec<SharedString>,
+}
+
+/// A registry of the [`SlashCommand`]s for the chat composer.
+///
+/// When set to the [`InputState`] by [`InputState::command_registry`]:
+///
+/// - Typing `/` at the start of the input completes the command names.
+/// - The placeholders of the remaining arguments are shown as an inline hint.
+/// - Pressing Enter on a command emits [`super::InputEvent::Command`] with the parsed arguments,
+///   instead of [`super::InputEvent::PressEnter`].
+///
+/// ```ignore
+/// let registry = CommandRegistry::new()
+///     .command(SlashCommand::new("model").description("Switch the model").arg("name"))
+///     .command(SlashCommand::new("clear").description("Clear the conversation"));
+///
+/// let input = cx.new(|cx| InputState::new(window, cx).command_registry(Rc::new(registry)));
+/// ```
+#[derive(Default)]
+pub struct CommandRegistry {
+    commands: Vec<SlashCommand>,
+}
+
+impl CommandRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the `command`, see also [`Self::register`].
+    pub fn command(mut self, command: SlashCommand) -> Self {
+        self.register(command);
+        self
+    }
+
+    /// Register the `command`, replacing the one with the same name.
+    pub fn register(&mut self, command: SlashCommand) {
+        match self.commands.iter_mut().find(|c| c.name == command.name) {
+            Some(existing) => *existing = command,
+            None => self.commands.push(command),
+        }
+    }
+
+    /// Returns all the registered commands.
+    pub fn commands(&self) -> &[SlashCommand] {
+        &self.commands
+    }
+
+    /// Returns the command by the `name`, without the leading `/`.
+    pub fn find(&self, name: &str) -> Option<&SlashCommand> {
+        self.commands
+            .iter()
+            .find(|command| command.name.as_ref() == name)
+    }
+
+    /// Split the `text` into the registered command and the rest text after its name.
+    fn split<'a>(&self, text: &'a str) -> Option<(&SlashCommand, &'a str)> {
+        let text = text.strip_prefix('/')?;
+        let end = text.find(char::is_whitespace).unwrap_or(text.len());
+        let command = self.find(&text[..end])?;
+        Some((command, &text[end..]))
+    }
+
+    /// Parse the `text` as a registered command, e.g.: `/model gpt-4o`,
+    /// returns `None` if it is not a registered command or has multiple lines.
+    ///
+    /// The text after the arguments of the command is ignored, e.g.: `/clear all` of a command
+    /// without arguments.
+    pub fn parse(&self, text: &str) -> Option<CommandInvocation> {
+        let text = text.trim();
+        if text.contains('\n') {
+            return None;
+        }
+
+        let (command, mut rest) = self.split(text)?;
+        let max_args = command.args.len();
+        let mut args = vec![];
+        while args.len() < max_args {
+            rest = rest.trim_start();
+            if rest.is_empty() {
+                break;
+            }
+            if args.len() + 1 == max_args {
+                args.push(rest.to_string().into());
+                break;
+            }
+
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            args.push(rest[..end].to_string().into());
+            rest = &rest[end..];
+        }
+
+        Some(CommandInvocation {
+            name: command.name.clone(),
+            args,
+        })
+    }
+
+    /// Returns the placeholders of the arguments not typed yet, e.g.: ` <name>` for `/model`.
+    fn hint(&self, text: &str) -> Option<String> {
+        if text.contains('\n') {
+            return None;
+        }
+
+        let (command, rest) = self.split(text)?;
+        let placeholders = command.placeholders(rest.split_whitespace().count());
+        if placeholders.is_empty() {
+            return None;
+        }
+
+        if text.ends_with(char::is_whitespace) {
+            Some(placeholders)
+        } else {
+            Some(format!(" {}", placeholders))
+        }
+    }
+}
+
+impl CompletionProvider for CommandRegistry {
+    fn completions(
+        &self,
+        text: &Rope,
+        offset: usize,
+        _: CompletionContext,
+        _: &mut Window,
+        _: &mut Context<InputState>,
+    ) -> Task<Result<CompletionResponse>> {
+        if offset == 0 || text.char_at(0) != Some('/') {
+            return Task::ready(Ok(CompletionResponse::Array(vec![])));
+        }
+
+        let query = text.slice(1..offset).to_string();
+        if query.contains(char::is_whitespace) {
+            return Task::ready(Ok(CompletionResponse::Array(vec![])));
+        }
+
+        let range =
+            lsp_types::Range::new(text.offset_to_position(0), text.offset_to_position(offset));
+        let items = self
+            .commands
+            .iter()
+            .filter(|command| command.name.starts_with(query.as_str()))
+            .map(|command| CompletionItem {
+                label: format!("/{}", command.name),
+                kind: Some(CompletionItemKind::FUNCTION),
+                detail: Some(command.placeholders(0)).filter(|s| !s.is_empty()),
+                documentation: command
+                    .description
+                    .as_ref()
+                    .map(|description| Documentation::String(description.to_string())),
+                text_edit: Some(CompletionTextEdit::Edit(TextEdit::new(
+                    range,
+                    format!("/{} ", command.name),
+                ))),
+                ..Default::default()
+            })
+            .collect();
+
+        Task::ready(Ok(CompletionResponse::Array(items)))
+    }
+
+    fn is_completion_trigger(&self, _: usize, _: &str, _: &mut Context<InputState>) -> bool {
+        true
+    }
+}
+
+impl InputState {
+    /// Set the [`CommandRegistry`] to complete, hint and dispatch the slash commands.
+    ///
+    /// The registry is also set as the completion provider, replacing the previous one.
+    pub fn command_registry(mut self, registry: Rc<CommandRegistry>) -> Self {
+        self.lsp.completion_provider = Some(registry.clone());
+        self.command_registry = Some(registry);
+        self
+    }
+
+    /// Returns the slash command of the value, if the value is a registered command.
+    pub(super) fn parse_command(&self) -> Option<CommandInvocation> {
+        let registry = self.command_registry.as_ref()?;
+        if self.text.char_at(0) != Some('/') {
+            return None;
+        }
+
+        registry.parse(&self.text.to_string())
+    }
+
+    /// Returns the inline hint of the arguments of the slash command, when the cursor is at the end.
+    pub(super) fn command_hint(&self) -> Option<String> {
+        let registry = self.command_registry.as_ref()?;
+        if self.text.char_at(0) != Some('/') || self.cursor() != self.text.len() {
+            return None;
+        }
+
+        registry.hint(&self.text.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommandInvocation, CommandRegistry, SlashCommand};
+
+    #[test]
+    fn test_command_registry() {
+        let registry = CommandRegistry::new()
+            .command(SlashCommand::new("model").arg("name"))
+            .command(SlashCommand::new("rename").arg("id").arg("title"))
+            .command(SlashCommand::new("clear"));
+
+        assert_eq!(
+            registry.parse(" /rename 1  Hello world "),
+            Some(CommandInvocation {
+                name: "rename".into(),
+                args: vec!["1".into(), "Hello world".into()],
+            })
+        );
+        assert_eq!(registry.parse("/clear").map(|c| c.args.len()), Some(0));
+        assert_eq!(
+            registry.parse("/clear all now"),
+            Some(CommandInvocation {
+                name: "clear".into(),
+                args: vec![],
+            })
+        );
+        assert_eq!(
+            registry.parse("/model gpt-4o mini"),
+            Some(CommandInvocation {
+                name: "model".into(),
+                args: vec!["gpt-4o mini".into()],
+            })
+        );
+        assert_eq!(registry.parse("/unknown"), None);
+        assert_eq!(registry.parse("/model a\nb"), None);
+        assert_eq!(registry.parse("model"), None);
+
+        assert_eq!(registry.hint("/model").as_deref(), Some(" <name>"));
+        assert_eq!(registry.hint("/rename ").as_deref(), Some("<id> <title>"));
+        assert_eq!(registry.hint("/rename 1").as_deref(), Some(" <title>"));
+        assert_eq!(registry.hint("/rename 1 Hello"), None);
+        assert_eq!(registry.hint("/clear"), None);
+        assert_eq!(registry.hint("/mod"), None);
+    }
+}
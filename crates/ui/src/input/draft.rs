@@ -0,0 +1,153 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use gpui::{Context, SharedString, Window};
+
+use super::InputState;
+
+/// A store of the unsent texts of the [`InputState`] by a key, e.g.: the session id of a chat,
+/// so switching the sessions does not lose the half-written prompt.
+///
+/// The drafts are kept in memory, or persisted as JSON by [`DraftStore::load`].
+///
+/// ```ignore
+/// // In the view, e.g.: `self.drafts = DraftStore::load("drafts.json")`.
+/// // When switching from the `old_id` session to the `new_id` session.
+/// self.input.update(cx, |input, cx| {
+///     input.save_draft(&mut self.drafts, old_id);
+///     input.restore_draft(&self.drafts, &new_id, window, cx);
+/// });
+/// ```
+#[derive(Default)]
+pub struct DraftStore {
+    drafts: BTreeMap<SharedString, SharedString>,
+    path: Option<PathBuf>,
+}
+
+impl DraftStore {
+    /// Create a new store in memory, it is not persisted.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load the drafts from the JSON file at `path`, and save to it when changed.
+    ///
+    /// The store is empty if the file does not exist or is invalid.
+    pub fn load(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let drafts = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| match serde_json::from_str(&content) {
+                Ok(drafts) => Some(drafts),
+                Err(err) => {
+                    tracing::error!("failed to parse drafts {}: {}", path.display(), err);
+                    None
+                }
+            })
+            .unwrap_or_default();
+
+        Self {
+            drafts,
+            path: Some(path),
+        }
+    }
+
+    /// Returns the path of the JSON file to persist.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Returns the draft of the `key`.
+    pub fn get(&self, key: &str) -> Option<&SharedString> {
+        self.drafts.get(key)
+    }
+
+    /// Set the draft of the `key` and save to the file, the empty `text` removes the draft.
+    pub fn set(&mut self, key: impl Into<SharedString>, text: impl Into<SharedString>) {
+        let key = key.into();
+        let text = text.into();
+        if text.trim().is_empty() {
+            return self.remove(&key);
+        }
+
+        if self.drafts.insert(key, text.clone()).as_ref() != Some(&text) {
+            self.save_or_log();
+        }
+    }
+
+    /// Remove the draft of the `key` and save to the file, e.g.: the session is deleted.
+    pub fn remove(&mut self, key: &str) {
+        if self.drafts.remove(key).is_some() {
+            self.save_or_log();
+        }
+    }
+
+    fn save_or_log(&self) {
+        if let Err(err) = self.save() {
+            tracing::error!("failed to save drafts: {}", err);
+        }
+    }
+
+    /// Save the drafts to the JSON file, do nothing if the store has no path.
+    pub fn save(&self) -> anyhow::Result<()> {
+        let Some(path) = self.path.as_ref() else {
+            return Ok(());
+        };
+
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(&self.drafts)?)?;
+        Ok(())
+    }
+}
+
+impl InputState {
+    /// Save the value as the draft of the `key` to the [`DraftStore`].
+    pub fn save_draft(&self, store: &mut DraftStore, key: impl Into<SharedString>) {
+        store.set(key, self.value());
+    }
+
+    /// Restore the draft of the `key` from the [`DraftStore`], the input is cleared if no draft.
+    ///
+    /// The cursor is moved to the end of the draft.
+    pub fn restore_draft(
+        &mut self,
+        store: &DraftStore,
+        key: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let text = store.get(key).cloned().unwrap_or_default();
+        self.set_value(text, window, cx);
+        let end = self.text.len();
+        self.selected_range = (end..end).into();
+        cx.notify();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DraftStore;
+
+    #[test]
+    fn test_draft_store() {
+        let path =
+            std::env::temp_dir().join(format!("gpui-component-drafts-{}.json", std::process::id()));
+        _ = std::fs::remove_file(&path);
+
+        let mut store = DraftStore::load(path.clone());
+        store.set("session-1", "Hello");
+        store.set("session-2", "World");
+        store.set("session-2", " ");
+        assert_eq!(store.get("session-1").map(|s| s.as_str()), Some("Hello"));
+        assert_eq!(store.get("session-2"), None);
+
+        let loaded = DraftStore::load(path.clone());
+        assert_eq!(loaded.get("session-1").map(|s| s.as_str()), Some("Hello"));
+        assert_eq!(loaded.get("session-2"), None);
+        _ = std::fs::remove_file(&path);
+    }
+}
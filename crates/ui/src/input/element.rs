@@ -17,7 +17,7 @@ use crate::{
     input::{RopeExt as _, blink_cursor::CURSOR_WIDTH, display_map::LineLayout},
 };
 
-use super::{InputState, LastLayout, WhitespaceIndicators, mode::InputMode};
+use super::{InputState, LastLayout, WhitespaceIndicators, mention, mode::InputMode};
 
 const BOTTOM_MARGIN_ROWS: usize = 3;
 pub(super) const RIGHT_MARGIN: Pixels = px(10.);
@@ -505,6 +505,26 @@ impl TextElement {
         paths
     }
 
+    fn layout_mentions(
+        &self,
+        last_layout: &LastLayout,
+        bounds: &Bounds<Pixels>,
+        cx: &mut App,
+    ) -> Vec<Path<Pixels>> {
+        let state = self.state.read(cx);
+        if state.masked {
+            return vec![];
+        }
+
+        state
+            .mentions
+            .iter()
+            .filter_map(|mention| {
+                Self::layout_match_range(mention.range.clone(), last_layout, bounds)
+            })
+            .collect()
+    }
+
     fn layout_selections(
         &self,
         last_layout: &LastLayout,
@@ -726,8 +746,12 @@ impl TextElement {
             return (None, vec![]);
         }
 
-        let Some(completion_item) = state.inline_completion.item.as_ref() else {
-            return (None, vec![]);
+        // Show the inline completion, or the arguments hint of the slash command.
+        let command_hint = state.command_hint();
+        let completion_text = match (&state.inline_completion.item, &command_hint) {
+            (Some(completion_item), _) => completion_item.insert_text.as_str(),
+            (None, Some(hint)) => hint.as_str(),
+            (None, None) => return (None, vec![]),
         };
 
         // Get cursor row from cursor position
@@ -738,7 +762,6 @@ impl TextElement {
             return (None, vec![]);
         }
 
-        let completion_text = &completion_item.insert_text;
         let completion_color = cx.theme().muted_foreground.opacity(0.5);
 
         let text_style = window.text_style();
@@ -1150,6 +1173,7 @@ pub(super) struct PrepaintState {
     hover_highlight_path: Option<Path<Pixels>>,
     search_match_paths: Vec<(Path<Pixels>, bool)>,
     document_color_paths: Vec<(Path<Pixels>, Hsla)>,
+    mention_paths: Vec<Path<Pixels>>,
     hover_definition_hitbox: Option<Hitbox>,
     indent_guides_path: Option<Path<Pixels>>,
     bounds: Bounds<Pixels>,
@@ -1364,66 +1388,82 @@ impl Element for TextElement {
             underline: None,
             strikethrough: None,
         };
-        let marked_run = TextRun {
-            len: 0,
-            font: style.font(),
-            color: text_color,
-            background_color: None,
-            underline: Some(UnderlineStyle {
-                thickness: px(1.),
-                color: Some(text_color),
-                wavy: false,
-            }),
-            strikethrough: None,
-        };
 
         let runs = if !is_empty {
             if let Some(highlight_styles) = highlight_styles {
-                let mut runs = Vec::with_capacity(highlight_styles.len());
-
-                runs.extend(highlight_styles.iter().map(|(range, style)| {
-                    let mut run = text_style.clone().highlight(*style).to_run(range.len());
-                    if let Some(ime_marked_range) = &state.ime_marked_range {
-                        if range.start >= ime_marked_range.start
-                            && range.end <= ime_marked_range.end
-                        {
-                            run.color = marked_run.color;
-                            run.strikethrough = marked_run.strikethrough;
-                            run.underline = marked_run.underline;
-                        }
-                    }
-
-                    run
-                }));
-
-                runs.into_iter().filter(|run| run.len > 0).collect()
+                highlight_styles
+                    .iter()
+                    .map(|(range, style)| text_style.clone().highlight(*style).to_run(range.len()))
+                    .filter(|run| run.len > 0)
+                    .collect()
+            } else if !state.masked && !state.mentions.is_empty() {
+                visible_mention_runs(
+                    &text,
+                    &last_layout.visible_buffer_lines,
+                    &state.mentions,
+                    display_text.len(),
+                )
+                .into_iter()
+                .map(|(len, is_mention)| TextRun {
+                    len,
+                    color: if is_mention {
+                        cx.theme().accent_foreground
+                    } else {
+                        text_color
+                    },
+                    ..run.clone()
+                })
+                .collect()
             } else {
                 vec![run]
             }
-        } else if let Some(ime_marked_range) = &state.ime_marked_range {
-            // IME marked text
-            vec![
-                TextRun {
-                    len: ime_marked_range.start,
-                    ..run.clone()
-                },
-                TextRun {
-                    len: ime_marked_range.end - ime_marked_range.start,
-                    underline: marked_run.underline,
-                    ..run.clone()
-                },
-                TextRun {
-                    len: display_text.len() - ime_marked_range.end,
-                    ..run.clone()
-                },
-            ]
-            .into_iter()
-            .filter(|run| run.len > 0)
-            .collect()
         } else {
             vec![run]
         };
 
+        // Underline the misspelled words.
+        let runs = match &state.spell_check {
+            Some(spell_check) if !is_empty && !state.masked && !spell_check.ranges.is_empty() => {
+                let underline = UnderlineStyle {
+                    thickness: px(1.),
+                    color: Some(cx.theme().danger),
+                    wavy: true,
+                };
+                let ranges = spell_check
+                    .ranges
+                    .iter()
+                    .filter_map(|range| {
+                        visible_run_range(&text, &last_layout.visible_buffer_lines, range)
+                    })
+                    .collect::<Vec<_>>();
+                underline_runs(runs, &ranges, underline)
+            }
+            _ => runs,
+        };
+
+        // Underline the IME preedit (marked) text, across all the highlighted runs.
+        let marked_range = state
+            .ime_marked_range
+            .filter(|_| !is_empty && !state.masked)
+            .and_then(|range| {
+                visible_run_range(
+                    &text,
+                    &last_layout.visible_buffer_lines,
+                    &(range.start..range.end),
+                )
+            });
+        let runs = match marked_range {
+            Some(marked_range) if !marked_range.is_empty() => {
+                let underline = UnderlineStyle {
+                    thickness: px(1.),
+                    color: Some(text_color),
+                    wavy: false,
+                };
+                underline_runs(runs, &[marked_range], underline)
+            }
+            _ => runs,
+        };
+
         let document_colors = state
             .lsp
             .document_colors_for_range(&text, &last_layout.visible_range);
@@ -1550,6 +1590,7 @@ impl Element for TextElement {
         let hover_highlight_path = self.layout_hover_highlight(&last_layout, &mut bounds, cx);
         let document_color_paths =
             self.layout_document_colors(&document_colors, &last_layout, &bounds, cx);
+        let mention_paths = self.layout_mentions(&last_layout, &bounds, cx);
 
         let state = self.state.read(cx);
         let line_numbers = if state.mode.line_number() {
@@ -1620,6 +1661,7 @@ impl Element for TextElement {
             hover_highlight_path,
             hover_definition_hitbox,
             document_color_paths,
+            mention_paths,
             indent_guides_path,
             fold_icon_layout,
             ghost_first_line,
@@ -1713,6 +1755,11 @@ impl Element for TextElement {
             window.paint_path(path, cx.theme().border.opacity(0.85));
         }
 
+        // Paint mentions as pills
+        for path in prepaint.mention_paths.iter() {
+            window.paint_path(path.clone(), cx.theme().accent);
+        }
+
         // Paint selections
         if window.is_window_active() {
             let secondary_selection = cx.theme().selection.saturation(0.1);
@@ -2049,10 +2096,143 @@ fn split_runs_by_bg_segments(
     result
 }
 
+/// Convert the byte `range` of the `text` to the coordinate space of the runs, which only
+/// contains the visible `lines`, returns `None` if the range is not in a single visible line.
+fn visible_run_range(text: &Rope, lines: &[usize], range: &Range<usize>) -> Option<Range<usize>> {
+    let mut run_offset = 0;
+    for &line in lines {
+        let start = text.line_start_offset(line);
+        let end = text.line_end_offset(line);
+        if range.start >= start && range.end <= end {
+            return Some(run_offset + range.start - start..run_offset + range.end - start);
+        }
+        // +1 for the `\n`
+        run_offset += end - start + 1;
+    }
+    None
+}
+
+/// Returns the runs of the `mentions` in the coordinate space of the runs, which only contains
+/// the visible `lines`, see [`mention::mention_runs`].
+fn visible_mention_runs(
+    text: &Rope,
+    lines: &[usize],
+    mentions: &[mention::Mention],
+    len: usize,
+) -> Vec<(usize, bool)> {
+    let ranges = mentions
+        .iter()
+        .filter_map(|mention| visible_run_range(text, lines, &mention.range))
+        .collect::<Vec<_>>();
+    mention::mention_runs(len, &ranges)
+}
+
+/// Split the `runs` at the boundaries of the sorted `ranges`, and underline the runs inside them.
+fn underline_runs(
+    runs: Vec<TextRun>,
+    ranges: &[Range<usize>],
+    underline: UnderlineStyle,
+) -> Vec<TextRun> {
+    let mut result = Vec::with_capacity(runs.len() + ranges.len() * 2);
+    let mut ranges = ranges.iter().peekable();
+    let mut offset = 0;
+    for run in runs {
+        let end = offset + run.len;
+        while offset < end {
+            while ranges.next_if(|range| range.end <= offset).is_some() {}
+
+            let (len, is_underlined) = match ranges.peek() {
+                Some(range) if range.start <= offset => (range.end.min(end) - offset, true),
+                Some(range) => (range.start.min(end) - offset, false),
+                None => (end - offset, false),
+            };
+            result.push(TextRun {
+                len,
+                underline: if is_underlined {
+                    Some(underline)
+                } else {
+                    run.underline
+                },
+                ..run.clone()
+            });
+            offset += len;
+        }
+    }
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_visible_run_range() {
+        let text = Rope::from("Hello\nWorld\nFoo");
+        assert_eq!(visible_run_range(&text, &[0, 1, 2], &(7..9)), Some(7..9));
+        // The first line is scrolled out, and the second line is folded.
+        assert_eq!(visible_run_range(&text, &[2], &(13..15)), Some(1..3));
+        assert_eq!(visible_run_range(&text, &[2], &(7..9)), None);
+        assert_eq!(visible_run_range(&text, &[0, 1], &(4..8)), None);
+    }
+
+    #[test]
+    fn test_visible_mention_runs() {
+        let text = Rope::from("Hi @foo\nline\nping @bar ok");
+        let mentions = vec![
+            mention::Mention {
+                range: 3..7,
+                value: "foo".into(),
+            },
+            mention::Mention {
+                range: 18..22,
+                value: "bar".into(),
+            },
+        ];
+
+        // All the lines are visible, the runs are in the text offsets.
+        assert_eq!(
+            visible_mention_runs(&text, &[0, 1, 2], &mentions, text.len()),
+            vec![(3, false), (4, true), (11, false), (4, true), (3, false)]
+        );
+        // Scrolled to the last two lines, the `@bar` is at the offset 10 of the visible text.
+        assert_eq!(
+            visible_mention_runs(&text, &[1, 2], &mentions, "line\nping @bar ok".len()),
+            vec![(10, false), (4, true), (3, false)]
+        );
+        // The second line is folded.
+        assert_eq!(
+            visible_mention_runs(&text, &[0, 2], &mentions, "Hi @foo\nping @bar ok".len()),
+            vec![(3, false), (4, true), (6, false), (4, true), (3, false)]
+        );
+    }
+
+    #[test]
+    fn test_underline_runs() {
+        let run = TextRun {
+            len: 0,
+            font: gpui::font(".SystemUIFont"),
+            color: gpui::black(),
+            background_color: None,
+            underline: None,
+            strikethrough: None,
+        };
+        let runs = vec![
+            TextRun {
+                len: 6,
+                ..run.clone()
+            },
+            TextRun { len: 6, ..run },
+        ];
+
+        let runs = underline_runs(runs, &[0..4, 5..8], UnderlineStyle::default());
+        assert_eq!(
+            runs.iter()
+                .map(|run| (run.len, run.underline.is_some()))
+                .collect::<Vec<_>>(),
+            vec![(4, true), (1, false), (1, true), (2, true), (4, false)]
+        );
+    }
+
     #[test]
     fn test_runs_for_range() {
         let run = TextRun {
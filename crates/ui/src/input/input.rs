@@ -44,6 +44,7 @@ pub struct Input {
     appearance: bool,
     cleanable: bool,
     mask_toggle: bool,
+    show_count: bool,
     disabled: bool,
     bordered: bool,
     focus_bordered: bool,
@@ -88,6 +89,7 @@ impl Input {
             appearance: true,
             cleanable: false,
             mask_toggle: false,
+            show_count: false,
             disabled: false,
             bordered: true,
             focus_bordered: true,
@@ -149,6 +151,13 @@ impl Input {
         self
     }
 
+    /// Set whether to show the character count, e.g.: `120/2000` with the
+    /// [`InputState::max_length`], default is false.
+    pub fn show_count(mut self, show_count: bool) -> Self {
+        self.show_count = show_count;
+        self
+    }
+
     /// Set to disable the input field.
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
@@ -191,6 +200,25 @@ impl Input {
             })
     }
 
+    fn render_count(state: &InputState, cx: &App) -> impl IntoElement {
+        let count = state.char_count();
+        let exceeded = state
+            .max_length
+            .is_some_and(|max_length| count > max_length);
+
+        div()
+            .text_xs()
+            .text_color(if exceeded {
+                cx.theme().danger
+            } else {
+                cx.theme().muted_foreground
+            })
+            .child(match state.max_length {
+                Some(max_length) => format!("{}/{}", count, max_length),
+                None => count.to_string(),
+            })
+    }
+
     /// This method must after the refine_style.
     fn render_editor(
         paddings: EdgesRefinement<DefiniteLength>,
@@ -267,8 +295,9 @@ impl Styled for Input {
 }
 
 impl RenderOnce for Input {
-    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+    fn render(mut self, window: &mut Window, cx: &mut App) -> impl IntoElement {
         const LINE_HEIGHT: Rems = Rems(1.25);
+        self.size = self.size.with_density(cx.theme().density);
         let text_align = self.style.text.text_align.unwrap_or(TextAlign::Left);
 
         self.state.update(cx, |state, _| {
@@ -304,7 +333,11 @@ impl RenderOnce for Input {
             && !state.loading
             && state.text.len() > 0
             && state.mode.is_single_line();
-        let has_suffix = suffix.is_some() || state.loading || self.mask_toggle || show_clear_button;
+        let has_suffix = suffix.is_some()
+            || state.loading
+            || self.mask_toggle
+            || self.show_count
+            || show_clear_button;
 
         div()
             .id(("input", self.state.entity_id()))
@@ -356,6 +389,13 @@ impl RenderOnce for Input {
 
                 result
             })
+            .when(
+                state.mode.is_single_line() && state.prompt_history.is_some(),
+                |this| {
+                    this.on_action(window.listener_for(&self.state, InputState::up))
+                        .on_action(window.listener_for(&self.state, InputState::down))
+                },
+            )
             .on_action(window.listener_for(&self.state, InputState::select_all))
             .on_action(window.listener_for(&self.state, InputState::select_to_start_of_line))
             .on_action(window.listener_for(&self.state, InputState::select_to_end_of_line))
@@ -456,6 +496,9 @@ impl RenderOnce for Input {
                                 }
                             }))
                         })
+                        .when(self.show_count, |this| {
+                            this.child(Self::render_count(state, cx))
+                        })
                         .children(suffix),
                 )
             })
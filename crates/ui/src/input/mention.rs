@@ -0,0 +1,206 @@
+use std::ops::Range;
+
+use gpui::SharedString;
+
+/// A non-editable token in the input text, e.g.: an `@file` reference or a `#session` tag.
+///
+/// The label is kept in the text and rendered as a pill, it is deleted as a whole.
+///
+/// See also: [`super::InputState::insert_mention`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mention {
+    /// The byte range of the label in the input text.
+    pub range: Range<usize>,
+    /// The structured value of the mention, e.g.: the file path or the session id.
+    pub value: SharedString,
+}
+
+/// A segment of the input value, to serialize the text with the mentions.
+///
+/// See also: [`super::InputState::segments`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSegment {
+    /// The plain text.
+    Text(SharedString),
+    /// A mention with the label in the text and the structured value.
+    Mention {
+        label: SharedString,
+        value: SharedString,
+    },
+}
+
+/// Returns true if the `range` changes the text inside the `mention`.
+///
+/// An insertion at the start or the end of the mention does not change it.
+fn is_overlapped(mention: &Mention, range: &Range<usize>) -> bool {
+    range.start < mention.range.end && range.end > mention.range.start
+}
+
+/// Expand the `range` to cover the whole mentions it overlaps, to delete the mentions atomically.
+pub(super) fn expand_range_to_mentions(mentions: &[Mention], range: Range<usize>) -> Range<usize> {
+    if range.is_empty() {
+        return range;
+    }
+
+    mentions
+        .iter()
+        .filter(|mention| is_overlapped(mention, &range))
+        .fold(range.clone(), |range, mention| {
+            range.start.min(mention.range.start)..range.end.max(mention.range.end)
+        })
+}
+
+/// Update the `mentions` after the `range` is replaced by the text with `new_len` bytes.
+///
+/// The overlapped mentions are removed, and the mentions after the `range` are shifted.
+pub(super) fn adjust_mentions_for_edit(
+    mentions: &mut Vec<Mention>,
+    range: &Range<usize>,
+    new_len: usize,
+) {
+    mentions.retain(|mention| !is_overlapped(mention, range));
+    for mention in mentions.iter_mut() {
+        if mention.range.start >= range.end {
+            mention.range.start = mention.range.start - range.len() + new_len;
+            mention.range.end = mention.range.end - range.len() + new_len;
+        }
+    }
+}
+
+/// Returns the `offset` moved out of the mention, to the start if `forward` is false.
+pub(super) fn offset_out_of_mentions(mentions: &[Mention], offset: usize, forward: bool) -> usize {
+    match mentions
+        .iter()
+        .find(|mention| mention.range.start < offset && offset < mention.range.end)
+    {
+        Some(mention) if forward => mention.range.end,
+        Some(mention) => mention.range.start,
+        None => offset,
+    }
+}
+
+/// Split the `text` into the plain text and the mentions, the `mentions` must be sorted.
+pub(super) fn segments(text: &str, mentions: &[Mention]) -> Vec<InputSegment> {
+    let mut segments = vec![];
+    let mut offset = 0;
+    for mention in mentions {
+        if mention.range.start > offset {
+            segments.push(InputSegment::Text(
+                text[offset..mention.range.start].to_string().into(),
+            ));
+        }
+        segments.push(InputSegment::Mention {
+            label: text[mention.range.clone()].to_string().into(),
+            value: mention.value.clone(),
+        });
+        offset = mention.range.end;
+    }
+    if offset < text.len() {
+        segments.push(InputSegment::Text(text[offset..].to_string().into()));
+    }
+    segments
+}
+
+/// Returns the length and whether it is a mention of each text run, to cover the `len` bytes.
+///
+/// The sorted `ranges` of the mentions are in the coordinate space of the runs.
+pub(super) fn mention_runs(len: usize, ranges: &[Range<usize>]) -> Vec<(usize, bool)> {
+    let mut runs = vec![];
+    let mut offset = 0;
+    for range in ranges {
+        if range.end > len {
+            break;
+        }
+        if range.start > offset {
+            runs.push((range.start - offset, false));
+        }
+        runs.push((range.len(), true));
+        offset = range.end;
+    }
+    if offset < len {
+        runs.push((len - offset, false));
+    }
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        InputSegment, Mention, adjust_mentions_for_edit, expand_range_to_mentions, mention_runs,
+        offset_out_of_mentions, segments,
+    };
+
+    fn mention(range: std::ops::Range<usize>, value: &'static str) -> Mention {
+        Mention {
+            range,
+            value: value.into(),
+        }
+    }
+
+    #[test]
+    fn test_expand_range_to_mentions() {
+        // "Hi @foo and #bar"
+        let mentions = vec![mention(3..7, "foo"), mention(12..16, "bar")];
+        assert_eq!(expand_range_to_mentions(&mentions, 6..7), 3..7);
+        assert_eq!(expand_range_to_mentions(&mentions, 5..13), 3..16);
+        assert_eq!(expand_range_to_mentions(&mentions, 7..8), 7..8);
+        assert_eq!(expand_range_to_mentions(&mentions, 5..5), 5..5);
+    }
+
+    #[test]
+    fn test_adjust_mentions_for_edit() {
+        let mut mentions = vec![mention(3..7, "foo"), mention(12..16, "bar")];
+        // Insert at the end of the first mention.
+        adjust_mentions_for_edit(&mut mentions, &(7..7), 2);
+        assert_eq!(mentions, vec![mention(3..7, "foo"), mention(14..18, "bar")]);
+
+        // Delete the text before the second mention.
+        adjust_mentions_for_edit(&mut mentions, &(8..13), 0);
+        assert_eq!(mentions, vec![mention(3..7, "foo"), mention(9..13, "bar")]);
+
+        // Edit inside the first mention.
+        adjust_mentions_for_edit(&mut mentions, &(5..5), 1);
+        assert_eq!(mentions, vec![mention(10..14, "bar")]);
+    }
+
+    #[test]
+    fn test_offset_out_of_mentions() {
+        let mentions = vec![mention(3..7, "foo")];
+        assert_eq!(offset_out_of_mentions(&mentions, 5, true), 7);
+        assert_eq!(offset_out_of_mentions(&mentions, 5, false), 3);
+        assert_eq!(offset_out_of_mentions(&mentions, 3, true), 3);
+        assert_eq!(offset_out_of_mentions(&mentions, 8, false), 8);
+    }
+
+    #[test]
+    fn test_segments() {
+        let text = "Hi @foo and #bar";
+        let mentions = vec![mention(3..7, "foo.rs"), mention(12..16, "bar-id")];
+        assert_eq!(
+            segments(text, &mentions),
+            vec![
+                InputSegment::Text("Hi ".into()),
+                InputSegment::Mention {
+                    label: "@foo".into(),
+                    value: "foo.rs".into()
+                },
+                InputSegment::Text(" and ".into()),
+                InputSegment::Mention {
+                    label: "#bar".into(),
+                    value: "bar-id".into()
+                },
+            ]
+        );
+        assert_eq!(segments("", &[]), vec![]);
+    }
+
+    #[test]
+    fn test_mention_runs() {
+        let ranges = vec![3..7, 7..11];
+        assert_eq!(
+            mention_runs(12, &ranges),
+            vec![(3, false), (4, true), (4, true), (1, false)]
+        );
+        assert_eq!(mention_runs(4, &ranges), vec![(4, false)]);
+    }
+}
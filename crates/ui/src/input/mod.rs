@@ -4,35 +4,45 @@ pub(super) const MASK_CHAR: char = '•';
 mod blink_cursor;
 mod change;
 mod clear_button;
+mod command;
 mod cursor;
 mod display_map;
+mod draft;
 mod element;
 mod indent;
 mod input;
 mod lsp;
 mod mask_pattern;
+mod mention;
 mod mode;
 mod movement;
 mod number_input;
 mod otp_input;
 pub(crate) mod popovers;
+mod prompt_history;
 mod rope_ext;
 mod search;
 mod selection;
+mod spell_check;
 mod state;
 
 pub(crate) use clear_button::*;
+pub use command::{CommandInvocation, CommandRegistry, SlashCommand};
 pub use cursor::*;
 #[cfg(target_family = "wasm")]
 pub use display_map::folding::Tree;
 pub use display_map::{BufferPoint, DisplayMap, DisplayPoint, FoldRange};
+pub use draft::DraftStore;
 pub use indent::TabSize;
 pub use input::*;
 pub use lsp::*;
 pub use lsp_types::Position;
 pub use mask_pattern::MaskPattern;
+pub use mention::{InputSegment, Mention};
 pub use number_input::{NumberInput, NumberInputEvent, StepAction};
 pub use otp_input::*;
+pub use prompt_history::PromptHistory;
 pub use rope_ext::{InputEdit, Point, RopeExt, RopeLines};
 pub use ropey::Rope;
+pub use spell_check::{SpellChecker, WordList};
 pub use state::*;
@@ -159,7 +159,12 @@ impl InputState {
             return;
         }
 
+        if self.recall_prompt_history(MoveDirection::Up, window, cx) {
+            return;
+        }
+
         if self.mode.is_single_line() {
+            cx.propagate();
             return;
         }
 
@@ -179,7 +184,12 @@ impl InputState {
             return;
         }
 
+        if self.recall_prompt_history(MoveDirection::Down, window, cx) {
+            return;
+        }
+
         if self.mode.is_single_line() {
+            cx.propagate();
             return;
         }
 
@@ -9,7 +9,7 @@ use crate::{
     ActiveTheme as _,
     global_state::GlobalState,
     input::{self, InputState, popovers::ContextMenu},
-    menu::PopupMenu,
+    menu::{PopupMenu, PopupMenuItem},
 };
 
 /// Context menu for mouse right clicks.
@@ -54,6 +54,18 @@ impl InputState {
         let is_selected = !self.selected_range.is_empty();
         let has_paste = is_enable && cx.read_from_clipboard().is_some();
 
+        // The suggestions of the misspelled word under the mouse.
+        let spelling = self
+            .spell_check
+            .as_ref()
+            .filter(|_| is_enable)
+            .and_then(|spell_check| {
+                let range = spell_check.range_for_offset(offset)?;
+                let word = self.text.slice(range.clone()).to_string();
+                Some((range, spell_check.suggest(&word)))
+            });
+
+        let editor = cx.entity();
         let action_context = self.focus_handle.clone();
         self.context_menu.update(cx, |this, cx| {
             this.mouse_position = event.position;
@@ -62,6 +74,30 @@ impl InputState {
                     builder(PopupMenu::new(cx), window, cx)
                 } else {
                     PopupMenu::new(cx)
+                        .when_some(spelling, |m, (range, suggestions)| {
+                            let m = if suggestions.is_empty() {
+                                m.label(t!("Input.No Suggestions"))
+                            } else {
+                                suggestions.into_iter().fold(m, |m, suggestion| {
+                                    let editor = editor.clone();
+                                    let range = range.clone();
+                                    m.item(PopupMenuItem::new(suggestion.clone()).on_click(
+                                        move |_, window, cx| {
+                                            editor.update(cx, |editor, cx| {
+                                                let range_utf16 = editor.range_to_utf16(&range);
+                                                editor.replace_text_in_range_silent(
+                                                    Some(range_utf16),
+                                                    &suggestion,
+                                                    window,
+                                                    cx,
+                                                );
+                                            });
+                                        },
+                                    ))
+                                })
+                            };
+                            m.separator()
+                        })
                         .when(is_code_editor, |m| {
                             m.menu_with_enable(
                                 t!("Input.Go to Definition"),
@@ -0,0 +1,165 @@
+use std::rc::Rc;
+
+use gpui::{App, Context, SharedString, Window};
+
+use super::{InputState, RopeExt as _, movement::MoveDirection};
+
+/// The history of the submitted prompts of the [`InputState`], like a shell, pressing `up` in
+/// an empty input recalls the previous prompts, and `down` goes back to the newer ones.
+///
+/// See also: [`InputState::prompt_history`], [`InputState::push_prompt_history`].
+///
+/// ```ignore
+/// let history = PromptHistory::new(100)
+///     .entries(load_prompts())
+///     .on_change(|entries, _| save_prompts(entries));
+///
+/// let input = cx.new(|cx| InputState::new(window, cx).auto_grow(1, 5).prompt_history(history));
+/// ```
+pub struct PromptHistory {
+    /// The entries, the newest last.
+    entries: Vec<SharedString>,
+    max_size: usize,
+    /// The index of the recalled entry, `None` if not recalling.
+    index: Option<usize>,
+    on_change: Option<Rc<dyn Fn(&[SharedString], &mut App)>>,
+}
+
+impl PromptHistory {
+    /// Create a new prompt history keeping at most `max_size` entries.
+    pub fn new(max_size: usize) -> Self {
+        Self {
+            entries: vec![],
+            max_size,
+            index: None,
+            on_change: None,
+        }
+    }
+
+    /// Set the initial entries (the newest last), e.g.: restored from the disk.
+    pub fn entries(mut self, entries: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.entries = entries.into_iter().map(Into::into).collect();
+        let overflow = self.entries.len().saturating_sub(self.max_size);
+        self.entries.drain(..overflow);
+        self
+    }
+
+    /// Set the callback when a new entry is pushed, with all the entries (the newest last),
+    /// use it to persist the history.
+    pub fn on_change(mut self, f: impl Fn(&[SharedString], &mut App) + 'static) -> Self {
+        self.on_change = Some(Rc::new(f));
+        self
+    }
+
+    fn push(&mut self, text: SharedString, cx: &mut App) {
+        self.reset();
+        if text.trim().is_empty() || self.entries.last() == Some(&text) {
+            return;
+        }
+
+        self.entries.push(text);
+        let overflow = self.entries.len().saturating_sub(self.max_size);
+        self.entries.drain(..overflow);
+
+        if let Some(on_change) = self.on_change.clone() {
+            on_change(&self.entries, cx);
+        }
+    }
+
+    /// Stop recalling, e.g.: the recalled text is edited.
+    pub(super) fn reset(&mut self) {
+        self.index = None;
+    }
+
+    /// Returns the index of the entry to recall in the `direction`.
+    ///
+    /// The `Some(None)` means to leave the history (after the newest entry).
+    fn step(&self, direction: MoveDirection) -> Option<Option<usize>> {
+        match (direction, self.index) {
+            (MoveDirection::Up, None) => Some(Some(self.entries.len().checked_sub(1)?)),
+            (MoveDirection::Up, Some(ix)) => Some(Some(ix.checked_sub(1)?)),
+            (MoveDirection::Down, None) => None,
+            (MoveDirection::Down, Some(ix)) => {
+                Some((ix + 1 < self.entries.len()).then_some(ix + 1))
+            }
+        }
+    }
+}
+
+impl InputState {
+    /// Set the [`PromptHistory`] to recall the previous prompts by `up` and `down`, default: None.
+    pub fn prompt_history(mut self, history: PromptHistory) -> Self {
+        self.prompt_history = Some(history);
+        self
+    }
+
+    /// Push the submitted `text` to the [`PromptHistory`], the empty and repeated text is skipped.
+    ///
+    /// Call this when the prompt is submitted, e.g.: on [`super::InputEvent::PressEnter`].
+    pub fn push_prompt_history(&mut self, text: impl Into<SharedString>, cx: &mut Context<Self>) {
+        if let Some(history) = self.prompt_history.as_mut() {
+            history.push(text.into(), cx);
+        }
+    }
+
+    /// Recall the prompt in the `direction`, returns false if the cursor should be moved instead.
+    ///
+    /// The `up` recalls when the input is empty, or when recalling with the cursor on the first line,
+    /// the `down` recalls when recalling with the cursor on the last line.
+    pub(super) fn recall_prompt_history(
+        &mut self,
+        direction: MoveDirection,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        let Some(history) = self.prompt_history.as_ref() else {
+            return false;
+        };
+
+        let row = self.text.offset_to_point(self.cursor()).row;
+        let can_recall = match direction {
+            MoveDirection::Up => self.text.len() == 0 || (history.index.is_some() && row == 0),
+            MoveDirection::Down => history.index.is_some() && row + 1 >= self.text.lines_len(),
+        };
+        if !can_recall {
+            return false;
+        }
+
+        let Some(index) = history.step(direction) else {
+            return false;
+        };
+        let text = index
+            .map(|ix| history.entries[ix].clone())
+            .unwrap_or_default();
+
+        self.replace_text(text, window, cx);
+        if let Some(history) = self.prompt_history.as_mut() {
+            history.index = index;
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::SharedString;
+
+    use super::PromptHistory;
+    use crate::input::movement::MoveDirection;
+
+    #[test]
+    fn test_prompt_history_step() {
+        let mut history = PromptHistory::new(2).entries(["a", "b", "c"]);
+        assert_eq!(history.entries, ["b", "c"].map(SharedString::from));
+
+        assert_eq!(history.step(MoveDirection::Down), None);
+        assert_eq!(history.step(MoveDirection::Up), Some(Some(1)));
+
+        history.index = Some(0);
+        assert_eq!(history.step(MoveDirection::Up), None);
+        assert_eq!(history.step(MoveDirection::Down), Some(Some(1)));
+
+        history.index = Some(1);
+        assert_eq!(history.step(MoveDirection::Down), Some(None));
+    }
+}
@@ -0,0 +1,213 @@
+use std::{collections::HashSet, ops::Range, rc::Rc};
+
+use gpui::SharedString;
+use ropey::Rope;
+use unicode_segmentation::UnicodeSegmentation as _;
+
+use super::RopeExt as _;
+
+/// A dictionary backend to check the spelling of the words in the [`super::InputState`].
+///
+/// See also: [`super::InputState::spell_checker`].
+pub trait SpellChecker {
+    /// Returns true if the `word` is spelled correctly.
+    fn check(&self, word: &str) -> bool;
+
+    /// Returns the suggestions to replace the misspelled `word`, the best first.
+    fn suggest(&self, word: &str) -> Vec<SharedString>;
+}
+
+/// A simple [`SpellChecker`] backed by a list of the known words, case-insensitive.
+///
+/// The suggestions are the known words within 2 edits of the misspelled word.
+pub struct WordList {
+    words: HashSet<String>,
+}
+
+impl WordList {
+    /// Create a word list with the given known words.
+    pub fn new(words: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        Self {
+            words: words
+                .into_iter()
+                .map(|word| word.as_ref().to_lowercase())
+                .collect(),
+        }
+    }
+}
+
+impl SpellChecker for WordList {
+    fn check(&self, word: &str) -> bool {
+        self.words.contains(&word.to_lowercase())
+    }
+
+    fn suggest(&self, word: &str) -> Vec<SharedString> {
+        let word = word.to_lowercase();
+        let mut suggestions = self
+            .words
+            .iter()
+            .filter_map(|known| {
+                let distance = edit_distance(&word, known);
+                (distance <= 2).then_some((distance, known))
+            })
+            .collect::<Vec<_>>();
+        suggestions.sort();
+        suggestions
+            .into_iter()
+            .take(5)
+            .map(|(_, known)| known.clone().into())
+            .collect()
+    }
+}
+
+/// Returns the Levenshtein distance of the two words.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cur = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                prev
+            } else {
+                prev.min(cur).min(row[j]) + 1
+            };
+            prev = cur;
+        }
+    }
+    row[b.len()]
+}
+
+/// Returns true if the `word` should be checked, e.g.: skip the numbers and the `snake_case`.
+fn is_checkable(word: &str) -> bool {
+    word.chars().count() > 1 && word.chars().all(|c| c.is_alphabetic() || c == '\'')
+}
+
+/// Returns the byte ranges of the misspelled words in the `text`.
+fn misspelled_ranges(text: &str, checker: &dyn SpellChecker) -> Vec<Range<usize>> {
+    text.split_word_bound_indices()
+        .filter(|(_, word)| is_checkable(word) && !checker.check(word))
+        .map(|(ix, word)| ix..ix + word.len())
+        .collect()
+}
+
+/// The state of the spell checking in the [`super::InputState`].
+pub(super) struct SpellCheck {
+    checker: Rc<dyn SpellChecker>,
+    /// The byte ranges of the misspelled words, sorted.
+    pub(super) ranges: Vec<Range<usize>>,
+}
+
+impl SpellCheck {
+    pub(super) fn new(checker: Rc<dyn SpellChecker>) -> Self {
+        Self {
+            checker,
+            ranges: vec![],
+        }
+    }
+
+    /// Check the spelling of the whole `text`.
+    pub(super) fn update(&mut self, text: &str) {
+        self.ranges = misspelled_ranges(text, self.checker.as_ref());
+    }
+
+    /// Check the spelling of the lines changed by replacing the `range` of the old text with
+    /// `new_len` bytes of the new `text`, the misspelled words after the lines are shifted.
+    pub(super) fn edit(&mut self, text: &Rope, range: &Range<usize>, new_len: usize) {
+        let start = text.line_start_offset(text.offset_to_point(range.start).row);
+        let end = text.line_end_offset(text.offset_to_point(range.start + new_len).row);
+        // The end of the changed lines in the old text.
+        let old_end = end + range.len() - new_len;
+
+        let mut ranges = Vec::with_capacity(self.ranges.len());
+        ranges.extend(
+            self.ranges
+                .iter()
+                .take_while(|word| word.end <= start)
+                .cloned(),
+        );
+        ranges.extend(
+            misspelled_ranges(&text.slice(start..end).to_string(), self.checker.as_ref())
+                .into_iter()
+                .map(|word| start + word.start..start + word.end),
+        );
+        ranges.extend(
+            self.ranges
+                .iter()
+                .filter(|word| word.start >= old_end)
+                .map(|word| word.start + end - old_end..word.end + end - old_end),
+        );
+        self.ranges = ranges;
+    }
+
+    /// Returns the range of the misspelled word at the `offset`.
+    pub(super) fn range_for_offset(&self, offset: usize) -> Option<Range<usize>> {
+        self.ranges
+            .iter()
+            .find(|range| range.start <= offset && offset <= range.end)
+            .cloned()
+    }
+
+    /// Returns the suggestions to replace the misspelled `word`.
+    pub(super) fn suggest(&self, word: &str) -> Vec<SharedString> {
+        self.checker.suggest(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::SharedString;
+
+    use std::rc::Rc;
+
+    use ropey::Rope;
+
+    use super::{SpellCheck, SpellChecker as _, WordList, edit_distance, misspelled_ranges};
+    use crate::input::RopeExt as _;
+
+    #[test]
+    fn test_word_list() {
+        let words = WordList::new(["hello", "world", "help", "don't"]);
+        assert!(words.check("Hello"));
+        assert!(words.check("don't"));
+        assert!(!words.check("helo"));
+        assert_eq!(
+            words.suggest("helo"),
+            vec![SharedString::from("hello"), SharedString::from("help")]
+        );
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+
+        let text = "Helo world, don't 42 snake_case wrld";
+        assert_eq!(misspelled_ranges(text, &words), vec![0..4, 32..36]);
+    }
+
+    #[test]
+    fn test_edit() {
+        let words = WordList::new(["hello", "world"]);
+        let mut spell_check = SpellCheck::new(Rc::new(words));
+        let mut text = Rope::from("helo world\nwrld\nhello wrld");
+        spell_check.update(&text.to_string());
+        assert_eq!(spell_check.ranges, vec![0..4, 11..15, 22..26]);
+
+        // Fix the first word, the words of the other lines are shifted.
+        text.replace(0..4, "hello");
+        spell_check.edit(&text, &(0..4), 5);
+        assert_eq!(spell_check.ranges, vec![12..16, 23..27]);
+
+        // Insert a new line with a misspelled word.
+        text.replace(17..17, "wold\n");
+        spell_check.edit(&text, &(17..17), 5);
+        assert_eq!(spell_check.ranges, vec![12..16, 17..21, 28..32]);
+        assert_eq!(
+            spell_check.ranges,
+            misspelled_ranges(&text.to_string(), &WordList::new(["hello", "world"]))
+        );
+
+        // Delete the lines.
+        text.replace(12..22, "");
+        spell_check.edit(&text, &(12..22), 0);
+        assert_eq!(spell_check.ranges, vec![18..22]);
+    }
+}
@@ -20,8 +20,9 @@ use sum_tree::Bias;
 use unicode_segmentation::*;
 
 use super::{
-    DisplayMap, MASK_CHAR, blink_cursor::BlinkCursor, change::Change, element::TextElement,
-    mask_pattern::MaskPattern, mode::InputMode, number_input,
+    CommandInvocation, CommandRegistry, DisplayMap, InputSegment, MASK_CHAR, Mention,
+    PromptHistory, SpellChecker, blink_cursor::BlinkCursor, change::Change, element::TextElement,
+    mask_pattern::MaskPattern, mention, mode::InputMode, number_input, spell_check::SpellCheck,
 };
 use crate::Size;
 use crate::actions::{SelectDown, SelectLeft, SelectRight, SelectUp};
@@ -97,9 +98,13 @@ actions!(
 #[derive(Clone)]
 pub enum InputEvent {
     Change,
-    PressEnter { secondary: bool },
+    PressEnter {
+        secondary: bool,
+    },
     Focus,
     Blur,
+    /// A slash command of the [`CommandRegistry`] is submitted by pressing Enter.
+    Command(CommandInvocation),
 }
 
 pub(super) const CONTEXT: &str = "Input";
@@ -330,6 +335,10 @@ pub struct InputState {
     pub(crate) cursor_line_end_affinity: bool,
     pub(super) pattern: Option<regex::Regex>,
     pub(super) validate: Option<Box<dyn Fn(&str, &mut Context<Self>) -> bool + 'static>>,
+    /// The max number of the characters (grapheme clusters) can be typed or pasted.
+    pub(super) max_length: Option<usize>,
+    pub(super) prompt_history: Option<PromptHistory>,
+    pub(super) command_registry: Option<Rc<CommandRegistry>>,
     pub(crate) scroll_handle: ScrollHandle,
     /// The deferred scroll offset to apply on next layout.
     pub(crate) deferred_scroll_offset: Option<Point<Pixels>>,
@@ -339,6 +348,12 @@ pub struct InputState {
 
     /// The mask pattern for formatting the input text
     pub(crate) mask_pattern: MaskPattern,
+    /// The mentions in the text, sorted by the range.
+    pub(super) mentions: Vec<Mention>,
+    /// The value of the mention to create by the next replacement.
+    pending_mention: Option<SharedString>,
+    /// The spell checking of the text, not for the code editor.
+    pub(super) spell_check: Option<SpellCheck>,
     pub(super) placeholder: SharedString,
 
     /// Popover
@@ -440,6 +455,9 @@ impl InputState {
             loading: false,
             pattern: None,
             validate: None,
+            max_length: None,
+            prompt_history: None,
+            command_registry: None,
             mode: InputMode::default(),
             last_layout: None,
             last_bounds: None,
@@ -451,6 +469,9 @@ impl InputState {
             preferred_column: None,
             placeholder: SharedString::default(),
             mask_pattern: MaskPattern::default(),
+            mentions: vec![],
+            pending_mention: None,
+            spell_check: None,
             text_align: TextAlign::Left,
             lsp: Lsp::default(),
             diagnostic_popover: None,
@@ -759,7 +780,55 @@ impl InputState {
         self.disabled = was_disabled;
     }
 
-    fn replace_text(
+    /// Insert a [`Mention`] at the current cursor position, replacing the selected text.
+    ///
+    /// The `label` (e.g.: `@main.rs`) is rendered as a pill and deleted as a whole,
+    /// the `value` (e.g.: the file path) is returned by [`Self::segments`].
+    pub fn insert_mention(
+        &mut self,
+        label: impl Into<SharedString>,
+        value: impl Into<SharedString>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let range = self.selected_range.into();
+        self.replace_with_mention(range, label, value, window, cx);
+    }
+
+    /// Replace the text in the `range` with a [`Mention`], e.g.: to replace the `@ma` typed
+    /// by the user with the selected file.
+    ///
+    /// The `range` is the byte range in the text, and the cursor is moved to the end of it.
+    pub fn replace_with_mention(
+        &mut self,
+        range: Range<usize>,
+        label: impl Into<SharedString>,
+        value: impl Into<SharedString>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let was_disabled = self.disabled;
+        self.disabled = false;
+        let label: SharedString = label.into();
+        let range_utf16 = self.range_to_utf16(&range);
+        self.pending_mention = Some(value.into());
+        self.replace_text_in_range_silent(Some(range_utf16), &label, window, cx);
+        self.pending_mention = None;
+        self.disabled = was_disabled;
+    }
+
+    /// Returns the mentions in the text, sorted by the range.
+    pub fn mentions(&self) -> &[Mention] {
+        &self.mentions
+    }
+
+    /// Returns the value split into the plain text and the mentions,
+    /// to serialize the structured value alongside the plain text of [`Self::value`].
+    pub fn segments(&self) -> Vec<InputSegment> {
+        mention::segments(&self.text.to_string(), &self.mentions)
+    }
+
+    pub(super) fn replace_text(
         &mut self,
         text: impl Into<SharedString>,
         window: &mut Window,
@@ -880,6 +949,26 @@ impl InputState {
         self
     }
 
+    /// Set the max number of the characters can be typed or pasted, default: None.
+    ///
+    /// The characters are counted as the grapheme clusters, e.g.: an emoji is 1 character.
+    /// The pasted text is truncated to fit the limit, but the value set by the code is not.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Set or remove the max length, see also [`Self::max_length`].
+    pub fn set_max_length(
+        &mut self,
+        max_length: Option<usize>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.max_length = max_length;
+        cx.notify();
+    }
+
     /// Set true to show spinner at the input right.
     ///
     /// Only for [`InputMode::SingleLine`] mode.
@@ -896,12 +985,73 @@ impl InputState {
         if let Some(diagnostics) = self.mode.diagnostics_mut() {
             diagnostics.reset(&self.text)
         }
+        self.update_spell_check();
         // Note: We can't call display_map.set_text here because it needs cx.
         // The text will be set during prepare_if_need in element.rs
         self._pending_update = true;
         self
     }
 
+    /// Set the [`SpellChecker`] to underline the misspelled words with the suggestions
+    /// in the right-click context menu, default: None.
+    ///
+    /// The checker can be shared by the inputs, e.g.: a dictionary loaded once.
+    /// It is ignored in the [`InputMode::CodeEditor`] mode.
+    pub fn spell_checker(mut self, checker: Rc<dyn SpellChecker>) -> Self {
+        self.spell_check = Some(SpellCheck::new(checker));
+        self.update_spell_check();
+        self
+    }
+
+    /// Set or remove the [`SpellChecker`], see also [`Self::spell_checker`].
+    pub fn set_spell_checker(
+        &mut self,
+        checker: Option<Rc<dyn SpellChecker>>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.spell_check = checker.map(SpellCheck::new);
+        self.update_spell_check();
+        cx.notify();
+    }
+
+    /// Check the spelling of the text, the IME composing text is skipped.
+    fn update_spell_check(&mut self) {
+        if self.mode.is_code_editor() {
+            return;
+        }
+        let Some(spell_check) = self.spell_check.as_mut() else {
+            return;
+        };
+
+        spell_check.update(&self.text.to_string());
+        self.skip_marked_spell_check();
+    }
+
+    /// Check the spelling of the lines changed by replacing the `range` with `new_len` bytes.
+    fn update_spell_check_for_edit(&mut self, range: &Range<usize>, new_len: usize) {
+        if self.mode.is_code_editor() {
+            return;
+        }
+        let Some(spell_check) = self.spell_check.as_mut() else {
+            return;
+        };
+
+        spell_check.edit(&self.text, range, new_len);
+        self.skip_marked_spell_check();
+    }
+
+    fn skip_marked_spell_check(&mut self) {
+        let Some(spell_check) = self.spell_check.as_mut() else {
+            return;
+        };
+        if let Some(marked_range) = self.ime_marked_range {
+            spell_check
+                .ranges
+                .retain(|range| range.end <= marked_range.start || range.start >= marked_range.end);
+        }
+    }
+
     /// Return the value of the input field.
     pub fn value(&self) -> SharedString {
         SharedString::new(self.text.to_string())
@@ -918,6 +1068,16 @@ impl InputState {
         self.mask_pattern.unmask(&self.text.to_string()).into()
     }
 
+    /// Return the number of the characters (grapheme clusters) of the value.
+    pub fn char_count(&self) -> usize {
+        self.text.to_string().graphemes(true).count()
+    }
+
+    /// Return the number of the words of the value.
+    pub fn word_count(&self) -> usize {
+        self.text.to_string().unicode_words().count()
+    }
+
     /// Return the text [`Rope`] of the input field.
     pub fn text(&self) -> &Rope {
         &self.text
@@ -978,7 +1138,7 @@ impl InputState {
         self.select_to(self.next_boundary(offset), cx);
     }
 
-    pub(super) fn select_all(&mut self, _: &SelectAll, _: &mut Window, cx: &mut Context<Self>) {
+    pub(crate) fn select_all(&mut self, _: &SelectAll, _: &mut Window, cx: &mut Context<Self>) {
         self.selected_range = (0..self.text.len()).into();
         cx.notify();
     }
@@ -1303,6 +1463,18 @@ impl InputState {
             return;
         }
 
+        // Commit the IME composition, instead of inserting a new line or emitting `PressEnter`.
+        if self.is_composing() {
+            self.unmark_text(window, cx);
+            return;
+        }
+
+        // Dispatch the slash command, instead of inserting a new line or emitting `PressEnter`.
+        if let Some(command) = self.parse_command() {
+            cx.emit(InputEvent::Command(command));
+            return;
+        }
+
         // Clear inline completion on enter (user chose not to accept it)
         if self.has_inline_completion() {
             self.clear_inline_completion(cx);
@@ -1729,6 +1901,14 @@ impl InputState {
         self.selected_range.into()
     }
 
+    /// Returns true if there is an active IME composition (the preedit text is not committed).
+    ///
+    /// The `enter` commits the composition without emitting [`InputEvent::PressEnter`],
+    /// use this to make the same check in the custom key handlers, e.g.: to send a message.
+    pub fn is_composing(&self) -> bool {
+        self.ime_marked_range.is_some()
+    }
+
     pub(crate) fn index_for_mouse_position(&self, position: Point<Pixels>) -> usize {
         // If the text is empty, always return 0
         if self.text.len() == 0 {
@@ -1905,6 +2085,7 @@ impl InputState {
                 offset -= 1;
             }
         }
+        let offset = mention::offset_out_of_mentions(&self.mentions, offset, false);
 
         self.clamp_offset_to_visible_backward(offset)
     }
@@ -1916,6 +2097,7 @@ impl InputState {
                 offset += 1;
             }
         }
+        let offset = mention::offset_out_of_mentions(&self.mentions, offset, true);
 
         self.clamp_offset_to_visible_forward(offset)
     }
@@ -2297,8 +2479,9 @@ impl EntityInputHandler for InputState {
             .map(|range| self.range_to_utf16(&range.into()))
     }
 
-    fn unmark_text(&mut self, _window: &mut Window, _cx: &mut Context<Self>) {
+    fn unmark_text(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
         self.ime_marked_range = None;
+        cx.notify();
     }
 
     /// Replace text in range.
@@ -2328,6 +2511,29 @@ impl EntityInputHandler for InputState {
                 self.range_from_utf16(&range)
             }))
             .unwrap_or(self.selected_range.into());
+        // Delete the mentions as a whole.
+        let range = mention::expand_range_to_mentions(&self.mentions, range);
+
+        // Truncate the new text to fit the max length, the undo and redo are not limited.
+        let new_text = match self.max_length {
+            Some(max_length) if !self.history.ignore => {
+                let removed = self
+                    .text
+                    .slice(range.clone())
+                    .to_string()
+                    .graphemes(true)
+                    .count();
+                let remaining = (max_length + removed).saturating_sub(self.char_count());
+                let truncated = truncate_graphemes(new_text, remaining);
+                if truncated.is_empty() && range.is_empty() && !new_text.is_empty() {
+                    self.ime_marked_range = None;
+                    cx.notify();
+                    return;
+                }
+                truncated
+            }
+            _ => new_text,
+        };
 
         let old_text = self.text.clone();
         self.text.replace(range.clone(), new_text);
@@ -2353,6 +2559,19 @@ impl EntityInputHandler for InputState {
 
         self.push_history(&old_text, &range, &new_text);
         self.history.end_grouping();
+        mention::adjust_mentions_for_edit(&mut self.mentions, &range, new_text.len());
+        if let Some(value) = self.pending_mention.take() {
+            let ix = self
+                .mentions
+                .partition_point(|mention| mention.range.start < range.start);
+            self.mentions.insert(
+                ix,
+                Mention {
+                    range: range.start..range.start + new_text.len(),
+                    value,
+                },
+            );
+        }
         if let Some(diagnostics) = self.mode.diagnostics_mut() {
             diagnostics.reset(&self.text)
         }
@@ -2373,6 +2592,10 @@ impl EntityInputHandler for InputState {
         self.lsp.update(&self.text, window, cx);
         self.selected_range = (new_offset..new_offset).into();
         self.ime_marked_range.take();
+        if let Some(history) = self.prompt_history.as_mut() {
+            history.reset();
+        }
+        self.update_spell_check_for_edit(&range, new_text.len());
         self.update_preferred_column();
         self.update_search(cx);
         self.mode.update_auto_grow(&self.display_map);
@@ -2420,6 +2643,7 @@ impl EntityInputHandler for InputState {
             }
         }
 
+        mention::adjust_mentions_for_edit(&mut self.mentions, &range, new_text.len());
         if let Some(diagnostics) = self.mode.diagnostics_mut() {
             diagnostics.reset(&self.text)
         }
@@ -2451,6 +2675,7 @@ impl EntityInputHandler for InputState {
                 .unwrap_or_else(|| range.start + new_text.len()..range.start + new_text.len())
                 .into();
         }
+        self.update_spell_check_for_edit(&range, new_text.len());
         self.mode.update_auto_grow(&self.display_map);
         self.history.start_grouping();
         self.push_history(&old_text, &range, new_text);
@@ -2507,8 +2732,10 @@ impl EntityInputHandler for InputState {
 
         let start_origin = start_origin.unwrap_or_default();
         let mut end_origin = end_origin.unwrap_or_default();
-        // Ensure at same line.
-        end_origin.y = start_origin.y;
+        // Ensure at same line, e.g.: the marked text is soft wrapped to the next line.
+        if end_origin.y != start_origin.y || end_origin.x < start_origin.x {
+            end_origin = start_origin;
+        }
 
         Some(Bounds::from_corners(
             bounds.origin + line_number_origin + start_origin,
@@ -2571,6 +2798,13 @@ impl Render for InputState {
     }
 }
 
+/// Returns the prefix of the `text` with at most `max` grapheme clusters.
+fn truncate_graphemes(text: &str, max: usize) -> &str {
+    text.grapheme_indices(true)
+        .nth(max)
+        .map_or(text, |(ix, _)| &text[..ix])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2608,6 +2842,152 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_truncate_graphemes() {
+        assert_eq!(truncate_graphemes("Hello", 3), "Hel");
+        assert_eq!(truncate_graphemes("Hello", 10), "Hello");
+        assert_eq!(truncate_graphemes("你好👋🏻!", 3), "你好👋🏻");
+        assert_eq!(truncate_graphemes("Hello", 0), "");
+    }
+
+    #[gpui::test]
+    fn test_max_length(cx: &mut TestAppContext) {
+        let input_view = InputView::new(cx);
+        let mut cx = VisualTestContext::from_window(input_view.window_handle.into(), cx);
+        let input = input_view.input;
+
+        cx.update(|window, cx| {
+            input.update(cx, |state, cx| {
+                state.set_max_length(Some(5), window, cx);
+                state.replace_text_in_range(None, "Hello world", window, cx);
+                assert_eq!(state.value(), "Hello");
+
+                state.replace_text_in_range(None, "!", window, cx);
+                assert_eq!(state.value(), "Hello");
+
+                // Replacing frees the room of the removed text.
+                state.replace_text_in_range(Some(0..5), "你好👋🏻 world", window, cx);
+                assert_eq!(state.value(), "你好👋🏻 w");
+                assert_eq!(state.char_count(), 5);
+
+                // The value set by the code is not truncated.
+                state.set_value("Hello world again", window, cx);
+                assert_eq!(state.char_count(), 17);
+                assert_eq!(state.word_count(), 3);
+            });
+        });
+    }
+
+    #[gpui::test]
+    fn test_prompt_history(cx: &mut TestAppContext) {
+        let input_view = InputView::new(cx);
+        let mut cx = VisualTestContext::from_window(input_view.window_handle.into(), cx);
+        let input = input_view.input;
+
+        cx.update(|window, cx| {
+            input.update(cx, |state, cx| {
+                state.prompt_history = Some(PromptHistory::new(10));
+                state.push_prompt_history("first", cx);
+                state.push_prompt_history("second", cx);
+                state.push_prompt_history("second", cx);
+
+                state.up(&MoveUp, window, cx);
+                assert_eq!(state.value(), "second");
+                state.up(&MoveUp, window, cx);
+                assert_eq!(state.value(), "first");
+                state.up(&MoveUp, window, cx);
+                assert_eq!(state.value(), "first");
+
+                state.down(&MoveDown, window, cx);
+                assert_eq!(state.value(), "second");
+                state.down(&MoveDown, window, cx);
+                assert_eq!(state.value(), "");
+
+                // Editing the recalled text stops recalling.
+                state.up(&MoveUp, window, cx);
+                state.replace_text_in_range(None, "!", window, cx);
+                state.down(&MoveDown, window, cx);
+                assert_eq!(state.value(), "second!");
+            });
+        });
+    }
+
+    #[gpui::test]
+    fn test_enter_commits_composition(cx: &mut TestAppContext) {
+        let input_view = InputView::new(cx);
+        let mut cx = VisualTestContext::from_window(input_view.window_handle.into(), cx);
+        let input = input_view.input;
+
+        cx.update(|window, cx| {
+            input.update(cx, |state, cx| {
+                state.replace_and_mark_text_in_range(None, "ni", None, window, cx);
+                assert!(state.is_composing());
+
+                state.enter(&Enter { secondary: false }, window, cx);
+                assert!(!state.is_composing());
+                assert_eq!(state.value(), "ni");
+
+                state.enter(&Enter { secondary: false }, window, cx);
+                assert_eq!(state.value(), "ni\n");
+            });
+        });
+    }
+
+    #[gpui::test]
+    fn test_enter_emits_command(cx: &mut TestAppContext) {
+        use crate::input::SlashCommand;
+        use std::cell::RefCell;
+
+        let input_view = InputView::new(cx);
+        let mut cx = VisualTestContext::from_window(input_view.window_handle.into(), cx);
+        let input = input_view.input;
+
+        let events = Rc::new(RefCell::new(vec![]));
+        cx.update(|_, cx| {
+            let events = events.clone();
+            cx.subscribe(&input, move |_, event: &InputEvent, _| match event {
+                InputEvent::Command(command) => events.borrow_mut().push(Some(command.clone())),
+                InputEvent::PressEnter { .. } => events.borrow_mut().push(None),
+                _ => {}
+            })
+            .detach();
+        });
+
+        cx.update(|window, cx| {
+            input.update(cx, |state, cx| {
+                let registry = CommandRegistry::new()
+                    .command(SlashCommand::new("model").arg("name"))
+                    .command(SlashCommand::new("clear"));
+                state.command_registry = Some(Rc::new(registry));
+
+                state.set_value("/model gpt-4o", window, cx);
+                state.enter(&Enter { secondary: false }, window, cx);
+                assert_eq!(state.value(), "/model gpt-4o");
+            });
+        });
+        cx.run_until_parked();
+        assert_eq!(
+            events.borrow_mut().drain(..).collect::<Vec<_>>(),
+            vec![Some(CommandInvocation {
+                name: "model".into(),
+                args: vec!["gpt-4o".into()],
+            })]
+        );
+
+        // Not a registered command.
+        cx.update(|window, cx| {
+            input.update(cx, |state, cx| {
+                state.set_value("/unknown", window, cx);
+                state.enter(&Enter { secondary: false }, window, cx);
+            });
+        });
+        cx.run_until_parked();
+        assert_eq!(
+            events.borrow_mut().drain(..).collect::<Vec<_>>(),
+            vec![None]
+        );
+    }
+
     #[gpui::test]
     fn test_highlighting_preserved_after_fold(cx: &mut TestAppContext) {
         use crate::highlighter::HighlightTheme;
@@ -1,41 +1,67 @@
 use gpui::{
-    Action, AsKeystroke, FocusHandle, Half, IntoElement, KeyContext, Keystroke, ParentElement as _,
-    RenderOnce, StyleRefinement, Styled, Window, div, prelude::FluentBuilder as _, relative,
+    Action, AsKeystroke, FocusHandle, Half, IntoElement, KeyBinding, KeyContext, Keystroke,
+    ParentElement as _, RenderOnce, StyleRefinement, Styled, Window, div,
+    prelude::FluentBuilder as _, relative,
 };
 
 use crate::{ActiveTheme, StyledExt};
 
 /// A tag for displaying keyboard keybindings.
+///
+/// A Kbd can contain a sequence of keystrokes (e.g. `cmd-k cmd-s`), they will be
+/// displayed in platform style separated by a space.
 #[derive(IntoElement, Clone, Debug)]
 pub struct Kbd {
     style: StyleRefinement,
-    stroke: Keystroke,
+    strokes: Vec<Keystroke>,
     appearance: bool,
     outline: bool,
 }
 
 impl From<Keystroke> for Kbd {
     fn from(stroke: Keystroke) -> Self {
-        Self {
-            style: StyleRefinement::default(),
-            stroke,
-            appearance: true,
-            outline: false,
-        }
+        Self::new(stroke)
+    }
+}
+
+impl From<&KeyBinding> for Kbd {
+    fn from(binding: &KeyBinding) -> Self {
+        Self::from_binding(binding)
     }
 }
 
 impl Kbd {
     /// Create a new Kbd element with the given [`Keystroke`].
     pub fn new(stroke: Keystroke) -> Self {
+        Self::with_keystrokes(vec![stroke])
+    }
+
+    /// Create a new Kbd element with a sequence of [`Keystroke`]s.
+    pub fn with_keystrokes(strokes: Vec<Keystroke>) -> Self {
         Self {
             style: StyleRefinement::default(),
-            stroke,
+            strokes,
             appearance: true,
             outline: false,
         }
     }
 
+    /// Create a new Kbd element with all the keystrokes of the given [`KeyBinding`].
+    pub fn from_binding(binding: &KeyBinding) -> Self {
+        Self::with_keystrokes(
+            binding
+                .keystrokes()
+                .iter()
+                .map(|key| key.as_keystroke().clone())
+                .collect(),
+        )
+    }
+
+    /// Return the keystrokes of the Kbd.
+    pub fn keystrokes(&self) -> &[Keystroke] {
+        &self.strokes
+    }
+
     /// Set the appearance of the keybinding, default is `true`.
     pub fn appearance(mut self, appearance: bool) -> Self {
         self.appearance = appearance;
@@ -62,11 +88,11 @@ impl Kbd {
             None => window.highest_precedence_binding_for_action(action),
         }?;
 
-        if let Some(key) = binding.keystrokes().first() {
-            Some(Self::new(key.as_keystroke().clone()))
-        } else {
-            None
+        if binding.keystrokes().is_empty() {
+            return None;
         }
+
+        Some(Self::from_binding(&binding))
     }
 
     /// Return the first keybinding for the given action and focus handle.
@@ -76,11 +102,33 @@ impl Kbd {
         window: &Window,
     ) -> Option<Self> {
         let binding = window.highest_precedence_binding_for_action_in(action, focus_handle)?;
-        if let Some(key) = binding.keystrokes().first() {
-            Some(Self::new(key.as_keystroke().clone()))
-        } else {
-            None
+        if binding.keystrokes().is_empty() {
+            return None;
         }
+
+        Some(Self::from_binding(&binding))
+    }
+
+    /// Return the Platform specific string of a [`KeyBinding`], the keystrokes are separated by a space.
+    ///
+    /// e.g.: `cmd-k cmd-s` will be `⌘K ⌘S` on macOS, and `Win+K Win+S` on others.
+    pub fn format_binding(binding: &KeyBinding) -> String {
+        Self::format_keystrokes(
+            &binding
+                .keystrokes()
+                .iter()
+                .map(|key| key.as_keystroke().clone())
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Return the Platform specific string of a sequence of keystrokes, separated by a space.
+    pub fn format_keystrokes(strokes: &[Keystroke]) -> String {
+        strokes
+            .iter()
+            .map(Self::format)
+            .collect::<Vec<_>>()
+            .join(" ")
     }
 
     /// Return the Platform specific keybinding string by KeyStroke
@@ -217,7 +265,7 @@ impl Styled for Kbd {
 impl RenderOnce for Kbd {
     fn render(self, _: &mut gpui::Window, cx: &mut gpui::App) -> impl gpui::IntoElement {
         if !self.appearance {
-            return Self::format(&self.stroke).into_any_element();
+            return Self::format_keystrokes(&self.strokes).into_any_element();
         }
 
         div()
@@ -238,7 +286,7 @@ impl RenderOnce for Kbd {
             .whitespace_normal()
             .flex_shrink_0()
             .refine_style(&self.style)
-            .child(Self::format(&self.stroke))
+            .child(Self::format_keystrokes(&self.strokes))
             .into_any_element()
     }
 }
@@ -321,4 +369,22 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_format_keystrokes() {
+        use super::Kbd;
+        use gpui::Keystroke;
+
+        let strokes = vec![
+            Keystroke::parse("ctrl-k").unwrap(),
+            Keystroke::parse("ctrl-s").unwrap(),
+        ];
+        if cfg!(target_os = "macos") {
+            assert_eq!(Kbd::format_keystrokes(&strokes), "⌃K ⌃S");
+        } else {
+            assert_eq!(Kbd::format_keystrokes(&strokes), "Ctrl+K Ctrl+S");
+        }
+        assert_eq!(Kbd::format_keystrokes(&[]), "");
+        assert_eq!(Kbd::with_keystrokes(strokes).keystrokes().len(), 2);
+    }
 }
@@ -1,14 +1,35 @@
 use std::ops::Range;
 
 use gpui::{
-    div, prelude::FluentBuilder, rems, App, HighlightStyle, IntoElement, ParentElement, RenderOnce,
-    SharedString, StyleRefinement, Styled, StyledText, Window,
+    App, AppContext as _, ClipboardItem, Context, ElementId, Entity, FocusHandle, HighlightStyle,
+    Hitbox, HitboxBehavior, InteractiveElement as _, IntoElement, KeyBinding, MouseButton,
+    MouseDownEvent, MouseMoveEvent, MouseUpEvent, ParentElement, RenderOnce, SharedString,
+    StyleRefinement, Styled, StyledText, TextLayout, Window, canvas, div, prelude::FluentBuilder,
+    rems,
 };
+use unicode_segmentation::UnicodeSegmentation as _;
 
-use crate::{ActiveTheme, StyledExt};
+use crate::{
+    ActiveTheme, StyledExt, WCAG_AA, check_contrast,
+    input::{Copy, SelectAll},
+};
 
 const MASKED: &'static str = "•";
 
+const CONTEXT: &'static str = "Label";
+pub(crate) fn init(cx: &mut App) {
+    cx.bind_keys(vec![
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-c", Copy, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-c", Copy, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-a", SelectAll, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-a", SelectAll, Some(CONTEXT)),
+    ]);
+}
+
 /// Represents the type of match for highlighting text in a label.
 #[derive(Clone)]
 pub enum HighlightsMatch {
@@ -56,6 +77,102 @@ pub struct Label {
     secondary: Option<SharedString>,
     masked: bool,
     highlights_text: Option<HighlightsMatch>,
+    selectable: Option<ElementId>,
+}
+
+/// The selection state of a selectable [`Label`].
+struct LabelSelection {
+    focus_handle: FocusHandle,
+    text: SharedString,
+    /// The selected byte range of the text.
+    range: Range<usize>,
+    /// The index where the selection started, `Some` while selecting by the mouse.
+    anchor: Option<usize>,
+}
+
+impl LabelSelection {
+    fn new(cx: &mut App) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            text: SharedString::default(),
+            range: 0..0,
+            anchor: None,
+        }
+    }
+
+    fn set_text(&mut self, text: SharedString) {
+        if self.text != text {
+            self.text = text;
+            self.range = 0..0;
+            self.anchor = None;
+        }
+    }
+
+    fn start(&mut self, ix: usize, cx: &mut Context<Self>) {
+        self.anchor = Some(ix);
+        self.range = ix..ix;
+        cx.notify();
+    }
+
+    fn extend_to(&mut self, ix: usize, cx: &mut Context<Self>) {
+        let Some(anchor) = self.anchor else {
+            return;
+        };
+
+        self.range = anchor.min(ix)..anchor.max(ix);
+        cx.notify();
+    }
+
+    fn clear(&mut self, cx: &mut Context<Self>) {
+        if self.range.is_empty() {
+            return;
+        }
+
+        self.range = 0..0;
+        cx.notify();
+    }
+
+    /// Select the word at the `ix`, e.g.: double click on the word.
+    fn select_word(&mut self, ix: usize, cx: &mut Context<Self>) {
+        self.anchor = None;
+        self.range = word_range(&self.text, ix);
+        cx.notify();
+    }
+
+    fn select_all(&mut self, cx: &mut Context<Self>) {
+        self.anchor = None;
+        self.range = 0..self.text.len();
+        cx.notify();
+    }
+
+    fn selected_text(&self) -> &str {
+        self.text.get(self.range.clone()).unwrap_or_default()
+    }
+
+    fn copy(&self, cx: &mut App) {
+        let text = self.selected_text();
+        if text.is_empty() {
+            return;
+        }
+
+        cx.write_to_clipboard(ClipboardItem::new_string(text.to_string()));
+    }
+
+    fn on_action_copy(&mut self, _: &Copy, _: &mut Window, cx: &mut Context<Self>) {
+        self.copy(cx);
+    }
+
+    fn on_action_select_all(&mut self, _: &SelectAll, _: &mut Window, cx: &mut Context<Self>) {
+        self.select_all(cx);
+    }
+}
+
+/// Returns the byte range of the word (or the run of the spaces, punctuations) at the `ix`.
+fn word_range(text: &str, ix: usize) -> Range<usize> {
+    text.split_word_bound_indices()
+        .map(|(start, word)| start..start + word.len())
+        .find(|range| range.contains(&ix))
+        .unwrap_or(text.len()..text.len())
 }
 
 impl Label {
@@ -68,6 +185,7 @@ impl Label {
             secondary: None,
             masked: false,
             highlights_text: None,
+            selectable: None,
         }
     }
 
@@ -90,6 +208,17 @@ impl Label {
         self
     }
 
+    /// Set the label text to be selectable by the mouse (double click to select a word), and copy
+    /// the selected text by `cmd-c`.
+    ///
+    /// The `id` is used to keep the selection state, it must be unique among the siblings.
+    ///
+    /// NOTE: The masked label is not selectable.
+    pub fn selectable(mut self, id: impl Into<ElementId>) -> Self {
+        self.selectable = Some(id.into());
+        self
+    }
+
     fn full_text(&self) -> SharedString {
         match &self.secondary {
             Some(secondary) => format!("{} {}", self.label, secondary).into(),
@@ -192,7 +321,7 @@ impl Styled for Label {
 }
 
 impl RenderOnce for Label {
-    fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
         let mut text = self.full_text();
         let chars_count = text.chars().count();
 
@@ -200,22 +329,204 @@ impl RenderOnce for Label {
             text = SharedString::from(MASKED.repeat(chars_count))
         };
 
-        let highlights = self.measure_highlights(text.len(), cx);
+        let selection = self.selectable.clone().filter(|_| !self.masked).map(|id| {
+            let selection = window.use_keyed_state(id, cx, |_, cx| LabelSelection::new(cx));
+            selection.update(cx, |selection, _| selection.set_text(text.clone()));
+            selection
+        });
+
+        let mut highlights = self.measure_highlights(text.len(), cx);
+        if let Some(selection) = &selection {
+            let range = selection.read(cx).range.clone();
+            if !range.is_empty() {
+                let selected = HighlightStyle {
+                    background_color: Some(cx.theme().selection),
+                    ..Default::default()
+                };
+                highlights = Some(
+                    gpui::combine_highlights(
+                        highlights.unwrap_or_default(),
+                        vec![(range, selected)],
+                    )
+                    .collect(),
+                );
+            }
+        }
+
+        check_contrast(
+            "Label",
+            self.style.text.color.unwrap_or(cx.theme().foreground),
+            cx.theme().background,
+            WCAG_AA,
+            cx,
+        );
+
+        let styled_text =
+            StyledText::new(&text).when_some(highlights, |this, hl| this.with_highlights(hl));
+        let text_layout = styled_text.layout().clone();
 
         div()
             .line_height(rems(1.25))
             .text_color(cx.theme().foreground)
             .refine_style(&self.style)
-            .child(
-                StyledText::new(&text).when_some(highlights, |this, hl| this.with_highlights(hl)),
-            )
+            .child(styled_text)
+            .when_some(selection, |this, selection| {
+                let focus_handle = selection.read(cx).focus_handle.clone();
+
+                this.relative()
+                    .cursor_text()
+                    .key_context(CONTEXT)
+                    .track_focus(&focus_handle)
+                    .on_action(window.listener_for(&selection, LabelSelection::on_action_copy))
+                    .on_action(
+                        window.listener_for(&selection, LabelSelection::on_action_select_all),
+                    )
+                    .child(
+                        canvas(
+                            |bounds, window, _| {
+                                window.insert_hitbox(bounds, HitboxBehavior::Normal)
+                            },
+                            move |_, hitbox, window, cx| {
+                                Self::paint_selection_listeners(
+                                    selection,
+                                    focus_handle,
+                                    text_layout,
+                                    hitbox,
+                                    window,
+                                    cx,
+                                )
+                            },
+                        )
+                        .absolute()
+                        .size_full(),
+                    )
+            })
+    }
+}
+
+impl Label {
+    /// Register the mouse listeners to select the text, the `hitbox` is the bounds of the label.
+    fn paint_selection_listeners(
+        selection: Entity<LabelSelection>,
+        focus_handle: FocusHandle,
+        text_layout: TextLayout,
+        hitbox: Hitbox,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        let is_selecting = selection.read(cx).anchor.is_some();
+
+        window.on_mouse_event({
+            let selection = selection.clone();
+            let text_layout = text_layout.clone();
+            move |event: &MouseDownEvent, phase, window, cx| {
+                if !phase.bubble() || event.button != MouseButton::Left {
+                    return;
+                }
+
+                if !hitbox.is_hovered(window) {
+                    selection.update(cx, |selection, cx| selection.clear(cx));
+                    return;
+                }
+
+                let ix = text_layout
+                    .index_for_position(event.position)
+                    .unwrap_or_else(|ix| ix);
+                focus_handle.focus(window, cx);
+                selection.update(cx, |selection, cx| {
+                    if event.click_count == 2 {
+                        selection.select_word(ix, cx);
+                    } else {
+                        selection.start(ix, cx);
+                    }
+                });
+            }
+        });
+
+        if !is_selecting {
+            return;
+        }
+
+        window.on_mouse_event({
+            let selection = selection.clone();
+            move |event: &MouseMoveEvent, phase, _, cx| {
+                if !phase.bubble() {
+                    return;
+                }
+
+                let ix = text_layout
+                    .index_for_position(event.position)
+                    .unwrap_or_else(|ix| ix);
+                selection.update(cx, |selection, cx| selection.extend_to(ix, cx));
+            }
+        });
+
+        window.on_mouse_event(move |_: &MouseUpEvent, phase, _, cx| {
+            if !phase.bubble() {
+                return;
+            }
+
+            selection.update(cx, |selection, _| selection.anchor = None);
+        });
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use gpui::TestAppContext;
+
     use super::*;
 
+    #[test]
+    fn test_word_range() {
+        let text = "Hello, 世界 world";
+        assert_eq!(word_range(text, 0), 0..5);
+        assert_eq!(word_range(text, 4), 0..5);
+        assert_eq!(word_range(text, 5), 5..6);
+        assert_eq!(&text[word_range(text, 15)], "world");
+        assert_eq!(word_range(text, text.len()), text.len()..text.len());
+    }
+
+    #[gpui::test]
+    fn test_label_selection(cx: &mut TestAppContext) {
+        let selection = cx.new(|cx| LabelSelection::new(cx));
+        selection.update(cx, |selection, cx| {
+            selection.set_text("Order ID: 2025-0001".into());
+            assert_eq!(selection.selected_text(), "");
+
+            // Drag to select.
+            selection.start(6, cx);
+            selection.extend_to(0, cx);
+            assert_eq!(selection.range, 0..6);
+            assert_eq!(selection.selected_text(), "Order ");
+
+            // Double click to select the word.
+            selection.select_word(7, cx);
+            assert_eq!(selection.selected_text(), "ID");
+            assert_eq!(selection.anchor, None);
+            selection.copy(cx);
+
+            selection.select_all(cx);
+            assert_eq!(selection.range, 0..19);
+        });
+        assert_eq!(
+            cx.read_from_clipboard().and_then(|item| item.text()),
+            Some("ID".to_string())
+        );
+
+        selection.update(cx, |selection, cx| {
+            selection.copy(cx);
+
+            // The selection is cleared when the text is changed.
+            selection.set_text("Changed".into());
+            assert_eq!(selection.selected_text(), "");
+        });
+        assert_eq!(
+            cx.read_from_clipboard().and_then(|item| item.text()),
+            Some("Order ID: 2025-0001".to_string())
+        );
+    }
+
     #[test]
     fn test_highlight_ranges() {
         // Basic functionality
@@ -4,6 +4,7 @@ use std::ops::Deref;
 mod async_util;
 mod element_ext;
 mod event;
+mod focus_group;
 mod focus_trap;
 mod geometry;
 pub mod global_state;
@@ -23,6 +24,7 @@ pub(crate) mod actions;
 
 pub mod accordion;
 pub mod alert;
+pub mod animated_image;
 pub mod animation;
 pub mod avatar;
 pub mod badge;
@@ -31,52 +33,75 @@ pub mod button;
 pub mod chart;
 pub mod checkbox;
 pub mod clipboard;
+pub mod code_block;
 pub mod collapsible;
 pub mod color_picker;
+pub mod command_palette;
 pub mod description_list;
 pub mod dialog;
 pub mod divider;
 pub mod dock;
+pub mod drag_drop;
+pub mod empty_state;
+pub mod find;
 pub mod form;
 pub mod group_box;
 pub mod highlighter;
 pub mod history;
 pub mod hover_card;
+pub mod image_viewer;
+pub mod infinite_scroll;
 pub mod input;
 pub mod kbd;
 pub mod label;
+pub mod lightbox;
 pub mod link;
 pub mod list;
 pub mod menu;
 pub mod notification;
+pub mod notification_center;
 pub mod pagination;
 pub mod plot;
 pub mod popover;
+pub mod profiler;
 pub mod progress;
 pub mod radio;
 pub mod rating;
 pub mod resizable;
 pub mod scroll;
 pub mod select;
+pub mod selection;
 pub mod setting;
+pub mod shared_state;
 pub mod sheet;
+pub mod shortcut_cheatsheet;
 pub mod sidebar;
 pub mod skeleton;
 pub mod slider;
 pub mod spinner;
+pub mod status_bar;
 pub mod stepper;
+pub mod svg_image;
 pub mod switch;
+#[cfg(all(feature = "system-notification", not(target_family = "wasm")))]
+pub mod system_notification;
 pub mod tab;
 pub mod table;
 pub mod tag;
+pub mod task_center;
+#[cfg(any(test, feature = "test-support"))]
+pub mod test;
 pub mod text;
 pub mod theme;
+pub mod theme_editor;
 pub mod tooltip;
 pub mod tree;
+pub mod typing_indicator;
 
 pub use crate::Disableable;
 pub use element_ext::*;
 pub use event::InteractiveElementExt;
+pub use focus_group::{FocusGroup, FocusGroupState};
 pub use focus_trap::FocusTrapElement;
 pub use geometry::*;
 pub use global_state::GlobalState;
@@ -87,9 +112,10 @@ pub use input::{Rope, RopeExt, RopeLines};
 #[cfg(any(feature = "inspector", debug_assertions))]
 pub use inspector::*;
 pub use root::Root;
+pub use shared_state::{Broadcast, SharedStateExt};
 pub use styled::*;
 pub use theme::*;
-pub use time::{calendar, date_picker};
+pub use time::{calendar, date_picker, duration_input, time_picker};
 pub use title_bar::*;
 pub use virtual_list::{VirtualList, VirtualListScrollHandle, h_virtual_list, v_virtual_list};
 pub use window_border::{WindowBorder, window_border, window_paddings};
@@ -103,22 +129,33 @@ rust_i18n::i18n!("locales", fallback = "en");
 pub fn init(cx: &mut App) {
     theme::init(cx);
     global_state::init(cx);
+    icon::init(cx);
     #[cfg(any(feature = "inspector", debug_assertions))]
     inspector::init(cx);
     root::init(cx);
     focus_trap::init(cx);
+    focus_group::init(cx);
     color_picker::init(cx);
     date_picker::init(cx);
+    duration_input::init(cx);
     dock::init(cx);
     sheet::init(cx);
     select::init(cx);
+    selection::init(cx);
     input::init(cx);
+    label::init(cx);
+    find::init(cx);
     list::init(cx);
     dialog::init(cx);
     popover::init(cx);
+    profiler::init(cx);
     menu::init(cx);
+    rating::init(cx);
+    image_viewer::init(cx);
+    lightbox::init(cx);
     table::init(cx);
     text::init(cx);
+    time_picker::init(cx);
     tree::init(cx);
     tooltip::init(cx);
 }
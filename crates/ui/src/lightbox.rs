@@ -0,0 +1,217 @@
+use gpui::{
+    App, AppContext as _, Context, Entity, FocusHandle, Focusable, ImageSource,
+    InteractiveElement as _, IntoElement, KeyBinding, ParentElement as _, RenderOnce, Styled,
+    Window, div, prelude::FluentBuilder as _, px, size, transparent_black,
+};
+
+use crate::{
+    ActiveTheme as _, Disableable as _, IconName, Sizable as _, WindowExt as _,
+    actions::{SelectLeft, SelectRight},
+    button::{Button, ButtonVariants as _},
+    dialog::Dialog,
+    h_flex,
+    image_viewer::{ImageViewer, ImageViewerState},
+    window_paddings,
+};
+
+const CONTEXT: &str = "Lightbox";
+pub(crate) fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("left", SelectLeft, Some(CONTEXT)),
+        KeyBinding::new("right", SelectRight, Some(CONTEXT)),
+    ]);
+}
+
+/// State of the [`Lightbox`], to keep the images and the current index.
+pub struct LightboxState {
+    images: Vec<ImageSource>,
+    ix: usize,
+    viewer: Entity<ImageViewerState>,
+}
+
+impl LightboxState {
+    /// Create a new lightbox state with the images, and display the image at `ix` first.
+    pub fn new(
+        images: impl IntoIterator<Item = impl Into<ImageSource>>,
+        ix: usize,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let images: Vec<ImageSource> = images.into_iter().map(Into::into).collect();
+        let ix = ix.min(images.len().saturating_sub(1));
+        let viewer = cx.new(|cx| {
+            let viewer = ImageViewerState::new(window, cx);
+            match images.get(ix) {
+                Some(source) => viewer.source(source.clone()),
+                None => viewer,
+            }
+        });
+
+        Self { images, ix, viewer }
+    }
+
+    /// Returns the images.
+    pub fn images(&self) -> &[ImageSource] {
+        &self.images
+    }
+
+    /// Returns the index of the current image.
+    pub fn index(&self) -> usize {
+        self.ix
+    }
+
+    /// Returns the [`ImageViewerState`] of the current image.
+    pub fn viewer(&self) -> &Entity<ImageViewerState> {
+        &self.viewer
+    }
+
+    /// Display the image at `ix`, this is no effect if the `ix` is out of bounds.
+    pub fn set_index(&mut self, ix: usize, cx: &mut Context<Self>) {
+        let Some(source) = self.images.get(ix).cloned() else {
+            return;
+        };
+
+        self.ix = ix;
+        self.viewer
+            .update(cx, |viewer, cx| viewer.set_source(source, cx));
+        cx.notify();
+    }
+
+    /// Display the previous image.
+    pub fn prev(&mut self, cx: &mut Context<Self>) {
+        if self.ix > 0 {
+            self.set_index(self.ix - 1, cx);
+        }
+    }
+
+    /// Display the next image.
+    pub fn next(&mut self, cx: &mut Context<Self>) {
+        self.set_index(self.ix + 1, cx);
+    }
+}
+
+impl Focusable for LightboxState {
+    fn focus_handle(&self, cx: &App) -> FocusHandle {
+        self.viewer.focus_handle(cx)
+    }
+}
+
+/// A full-window overlay to view the images with zoom, use [`WindowExt::open_lightbox`] to open it.
+///
+/// - Use `left` and `right` keys or the buttons to switch the images.
+/// - Press `escape` to close.
+#[derive(IntoElement)]
+pub struct Lightbox {
+    state: Entity<LightboxState>,
+}
+
+impl Lightbox {
+    /// Create a new Lightbox with the given [`LightboxState`].
+    pub fn new(state: &Entity<LightboxState>) -> Self {
+        Self {
+            state: state.clone(),
+        }
+    }
+
+    /// Make the dialog to cover the whole window, and put the lightbox in it.
+    pub(crate) fn into_dialog(self, dialog: Dialog, window: &mut Window, _: &mut App) -> Dialog {
+        let paddings = window_paddings(window);
+        let view_size = window.viewport_size()
+            - size(
+                paddings.left + paddings.right,
+                paddings.top + paddings.bottom,
+            );
+
+        dialog
+            .close_button(false)
+            .overlay_closable(false)
+            .margin_top(px(0.))
+            .w(view_size.width)
+            .h(view_size.height)
+            .p_0()
+            .border_0()
+            .rounded_none()
+            .bg(transparent_black())
+            .child(self)
+    }
+}
+
+impl RenderOnce for Lightbox {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let state = self.state.read(cx);
+        let viewer = state.viewer.clone();
+        let ix = state.ix;
+        let count = state.images.len();
+
+        div()
+            .id("lightbox")
+            .key_context(CONTEXT)
+            .on_action(
+                window.listener_for(&self.state, |state, _: &SelectLeft, _, cx| {
+                    state.prev(cx);
+                }),
+            )
+            .on_action(
+                window.listener_for(&self.state, |state, _: &SelectRight, _, cx| {
+                    state.next(cx);
+                }),
+            )
+            .relative()
+            .size_full()
+            .bg(cx.theme().background.opacity(0.95))
+            .child(
+                ImageViewer::new(&viewer)
+                    .size_full()
+                    .bg(transparent_black()),
+            )
+            .child(
+                h_flex()
+                    .absolute()
+                    .top_0()
+                    .left_0()
+                    .right_0()
+                    .p_3()
+                    .justify_between()
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(cx.theme().muted_foreground)
+                            .when(count > 1, |this| {
+                                this.child(format!("{} / {}", ix + 1, count))
+                            }),
+                    )
+                    .child(
+                        Button::new("close")
+                            .ghost()
+                            .icon(IconName::Close)
+                            .on_click(|_, window, cx| window.close_dialog(cx)),
+                    ),
+            )
+            .when(count > 1, |this| {
+                this.child(
+                    h_flex().absolute().top_0().bottom_0().left_3().child(
+                        Button::new("prev")
+                            .outline()
+                            .large()
+                            .icon(IconName::ChevronLeft)
+                            .disabled(ix == 0)
+                            .on_click(window.listener_for(&self.state, |state, _, _, cx| {
+                                state.prev(cx);
+                            })),
+                    ),
+                )
+                .child(
+                    h_flex().absolute().top_0().bottom_0().right_3().child(
+                        Button::new("next")
+                            .outline()
+                            .large()
+                            .icon(IconName::ChevronRight)
+                            .disabled(ix + 1 >= count)
+                            .on_click(window.listener_for(&self.state, |state, _, _, cx| {
+                                state.next(cx);
+                            })),
+                    ),
+                )
+            })
+    }
+}
@@ -132,6 +132,26 @@ pub trait ListDelegate: Sized + 'static {
     ) {
     }
 
+    /// Return true to allow the item at the given index to be reordered by drag and drop.
+    ///
+    /// Default: false
+    fn can_move_item(&self, ix: IndexPath, cx: &App) -> bool {
+        false
+    }
+
+    /// Move the item `from` index to `to` index, this is called when an item is dropped
+    /// on another item in the same section.
+    ///
+    /// See also [`move_item`](crate::drag_drop::move_item) to reorder the items in a `Vec`.
+    fn move_item(
+        &mut self,
+        from: IndexPath,
+        to: IndexPath,
+        window: &mut Window,
+        cx: &mut Context<ListState<Self>>,
+    ) {
+    }
+
     /// Set the confirm and give the selected index,
     /// this is means user have clicked the item or pressed Enter.
     ///
@@ -2,10 +2,12 @@ use std::ops::Range;
 use instant::Duration;
 
 use crate::actions::{Cancel, Confirm, SelectDown, SelectUp};
+use crate::drag_drop::{DragItem, ReorderEvent, SortableExt as _, moved_index};
 use crate::input::InputState;
 use crate::list::cache::{MeasuredEntrySize, RowEntry, RowsCache};
 use crate::{
     ActiveTheme, IconName, Size,
+    animation::UiTestMode,
     input::{Input, InputEvent},
     scroll::Scrollbar,
     v_flex,
@@ -13,7 +15,7 @@ use crate::{
 use crate::{Icon, IndexPath, Selectable, Sizable, StyledExt};
 use crate::{VirtualListScrollHandle, list::ListDelegate, v_virtual_list};
 use gpui::{
-    App, AvailableSpace, ClickEvent, Context, DefiniteLength, EdgesRefinement, EventEmitter,
+    App, AvailableSpace, Axis, ClickEvent, Context, DefiniteLength, EdgesRefinement, EventEmitter,
     ListSizingBehavior, RenderOnce, ScrollStrategy, SharedString, StatefulInteractiveElement,
     StyleRefinement, Subscription, px, size,
 };
@@ -211,6 +213,29 @@ where
         self.mouse_right_clicked_index
     }
 
+    /// Move the item `from` index to `to` index in the same section, the selected index
+    /// is updated to keep the selection.
+    pub fn move_item(
+        &mut self,
+        from: IndexPath,
+        to: IndexPath,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if from.section != to.section || from.row == to.row {
+            return;
+        }
+
+        self.delegate.move_item(from, to, window, cx);
+        if let Some(selected) = self.selected_index
+            && selected.section == from.section
+        {
+            let row = moved_index(selected.row, from.row, to.row);
+            self.set_selected_index(Some(selected.row(row)), window, cx);
+        }
+        cx.notify();
+    }
+
     /// Set the query text of the search input, this will trigger a search.
     pub fn set_query(&mut self, query: &str, window: &mut Window, cx: &mut Context<Self>) {
         let query = query.to_string();
@@ -286,6 +311,7 @@ where
                     self._set_selected_index(None, window, cx);
                 }
 
+                let flicker_delay = UiTestMode::delay(Duration::from_millis(100), cx);
                 self._search_task = cx.spawn_in(window, async move |this, window| {
                     search.await;
 
@@ -295,7 +321,7 @@ where
                     });
 
                     // Always wait 100ms to avoid flicker
-                    window.background_executor().timer(Duration::from_millis(100)).await;
+                    window.background_executor().timer(flicker_delay).await;
                     _ = this.update_in(window, |this, window, cx| {
                         this.set_searching(false, window, cx);
                     });
@@ -469,6 +495,15 @@ where
                 item.selected(selected)
                     .secondary_selected(mouse_right_clicked)
             }))
+            .when(self.delegate.can_move_item(ix, cx), |this| {
+                this.sortable(
+                    DragItem::new(format!("list-{}-{}", cx.entity_id(), ix.section), ix.row),
+                    Axis::Vertical,
+                    cx.listener(move |this, e: &ReorderEvent, window, cx| {
+                        this.move_item(ix.row(e.from), ix.row(e.to), window, cx);
+                    }),
+                )
+            })
             .when(selectable, |this| {
                 this.on_click(cx.listener(move |this, e: &ClickEvent, window, cx| {
                     this.set_right_clicked_index(None, window, cx);
@@ -503,9 +538,11 @@ where
         let scroll_handle = self.scroll_handle.clone();
 
         v_flex()
+            .id("list-items")
             .flex_grow()
             .relative()
             .size_full()
+            .auto_scroll_on_drag(scroll_handle.base_handle(), Axis::Vertical)
             .when_some(self.options.max_height, |this, h| this.max_h(h))
             .overflow_hidden()
             .when(items_count == 0, |this| {
@@ -744,6 +781,7 @@ where
         self.options.max_height = self.style.max_size.height;
         self.style.padding = EdgesRefinement::default();
         self.style.max_size.height = None;
+        self.options.size = self.options.size.with_density(cx.theme().density);
 
         self.state.update(cx, |state, _| {
             state.options = self.options;
@@ -176,9 +176,13 @@ impl RenderOnce for ListItem {
         self.base
             .relative()
             .gap_x_1()
-            .py_1()
-            .px_3()
-            .text_base()
+            .map(|this| {
+                if cx.theme().density.is_compact() {
+                    this.py_0p5().px_2().text_sm()
+                } else {
+                    this.py_1().px_3().text_base()
+                }
+            })
             .text_color(cx.theme().foreground)
             .relative()
             .items_center()
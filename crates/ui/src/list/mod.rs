@@ -4,6 +4,7 @@ mod list;
 mod list_item;
 mod loading;
 mod separator_item;
+mod swipe_row;
 
 pub use delegate::*;
 pub use list::*;
@@ -11,6 +12,7 @@ pub use list_item::*;
 use schemars::JsonSchema;
 pub use separator_item::*;
 use serde::{Deserialize, Serialize};
+pub use swipe_row::*;
 
 /// Settings for List.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
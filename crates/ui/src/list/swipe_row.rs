@@ -0,0 +1,363 @@
+use std::time::Duration;
+
+use gpui::{
+    AnyElement, App, Div, ElementId, InteractiveElement as _, IntoElement, MouseButton,
+    MouseDownEvent, MouseMoveEvent, MouseUpEvent, ParentElement, Pixels, RenderOnce,
+    ScrollWheelEvent, StyleRefinement, Styled, Task, TouchPhase, Window, div,
+    prelude::FluentBuilder as _, px,
+};
+
+use crate::{
+    ActiveTheme as _, StyledExt as _,
+    animation::UiTestMode,
+    button::{Button, ButtonRounded},
+    h_flex,
+};
+
+/// The width of each swipe action.
+const ACTION_WIDTH: Pixels = px(72.);
+/// The distance to move the mouse before starting to swipe, to keep the click working.
+const DRAG_THRESHOLD: Pixels = px(8.);
+/// The delay to snap the row after the last scroll wheel event.
+const WHEEL_SNAP_DELAY: Duration = Duration::from_millis(150);
+
+#[derive(Default)]
+struct SwipeRowState {
+    /// The offset of the content, positive to reveal the leading actions,
+    /// negative to reveal the trailing actions.
+    offset: Pixels,
+    /// The mouse position and the offset when the mouse down.
+    drag_start: Option<(Pixels, Pixels)>,
+    swiping: bool,
+    /// Whether to stop the click of the content on the mouse up, after swiping or closing.
+    stop_click: bool,
+    _snap_task: Option<Task<()>>,
+}
+
+/// A row with the leading and trailing actions revealed by swiping horizontally,
+/// e.g.: the delete or archive buttons of a session in a virtual list.
+///
+/// - Swipe on the trackpad, or drag the row by the mouse to reveal the actions.
+/// - The row snaps to open when swiped more than half of the actions, otherwise snaps back.
+/// - Click the row or any action to close it.
+///
+/// ```ignore
+/// SwipeRow::new(("session", ix))
+///     .trailing_action(Button::new("delete").danger().icon(IconName::Delete).on_click(...))
+///     .leading_action(Button::new("archive").primary().label("Archive").on_click(...))
+///     .child(ListItem::new(ix).child(session.title.clone()))
+/// ```
+#[derive(IntoElement)]
+pub struct SwipeRow {
+    id: ElementId,
+    base: Div,
+    style: StyleRefinement,
+    leading_actions: Vec<Button>,
+    trailing_actions: Vec<Button>,
+}
+
+impl SwipeRow {
+    /// Create a new swipe row, the `id` is used to keep the swipe state,
+    /// so it must be unique in the list, e.g. the item index.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: div(),
+            style: StyleRefinement::default(),
+            leading_actions: vec![],
+            trailing_actions: vec![],
+        }
+    }
+
+    /// Add an action to the leading side, revealed by swiping right.
+    pub fn leading_action(mut self, action: Button) -> Self {
+        self.leading_actions.push(action);
+        self
+    }
+
+    /// Add an action to the trailing side, revealed by swiping left.
+    pub fn trailing_action(mut self, action: Button) -> Self {
+        self.trailing_actions.push(action);
+        self
+    }
+}
+
+impl ParentElement for SwipeRow {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.base.extend(elements);
+    }
+}
+
+impl Styled for SwipeRow {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+/// Clamp the `offset` to the width of the leading and trailing actions.
+fn clamp_offset(offset: Pixels, leading_width: Pixels, trailing_width: Pixels) -> Pixels {
+    offset.clamp(-trailing_width, leading_width)
+}
+
+/// Snap the `offset` to open the actions if swiped more than half, otherwise close.
+fn snap_offset(offset: Pixels, leading_width: Pixels, trailing_width: Pixels) -> Pixels {
+    if offset > px(0.) && offset > leading_width / 2. {
+        leading_width
+    } else if offset < px(0.) && -offset > trailing_width / 2. {
+        -trailing_width
+    } else {
+        px(0.)
+    }
+}
+
+impl RenderOnce for SwipeRow {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let leading_width = ACTION_WIDTH * self.leading_actions.len() as f32;
+        let trailing_width = ACTION_WIDTH * self.trailing_actions.len() as f32;
+        let state = window.use_keyed_state(self.id.clone(), cx, |_, _| SwipeRowState::default());
+        let offset = clamp_offset(state.read(cx).offset, leading_width, trailing_width);
+        let is_open = offset != px(0.);
+
+        let render_actions = |actions: Vec<Button>| {
+            h_flex()
+                .absolute()
+                .top_0()
+                .bottom_0()
+                .children(
+                    actions
+                        .into_iter()
+                        .map(|action| action.rounded(ButtonRounded::None).w(ACTION_WIDTH).h_full()),
+                )
+                // Close the row after the action is clicked.
+                .on_mouse_up(MouseButton::Left, {
+                    let state = state.clone();
+                    move |_, window, cx| {
+                        state.update(cx, |state, _| state.offset = px(0.));
+                        window.refresh();
+                    }
+                })
+        };
+
+        div()
+            .id(self.id)
+            .relative()
+            .w_full()
+            .overflow_hidden()
+            .refine_style(&self.style)
+            .when(offset > px(0.), |this| {
+                this.child(render_actions(self.leading_actions).left_0())
+            })
+            .when(offset < px(0.), |this| {
+                this.child(render_actions(self.trailing_actions).right_0())
+            })
+            .child(
+                self.base
+                    .relative()
+                    .w_full()
+                    .left(offset)
+                    .bg(cx.theme().list)
+                    .on_scroll_wheel(window.listener_for(
+                        &state,
+                        move |state, event: &ScrollWheelEvent, window, cx| {
+                            // The ended event of the trackpad gesture has no delta.
+                            if event.touch_phase == TouchPhase::Ended {
+                                state._snap_task = None;
+                                state.offset =
+                                    snap_offset(state.offset, leading_width, trailing_width);
+                                cx.notify();
+                                return;
+                            }
+
+                            let delta = event.delta.pixel_delta(window.line_height());
+                            if delta.x.abs() <= delta.y.abs() {
+                                return;
+                            }
+
+                            state.offset =
+                                clamp_offset(state.offset + delta.x, leading_width, trailing_width);
+                            cx.stop_propagation();
+                            cx.notify();
+
+                            // The mouse wheel has no ended phase, snap the row after the
+                            // scrolling stops for a moment.
+                            let delay = UiTestMode::delay(WHEEL_SNAP_DELAY, cx);
+                            state._snap_task = Some(cx.spawn(async move |state, cx| {
+                                cx.background_executor().timer(delay).await;
+                                _ = state.update(cx, |state, cx| {
+                                    state.offset =
+                                        snap_offset(state.offset, leading_width, trailing_width);
+                                    cx.notify();
+                                });
+                            }));
+                        },
+                    ))
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        window.listener_for(&state, move |state, event: &MouseDownEvent, _, cx| {
+                            state.drag_start = Some((event.position.x, state.offset));
+                            // Click to close the opened row, without clicking the content.
+                            if is_open {
+                                state.offset = px(0.);
+                                state.drag_start = None;
+                                state.stop_click = true;
+                                cx.stop_propagation();
+                                cx.notify();
+                            }
+                        }),
+                    )
+                    .on_mouse_move(window.listener_for(
+                        &state,
+                        move |state, event: &MouseMoveEvent, _, cx| {
+                            let Some((start_x, start_offset)) = state.drag_start else {
+                                return;
+                            };
+                            if !event.dragging() {
+                                state.drag_start = None;
+                                state.swiping = false;
+                                return;
+                            }
+
+                            let delta = event.position.x - start_x;
+                            if !state.swiping && delta.abs() < DRAG_THRESHOLD {
+                                return;
+                            }
+
+                            state.swiping = true;
+                            state.offset =
+                                clamp_offset(start_offset + delta, leading_width, trailing_width);
+                            cx.stop_propagation();
+                            cx.notify();
+                        },
+                    ))
+                    // In the capture phase, to stop the click of the content after swiping.
+                    .capture_any_mouse_up(window.listener_for(
+                        &state,
+                        move |state, event: &MouseUpEvent, _, cx| {
+                            if event.button != MouseButton::Left {
+                                return;
+                            }
+
+                            state.drag_start = None;
+                            if state.swiping {
+                                state.swiping = false;
+                                state.offset =
+                                    snap_offset(state.offset, leading_width, trailing_width);
+                                state.stop_click = true;
+                                cx.notify();
+                            }
+                            if state.stop_click {
+                                state.stop_click = false;
+                                cx.stop_propagation();
+                            }
+                        },
+                    ))
+                    .on_mouse_up_out(
+                        MouseButton::Left,
+                        window.listener_for(&state, move |state, _: &MouseUpEvent, _, cx| {
+                            state.drag_start = None;
+                            state.stop_click = false;
+                            if state.swiping {
+                                state.swiping = false;
+                                state.offset =
+                                    snap_offset(state.offset, leading_width, trailing_width);
+                                cx.notify();
+                            }
+                        }),
+                    ),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use gpui::{
+        Context, InteractiveElement as _, IntoElement, Modifiers, ParentElement as _, Render,
+        ScrollDelta, ScrollWheelEvent, StatefulInteractiveElement as _, Styled as _,
+        TestAppContext, TouchPhase, Window, div, point, px,
+    };
+
+    use super::{SwipeRow, clamp_offset, snap_offset};
+    use crate::{button::Button, test::TestWindow};
+
+    struct Row {
+        clicks: Rc<Cell<usize>>,
+    }
+
+    impl Render for Row {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            let clicks = self.clicks.clone();
+            SwipeRow::new("row")
+                .trailing_action(Button::new("delete").label("Delete"))
+                .child(
+                    div()
+                        .id("content")
+                        .debug_selector(|| "content".into())
+                        .w_full()
+                        .h(px(40.))
+                        .on_click(move |_, _, _| clicks.set(clicks.get() + 1)),
+                )
+        }
+    }
+
+    #[test]
+    fn test_clamp_offset() {
+        assert_eq!(clamp_offset(px(100.), px(72.), px(144.)), px(72.));
+        assert_eq!(clamp_offset(px(-200.), px(72.), px(144.)), px(-144.));
+        assert_eq!(clamp_offset(px(-20.), px(72.), px(144.)), px(-20.));
+        assert_eq!(clamp_offset(px(20.), px(0.), px(144.)), px(0.));
+    }
+
+    #[test]
+    fn test_snap_offset() {
+        assert_eq!(snap_offset(px(40.), px(72.), px(144.)), px(72.));
+        assert_eq!(snap_offset(px(30.), px(72.), px(144.)), px(0.));
+        assert_eq!(snap_offset(px(-80.), px(72.), px(144.)), px(-144.));
+        assert_eq!(snap_offset(px(-70.), px(72.), px(144.)), px(0.));
+        assert_eq!(snap_offset(px(0.), px(0.), px(0.)), px(0.));
+    }
+
+    #[gpui::test]
+    fn test_swipe_stops_click(cx: &mut TestAppContext) {
+        let clicks = Rc::new(Cell::new(0));
+        let mut window = TestWindow::new(cx, {
+            let clicks = clicks.clone();
+            move |_, _| Row { clicks }
+        });
+
+        window.click_on("content");
+        assert_eq!(clicks.get(), 1);
+
+        let bounds = window.cx().debug_bounds("content").unwrap();
+        window.drag(bounds.center(), bounds.center() - point(px(100.), px(0.)));
+        assert_eq!(clicks.get(), 1);
+        let swiped = window.cx().debug_bounds("content").unwrap();
+        assert_eq!(swiped.origin.x, bounds.origin.x - px(72.));
+
+        // Click to close the opened row, without clicking the content.
+        window.click_on("content");
+        assert_eq!(clicks.get(), 1);
+        let closed = window.cx().debug_bounds("content").unwrap();
+        assert_eq!(closed.origin.x, bounds.origin.x);
+    }
+
+    #[gpui::test]
+    fn test_wheel_snap(cx: &mut TestAppContext) {
+        let clicks = Rc::new(Cell::new(0));
+        let mut window = TestWindow::new(cx, move |_, _| Row { clicks });
+
+        let bounds = window.cx().debug_bounds("content").unwrap();
+        // The mouse wheel has no ended phase.
+        window.cx().simulate_event(ScrollWheelEvent {
+            position: bounds.center(),
+            delta: ScrollDelta::Pixels(point(px(-60.), px(0.))),
+            modifiers: Modifiers::default(),
+            touch_phase: TouchPhase::Moved,
+        });
+        window.run_until_parked();
+
+        let snapped = window.cx().debug_bounds("content").unwrap();
+        assert_eq!(snapped.origin.x, bounds.origin.x - px(72.));
+    }
+}
@@ -14,12 +14,12 @@ use gpui::{
 
 use crate::{
     ActiveTheme as _, Edges, Icon, IconName, Sizable as _, StyledExt, TITLE_BAR_HEIGHT,
-    animation::cubic_bezier,
+    animation::{UiTestMode, cubic_bezier, motion_duration},
     button::{Button, ButtonVariants as _},
     h_flex, v_flex,
 };
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum NotificationType {
     #[default]
     Info,
@@ -29,7 +29,7 @@ pub enum NotificationType {
 }
 
 impl NotificationType {
-    fn icon(&self, cx: &App) -> Icon {
+    pub(crate) fn icon(&self, cx: &App) -> Icon {
         match self {
             Self::Info => Icon::new(IconName::Info).text_color(cx.theme().info),
             Self::Success => Icon::new(IconName::CircleCheck).text_color(cx.theme().success),
@@ -255,10 +255,9 @@ impl Notification {
 
         let on_close = self.on_close.clone();
         // Dismiss the notification after 0.15s to show the animation.
+        let delay = UiTestMode::delay(Duration::from_secs_f32(0.15), cx);
         cx.spawn_in(window, async move |view, cx| {
-            cx.background_executor()
-                .timer(Duration::from_secs_f32(0.15))
-                .await;
+            cx.background_executor().timer(delay).await;
             _ = view.update_in(cx, |view, _, cx| {
                 view.closing = false;
                 cx.emit(DismissEvent);
@@ -367,7 +366,7 @@ impl Render for Notification {
             }))
             .with_animation(
                 ElementId::NamedInteger("slide-down".into(), closing as u64),
-                Animation::new(Duration::from_secs_f64(0.25))
+                Animation::new(motion_duration(Duration::from_secs_f64(0.25), cx))
                     .with_easing(cubic_bezier(0.4, 0., 0.2, 1.)),
                 move |this, delta| {
                     if closing {
@@ -442,10 +441,67 @@ impl Default for NotificationSettings {
     }
 }
 
+/// The maximum number of records to keep in the notification history.
+const MAX_HISTORY: usize = 100;
+
+/// A record of a pushed notification, kept in the history of the [`NotificationList`].
+#[derive(Debug, Clone)]
+pub struct NotificationRecord {
+    id: NotificationId,
+    type_: NotificationType,
+    title: Option<SharedString>,
+    message: Option<SharedString>,
+    time: chrono::DateTime<chrono::Local>,
+    read: bool,
+}
+
+impl NotificationRecord {
+    fn new(notification: &Notification) -> Self {
+        Self {
+            id: notification.id.clone(),
+            type_: notification.type_.unwrap_or_default(),
+            title: notification.title.clone(),
+            message: notification.message.clone(),
+            time: chrono::Local::now(),
+            read: false,
+        }
+    }
+
+    /// Returns the type of the notification.
+    pub fn notification_type(&self) -> NotificationType {
+        self.type_
+    }
+
+    /// Returns the title of the notification.
+    pub fn title(&self) -> Option<&SharedString> {
+        self.title.as_ref()
+    }
+
+    /// Returns the message of the notification.
+    pub fn message(&self) -> Option<&SharedString> {
+        self.message.as_ref()
+    }
+
+    /// Returns the time when the notification was pushed.
+    pub fn time(&self) -> chrono::DateTime<chrono::Local> {
+        self.time
+    }
+
+    /// Returns true if the notification has been marked as read.
+    pub fn is_read(&self) -> bool {
+        self.read
+    }
+}
+
 /// A list of notifications.
+///
+/// All the pushed notifications with a title or message are kept in the history,
+/// and the toasts are not shown when do-not-disturb is enabled.
 pub struct NotificationList {
     /// Notifications that will be auto hidden.
     pub(crate) notifications: VecDeque<Entity<Notification>>,
+    history: VecDeque<NotificationRecord>,
+    do_not_disturb: bool,
     expanded: bool,
     _subscriptions: HashMap<NotificationId, Subscription>,
 }
@@ -454,6 +510,8 @@ impl NotificationList {
     pub fn new(_window: &mut Window, _cx: &mut Context<Self>) -> Self {
         Self {
             notifications: VecDeque::new(),
+            history: VecDeque::new(),
+            do_not_disturb: false,
             expanded: false,
             _subscriptions: HashMap::new(),
         }
@@ -469,6 +527,18 @@ impl NotificationList {
         let id = notification.id.clone();
         let autohide = notification.autohide;
 
+        if notification.title.is_some() || notification.message.is_some() {
+            // Replace the record of the same id, e.g.: the progress updates of a task.
+            self.history.retain(|record| record.id != id);
+            self.history
+                .push_front(NotificationRecord::new(&notification));
+            self.history.truncate(MAX_HISTORY);
+        }
+        if self.do_not_disturb {
+            cx.notify();
+            return;
+        }
+
         // Remove the notification by id, for keep unique.
         self.notifications.retain(|note| note.read(cx).id != id);
 
@@ -520,6 +590,54 @@ impl NotificationList {
     pub fn notifications(&self) -> Vec<Entity<Notification>> {
         self.notifications.iter().cloned().collect()
     }
+
+    /// Returns the history of the notifications, the newest first.
+    pub fn history(&self) -> &VecDeque<NotificationRecord> {
+        &self.history
+    }
+
+    /// Returns the number of the unread notifications in the history.
+    pub fn unread_count(&self) -> usize {
+        self.history.iter().filter(|record| !record.read).count()
+    }
+
+    /// Mark the notification at the `ix` of the history as read.
+    pub fn mark_read(&mut self, ix: usize, cx: &mut Context<Self>) {
+        if let Some(record) = self.history.get_mut(ix) {
+            record.read = true;
+            cx.notify();
+        }
+    }
+
+    /// Mark all the notifications in the history as read.
+    pub fn mark_all_read(&mut self, cx: &mut Context<Self>) {
+        for record in self.history.iter_mut() {
+            record.read = true;
+        }
+        cx.notify();
+    }
+
+    /// Clear the history of the notifications.
+    pub fn clear_history(&mut self, cx: &mut Context<Self>) {
+        self.history.clear();
+        cx.notify();
+    }
+
+    /// Returns true if do-not-disturb is enabled.
+    pub fn do_not_disturb(&self) -> bool {
+        self.do_not_disturb
+    }
+
+    /// Set do-not-disturb, the notifications are only kept in the history when enabled.
+    ///
+    /// The shown notifications are hidden when it is enabled.
+    pub fn set_do_not_disturb(&mut self, do_not_disturb: bool, cx: &mut Context<Self>) {
+        self.do_not_disturb = do_not_disturb;
+        if do_not_disturb {
+            self.notifications.clear();
+        }
+        cx.notify();
+    }
 }
 
 impl Render for NotificationList {
@@ -566,3 +684,56 @@ impl Render for NotificationList {
             .children(items)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gpui::TestAppContext;
+
+    use super::{Notification, NotificationList};
+
+    #[gpui::test]
+    fn test_notification_history(cx: &mut TestAppContext) {
+        let window = cx.add_window(|window, cx| NotificationList::new(window, cx));
+
+        window
+            .update(cx, |list, window, cx| {
+                list.push(Notification::info("Hello"), window, cx);
+                list.push(Notification::error("Failed").title("Export"), window, cx);
+                assert_eq!(list.notifications().len(), 2);
+                assert_eq!(list.unread_count(), 2);
+                assert_eq!(
+                    list.history()[0].title().map(|title| title.as_ref()),
+                    Some("Export")
+                );
+
+                list.mark_read(0, cx);
+                assert_eq!(list.unread_count(), 1);
+
+                list.set_do_not_disturb(true, cx);
+                assert!(list.notifications().is_empty());
+                list.push(Notification::info("Quiet"), window, cx);
+                assert!(list.notifications().is_empty());
+                assert_eq!(list.history().len(), 3);
+
+                // The notification with the same id replaces the record.
+                struct Export;
+                list.push(Notification::info("Exporting").id::<Export>(), window, cx);
+                list.push(Notification::success("Exported").id::<Export>(), window, cx);
+                assert_eq!(list.history().len(), 4);
+                assert_eq!(
+                    list.history()[0].message().map(|message| message.as_ref()),
+                    Some("Exported")
+                );
+                assert_eq!(
+                    list.history()[1].message().map(|message| message.as_ref()),
+                    Some("Quiet")
+                );
+
+                list.mark_all_read(cx);
+                assert_eq!(list.unread_count(), 0);
+                list.clear_history(cx);
+                assert!(list.history().is_empty());
+            })
+            .unwrap();
+    }
+}
@@ -0,0 +1,179 @@
+//! A panel to browse the history of the notifications and toggle do-not-disturb,
+//! e.g.: opened from the [`StatusBar`](crate::status_bar::StatusBar).
+use gpui::{
+    App, Entity, InteractiveElement as _, IntoElement, ParentElement as _, RenderOnce,
+    StatefulInteractiveElement as _, StyleRefinement, Styled, Window, div,
+    prelude::FluentBuilder as _,
+};
+use rust_i18n::t;
+
+use crate::{
+    ActiveTheme as _, Disableable as _, IconName, Root, Sizable as _, StyledExt as _,
+    button::{Button, ButtonVariants as _},
+    h_flex,
+    notification::NotificationList,
+    scroll::ScrollableElement as _,
+    status_bar::StatusBarItem,
+    switch::Switch,
+    v_flex,
+};
+
+/// A notification center that lists the history of the notifications pushed to the window,
+/// the notifications can be marked as read or cleared, and do-not-disturb can be toggled.
+///
+/// ```ignore
+/// StatusBar::new().right(NotificationCenter::status_item(window, cx))
+/// ```
+#[derive(IntoElement)]
+pub struct NotificationCenter {
+    list: Entity<NotificationList>,
+    style: StyleRefinement,
+}
+
+impl NotificationCenter {
+    /// Create a notification center of the notifications of the window.
+    pub fn new(window: &Window, cx: &App) -> Self {
+        Self {
+            list: Root::read(window, cx).notification.clone(),
+            style: StyleRefinement::default(),
+        }
+    }
+
+    /// Returns a [`StatusBarItem`] to show the unread count and open the notification center.
+    pub fn status_item(window: &Window, cx: &App) -> StatusBarItem {
+        let list = Root::read(window, cx).notification.read(cx);
+        let unread = list.unread_count();
+
+        StatusBarItem::new("notification-center")
+            .icon(if list.do_not_disturb() {
+                IconName::Moon
+            } else {
+                IconName::Bell
+            })
+            .tooltip(t!("NotificationCenter.title"))
+            .when(unread > 0, |this| this.label(unread.to_string()))
+            .popover(|_, window, cx| NotificationCenter::new(window, cx).w_80())
+    }
+}
+
+impl Styled for NotificationCenter {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl RenderOnce for NotificationCenter {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let list = self.list.read(cx);
+        let do_not_disturb = list.do_not_disturb();
+        let is_empty = list.history().is_empty();
+
+        v_flex()
+            .gap_3()
+            .text_sm()
+            .refine_style(&self.style)
+            .child(
+                h_flex()
+                    .gap_2()
+                    .justify_between()
+                    .child(div().font_semibold().child(t!("NotificationCenter.title")))
+                    .child(
+                        h_flex()
+                            .gap_1()
+                            .child(
+                                Button::new("mark-all-read")
+                                    .ghost()
+                                    .xsmall()
+                                    .icon(IconName::Check)
+                                    .tooltip(t!("NotificationCenter.mark_all_read"))
+                                    .disabled(list.unread_count() == 0)
+                                    .on_click(window.listener_for(&self.list, |list, _, _, cx| {
+                                        list.mark_all_read(cx)
+                                    })),
+                            )
+                            .child(
+                                Button::new("clear-all")
+                                    .ghost()
+                                    .xsmall()
+                                    .icon(IconName::Delete)
+                                    .tooltip(t!("NotificationCenter.clear_all"))
+                                    .disabled(is_empty)
+                                    .on_click(window.listener_for(&self.list, |list, _, _, cx| {
+                                        list.clear_history(cx)
+                                    })),
+                            ),
+                    ),
+            )
+            .child(
+                Switch::new("do-not-disturb")
+                    .small()
+                    .checked(do_not_disturb)
+                    .label(t!("NotificationCenter.do_not_disturb").to_string())
+                    .on_click(
+                        window.listener_for(&self.list, |list, checked: &bool, _, cx| {
+                            list.set_do_not_disturb(*checked, cx)
+                        }),
+                    ),
+            )
+            .when(is_empty, |this| {
+                this.child(
+                    div()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(t!("NotificationCenter.empty")),
+                )
+            })
+            .when(!is_empty, |this| {
+                this.child(
+                    v_flex()
+                        .max_h_96()
+                        .gap_1()
+                        .children(list.history().iter().enumerate().map(|(ix, record)| {
+                            h_flex()
+                                .id(ix)
+                                .gap_2()
+                                .items_start()
+                                .p_2()
+                                .rounded(cx.theme().radius)
+                                .cursor_pointer()
+                                .hover(|this| this.bg(cx.theme().accent))
+                                .child(record.notification_type().icon(cx).mt_0p5())
+                                .child(
+                                    v_flex()
+                                        .flex_1()
+                                        .overflow_hidden()
+                                        .when_some(record.title().cloned(), |this, title| {
+                                            this.child(div().font_semibold().child(title))
+                                        })
+                                        .when_some(record.message().cloned(), |this, message| {
+                                            this.child(
+                                                div()
+                                                    .text_color(cx.theme().muted_foreground)
+                                                    .child(message),
+                                            )
+                                        }),
+                                )
+                                .child(
+                                    v_flex()
+                                        .items_end()
+                                        .gap_1()
+                                        .text_xs()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child(record.time().format("%H:%M").to_string())
+                                        .when(!record.is_read(), |this| {
+                                            this.child(
+                                                div()
+                                                    .size_2()
+                                                    .rounded_full()
+                                                    .bg(cx.theme().primary),
+                                            )
+                                        }),
+                                )
+                                .on_click(window.listener_for(&self.list, move |list, _, _, cx| {
+                                    list.mark_read(ix, cx)
+                                }))
+                        }))
+                        .overflow_y_scrollbar(),
+                )
+            })
+    }
+}
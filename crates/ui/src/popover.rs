@@ -1,16 +1,20 @@
 use gpui::{
-    Anchor, AnyElement, App, Bounds, Context, Deferred, DismissEvent, Div, ElementId,
-    EventEmitter, FocusHandle, Focusable, InteractiveElement as _, IntoElement, KeyBinding,
-    MouseButton, ParentElement, Pixels, Point, Render, RenderOnce, Stateful, StyleRefinement,
-    Styled, Subscription, Window, anchored, deferred, div, prelude::FluentBuilder as _, px,
+    Anchor, AnyElement, App, Bounds, Context, Deferred, DismissEvent, Div, ElementId, EventEmitter,
+    FocusHandle, Focusable, Hsla, InteractiveElement as _, IntoElement, KeyBinding, MouseButton,
+    ParentElement, PathBuilder, Pixels, Point, Render, RenderOnce, Size, Stateful, StyleRefinement,
+    Styled, Subscription, Window, anchored, canvas, deferred, div, point,
+    prelude::FluentBuilder as _, px, relative,
 };
 use std::{cell::Cell, rc::Rc};
 
 use crate::{
-    ElementExt, Selectable, StyledExt as _, actions::Cancel, global_state::GlobalState, v_flex,
+    ActiveTheme as _, ElementExt, Selectable, StyledExt as _, actions::Cancel,
+    global_state::GlobalState, v_flex,
 };
 
 const CONTEXT: &str = "Popover";
+/// The size of the arrow indicator, width is twice of the height.
+const ARROW_SIZE: Pixels = px(6.);
 pub(crate) fn init(cx: &mut App) {
     cx.bind_keys([KeyBinding::new("escape", Cancel, Some(CONTEXT))])
 }
@@ -38,6 +42,8 @@ pub struct Popover {
     mouse_button: MouseButton,
     appearance: bool,
     overlay_closable: bool,
+    auto_flip: bool,
+    arrow: bool,
     on_open_change: Option<Rc<dyn Fn(&bool, &mut Window, &mut App)>>,
 }
 
@@ -56,6 +62,8 @@ impl Popover {
             mouse_button: MouseButton::Left,
             appearance: true,
             overlay_closable: true,
+            auto_flip: true,
+            arrow: false,
             default_open: false,
             open: None,
             on_open_change: None,
@@ -134,6 +142,21 @@ impl Popover {
         self
     }
 
+    /// Set whether to flip the popover to the opposite side of the trigger
+    /// when there is not enough space in the window, default is `true`.
+    pub fn auto_flip(mut self, auto_flip: bool) -> Self {
+        self.auto_flip = auto_flip;
+        self
+    }
+
+    /// Set whether to show an arrow indicator pointing to the trigger, default is `false`.
+    ///
+    /// The arrow is only shown when the popover has appearance.
+    pub fn arrow(mut self, arrow: bool) -> Self {
+        self.arrow = arrow;
+        self
+    }
+
     /// Set the content builder for content of the Popover.
     ///
     /// This callback will called every time on render the popover.
@@ -190,6 +213,109 @@ impl Popover {
             _ => trigger_bounds.origin,
         }
     }
+
+    /// Returns the anchor flipped to the opposite side when the popover
+    /// would overflow the viewport and the opposite side has enough space.
+    ///
+    /// The `Top*` anchors place the popover below the trigger, `Bottom*` above it,
+    /// and `*Left` / `*Right` align the popover to the left / right edge of the trigger.
+    pub(crate) fn flip_anchor(
+        anchor: Anchor,
+        trigger_bounds: Bounds<Pixels>,
+        size: Size<Pixels>,
+        viewport_size: Size<Pixels>,
+    ) -> Anchor {
+        let fits_below = trigger_bounds.bottom() + size.height <= viewport_size.height;
+        let fits_above = trigger_bounds.top() - size.height >= px(0.);
+        let fits_left_aligned = trigger_bounds.left() + size.width <= viewport_size.width;
+        let fits_right_aligned = trigger_bounds.right() - size.width >= px(0.);
+
+        let anchor = match anchor {
+            Anchor::TopLeft if !fits_below && fits_above => Anchor::BottomLeft,
+            Anchor::TopCenter if !fits_below && fits_above => Anchor::BottomCenter,
+            Anchor::TopRight if !fits_below && fits_above => Anchor::BottomRight,
+            Anchor::BottomLeft if !fits_above && fits_below => Anchor::TopLeft,
+            Anchor::BottomCenter if !fits_above && fits_below => Anchor::TopCenter,
+            Anchor::BottomRight if !fits_above && fits_below => Anchor::TopRight,
+            anchor => anchor,
+        };
+
+        match anchor {
+            Anchor::TopLeft if !fits_left_aligned && fits_right_aligned => Anchor::TopRight,
+            Anchor::BottomLeft if !fits_left_aligned && fits_right_aligned => Anchor::BottomRight,
+            Anchor::TopRight if !fits_right_aligned && fits_left_aligned => Anchor::TopLeft,
+            Anchor::BottomRight if !fits_right_aligned && fits_left_aligned => Anchor::BottomLeft,
+            anchor => anchor,
+        }
+    }
+
+    /// Render an arrow indicator on the edge of the popover content that faces the trigger.
+    fn render_arrow(anchor: Anchor, cx: &App) -> Option<impl IntoElement> {
+        let points_up = match anchor {
+            Anchor::TopLeft | Anchor::TopCenter | Anchor::TopRight => true,
+            Anchor::BottomLeft | Anchor::BottomCenter | Anchor::BottomRight => false,
+            Anchor::LeftCenter | Anchor::RightCenter => return None,
+        };
+        let bg = cx.theme().popover;
+        let border = cx.theme().border;
+
+        Some(
+            canvas(
+                |_, _, _| {},
+                move |bounds, _, window, _| paint_arrow(bounds, points_up, bg, border, window),
+            )
+            .absolute()
+            .w(ARROW_SIZE * 2.)
+            .h(ARROW_SIZE)
+            // Overlap the content border, so the arrow base covers it.
+            .map(|this| {
+                if points_up {
+                    this.top(-ARROW_SIZE + px(1.))
+                } else {
+                    this.bottom(-ARROW_SIZE + px(1.))
+                }
+            })
+            .map(|this| match anchor {
+                Anchor::TopLeft | Anchor::BottomLeft => this.left(ARROW_SIZE * 2.),
+                Anchor::TopRight | Anchor::BottomRight => this.right(ARROW_SIZE * 2.),
+                _ => this.left(relative(0.5)).ml(-ARROW_SIZE),
+            }),
+        )
+    }
+}
+
+fn paint_arrow(
+    bounds: Bounds<Pixels>,
+    points_up: bool,
+    bg: Hsla,
+    border: Hsla,
+    window: &mut Window,
+) {
+    let (base_y, tip_y) = if points_up {
+        (bounds.bottom(), bounds.top())
+    } else {
+        (bounds.top(), bounds.bottom())
+    };
+    let left = point(bounds.left(), base_y);
+    let tip = point(bounds.center().x, tip_y);
+    let right = point(bounds.right(), base_y);
+
+    let mut builder = PathBuilder::fill();
+    builder.move_to(left);
+    builder.line_to(tip);
+    builder.line_to(right);
+    builder.close();
+    if let Ok(path) = builder.build() {
+        window.paint_path(path, bg);
+    }
+
+    let mut builder = PathBuilder::stroke(px(1.));
+    builder.move_to(left);
+    builder.line_to(tip);
+    builder.line_to(right);
+    if let Ok(path) = builder.build() {
+        window.paint_path(path, border);
+    }
 }
 
 impl ParentElement for Popover {
@@ -210,6 +336,8 @@ pub struct PopoverState {
     previous_focus_handle: Option<FocusHandle>,
     trigger_bounds: Bounds<Pixels>,
     trigger_bounds_captured: bool,
+    /// The last measured size of the popover content, used to flip the anchor.
+    content_size: Size<Pixels>,
     open: bool,
     on_open_change: Option<Rc<dyn Fn(&bool, &mut Window, &mut App)>>,
 
@@ -224,6 +352,7 @@ impl PopoverState {
             previous_focus_handle: None,
             trigger_bounds: Bounds::default(),
             trigger_bounds_captured: false,
+            content_size: Size::default(),
             open: default_open,
             on_open_change: None,
             _dismiss_subscription: None,
@@ -382,6 +511,16 @@ impl RenderOnce for Popover {
         let focus_handle = state.read(cx).focus_handle.clone();
         let trigger_bounds = state.read(cx).trigger_bounds;
         let trigger_bounds_captured = state.read(cx).trigger_bounds_captured;
+        let anchor = if self.auto_flip {
+            Self::flip_anchor(
+                self.anchor,
+                trigger_bounds,
+                state.read(cx).content_size,
+                window.viewport_size(),
+            )
+        } else {
+            self.anchor
+        };
 
         let Some(trigger) = self.trigger else {
             return div().id("empty");
@@ -391,10 +530,7 @@ impl RenderOnce for Popover {
 
         // Shared cell so the deferred Anchored element can read the real trigger bounds at
         // prepaint time (after trigger's on_prepaint has already fired with the correct bounds).
-        let position = Rc::new(Cell::new(Self::resolved_corner(
-            self.anchor,
-            trigger_bounds,
-        )));
+        let position = Rc::new(Cell::new(Self::resolved_corner(anchor, trigger_bounds)));
 
         let el = div()
             .id(self.id)
@@ -415,7 +551,6 @@ impl RenderOnce for Popover {
             .on_prepaint({
                 let state = state.clone();
                 let position = position.clone();
-                let anchor = self.anchor;
                 move |bounds, window, cx| {
                     position.set(Self::resolved_corner(anchor, bounds));
                     let first_capture = state.update(cx, |state, _| {
@@ -436,30 +571,55 @@ impl RenderOnce for Popover {
             return el;
         }
 
-        let popover_content =
-            Self::render_popover_content(self.anchor, self.appearance, window, cx)
-                .track_focus(&focus_handle)
-                .key_context(CONTEXT)
-                .on_action(window.listener_for(&state, PopoverState::on_action_cancel))
-                .when_some(self.content, |this, content| {
-                    this.child(state.update(cx, |state, cx| (content)(state, window, cx)))
-                })
-                .children(self.children)
-                .when(self.overlay_closable, |this| {
-                    this.on_mouse_down_out({
-                        let state = state.clone();
-                        move |_, window, cx| {
-                            state.update(cx, |state, cx| {
-                                state.dismiss(window, cx);
-                            });
-                            cx.notify(parent_view_id);
-                        }
-                    })
+        let arrow = if self.arrow && self.appearance {
+            Self::render_arrow(anchor, cx)
+        } else {
+            None
+        };
+
+        let popover_content = Self::render_popover_content(anchor, self.appearance, window, cx)
+            .when(arrow.is_some(), |this| match anchor {
+                Anchor::TopLeft | Anchor::TopCenter | Anchor::TopRight => this.top_2(),
+                Anchor::BottomLeft | Anchor::BottomCenter | Anchor::BottomRight => this.bottom_2(),
+                _ => this,
+            })
+            .track_focus(&focus_handle)
+            .key_context(CONTEXT)
+            .on_action(window.listener_for(&state, PopoverState::on_action_cancel))
+            .when_some(self.content, |this, content| {
+                this.child(state.update(cx, |state, cx| (content)(state, window, cx)))
+            })
+            .children(self.children)
+            .when(self.overlay_closable, |this| {
+                this.on_mouse_down_out({
+                    let state = state.clone();
+                    move |_, window, cx| {
+                        state.update(cx, |state, cx| {
+                            state.dismiss(window, cx);
+                        });
+                        cx.notify(parent_view_id);
+                    }
                 })
-                .refine_style(&self.style);
+            })
+            .refine_style(&self.style)
+            .children(arrow)
+            .on_prepaint({
+                let state = state.clone();
+                move |bounds, window, cx| {
+                    let changed = state.update(cx, |state, _| {
+                        let changed = state.content_size != bounds.size;
+                        state.content_size = bounds.size;
+                        changed
+                    });
+                    // Re-render to flip the anchor with the measured size.
+                    if changed {
+                        window.refresh();
+                    }
+                }
+            });
 
         el.child(Self::render_popover(
-            self.anchor,
+            anchor,
             position,
             popover_content,
             window,
@@ -480,13 +640,64 @@ mod tests {
             .mouse_button(MouseButton::Right)
             .default_open(true)
             .appearance(false)
-            .overlay_closable(false);
+            .overlay_closable(false)
+            .auto_flip(false)
+            .arrow(true);
 
         assert_eq!(popover.anchor, Anchor::BottomCenter);
         assert_eq!(popover.mouse_button, MouseButton::Right);
         assert!(popover.default_open);
         assert!(!popover.appearance);
         assert!(!popover.overlay_closable);
+        assert!(!popover.auto_flip);
+        assert!(popover.arrow);
+    }
+
+    #[test]
+    fn test_flip_anchor() {
+        use gpui::{bounds, point, px, size};
+
+        let viewport = size(px(800.), px(600.));
+        let content = size(px(200.), px(150.));
+
+        // Enough space, keep the anchor.
+        let trigger = bounds(point(px(100.), px(100.)), size(px(80.), px(30.)));
+        assert_eq!(
+            Popover::flip_anchor(Anchor::TopLeft, trigger, content, viewport),
+            Anchor::TopLeft
+        );
+
+        // Not enough space below, flip above.
+        let trigger = bounds(point(px(100.), px(500.)), size(px(80.), px(30.)));
+        assert_eq!(
+            Popover::flip_anchor(Anchor::TopLeft, trigger, content, viewport),
+            Anchor::BottomLeft
+        );
+        assert_eq!(
+            Popover::flip_anchor(Anchor::TopCenter, trigger, content, viewport),
+            Anchor::BottomCenter
+        );
+
+        // Not enough space above, flip below.
+        let trigger = bounds(point(px(100.), px(50.)), size(px(80.), px(30.)));
+        assert_eq!(
+            Popover::flip_anchor(Anchor::BottomRight, trigger, content, viewport),
+            Anchor::TopLeft
+        );
+
+        // Not enough space on the right, align to the right edge.
+        let trigger = bounds(point(px(700.), px(100.)), size(px(80.), px(30.)));
+        assert_eq!(
+            Popover::flip_anchor(Anchor::TopLeft, trigger, content, viewport),
+            Anchor::TopRight
+        );
+
+        // Neither vertical side fits, only flip horizontally.
+        let tall = size(px(200.), px(700.));
+        assert_eq!(
+            Popover::flip_anchor(Anchor::TopLeft, trigger, tall, viewport),
+            Anchor::TopRight
+        );
     }
 
     #[test]
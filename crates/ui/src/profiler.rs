@@ -0,0 +1,301 @@
+//! A debug overlay showing the rendering statistics of the last frame, to diagnose the jank
+//! of the complex views, e.g.: a long chat session.
+//!
+//! Toggle it by the [`ToggleProfiler`] action (`cmd-alt-shift-p` on macOS, `ctrl-alt-shift-p`
+//! on the other platforms), the overlay is shown at the top right of the [`Root`].
+//!
+//! GPUI does not expose the element count and the image cache of a window, so the overlay
+//! shows the counters recorded by the components instead, e.g.: the items rendered by the
+//! virtual lists and the cache hits of the [`SvgImage`](crate::svg_image::SvgImage).
+//!
+//! [`Root`]: crate::Root
+use std::{
+    collections::{BTreeMap, HashMap},
+    time::Duration,
+};
+
+use gpui::{
+    App, Global, IntoElement, KeyBinding, ParentElement as _, RenderOnce, Styled, Window, WindowId,
+    actions, canvas, prelude::FluentBuilder as _, px,
+};
+use instant::Instant;
+
+use crate::{ActiveTheme as _, StyledExt as _, h_flex, v_flex};
+
+actions!(profiler, [ToggleProfiler]);
+
+pub(crate) fn init(cx: &mut App) {
+    cx.bind_keys(vec![
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-alt-shift-p", ToggleProfiler, None),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-alt-shift-p", ToggleProfiler, None),
+    ]);
+
+    cx.on_action(|_: &ToggleProfiler, cx| {
+        Profiler::set_enabled(!Profiler::is_enabled(cx), cx);
+        cx.refresh_windows();
+    });
+}
+
+/// The rendering statistics of a frame of a window, see [`Profiler::last_frame`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FrameStats {
+    /// The time from rendering the [`Root`](crate::Root) to painting the overlay, including the
+    /// render, layout, prepaint and paint of the views.
+    pub render_time: Duration,
+    /// The time since the previous frame started.
+    pub frame_interval: Duration,
+    /// The counters recorded by [`Profiler::count`] in the frame, e.g.: `virtual_list.items`.
+    pub counters: BTreeMap<&'static str, usize>,
+}
+
+/// A global to record the rendering statistics of the frames of each window, when enabled.
+///
+/// The counters of the crate:
+///
+/// - `virtual_list.items` - the items rendered by the virtual lists.
+/// - `virtual_list.measured` - the items measured (`layout_as_root`) by the virtual lists.
+/// - `svg_image.cache_hit`, `svg_image.cache_miss` - the colored images of the [`SvgImage`]s.
+/// - `animated_image.frames` - the frames painted by the [`AnimatedImage`]s.
+///
+/// Record the own counters by [`Profiler::count`], e.g.: the messages of a chat.
+///
+/// [`SvgImage`]: crate::svg_image::SvgImage
+/// [`AnimatedImage`]: crate::animated_image::AnimatedImage
+#[derive(Default)]
+pub struct Profiler {
+    enabled: bool,
+    windows: HashMap<WindowId, WindowFrames>,
+}
+
+impl Global for Profiler {}
+
+/// The frames of a window, the statistics of the windows are not mixed.
+#[derive(Default)]
+struct WindowFrames {
+    frame_started_at: Option<Instant>,
+    counters: BTreeMap<&'static str, usize>,
+    last_frame: FrameStats,
+}
+
+impl Profiler {
+    /// Returns true if the profiler is enabled.
+    pub fn is_enabled(cx: &App) -> bool {
+        cx.try_global::<Self>()
+            .is_some_and(|profiler| profiler.enabled)
+    }
+
+    /// Enable or disable the profiler, the statistics are cleared when disabled.
+    pub fn set_enabled(enabled: bool, cx: &mut App) {
+        cx.set_global(Self {
+            enabled,
+            ..Default::default()
+        });
+    }
+
+    /// Add `n` to the counter of the `name` in the current frame of the `window`,
+    /// do nothing if disabled.
+    pub fn count(name: &'static str, n: usize, window: &Window, cx: &mut App) {
+        if !Self::is_enabled(cx) {
+            return;
+        }
+
+        *cx.global_mut::<Self>()
+            .window_frames(window.window_handle().window_id())
+            .counters
+            .entry(name)
+            .or_default() += n;
+    }
+
+    /// Returns the statistics of the last frame of the `window`, or `None` if disabled
+    /// or no frame has been rendered.
+    pub fn last_frame<'a>(window: &Window, cx: &'a App) -> Option<&'a FrameStats> {
+        cx.try_global::<Self>()
+            .filter(|profiler| profiler.enabled)
+            .and_then(|profiler| profiler.windows.get(&window.window_handle().window_id()))
+            .map(|frames| &frames.last_frame)
+    }
+
+    /// Start a frame of the `window`, called when rendering the [`Root`](crate::Root).
+    pub(crate) fn begin_frame(window: &Window, cx: &mut App) {
+        if !Self::is_enabled(cx) {
+            return;
+        }
+
+        // Forget the closed windows.
+        let window_id = window.window_handle().window_id();
+        let window_ids = cx
+            .windows()
+            .iter()
+            .map(|window| window.window_id())
+            .collect::<Vec<_>>();
+        let profiler = cx.global_mut::<Self>();
+        profiler
+            .windows
+            .retain(|id, _| *id == window_id || window_ids.contains(id));
+        profiler
+            .window_frames(window_id)
+            .begin_frame_at(Instant::now());
+    }
+
+    /// End the frame of the `window`, called when painting the overlay.
+    fn end_frame(window: &Window, cx: &mut App) {
+        cx.global_mut::<Self>()
+            .window_frames(window.window_handle().window_id())
+            .end_frame_at(Instant::now());
+    }
+
+    fn window_frames(&mut self, window_id: WindowId) -> &mut WindowFrames {
+        self.windows.entry(window_id).or_default()
+    }
+}
+
+impl WindowFrames {
+    fn begin_frame_at(&mut self, now: Instant) {
+        self.last_frame.frame_interval =
+            self.frame_started_at.map_or(Duration::ZERO, |started_at| {
+                now.saturating_duration_since(started_at)
+            });
+        self.frame_started_at = Some(now);
+        self.counters.clear();
+    }
+
+    fn end_frame_at(&mut self, now: Instant) {
+        let Some(started_at) = self.frame_started_at else {
+            return;
+        };
+
+        self.last_frame.render_time = now.saturating_duration_since(started_at);
+        self.last_frame.counters = std::mem::take(&mut self.counters);
+    }
+}
+
+/// The overlay of the [`Profiler`], rendered by the [`Root`](crate::Root) when enabled.
+#[derive(IntoElement)]
+pub(crate) struct ProfilerOverlay;
+
+impl RenderOnce for ProfilerOverlay {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let stats = Profiler::last_frame(window, cx)
+            .cloned()
+            .unwrap_or_default();
+        let fps = if stats.frame_interval.is_zero() {
+            0.
+        } else {
+            1. / stats.frame_interval.as_secs_f32()
+        };
+
+        let row = |label: String, value: String| {
+            h_flex().gap_4().justify_between().child(label).child(value)
+        };
+
+        v_flex()
+            .absolute()
+            .top_2()
+            .right_2()
+            .min_w(px(200.))
+            .p_2()
+            .gap_0p5()
+            .popover_style(cx)
+            .font_family(cx.theme().mono_font_family.clone())
+            .text_xs()
+            .child(
+                row(
+                    "render".into(),
+                    format!("{:.1} ms", stats.render_time.as_secs_f32() * 1000.),
+                )
+                .font_semibold(),
+            )
+            .child(row("fps".into(), format!("{:.0}", fps)))
+            .when(!stats.counters.is_empty(), |this| {
+                this.children(
+                    stats
+                        .counters
+                        .iter()
+                        .map(|(name, count)| row(name.to_string(), count.to_string())),
+                )
+            })
+            // Painted after the views, so the frame ends here.
+            .child(
+                canvas(
+                    |_, _, _| {},
+                    |_, _, window, cx| {
+                        if Profiler::is_enabled(cx) {
+                            Profiler::end_frame(window, cx);
+                            window.request_animation_frame();
+                        }
+                    },
+                )
+                .absolute()
+                .size_0(),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use gpui::{Context, IntoElement, Render, TestAppContext, Window};
+    use instant::Instant;
+
+    use super::{Profiler, WindowFrames};
+
+    struct TestView;
+
+    impl Render for TestView {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            gpui::Empty
+        }
+    }
+
+    #[gpui::test]
+    fn test_profiler_windows(cx: &mut TestAppContext) {
+        cx.update(|cx| Profiler::set_enabled(true, cx));
+        let window1 = cx.add_window(|_, _| TestView);
+        let window2 = cx.add_window(|_, _| TestView);
+
+        window1
+            .update(cx, |_, window, cx| {
+                Profiler::count("virtual_list.items", 1, window, cx)
+            })
+            .unwrap();
+        window2
+            .update(cx, |_, window, cx| {
+                Profiler::count("virtual_list.items", 2, window, cx)
+            })
+            .unwrap();
+
+        cx.update(|cx| {
+            let profiler = cx.global::<Profiler>();
+            let counter = |id| profiler.windows[&id].counters.get("virtual_list.items");
+            assert_eq!(counter(window1.window_id()), Some(&1));
+            assert_eq!(counter(window2.window_id()), Some(&2));
+        });
+    }
+
+    #[test]
+    fn test_profiler_frames() {
+        let now = Instant::now();
+        let ms = Duration::from_millis;
+        let mut profiler = WindowFrames::default();
+
+        profiler.begin_frame_at(now);
+        *profiler.counters.entry("virtual_list.items").or_default() += 20;
+        profiler.end_frame_at(now + ms(4));
+        assert_eq!(profiler.last_frame.render_time, ms(4));
+        assert_eq!(profiler.last_frame.frame_interval, Duration::ZERO);
+        assert_eq!(
+            profiler.last_frame.counters.get("virtual_list.items"),
+            Some(&20)
+        );
+
+        profiler.begin_frame_at(now + ms(16));
+        assert!(profiler.counters.is_empty());
+        profiler.end_frame_at(now + ms(18));
+        assert_eq!(profiler.last_frame.render_time, ms(2));
+        assert_eq!(profiler.last_frame.frame_interval, ms(16));
+        assert!(profiler.last_frame.counters.is_empty());
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{ActiveTheme, Sizable, Size, StyledExt};
+use crate::{
+    ActiveTheme, Sizable, Size, StyledExt,
+    animation::{UiTestMode, is_motion_reduced, motion_duration},
+};
 use gpui::{
     Animation, AnimationExt as _, App, ElementId, Hsla, InteractiveElement as _, IntoElement,
     ParentElement, RenderOnce, StyleRefinement, Styled, Window, div, ease_in_out,
@@ -118,10 +121,11 @@ impl RenderOnce for Progress {
                             state.read(cx).set_target(value);
 
                             let duration = Duration::from_secs_f64(0.15);
+                            let delay = UiTestMode::delay(duration, cx);
                             cx.spawn({
                                 let state = state.clone();
                                 async move |cx| {
-                                    cx.background_executor().timer(duration).await;
+                                    cx.background_executor().timer(delay).await;
                                     _ = state.update(cx, |this, _| {
                                         this.value = this.target();
                                     });
@@ -131,7 +135,7 @@ impl RenderOnce for Progress {
 
                             this.with_animation(
                                 "progress-animation",
-                                Animation::new(duration),
+                                Animation::new(motion_duration(duration, cx)),
                                 move |this, delta| {
                                     let current_value = from + (value - from) * delta;
                                     let w = relative((current_value / 100.).clamp(0., 1.));
@@ -139,7 +143,7 @@ impl RenderOnce for Progress {
                                 },
                             )
                             .into_any_element()
-                        } else if loading {
+                        } else if loading && !is_motion_reduced(cx) {
                             this.with_animation(
                                 "progress-loading",
                                 Animation::new(Duration::from_secs(1)).repeat(),
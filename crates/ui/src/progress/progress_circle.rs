@@ -1,4 +1,7 @@
-use crate::{ActiveTheme, Sizable, Size, StyledExt};
+use crate::{
+    ActiveTheme, Sizable, Size, StyledExt,
+    animation::{UiTestMode, is_motion_reduced, motion_duration},
+};
 use gpui::prelude::FluentBuilder as _;
 use gpui::{
     Animation, AnimationExt as _, AnyElement, App, ElementId, Hsla, InteractiveElement as _,
@@ -63,7 +66,7 @@ impl ProgressCircle {
 
     /// Render the arc canvas. `start_value` and `end_value` are in 0.0–100.0 percentage.
     /// The progress arc is skipped when `end_value <= 0`.
-    fn render_circle(start_value: f32, end_value: f32, color: Hsla) -> impl IntoElement {
+    pub(crate) fn render_circle(start_value: f32, end_value: f32, color: Hsla) -> impl IntoElement {
         struct PrepaintState {
             start_value: f32,
             end_value: f32,
@@ -183,10 +186,11 @@ impl RenderOnce for ProgressCircle {
                     state.read(cx).set_target(value);
 
                     let duration = Duration::from_secs_f64(0.15);
+                    let delay = UiTestMode::delay(duration, cx);
                     cx.spawn({
                         let state = state.clone();
                         async move |cx| {
-                            cx.background_executor().timer(duration).await;
+                            cx.background_executor().timer(delay).await;
                             _ = state.update(cx, |this, _| {
                                 this.value = this.target();
                             });
@@ -196,14 +200,14 @@ impl RenderOnce for ProgressCircle {
 
                     this.with_animation(
                         format!("progress-circle-{}", from),
-                        Animation::new(duration),
+                        Animation::new(motion_duration(duration, cx)),
                         move |this, delta| {
                             let v = from + (value - from) * delta;
                             this.child(Self::render_circle(0., v, color))
                         },
                     )
                     .into_any_element()
-                } else if loading {
+                } else if loading && !is_motion_reduced(cx) {
                     this.with_animation(
                         "progress-circle-loading",
                         Animation::new(Duration::from_secs(1)).repeat(),
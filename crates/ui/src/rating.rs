@@ -1,24 +1,42 @@
+use crate::actions::{SelectDown, SelectFirst, SelectLast, SelectLeft, SelectRight, SelectUp};
 use crate::theme::ActiveTheme;
-use crate::{Disableable, Icon, IconName, Sizable, Size, StyledExt, h_flex};
+use crate::{Disableable, FocusableExt, Icon, IconName, Sizable, Size, StyledExt, h_flex};
 use std::rc::Rc;
 
 use gpui::{
-    App, ElementId, InteractiveElement, IntoElement, ParentElement, RenderOnce, StyleRefinement,
-    Styled, Window, div, prelude::FluentBuilder as _,
+    App, ElementId, FocusHandle, InteractiveElement, IntoElement, KeyBinding, ParentElement,
+    RenderOnce, StyleRefinement, Styled, Window, div, prelude::FluentBuilder as _, px, relative,
 };
 use gpui::{ClickEvent, Hsla, StatefulInteractiveElement};
 
+const CONTEXT: &str = "Rating";
+pub(crate) fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("left", SelectLeft, Some(CONTEXT)),
+        KeyBinding::new("right", SelectRight, Some(CONTEXT)),
+        KeyBinding::new("down", SelectDown, Some(CONTEXT)),
+        KeyBinding::new("up", SelectUp, Some(CONTEXT)),
+        KeyBinding::new("home", SelectFirst, Some(CONTEXT)),
+        KeyBinding::new("end", SelectLast, Some(CONTEXT)),
+    ]);
+}
+
 /// A simple star Rating element.
+///
+/// When focused, use `left` and `right` (or `down` and `up`) keys to change the rating by one step,
+/// `home` and `end` to set it to `0` or `max`.
 #[derive(IntoElement)]
 pub struct Rating {
     id: ElementId,
     style: StyleRefinement,
     size: Size,
     disabled: bool,
-    value: usize,
+    value: f32,
     max: usize,
+    half: bool,
     color: Option<Hsla>,
     on_click: Option<Rc<dyn Fn(&usize, &mut Window, &mut App) + 'static>>,
+    on_change: Option<Rc<dyn Fn(&f32, &mut Window, &mut App) + 'static>>,
 }
 
 impl Rating {
@@ -29,10 +47,12 @@ impl Rating {
             style: StyleRefinement::default(),
             size: Size::Medium,
             disabled: false,
-            value: 0,
+            value: 0.,
             max: 5,
+            half: false,
             color: None,
             on_click: None,
+            on_change: None,
         }
     }
 
@@ -56,29 +76,47 @@ impl Rating {
 
     /// Set initial value (0..=max).
     pub fn value(mut self, value: usize) -> Self {
+        self.value = value as f32;
+        self
+    }
+
+    /// Set initial value (0..=max) with the half star, e.g.: `3.5`, see [`Rating::half`].
+    ///
+    /// The value will be rounded to the nearest step.
+    pub fn value_f32(mut self, value: f32) -> Self {
         self.value = value;
-        if self.value > self.max {
-            self.value = self.max;
-        }
         self
     }
 
     /// Set maximum number of stars.
     pub fn max(mut self, max: usize) -> Self {
         self.max = max;
-        if self.value > self.max {
-            self.value = self.max;
-        }
+        self
+    }
+
+    /// Set to allow half star precision, default: false
+    ///
+    /// Use [`Rating::value_f32`] and [`Rating::on_change`] to set and get the half star value.
+    pub fn half(mut self, half: bool) -> Self {
+        self.half = half;
         self
     }
 
     /// Add on_click handler when the rating changes.
     ///
-    /// The `&usize` parameter is the new rating value.
+    /// The `&usize` parameter is the new rating value, the half star is rounded up.
     pub fn on_click(mut self, handler: impl Fn(&usize, &mut Window, &mut App) + 'static) -> Self {
         self.on_click = Some(Rc::new(handler));
         self
     }
+
+    /// Add on_change handler when the rating changes, called after the `on_click` handler.
+    ///
+    /// The `&f32` parameter is the new rating value, including the half star, e.g.: `3.5`.
+    pub fn on_change(mut self, handler: impl Fn(&f32, &mut Window, &mut App) + 'static) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
 }
 
 impl Styled for Rating {
@@ -101,13 +139,40 @@ impl Disableable for Rating {
     }
 }
 
-struct RaingState {
+/// Returns the step of the rating value.
+fn rating_step(half: bool) -> f32 {
+    if half { 0.5 } else { 1. }
+}
+
+/// Clamp the value to `0..=max` and round it to the nearest step.
+fn normalize_value(value: f32, max: usize, half: bool) -> f32 {
+    let step = rating_step(half);
+    ((value / step).round() * step).clamp(0., max as f32)
+}
+
+/// Returns the new value when clicking on `target`, clicking on a selected star (at or below
+/// the current value) sets the value to one step below it.
+fn click_value(value: f32, target: f32, half: bool) -> f32 {
+    if value >= target {
+        (target - rating_step(half)).max(0.)
+    } else {
+        target
+    }
+}
+
+/// Returns how much of the star at `ix` (1-based) is filled by `value`, in `0.0..=1.0`.
+fn star_fill(value: f32, ix: usize) -> f32 {
+    (value - (ix - 1) as f32).clamp(0., 1.)
+}
+
+struct RatingState {
+    focus_handle: FocusHandle,
     /// To save the default value on init state, to detect external value changes.
-    default_value: usize,
+    default_value: f32,
     /// To store the current selected value.
-    value: usize,
+    value: f32,
     /// To store the currently hovered value.
-    hovered_value: usize,
+    hovered_value: Option<f32>,
 }
 
 impl RenderOnce for Rating {
@@ -116,14 +181,18 @@ impl RenderOnce for Rating {
         let size = self.size;
         let disabled = self.disabled;
         let max = self.max;
-        let default_value = self.value;
+        let half = self.half;
+        let step = rating_step(half);
+        let default_value = normalize_value(self.value, max, half);
         let active_color = self.color.unwrap_or(cx.theme().yellow);
         let on_click = self.on_click.clone();
+        let on_change = self.on_change.clone();
 
-        let state = window.use_keyed_state(id.clone(), cx, |_, _| RaingState {
+        let state = window.use_keyed_state(id.clone(), cx, |_, cx| RatingState {
+            focus_handle: cx.focus_handle(),
             default_value,
             value: default_value,
-            hovered_value: 0,
+            hovered_value: None,
         });
 
         // Reset state if outside has changed `value` prop.
@@ -134,65 +203,145 @@ impl RenderOnce for Rating {
             });
         }
         let value = state.read(cx).value;
+        let display_value = state.read(cx).hovered_value.unwrap_or(value);
+        let focus_handle = state.read(cx).focus_handle.clone();
+        let is_focused = focus_handle.is_focused(window);
+
+        let set_value = Rc::new({
+            let state = state.clone();
+            let on_click = on_click.clone();
+            let on_change = on_change.clone();
+            move |new: f32, window: &mut Window, cx: &mut App| {
+                let new = normalize_value(new, max, half);
+                state.update(cx, |state, cx| {
+                    state.value = new;
+                    cx.notify();
+                });
+
+                if let Some(on_click) = &on_click {
+                    on_click(&(new.ceil() as usize), window, cx);
+                }
+                if let Some(on_change) = &on_change {
+                    on_change(&new, window, cx);
+                }
+            }
+        });
 
         h_flex()
             .id(id)
+            .key_context(CONTEXT)
             .flex_nowrap()
+            .rounded(cx.theme().radius * 0.5)
+            .when(!disabled, |this| {
+                this.track_focus(&focus_handle.tab_stop(true))
+                    .on_action({
+                        let set_value = set_value.clone();
+                        move |_: &SelectLeft, window, cx| set_value(value - step, window, cx)
+                    })
+                    .on_action({
+                        let set_value = set_value.clone();
+                        move |_: &SelectDown, window, cx| set_value(value - step, window, cx)
+                    })
+                    .on_action({
+                        let set_value = set_value.clone();
+                        move |_: &SelectRight, window, cx| set_value(value + step, window, cx)
+                    })
+                    .on_action({
+                        let set_value = set_value.clone();
+                        move |_: &SelectUp, window, cx| set_value(value + step, window, cx)
+                    })
+                    .on_action({
+                        let set_value = set_value.clone();
+                        move |_: &SelectFirst, window, cx| set_value(0., window, cx)
+                    })
+                    .on_action({
+                        let set_value = set_value.clone();
+                        move |_: &SelectLast, window, cx| set_value(max as f32, window, cx)
+                    })
+            })
+            .focus_ring(is_focused, px(2.), window, cx)
             .refine_style(&self.style)
             .on_hover(window.listener_for(&state, move |state, hovered, _, cx| {
                 if !hovered {
-                    state.hovered_value = 0;
+                    state.hovered_value = None;
                     cx.notify();
                 }
             }))
             .map(|mut this| {
                 for ix in 1..=max {
-                    let filled = ix <= value;
-                    let hovered = state.read(cx).hovered_value >= ix;
+                    let fill = star_fill(display_value, ix);
+
+                    // The targets to select when clicking on the left and right half of the star.
+                    let targets = if half {
+                        vec![ix as f32 - 0.5, ix as f32]
+                    } else {
+                        vec![ix as f32]
+                    };
+                    let target_width = 1. / targets.len() as f32;
 
                     this = this.child(
                         div()
                             .id(ix)
+                            .relative()
                             .p_0p5()
                             .flex_none()
                             .flex_shrink_0()
-                            .when(filled || hovered, |this| this.text_color(active_color))
+                            .when(fill > 0., |this| this.text_color(active_color))
                             .child(
-                                Icon::new(if filled {
-                                    IconName::StarFill
-                                } else {
-                                    IconName::Star
-                                })
-                                .with_size(size),
+                                div()
+                                    .relative()
+                                    .child(
+                                        Icon::new(if fill >= 1. {
+                                            IconName::StarFill
+                                        } else {
+                                            IconName::Star
+                                        })
+                                        .with_size(size),
+                                    )
+                                    .when(fill > 0. && fill < 1., |this| {
+                                        this.child(
+                                            div()
+                                                .absolute()
+                                                .top_0()
+                                                .left_0()
+                                                .h_full()
+                                                .w(relative(fill))
+                                                .overflow_hidden()
+                                                .child(
+                                                    Icon::new(IconName::StarFill).with_size(size),
+                                                ),
+                                        )
+                                    }),
                             )
                             .when(!disabled, |this| {
-                                this.on_mouse_move(window.listener_for(
-                                    &state,
-                                    move |state, _, _, cx| {
-                                        state.hovered_value = ix;
-                                        cx.notify();
-                                    },
-                                ))
-                                .on_click({
-                                    let state = state.clone();
-                                    let on_click = on_click.clone();
-                                    move |_: &ClickEvent, window, cx| {
-                                        let new = if value >= ix {
-                                            ix.saturating_sub(1)
-                                        } else {
-                                            ix
-                                        };
-
-                                        state.update(cx, |state, cx| {
-                                            state.value = new;
-                                            cx.notify();
-                                        });
-
-                                        if let Some(on_click) = &on_click {
-                                            on_click(&new, window, cx);
-                                        }
-                                    }
-                                })
+                                this.children(targets.into_iter().enumerate().map(|(i, target)| {
+                                    div()
+                                        .id(i)
+                                        .absolute()
+                                        .top_0()
+                                        .left(relative(target_width * i as f32))
+                                        .h_full()
+                                        .w(relative(target_width))
+                                        .on_mouse_move(window.listener_for(
+                                            &state,
+                                            move |state, _, _, cx| {
+                                                if state.hovered_value != Some(target) {
+                                                    state.hovered_value = Some(target);
+                                                    cx.notify();
+                                                }
+                                            },
+                                        ))
+                                        .on_click({
+                                            let set_value = set_value.clone();
+                                            move |_: &ClickEvent, window, cx| {
+                                                set_value(
+                                                    click_value(value, target, half),
+                                                    window,
+                                                    cx,
+                                                );
+                                            }
+                                        })
+                                }))
                             }),
                     );
                 }
@@ -201,3 +350,37 @@ impl RenderOnce for Rating {
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{click_value, normalize_value, star_fill};
+
+    #[test]
+    fn test_normalize_value() {
+        assert_eq!(normalize_value(3., 5, false), 3.);
+        assert_eq!(normalize_value(3.4, 5, false), 3.);
+        assert_eq!(normalize_value(3.4, 5, true), 3.5);
+        assert_eq!(normalize_value(7., 5, true), 5.);
+        assert_eq!(normalize_value(-1., 5, true), 0.);
+    }
+
+    #[test]
+    fn test_click_value() {
+        assert_eq!(click_value(2., 4., false), 4.);
+        assert_eq!(click_value(4., 4., false), 3.);
+        // Clicking a selected star below the value sets the value below it.
+        assert_eq!(click_value(4., 2., false), 1.);
+        assert_eq!(click_value(1., 1., false), 0.);
+        assert_eq!(click_value(3., 2.5, true), 2.);
+        assert_eq!(click_value(2.5, 3., true), 3.);
+        assert_eq!(click_value(2.5, 2.5, true), 2.);
+        assert_eq!(click_value(0.5, 0.5, true), 0.);
+    }
+
+    #[test]
+    fn test_star_fill() {
+        assert_eq!(star_fill(2.5, 1), 1.);
+        assert_eq!(star_fill(2.5, 3), 0.5);
+        assert_eq!(star_fill(2.5, 4), 0.);
+    }
+}
@@ -86,6 +86,141 @@ impl ResizableState {
         self.done_resizing(cx);
     }
 
+    /// Returns true if the panel at `ix` is collapsed.
+    pub fn is_collapsed(&self, ix: usize) -> bool {
+        self.panels.get(ix).is_some_and(|panel| panel.collapsed)
+    }
+
+    /// Collapse the panel at `ix` to zero, the space is given to the next panel,
+    /// or the previous one for the last panel.
+    ///
+    /// The size before collapsed is restored by [`Self::expand_panel`].
+    pub fn collapse_panel(&mut self, ix: usize, _: &mut Window, cx: &mut Context<Self>) {
+        if self.set_collapsed(ix, true) {
+            cx.emit(ResizablePanelEvent::Resized);
+            cx.notify();
+        }
+    }
+
+    /// Expand the collapsed panel at `ix` to the size before collapsed.
+    pub fn expand_panel(&mut self, ix: usize, _: &mut Window, cx: &mut Context<Self>) {
+        if self.set_collapsed(ix, false) {
+            cx.emit(ResizablePanelEvent::Resized);
+            cx.notify();
+        }
+    }
+
+    /// Toggle the collapsed state of the panel at `ix`.
+    pub fn toggle_panel(&mut self, ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+        if self.is_collapsed(ix) {
+            self.expand_panel(ix, window, cx);
+        } else {
+            self.collapse_panel(ix, window, cx);
+        }
+    }
+
+    /// Reset the panel at `ix` to its default size, that is the `size` of the [`ResizablePanel`].
+    ///
+    /// The collapsed panel is expanded first, this is a no-op if the panel has no default size.
+    pub fn reset_panel(&mut self, ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(default_size) = self.panels.get(ix).and_then(|panel| panel.default_size) else {
+            return;
+        };
+
+        self.set_collapsed(ix, false);
+        self.resize_panel(ix, default_size, window, cx);
+    }
+
+    /// Reset the panels beside the handle at `ix` (between panel `ix` and `ix + 1`),
+    /// the first one with a default size is reset.
+    pub(crate) fn reset_panel_at_handle(
+        &mut self,
+        ix: usize,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(panel_ix) = [ix, ix + 1].into_iter().find(|&ix| {
+            self.panels
+                .get(ix)
+                .is_some_and(|panel| panel.default_size.is_some())
+        }) else {
+            return;
+        };
+
+        self.reset_panel(panel_ix, window, cx);
+    }
+
+    /// Collapse or expand the panel at `ix`, returns true if changed.
+    fn set_collapsed(&mut self, ix: usize, collapsed: bool) -> bool {
+        let len = self.panels.len();
+        if ix >= len || len < 2 || self.panels[ix].collapsed == collapsed {
+            return false;
+        }
+
+        let neighbor_ix = if ix + 1 < len { ix + 1 } else { ix - 1 };
+        if collapsed {
+            self.panels[ix].restore_size = Some(self.sizes[ix]);
+            self.sizes[neighbor_ix] += self.sizes[ix];
+            self.sizes[ix] = px(0.);
+        } else {
+            let min_size = self.panel_size_range(ix).start;
+            let neighbor_min_size = self.panel_size_range(neighbor_ix).start;
+            let available_size = (self.sizes[neighbor_ix] - neighbor_min_size).max(px(0.));
+            let size = self.panels[ix]
+                .restore_size
+                .take()
+                .unwrap_or(min_size)
+                .max(min_size)
+                .min(available_size);
+            self.sizes[neighbor_ix] -= size;
+            self.sizes[ix] = size;
+        }
+
+        self.panels[ix].collapsed = collapsed;
+        self.panels[ix].size = Some(self.sizes[ix]);
+        self.panels[neighbor_ix].size = Some(self.sizes[neighbor_ix]);
+        true
+    }
+
+    /// Resize the panels by dragging the handle at `ix` to make the panel `ix` in `size`.
+    ///
+    /// The collapsible panels beside the handle are collapsed when dragged to less than half
+    /// of their min size, and expanded when dragged back.
+    fn drag_handle(
+        &mut self,
+        ix: usize,
+        size: Pixels,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let next_ix = ix + 1;
+        if next_ix >= self.panels.len() {
+            return;
+        }
+
+        let next_size = self.sizes[ix] + self.sizes[next_ix] - size;
+        for (panel_ix, size) in [(ix, size), (next_ix, next_size)] {
+            let collapsed = self.panels[panel_ix].collapsed;
+            if !self.panels[panel_ix].collapsible && !collapsed {
+                continue;
+            }
+
+            let min_size = self.panel_size_range(panel_ix).start;
+            match drag_collapse(size, min_size, collapsed) {
+                Some(collapsed) => {
+                    self.set_collapsed(panel_ix, collapsed);
+                    cx.notify();
+                    return;
+                }
+                // Keep collapsed until dragged over the threshold.
+                None if collapsed => return,
+                None => {}
+            }
+        }
+
+        self.resize_panel_at_handle(ix, size, window, cx);
+    }
+
     pub(crate) fn insert_panel(
         &mut self,
         size: Option<Pixels>,
@@ -155,6 +290,8 @@ impl ResizableState {
         panel_ix: usize,
         bounds: Bounds<Pixels>,
         size_range: Range<Pixels>,
+        default_size: Option<Pixels>,
+        collapsible: bool,
         cx: &mut Context<Self>,
     ) {
         let size = bounds.size.along(self.axis);
@@ -167,6 +304,8 @@ impl ResizableState {
         }
         self.panels[panel_ix].bounds = bounds;
         self.panels[panel_ix].size_range = size_range;
+        self.panels[panel_ix].default_size = default_size;
+        self.panels[panel_ix].collapsible = collapsible;
         cx.notify();
     }
 
@@ -331,4 +470,39 @@ pub(crate) struct ResizablePanelState {
     pub size: Option<Pixels>,
     pub size_range: Range<Pixels>,
     bounds: Bounds<Pixels>,
+    /// The initial size of the panel, to reset by double-clicking the handle.
+    default_size: Option<Pixels>,
+    collapsible: bool,
+    pub collapsed: bool,
+    /// The size before collapsed.
+    restore_size: Option<Pixels>,
+}
+
+/// Returns `Some(true)` to collapse or `Some(false)` to expand the collapsible panel,
+/// when it is dragged to `size` over the half of its `min_size`.
+fn drag_collapse(size: Pixels, min_size: Pixels, collapsed: bool) -> Option<bool> {
+    let threshold = min_size / 2.;
+    if collapsed {
+        (size >= threshold).then_some(false)
+    } else {
+        (size < threshold).then_some(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::px;
+
+    use super::drag_collapse;
+
+    #[test]
+    fn test_drag_collapse() {
+        assert_eq!(drag_collapse(px(100.), px(100.), false), None);
+        assert_eq!(drag_collapse(px(60.), px(100.), false), None);
+        assert_eq!(drag_collapse(px(40.), px(100.), false), Some(true));
+        assert_eq!(drag_collapse(px(40.), px(100.), true), None);
+        assert_eq!(drag_collapse(px(50.), px(100.), true), Some(false));
+        // The panel without min size can not be collapsed by dragging.
+        assert_eq!(drag_collapse(px(0.), px(0.), false), None);
+    }
 }
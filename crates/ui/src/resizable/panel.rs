@@ -6,11 +6,14 @@ use std::{
 use gpui::{
     Along, AnyElement, App, AppContext, Axis, Bounds, Context, Element, ElementId, Empty, Entity,
     EventEmitter, InteractiveElement as _, IntoElement, IsZero as _, MouseMoveEvent, MouseUpEvent,
-    ParentElement, Pixels, Render, RenderOnce, Style, StyleRefinement, Styled, Window, div,
-    prelude::FluentBuilder,
+    ParentElement, Pixels, Render, RenderOnce, StatefulInteractiveElement as _, Style,
+    StyleRefinement, Styled, Window, div, prelude::FluentBuilder, px,
 };
 
-use crate::{AxisExt, ElementExt, h_flex, resizable::PANEL_MIN_SIZE, styled::StyledExt as _, v_flex};
+use crate::{
+    ActiveTheme as _, AxisExt, ElementExt, Icon, IconName, Sizable as _, h_flex,
+    resizable::PANEL_MIN_SIZE, styled::StyledExt as _, v_flex,
+};
 
 use super::{ResizableState, resizable_panel, resize_handle};
 
@@ -213,6 +216,7 @@ pub struct ResizablePanel {
     initial_size: Option<Pixels>,
     /// size range limit of this panel.
     size_range: Range<Pixels>,
+    collapsible: bool,
     children: Vec<AnyElement>,
     visible: bool,
     style: StyleRefinement,
@@ -226,6 +230,7 @@ impl ResizablePanel {
             initial_size: None,
             state: None,
             size_range: (PANEL_MIN_SIZE..Pixels::MAX),
+            collapsible: false,
             axis: Axis::Horizontal,
             children: vec![],
             visible: true,
@@ -252,6 +257,29 @@ impl ResizablePanel {
         self.size_range = range.into();
         self
     }
+
+    /// Set the min size of the panel, default is [`PANEL_MIN_SIZE`].
+    pub fn min_size(mut self, min_size: impl Into<Pixels>) -> Self {
+        self.size_range.start = min_size.into();
+        self
+    }
+
+    /// Set the max size of the panel, default is [`Pixels::MAX`].
+    pub fn max_size(mut self, max_size: impl Into<Pixels>) -> Self {
+        self.size_range.end = max_size.into();
+        self
+    }
+
+    /// Set the panel can be collapsed by dragging the handle, default is false.
+    ///
+    /// The panel is collapsed to zero when dragged to less than half of its min size,
+    /// and a button is shown on the handle to restore it.
+    ///
+    /// See also [`ResizableState::collapse_panel`] and [`ResizableState::expand_panel`].
+    pub fn collapsible(mut self, collapsible: bool) -> Self {
+        self.collapsible = collapsible;
+        self
+    }
 }
 
 impl Styled for ResizablePanel {
@@ -281,6 +309,20 @@ impl RenderOnce for ResizablePanel {
             .get(self.panel_ix)
             .expect("BUG: The `index` of ResizablePanel should be one of in `state`.");
         let size_range = self.size_range.clone();
+        let collapsed = panel_state.collapsed;
+        let panels_count = state.read(cx).panels.len();
+
+        // The collapsed panel is restored by the button on the handle after it,
+        // or before it for the last panel.
+        let restore_ix = self.panel_ix.checked_sub(1).and_then(|prev_ix| {
+            if state.read(cx).is_collapsed(prev_ix) {
+                Some(prev_ix)
+            } else if collapsed && self.panel_ix + 1 == panels_count {
+                Some(self.panel_ix)
+            } else {
+                None
+            }
+        });
 
         div()
             .id(("resizable-panel", self.panel_ix))
@@ -319,28 +361,93 @@ impl RenderOnce for ResizablePanel {
                 Some(size) => this.flex_basis(size.min(size_range.end).max(size_range.start)),
                 None => this,
             })
+            .when(collapsed, |this| {
+                this.flex_none()
+                    .flex_basis(px(0.))
+                    .min_w(px(0.))
+                    .min_h(px(0.))
+            })
             .on_prepaint({
                 let state = state.clone();
+                let initial_size = self.initial_size;
+                let collapsible = self.collapsible;
                 move |bounds, _, cx| {
                     state.update(cx, |state, cx| {
-                        state.update_panel_size(self.panel_ix, bounds, self.size_range, cx)
+                        state.update_panel_size(
+                            self.panel_ix,
+                            bounds,
+                            self.size_range,
+                            initial_size,
+                            collapsible,
+                            cx,
+                        )
                     })
                 }
             })
-            .children(self.children)
+            .when(!collapsed, |this| this.children(self.children))
             .when(self.panel_ix > 0, |this| {
                 let ix = self.panel_ix - 1;
-                this.child(resize_handle(("resizable-handle", ix), self.axis).on_drag(
-                    DragPanel,
-                    move |drag_panel, _, _, cx| {
-                        cx.stop_propagation();
-                        // Set current resizing panel ix
-                        state.update(cx, |state, _| {
-                            state.resizing_panel_ix = Some(ix);
-                        });
-                        cx.new(|_| drag_panel.deref().clone())
-                    },
-                ))
+                this.child(
+                    resize_handle(("resizable-handle", ix), self.axis)
+                        .on_drag(DragPanel, {
+                            let state = state.clone();
+                            move |drag_panel, _, _, cx| {
+                                cx.stop_propagation();
+                                // Set current resizing panel ix
+                                state.update(cx, |state, _| {
+                                    state.resizing_panel_ix = Some(ix);
+                                });
+                                cx.new(|_| drag_panel.deref().clone())
+                            }
+                        })
+                        .on_double_click({
+                            let state = state.clone();
+                            move |window, cx| {
+                                state.update(cx, |state, cx| {
+                                    state.reset_panel_at_handle(ix, window, cx);
+                                })
+                            }
+                        }),
+                )
+            })
+            .when_some(restore_ix, |this, restore_ix| {
+                let icon = match (self.axis, restore_ix < self.panel_ix) {
+                    (Axis::Horizontal, true) => IconName::ChevronRight,
+                    (Axis::Horizontal, false) => IconName::ChevronLeft,
+                    (Axis::Vertical, true) => IconName::ChevronDown,
+                    (Axis::Vertical, false) => IconName::ChevronUp,
+                };
+                let hover_bg = cx.theme().accent;
+
+                this.child(
+                    div()
+                        .id(("resizable-restore", restore_ix))
+                        .absolute()
+                        .flex()
+                        .items_center()
+                        .justify_center()
+                        .size(px(16.))
+                        .rounded_full()
+                        .border_1()
+                        .border_color(cx.theme().border)
+                        .bg(cx.theme().background)
+                        .text_color(cx.theme().muted_foreground)
+                        .cursor_pointer()
+                        .hover(|this| this.bg(hover_bg))
+                        .when(self.axis.is_horizontal(), |this| {
+                            this.top_1_2().mt(px(-8.)).left(px(-8.))
+                        })
+                        .when(self.axis.is_vertical(), |this| {
+                            this.left_1_2().ml(px(-8.)).top(px(-8.))
+                        })
+                        .child(Icon::new(icon).xsmall())
+                        .on_click(move |_, window, cx| {
+                            cx.stop_propagation();
+                            state.update(cx, |state, cx| {
+                                state.expand_panel(restore_ix, window, cx);
+                            })
+                        }),
+                )
             })
     }
 }
@@ -417,18 +524,12 @@ impl Element for ResizePanelGroupElement {
                     let panel = state.panels.get(ix).expect("BUG: invalid panel index");
 
                     match axis {
-                        Axis::Horizontal => state.resize_panel_at_handle(
-                            ix,
-                            e.position.x - panel.bounds.left(),
-                            window,
-                            cx,
-                        ),
-                        Axis::Vertical => state.resize_panel_at_handle(
-                            ix,
-                            e.position.y - panel.bounds.top(),
-                            window,
-                            cx,
-                        ),
+                        Axis::Horizontal => {
+                            state.drag_handle(ix, e.position.x - panel.bounds.left(), window, cx)
+                        }
+                        Axis::Vertical => {
+                            state.drag_handle(ix, e.position.y - panel.bounds.top(), window, cx)
+                        }
                     }
                     cx.notify();
                 })
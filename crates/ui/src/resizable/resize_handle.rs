@@ -25,6 +25,7 @@ pub(crate) struct ResizeHandle<T: 'static, E: 'static + Render> {
     drag_value: Option<Rc<T>>,
     placement: Option<DockPlacement>,
     on_drag: Option<Rc<dyn Fn(&Point<Pixels>, &mut Window, &mut App) -> Entity<E>>>,
+    on_double_click: Option<Rc<dyn Fn(&mut Window, &mut App)>>,
 }
 
 impl<T: 'static, E: 'static + Render> ResizeHandle<T, E> {
@@ -33,6 +34,7 @@ impl<T: 'static, E: 'static + Render> ResizeHandle<T, E> {
         Self {
             id: id.clone(),
             on_drag: None,
+            on_double_click: None,
             drag_value: None,
             placement: None,
             axis,
@@ -52,6 +54,12 @@ impl<T: 'static, E: 'static + Render> ResizeHandle<T, E> {
         self
     }
 
+    /// Set the callback when the handle is double-clicked, e.g.: to reset the panel size.
+    pub(crate) fn on_double_click(mut self, f: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_double_click = Some(Rc::new(f));
+        self
+    }
+
     pub(crate) fn placement(mut self, placement: DockPlacement) -> Self {
         self.placement = Some(placement);
         self
@@ -196,9 +204,15 @@ impl<T: 'static, E: 'static + Render> Element for ResizeHandle<T, E> {
 
             window.on_mouse_event({
                 let state = state.clone();
-                move |ev: &MouseDownEvent, phase, window, _| {
+                let on_double_click = self.on_double_click.clone();
+                move |ev: &MouseDownEvent, phase, window, cx| {
                     if bounds.contains(&ev.position) && phase.bubble() {
                         state.set_active(true);
+                        if ev.click_count == 2 {
+                            if let Some(on_double_click) = on_double_click.as_ref() {
+                                on_double_click(window, cx);
+                            }
+                        }
                         window.refresh();
                     }
                 }
@@ -1,9 +1,11 @@
 use crate::{
-    ActiveTheme, ElementExt, Placement, StyledExt,
+    ActiveTheme, ElementExt, FocusVisible, Placement, StyledExt, Theme, ThemeMode,
+    animation::UiTestMode,
     dialog::{ANIMATION_DURATION, Dialog},
     focus_trap::FocusTrapManager,
     input::InputState,
     notification::{Notification, NotificationList},
+    profiler::{Profiler, ProfilerOverlay},
     sheet::Sheet,
     tooltip::TooltipOverlay,
     window_border,
@@ -11,17 +13,22 @@ use crate::{
 use gpui::{
     Anchor, AnyView, App, AppContext, Context, DefiniteLength, Entity, FocusHandle,
     InteractiveElement, IntoElement, KeyBinding, ParentElement as _, Pixels, Render,
-    StyleRefinement, Styled, WeakFocusHandle, Window, actions, div, prelude::FluentBuilder as _,
+    StyleRefinement, Styled, Subscription, WeakFocusHandle, Window, actions, div,
+    prelude::FluentBuilder as _,
 };
 use std::{any::TypeId, rc::Rc};
 
-actions!(root, [Tab, TabPrev]);
+actions!(root, [Tab, TabPrev, ZoomIn, ZoomOut, ResetZoom]);
 
 const CONTEXT: &str = "Root";
 pub(crate) fn init(cx: &mut App) {
     cx.bind_keys([
         KeyBinding::new("tab", Tab, Some(CONTEXT)),
         KeyBinding::new("shift-tab", TabPrev, Some(CONTEXT)),
+        KeyBinding::new("secondary-=", ZoomIn, Some(CONTEXT)),
+        KeyBinding::new("secondary-+", ZoomIn, Some(CONTEXT)),
+        KeyBinding::new("secondary--", ZoomOut, Some(CONTEXT)),
+        KeyBinding::new("secondary-0", ResetZoom, Some(CONTEXT)),
     ]);
 }
 
@@ -41,6 +48,7 @@ pub struct Root {
     /// The focus handle that will be restored after a dialog is closed with animation.
     /// Used to handle rapid dialog opening/closing to maintain correct focus chain.
     pending_focus_restore: Option<WeakFocusHandle>,
+    _subscriptions: Vec<Subscription>,
 }
 
 #[derive(Clone)]
@@ -77,6 +85,14 @@ impl ActiveDialog {
 impl Root {
     /// Create a new Root view.
     pub fn new(view: impl Into<AnyView>, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let _subscriptions = vec![cx.observe_window_appearance(window, |_, window, cx| {
+            // Follow the OS appearance if the theme mode is `ThemeMode::System`.
+            if cx.theme().follow_system {
+                Theme::change(ThemeMode::System, Some(window), cx);
+                cx.refresh_windows();
+            }
+        })];
+
         Self {
             style: StyleRefinement::default(),
             view: view.into(),
@@ -88,6 +104,7 @@ impl Root {
             sheet_size: None,
             window_shadow_size: window_border::SHADOW_SIZE,
             pending_focus_restore: None,
+            _subscriptions,
         }
     }
 
@@ -287,8 +304,9 @@ impl Root {
             // Save for new dialogs opened during animation to maintain focus chain
             self.pending_focus_restore = Some(handle.downgrade());
 
+            let delay = UiTestMode::delay(*ANIMATION_DURATION, cx);
             cx.spawn_in(window, async move |this, cx| {
-                cx.background_executor().timer(*ANIMATION_DURATION).await;
+                cx.background_executor().timer(delay).await;
                 let _ = this.update_in(cx, |this, window, cx| {
                     let current_dialogs_count = this.active_dialogs.len();
                     // Only restore focus if no new dialogs were opened during animation
@@ -465,6 +483,18 @@ impl Root {
         // Normal tab navigation
         window.focus_prev(cx);
     }
+
+    fn on_action_zoom_in(&mut self, _: &ZoomIn, _: &mut Window, cx: &mut Context<Self>) {
+        Theme::set_ui_scale(cx.theme().ui_scale + Theme::UI_SCALE_STEP, cx);
+    }
+
+    fn on_action_zoom_out(&mut self, _: &ZoomOut, _: &mut Window, cx: &mut Context<Self>) {
+        Theme::set_ui_scale(cx.theme().ui_scale - Theme::UI_SCALE_STEP, cx);
+    }
+
+    fn on_action_reset_zoom(&mut self, _: &ResetZoom, _: &mut Window, cx: &mut Context<Self>) {
+        Theme::set_ui_scale(1., cx);
+    }
 }
 
 impl Styled for Root {
@@ -475,7 +505,8 @@ impl Styled for Root {
 
 impl Render for Root {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
-        window.set_rem_size(cx.theme().font_size);
+        window.set_rem_size(cx.theme().rem_size());
+        Profiler::begin_frame(window, cx);
 
         window_border().shadow_size(self.window_shadow_size).child(
             div()
@@ -483,6 +514,16 @@ impl Render for Root {
                 .key_context(CONTEXT)
                 .on_action(cx.listener(Self::on_action_tab))
                 .on_action(cx.listener(Self::on_action_tab_prev))
+                .on_action(cx.listener(Self::on_action_zoom_in))
+                .on_action(cx.listener(Self::on_action_zoom_out))
+                .on_action(cx.listener(Self::on_action_reset_zoom))
+                .capture_key_down(|event, window, cx| {
+                    // Only the navigation keys make the focus visible, not the modifiers alone.
+                    if !event.keystroke.modifiers.modified() || event.keystroke.key == "tab" {
+                        FocusVisible::set(true, window, cx);
+                    }
+                })
+                .capture_any_mouse_down(|_, window, cx| FocusVisible::set(false, window, cx))
                 .relative()
                 .size_full()
                 .font_family(cx.theme().font_family.clone())
@@ -490,7 +531,8 @@ impl Render for Root {
                 .text_color(cx.theme().foreground)
                 .refine_style(&self.style)
                 .child(self.view.clone())
-                .child(self.tooltip_overlay.clone()),
+                .child(self.tooltip_overlay.clone())
+                .when(Profiler::is_enabled(cx), |this| this.child(ProfilerOverlay)),
         )
     }
 }
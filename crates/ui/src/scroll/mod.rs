@@ -1,7 +1,9 @@
+mod scroll_sync;
 mod scrollable;
 mod scrollable_mask;
 mod scrollbar;
 
+pub use scroll_sync::*;
 pub use scrollable::*;
 pub use scrollable_mask::*;
 pub use scrollbar::*;
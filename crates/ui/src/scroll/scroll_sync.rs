@@ -0,0 +1,254 @@
+use std::{cell::RefCell, rc::Rc};
+
+use gpui::{InteractiveElement, ParentElement, Pixels, Point, ScrollHandle, point, px};
+
+use crate::{ElementExt as _, scroll::ScrollbarAxis};
+
+/// The mode to sync the scroll offset of the [`ScrollSyncGroup`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScrollSyncMode {
+    /// Scroll all the handles to the same offset, e.g.: the panes with the same line height.
+    #[default]
+    Absolute,
+    /// Scroll all the handles to the same ratio of their max offset, e.g.: a minimap.
+    Proportional,
+}
+
+struct ScrollSyncMember {
+    handle: ScrollHandle,
+    /// The offset after the last sync, to find the handle scrolled by the user.
+    last_offset: Point<Pixels>,
+}
+
+struct ScrollSyncGroupState {
+    axis: ScrollbarAxis,
+    mode: ScrollSyncMode,
+    members: Vec<ScrollSyncMember>,
+}
+
+/// A group to link the scroll offset of multiple [`ScrollHandle`]s, e.g.: the old and new
+/// panes of a side-by-side diff.
+///
+/// When a handle is scrolled by the wheel, the scrollbar or `set_offset`, the other handles
+/// are scrolled to follow it before the next paint, so there is no lag between the panes.
+///
+/// ```ignore
+/// let group = ScrollSyncGroup::new().axis(ScrollbarAxis::Both);
+/// group.add(&left_scroll_handle);
+/// group.add(&right_scroll_handle);
+///
+/// h_flex()
+///     .scroll_sync(&group)
+///     .child(div().id("left").overflow_scroll().track_scroll(&left_scroll_handle))
+///     .child(div().id("right").overflow_scroll().track_scroll(&right_scroll_handle))
+/// ```
+#[derive(Clone)]
+pub struct ScrollSyncGroup(Rc<RefCell<ScrollSyncGroupState>>);
+
+impl ScrollSyncGroup {
+    /// Create a new group to sync the vertical offset absolutely.
+    pub fn new() -> Self {
+        Self(Rc::new(RefCell::new(ScrollSyncGroupState {
+            axis: ScrollbarAxis::Vertical,
+            mode: ScrollSyncMode::default(),
+            members: vec![],
+        })))
+    }
+
+    /// Set the axis to sync, default is vertical.
+    pub fn axis(self, axis: impl Into<ScrollbarAxis>) -> Self {
+        self.0.borrow_mut().axis = axis.into();
+        self
+    }
+
+    /// Set the mode to sync, default is [`ScrollSyncMode::Absolute`].
+    pub fn mode(self, mode: ScrollSyncMode) -> Self {
+        self.0.borrow_mut().mode = mode;
+        self
+    }
+
+    /// Add a scroll handle to the group, it is scrolled to follow the others.
+    pub fn add(&self, handle: &ScrollHandle) {
+        self.0.borrow_mut().members.push(ScrollSyncMember {
+            handle: handle.clone(),
+            last_offset: handle.offset(),
+        });
+    }
+
+    /// Remove all the scroll handles from the group.
+    pub fn clear(&self) {
+        self.0.borrow_mut().members.clear();
+    }
+
+    /// Sync the offset of the handles to the one scrolled since the last sync.
+    ///
+    /// Returns true if any handle is scrolled.
+    ///
+    /// This is called by [`ScrollSyncExt::scroll_sync`], call it manually after scrolling
+    /// a handle out of the element, if the panes are not repainted.
+    pub fn sync(&self) -> bool {
+        let mut state = self.0.borrow_mut();
+        let (axis, mode) = (state.axis, state.mode);
+
+        let Some(leader_ix) = state
+            .members
+            .iter()
+            .position(|member| member.handle.offset() != member.last_offset)
+        else {
+            return false;
+        };
+
+        let leader = &state.members[leader_ix].handle;
+        let (leader_offset, leader_max) = (leader.offset(), leader.max_offset());
+
+        let mut changed = false;
+        for (ix, member) in state.members.iter_mut().enumerate() {
+            if ix != leader_ix {
+                let offset = member.handle.offset();
+                let new_offset = synced_offset(
+                    leader_offset,
+                    leader_max,
+                    offset,
+                    member.handle.max_offset(),
+                    axis,
+                    mode,
+                );
+                if new_offset != offset {
+                    member.handle.set_offset(new_offset);
+                    changed = true;
+                }
+            }
+            member.last_offset = member.handle.offset();
+        }
+        changed
+    }
+}
+
+/// Returns the offset of the target handle to follow the leader handle.
+///
+/// The offsets are negative, from `-max_offset` to `0`.
+fn synced_offset(
+    leader_offset: Point<Pixels>,
+    leader_max: Point<Pixels>,
+    offset: Point<Pixels>,
+    max_offset: Point<Pixels>,
+    axis: ScrollbarAxis,
+    mode: ScrollSyncMode,
+) -> Point<Pixels> {
+    let follow = |leader: Pixels, leader_max: Pixels, max: Pixels| match mode {
+        ScrollSyncMode::Absolute => leader.clamp(-max, px(0.)),
+        ScrollSyncMode::Proportional => {
+            if leader_max <= px(0.) {
+                px(0.)
+            } else {
+                max * (leader / leader_max).clamp(-1., 0.)
+            }
+        }
+    };
+
+    point(
+        if axis.has_horizontal() {
+            follow(leader_offset.x, leader_max.x, max_offset.x)
+        } else {
+            offset.x
+        },
+        if axis.has_vertical() {
+            follow(leader_offset.y, leader_max.y, max_offset.y)
+        } else {
+            offset.y
+        },
+    )
+}
+
+/// A trait to sync the scroll handles of a [`ScrollSyncGroup`] on an element.
+pub trait ScrollSyncExt: InteractiveElement + ParentElement + Sized {
+    /// Sync the scroll handles in the `group`, when the element or its children are scrolled.
+    ///
+    /// Use it on the element that contains all the synced panes, or on each pane.
+    fn scroll_sync(self, group: &ScrollSyncGroup) -> Self {
+        let wheel_group = group.clone();
+        let group = group.clone();
+
+        // The wheel is handled by the scrolled children first in the bubble phase,
+        // so the offset is synced in the same event.
+        self.on_scroll_wheel(move |_, window, _| {
+            if wheel_group.sync() {
+                window.refresh();
+            }
+        })
+        // For the scrollbar and `set_offset`.
+        .on_prepaint(move |_, window, _| {
+            if group.sync() {
+                window.refresh();
+            }
+        })
+    }
+}
+
+impl<T: InteractiveElement + ParentElement + Sized> ScrollSyncExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{point, px};
+
+    use super::{ScrollSyncMode, synced_offset};
+    use crate::scroll::ScrollbarAxis;
+
+    #[test]
+    fn test_synced_offset() {
+        let leader_max = point(px(100.), px(1000.));
+        let max = point(px(50.), px(500.));
+        let offset = point(px(-10.), px(-20.));
+
+        let leader = point(px(-40.), px(-300.));
+        assert_eq!(
+            synced_offset(
+                leader,
+                leader_max,
+                offset,
+                max,
+                ScrollbarAxis::Vertical,
+                ScrollSyncMode::Absolute
+            ),
+            point(px(-10.), px(-300.))
+        );
+        assert_eq!(
+            synced_offset(
+                leader,
+                leader_max,
+                offset,
+                max,
+                ScrollbarAxis::Both,
+                ScrollSyncMode::Proportional
+            ),
+            point(px(-20.), px(-150.))
+        );
+
+        // Clamp to the max offset of the target.
+        let leader = point(px(-80.), px(-800.));
+        assert_eq!(
+            synced_offset(
+                leader,
+                leader_max,
+                offset,
+                max,
+                ScrollbarAxis::Horizontal,
+                ScrollSyncMode::Absolute
+            ),
+            point(px(-50.), px(-20.))
+        );
+
+        // The leader can not be scrolled.
+        assert_eq!(
+            synced_offset(
+                leader,
+                point(px(0.), px(0.)),
+                offset,
+                max,
+                ScrollbarAxis::Both,
+                ScrollSyncMode::Proportional
+            ),
+            point(px(0.), px(0.))
+        );
+    }
+}
@@ -25,6 +25,8 @@ const THUMB_ACTIVE_WIDTH: Pixels = px(8.);
 const THUMB_ACTIVE_RADIUS: Pixels = px(8. / 2.);
 const THUMB_ACTIVE_INSET: Pixels = px(4.);
 
+const MARKER_HEIGHT: Pixels = px(2.);
+
 const FADE_OUT_DURATION: f32 = 3.0;
 const FADE_OUT_DELAY: f32 = 2.0;
 
@@ -302,6 +304,25 @@ impl ScrollbarAxis {
     }
 }
 
+/// A marker on the vertical scrollbar track, e.g.: the find matches or the diff hunks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScrollbarMarker {
+    /// The position of the marker on the track, from 0.0 (top) to 1.0 (bottom).
+    pub position: f32,
+    /// The color of the marker.
+    pub color: Hsla,
+}
+
+impl ScrollbarMarker {
+    /// Create a new marker at the `position` (0.0 to 1.0) of the content.
+    pub fn new(position: f32, color: impl Into<Hsla>) -> Self {
+        Self {
+            position: position.clamp(0., 1.),
+            color: color.into(),
+        }
+    }
+}
+
 /// Scrollbar control for scroll-area or a uniform-list.
 pub struct Scrollbar {
     pub(crate) id: ElementId,
@@ -314,6 +335,7 @@ pub struct Scrollbar {
     /// This is used to limit the update rate of the scrollbar when it is
     /// being dragged for some complex interactions for reducing CPU usage.
     max_fps: usize,
+    markers: Vec<ScrollbarMarker>,
 }
 
 impl Scrollbar {
@@ -330,6 +352,7 @@ impl Scrollbar {
             scroll_handle: Rc::new(scroll_handle.clone()),
             max_fps: 120,
             scroll_size: None,
+            markers: vec![],
         }
     }
 
@@ -373,6 +396,12 @@ impl Scrollbar {
         self
     }
 
+    /// Set the markers to paint on the vertical scrollbar track.
+    pub fn markers(mut self, markers: impl IntoIterator<Item = ScrollbarMarker>) -> Self {
+        self.markers = markers.into_iter().collect();
+        self
+    }
+
     /// Set maximum frames per second for scrolling by drag. Default is 120 FPS.
     ///
     /// If you have very high CPU usage, consider reducing this value to improve performance.
@@ -799,6 +828,20 @@ impl Element for Scrollbar {
                         cx.paint_quad(
                             fill(state.thumb_fill_bounds, state.thumb_bg).corner_radii(radius),
                         );
+
+                        if is_vertical {
+                            for marker in self.markers.iter() {
+                                let top = bounds.top()
+                                    + (bounds.size.height - MARKER_HEIGHT) * marker.position;
+                                cx.paint_quad(fill(
+                                    Bounds::new(
+                                        point(bounds.left() + THUMB_INSET, top),
+                                        size(bounds.size.width - THUMB_INSET * 2., MARKER_HEIGHT),
+                                    ),
+                                    marker.color,
+                                ));
+                            }
+                        }
                     });
 
                     window.on_mouse_event({
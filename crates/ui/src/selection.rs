@@ -0,0 +1,458 @@
+//! A reusable selection model for the lists, it is shared by the rows of the
+//! [`crate::table::Table`], the entries of the [`crate::tree::Tree`], and the lists that render
+//! the items by themselves, e.g.: the virtual lists.
+//!
+//! - Click to select an item, `shift` click to select a range, `cmd` (`ctrl` on Linux and
+//!   Windows) click to toggle an item.
+//! - `up` / `down` to move the selection, `shift-up` / `shift-down` to extend the range,
+//!   `space` to toggle the active item, `enter` to confirm.
+use std::{collections::BTreeSet, ops::RangeInclusive};
+
+use gpui::{
+    App, Context, Entity, EventEmitter, InteractiveElement, KeyBinding, Modifiers, actions,
+};
+
+use crate::{
+    actions::{Cancel, Confirm, SelectDown, SelectFirst, SelectLast, SelectUp},
+    input::SelectAll,
+};
+
+const CONTEXT: &str = "Selection";
+
+actions!(selection, [ExtendUp, ExtendDown, ToggleSelection]);
+
+pub(crate) fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("up", SelectUp, Some(CONTEXT)),
+        KeyBinding::new("down", SelectDown, Some(CONTEXT)),
+        KeyBinding::new("home", SelectFirst, Some(CONTEXT)),
+        KeyBinding::new("end", SelectLast, Some(CONTEXT)),
+        KeyBinding::new("shift-up", ExtendUp, Some(CONTEXT)),
+        KeyBinding::new("shift-down", ExtendDown, Some(CONTEXT)),
+        KeyBinding::new("space", ToggleSelection, Some(CONTEXT)),
+        KeyBinding::new("enter", Confirm { secondary: false }, Some(CONTEXT)),
+        KeyBinding::new("escape", Cancel, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-a", SelectAll, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-a", SelectAll, Some(CONTEXT)),
+    ]);
+}
+
+/// The selection mode of the [`SelectionState`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SelectionMode {
+    /// Only one item can be selected.
+    #[default]
+    Single,
+    /// Multiple items can be selected by `shift` (range) or `cmd` (toggle).
+    Multiple,
+}
+
+impl SelectionMode {
+    /// Returns true if the mode is [`SelectionMode::Multiple`].
+    #[inline]
+    pub fn is_multiple(&self) -> bool {
+        matches!(self, Self::Multiple)
+    }
+}
+
+/// The events emitted by the [`SelectionState`].
+#[derive(Debug, Clone)]
+pub enum SelectionEvent {
+    /// The selected items or the active item is changed.
+    Change,
+    /// The active item is confirmed by `enter`.
+    Confirm(usize),
+}
+
+/// The state of the selected items in a list of `len` items.
+///
+/// The active item is the one moved by the keyboard, and is the end of the range selection.
+pub struct SelectionState {
+    mode: SelectionMode,
+    len: usize,
+    selected: BTreeSet<usize>,
+    /// The start of the range selection.
+    anchor: Option<usize>,
+    active: Option<usize>,
+}
+
+impl EventEmitter<SelectionEvent> for SelectionState {}
+
+impl SelectionState {
+    /// Create a new selection state with the `mode` for `len` items.
+    pub fn new(mode: SelectionMode, len: usize) -> Self {
+        Self {
+            mode,
+            len,
+            selected: BTreeSet::new(),
+            anchor: None,
+            active: None,
+        }
+    }
+
+    /// Returns the selection mode.
+    pub fn mode(&self) -> SelectionMode {
+        self.mode
+    }
+
+    /// Returns the number of the items.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if there is no item.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Set the number of the items, the selected items out of range are removed.
+    pub fn set_len(&mut self, len: usize, cx: &mut Context<Self>) {
+        let count = self.selected.len();
+        let active = self.active;
+        self.set_len_inner(len);
+
+        if count != self.selected.len() || active != self.active {
+            cx.emit(SelectionEvent::Change);
+        }
+        cx.notify();
+    }
+
+    /// Returns the selected indexes in ascending order.
+    pub fn selected_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.selected.iter().copied()
+    }
+
+    /// Returns the number of the selected items.
+    pub fn selected_count(&self) -> usize {
+        self.selected.len()
+    }
+
+    /// Returns true if the item at `ix` is selected.
+    pub fn is_selected(&self, ix: usize) -> bool {
+        self.selected.contains(&ix)
+    }
+
+    /// Returns the index of the active item, e.g.: to scroll it into view.
+    pub fn active_index(&self) -> Option<usize> {
+        self.active
+    }
+
+    /// Returns the index of the active item if it is selected.
+    ///
+    /// This is the selected item in [`SelectionMode::Single`].
+    pub fn selected_active_index(&self) -> Option<usize> {
+        self.active.filter(|ix| self.selected.contains(ix))
+    }
+
+    /// Handle the click on the item at `ix` with the `modifiers` of the click event.
+    pub fn click(&mut self, ix: usize, modifiers: &Modifiers, cx: &mut Context<Self>) {
+        self.update(cx, |this| {
+            this.click_inner(ix, modifiers.shift, modifiers.secondary())
+        });
+    }
+
+    /// Select only the item at `ix`.
+    pub fn select(&mut self, ix: usize, cx: &mut Context<Self>) {
+        self.update(cx, |this| this.click_inner(ix, false, false));
+    }
+
+    /// Select all the items, only for [`SelectionMode::Multiple`].
+    pub fn select_all(&mut self, cx: &mut Context<Self>) {
+        self.update(cx, Self::select_all_inner);
+    }
+
+    /// Clear the selection.
+    pub fn clear(&mut self, cx: &mut Context<Self>) {
+        self.update(cx, |this| {
+            this.selected.clear();
+            this.anchor = None;
+        });
+    }
+
+    /// Move the active item to the next (or previous) one, and extend the range if `extend` is
+    /// true, otherwise select the active item only.
+    pub fn move_active(&mut self, forward: bool, extend: bool, cx: &mut Context<Self>) {
+        self.update(cx, |this| this.move_active_inner(forward, extend));
+    }
+
+    /// Move the active item to the first (or last) one.
+    pub fn move_to_edge(&mut self, last: bool, cx: &mut Context<Self>) {
+        if self.len == 0 {
+            return;
+        }
+
+        let ix = if last { self.len - 1 } else { 0 };
+        self.select(ix, cx);
+    }
+
+    /// Toggle the active item in [`SelectionMode::Multiple`], or select it in
+    /// [`SelectionMode::Single`].
+    pub fn toggle_active(&mut self, cx: &mut Context<Self>) {
+        let Some(ix) = self.active else {
+            return;
+        };
+
+        self.update(cx, |this| {
+            this.click_inner(ix, false, this.mode.is_multiple())
+        });
+    }
+
+    /// Emit [`SelectionEvent::Confirm`] with the active item.
+    pub fn confirm(&mut self, cx: &mut Context<Self>) {
+        if let Some(ix) = self.active {
+            cx.emit(SelectionEvent::Confirm(ix));
+        }
+    }
+
+    /// Run `f` and emit [`SelectionEvent::Change`] if the selection is changed.
+    fn update(&mut self, cx: &mut Context<Self>, f: impl FnOnce(&mut Self)) {
+        let selected = self.selected.clone();
+        let active = self.active;
+        f(self);
+
+        if self.selected != selected || self.active != active {
+            cx.emit(SelectionEvent::Change);
+            cx.notify();
+        }
+    }
+
+    pub(crate) fn set_len_inner(&mut self, len: usize) {
+        self.len = len;
+        self.selected.retain(|&ix| ix < len);
+        self.anchor = self.anchor.filter(|&ix| ix < len);
+        self.active = self.active.filter(|&ix| ix < len);
+    }
+
+    /// Clear the selection and the active item, and set the number of the items.
+    pub(crate) fn reset(&mut self, len: usize) {
+        self.len = len;
+        self.selected.clear();
+        self.anchor = None;
+        self.active = None;
+    }
+
+    /// Move the selection to the new indexes after the items are rebuilt, e.g.: a tree folder
+    /// is collapsed, the items mapped to `None` are unselected.
+    pub(crate) fn remap(&mut self, len: usize, f: impl Fn(usize) -> Option<usize>) {
+        let map = |ix: usize| f(ix).filter(|&ix| ix < len);
+        self.len = len;
+        self.selected = self.selected.iter().filter_map(|&ix| map(ix)).collect();
+        self.anchor = self.anchor.and_then(map);
+        self.active = self.active.and_then(map);
+    }
+
+    pub(crate) fn click_inner(&mut self, ix: usize, shift: bool, secondary: bool) {
+        if ix >= self.len {
+            return;
+        }
+
+        self.active = Some(ix);
+        if !self.mode.is_multiple() {
+            self.selected = BTreeSet::from([ix]);
+            self.anchor = Some(ix);
+            return;
+        }
+
+        match (shift, secondary, self.anchor) {
+            (true, _, Some(anchor)) => {
+                // `cmd-shift` to add the range to the selection.
+                if !secondary {
+                    self.selected.clear();
+                }
+                self.selected.extend(range_between(anchor, ix));
+            }
+            (_, true, _) => {
+                if !self.selected.remove(&ix) {
+                    self.selected.insert(ix);
+                }
+                self.anchor = Some(ix);
+            }
+            _ => {
+                self.selected = BTreeSet::from([ix]);
+                self.anchor = Some(ix);
+            }
+        }
+    }
+
+    fn select_all_inner(&mut self) {
+        if !self.mode.is_multiple() || self.len == 0 {
+            return;
+        }
+
+        self.selected = (0..self.len).collect();
+        self.anchor = Some(0);
+        self.active = Some(self.active.unwrap_or(self.len - 1));
+    }
+
+    fn move_active_inner(&mut self, forward: bool, extend: bool) {
+        if self.len == 0 {
+            return;
+        }
+
+        let ix = match (self.active, forward) {
+            (None, true) => 0,
+            (None, false) => self.len - 1,
+            (Some(ix), true) => (ix + 1).min(self.len - 1),
+            (Some(ix), false) => ix.saturating_sub(1),
+        };
+
+        self.click_inner(ix, extend, false);
+    }
+}
+
+/// Returns the inclusive range between `a` and `b` in any order.
+fn range_between(a: usize, b: usize) -> RangeInclusive<usize> {
+    a.min(b)..=a.max(b)
+}
+
+/// An extension trait to handle the keyboard actions of the [`SelectionState`].
+pub trait SelectionExt: InteractiveElement + Sized {
+    /// Handle the keyboard actions to change the selection of the `state`.
+    ///
+    /// NOTE: This sets the key context of the element to `Selection`, the element should track
+    /// a focus handle to receive the actions.
+    fn selection(self, state: &Entity<SelectionState>) -> Self {
+        let update =
+            |state: &Entity<SelectionState>,
+             f: fn(&mut SelectionState, &mut Context<SelectionState>)| {
+                let state = state.clone();
+                move |cx: &mut App| state.update(cx, f)
+            };
+
+        let up = update(state, |this, cx| this.move_active(false, false, cx));
+        let down = update(state, |this, cx| this.move_active(true, false, cx));
+        let extend_up = update(state, |this, cx| this.move_active(false, true, cx));
+        let extend_down = update(state, |this, cx| this.move_active(true, true, cx));
+        let first = update(state, |this, cx| this.move_to_edge(false, cx));
+        let last = update(state, |this, cx| this.move_to_edge(true, cx));
+        let toggle = update(state, |this, cx| this.toggle_active(cx));
+        let select_all = update(state, |this, cx| this.select_all(cx));
+        let clear = update(state, |this, cx| this.clear(cx));
+        let confirm = update(state, |this, cx| this.confirm(cx));
+        let cancel_state = state.clone();
+
+        self.key_context(CONTEXT)
+            .on_action(move |_: &SelectUp, _, cx| up(cx))
+            .on_action(move |_: &SelectDown, _, cx| down(cx))
+            .on_action(move |_: &ExtendUp, _, cx| extend_up(cx))
+            .on_action(move |_: &ExtendDown, _, cx| extend_down(cx))
+            .on_action(move |_: &SelectFirst, _, cx| first(cx))
+            .on_action(move |_: &SelectLast, _, cx| last(cx))
+            .on_action(move |_: &ToggleSelection, _, cx| toggle(cx))
+            .on_action(move |_: &SelectAll, _, cx| select_all(cx))
+            .on_action(move |_: &Cancel, _, cx| {
+                // Let the dialogs or popovers above close by `escape` if nothing to clear.
+                if cancel_state.read(cx).selected_count() == 0 {
+                    cx.propagate();
+                    return;
+                }
+                clear(cx)
+            })
+            .on_action(move |_: &Confirm, _, cx| confirm(cx))
+    }
+}
+
+impl<T: InteractiveElement + Sized> SelectionExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::{SelectionMode, SelectionState};
+
+    fn selected(state: &SelectionState) -> Vec<usize> {
+        state.selected_indices().collect()
+    }
+
+    #[test]
+    fn test_single_selection() {
+        let mut state = SelectionState::new(SelectionMode::Single, 5);
+        state.click_inner(1, false, false);
+        assert_eq!(selected(&state), vec![1]);
+        // The modifiers are ignored in single mode.
+        state.click_inner(3, true, true);
+        assert_eq!(selected(&state), vec![3]);
+        state.select_all_inner();
+        assert_eq!(selected(&state), vec![3]);
+        // Out of range.
+        state.click_inner(5, false, false);
+        assert_eq!(selected(&state), vec![3]);
+        assert_eq!(state.active_index(), Some(3));
+    }
+
+    #[test]
+    fn test_multiple_selection() {
+        let mut state = SelectionState::new(SelectionMode::Multiple, 10);
+        state.click_inner(2, false, false);
+        state.click_inner(5, true, false);
+        assert_eq!(selected(&state), vec![2, 3, 4, 5]);
+        // The range is from the anchor.
+        state.click_inner(0, true, false);
+        assert_eq!(selected(&state), vec![0, 1, 2]);
+        // Toggle.
+        state.click_inner(7, false, true);
+        assert_eq!(selected(&state), vec![0, 1, 2, 7]);
+        state.click_inner(1, false, true);
+        assert_eq!(selected(&state), vec![0, 2, 7]);
+        // Add a range from the new anchor.
+        state.click_inner(9, true, true);
+        assert_eq!(selected(&state), vec![0, 2, 7, 8, 9]);
+        state.click_inner(4, false, false);
+        assert_eq!(selected(&state), vec![4]);
+        state.select_all_inner();
+        assert_eq!(selected(&state), (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_move_active() {
+        let mut state = SelectionState::new(SelectionMode::Multiple, 5);
+        state.move_active_inner(true, false);
+        assert_eq!(selected(&state), vec![0]);
+        state.move_active_inner(true, true);
+        state.move_active_inner(true, true);
+        assert_eq!(selected(&state), vec![0, 1, 2]);
+        assert_eq!(state.active_index(), Some(2));
+        state.move_active_inner(false, true);
+        assert_eq!(selected(&state), vec![0, 1]);
+        state.move_active_inner(false, false);
+        state.move_active_inner(false, false);
+        assert_eq!(selected(&state), vec![0]);
+
+        let mut state = SelectionState::new(SelectionMode::Multiple, 5);
+        state.move_active_inner(false, false);
+        assert_eq!(selected(&state), vec![4]);
+        state.move_active_inner(true, false);
+        assert_eq!(selected(&state), vec![4]);
+
+        let mut state = SelectionState::new(SelectionMode::Multiple, 0);
+        state.move_active_inner(true, false);
+        assert_eq!(state.active_index(), None);
+    }
+
+    #[test]
+    fn test_remap() {
+        let mut state = SelectionState::new(SelectionMode::Multiple, 6);
+        state.click_inner(1, false, false);
+        state.click_inner(3, false, true);
+        state.click_inner(4, false, true);
+        assert_eq!(state.selected_active_index(), Some(4));
+
+        // The item at 2 is removed, and the ones after it are moved up.
+        state.remap(5, |ix| match ix {
+            2 => None,
+            ix if ix > 2 => Some(ix - 1),
+            ix => Some(ix),
+        });
+        assert_eq!(selected(&state), vec![1, 2, 3]);
+        assert_eq!(state.active_index(), Some(3));
+
+        state.remap(5, |ix| if ix == 3 { None } else { Some(ix) });
+        assert_eq!(selected(&state), vec![1, 2]);
+        assert_eq!(state.active_index(), None);
+        assert_eq!(state.selected_active_index(), None);
+
+        state.reset(3);
+        assert_eq!(state.selected_count(), 0);
+        assert_eq!(state.len(), 3);
+    }
+}
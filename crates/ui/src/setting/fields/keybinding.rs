@@ -0,0 +1,98 @@
+use std::rc::Rc;
+
+use gpui::{
+    AnyElement, App, FocusHandle, InteractiveElement as _, IntoElement, KeyDownEvent, Keystroke,
+    ParentElement as _, SharedString, StatefulInteractiveElement as _, StyleRefinement, Styled,
+    Window, div, prelude::FluentBuilder as _,
+};
+use rust_i18n::t;
+
+use crate::{
+    ActiveTheme as _, StyledExt,
+    kbd::Kbd,
+    setting::{
+        AnySettingField, RenderOptions,
+        fields::{SettingFieldRender, get_value, set_value},
+    },
+};
+
+/// Record the keystroke pressed when focused, `escape` to cancel, `backspace` to clear.
+pub(crate) struct KeyBindingField;
+
+impl SettingFieldRender for KeyBindingField {
+    fn render(
+        &self,
+        field: Rc<dyn AnySettingField>,
+        options: &RenderOptions,
+        style: &StyleRefinement,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> AnyElement {
+        let value = get_value::<SharedString>(&field, cx);
+        let set_value = set_value::<SharedString>(&field, cx);
+
+        let focus_handle = window
+            .use_keyed_state(
+                SharedString::from(format!(
+                    "keybinding-state-{}-{}-{}",
+                    options.page_ix, options.group_ix, options.item_ix
+                )),
+                cx,
+                |_, cx| cx.focus_handle(),
+            )
+            .read(cx)
+            .clone();
+        let recording = focus_handle.is_focused(window);
+        let keystrokes = value
+            .split_whitespace()
+            .map(Keystroke::parse)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_or_default();
+
+        div()
+            .id("keybinding")
+            .track_focus(&focus_handle)
+            .flex()
+            .items_center()
+            .h_8()
+            .min_w_32()
+            .px_2()
+            .border_1()
+            .border_color(if recording {
+                cx.theme().ring
+            } else {
+                cx.theme().input
+            })
+            .rounded(cx.theme().radius)
+            .bg(cx.theme().background)
+            .text_sm()
+            .cursor_pointer()
+            .refine_style(style)
+            .on_click({
+                let focus_handle = focus_handle.clone();
+                move |_, window, cx| focus_handle.focus(window, cx)
+            })
+            .on_key_down(move |event: &KeyDownEvent, window, cx| {
+                match event.keystroke.key.as_str() {
+                    "escape" => {}
+                    "backspace" => set_value(SharedString::default(), cx),
+                    _ => set_value(event.keystroke.unparse().into(), cx),
+                }
+                window.blur();
+                window.prevent_default();
+                cx.stop_propagation();
+            })
+            .map(|this| {
+                if recording {
+                    this.text_color(cx.theme().muted_foreground)
+                        .child(t!("Settings.press_keys"))
+                } else if keystrokes.is_empty() {
+                    this.text_color(cx.theme().muted_foreground)
+                        .child(t!("Settings.unbound"))
+                } else {
+                    this.child(Kbd::with_keystrokes(keystrokes).appearance(false))
+                }
+            })
+            .into_any_element()
+    }
+}
@@ -1,13 +1,17 @@
 mod bool;
 mod dropdown;
 mod element;
+mod keybinding;
 mod number;
+mod slider;
 mod string;
 
 pub(crate) use bool::*;
 pub(crate) use dropdown::*;
 pub(crate) use element::*;
+pub(crate) use keybinding::*;
 pub(crate) use number::*;
+pub(crate) use slider::*;
 pub(crate) use string::*;
 
 pub use element::SettingFieldElement;
@@ -57,7 +61,11 @@ pub enum SettingFieldType {
     NumberInput {
         options: NumberFieldOptions,
     },
+    Slider {
+        options: NumberFieldOptions,
+    },
     Input,
+    KeyBinding,
     Dropdown {
         options: Vec<(SharedString, SharedString)>,
         scrollable: bool,
@@ -78,11 +86,21 @@ impl SettingFieldType {
         matches!(self, SettingFieldType::NumberInput { .. })
     }
 
+    #[inline]
+    pub(crate) fn is_slider(&self) -> bool {
+        matches!(self, SettingFieldType::Slider { .. })
+    }
+
     #[inline]
     pub(crate) fn is_input(&self) -> bool {
         matches!(self, SettingFieldType::Input)
     }
 
+    #[inline]
+    pub(crate) fn is_keybinding(&self) -> bool {
+        matches!(self, SettingFieldType::KeyBinding)
+    }
+
     #[inline]
     pub(crate) fn is_dropdown(&self) -> bool {
         matches!(self, SettingFieldType::Dropdown { .. })
@@ -112,7 +130,9 @@ impl SettingFieldType {
     #[inline]
     pub(super) fn number_input_options(&self) -> Option<&NumberFieldOptions> {
         match self {
-            SettingFieldType::NumberInput { options } => Some(options),
+            SettingFieldType::NumberInput { options } | SettingFieldType::Slider { options } => {
+                Some(options)
+            }
             _ => None,
         }
     }
@@ -167,6 +187,17 @@ impl SettingField<SharedString> {
         Self::new(SettingFieldType::Input, value, set_value)
     }
 
+    /// Create a new KeyBinding field to record a keystroke, e.g.: `cmd-shift-p`.
+    ///
+    /// Click the field and press the keys to record, `escape` to cancel, `backspace` to clear.
+    pub fn keybinding<V, S>(value: V, set_value: S) -> Self
+    where
+        V: Fn(&App) -> SharedString + 'static,
+        S: Fn(SharedString, &mut App) + 'static,
+    {
+        Self::new(SettingFieldType::KeyBinding, value, set_value)
+    }
+
     /// Create a new Dropdown field with the given options.
     ///
     /// The popup menu does not scroll. For long option lists that may exceed
@@ -253,6 +284,15 @@ impl SettingField<f64> {
     {
         Self::new(SettingFieldType::NumberInput { options }, value, set_value)
     }
+
+    /// Create a new Slider field with the given options.
+    pub fn slider<V, S>(options: NumberFieldOptions, value: V, set_value: S) -> Self
+    where
+        V: Fn(&App) -> f64 + 'static,
+        S: Fn(f64, &mut App) + 'static,
+    {
+        Self::new(SettingFieldType::Slider { options }, value, set_value)
+    }
 }
 
 impl<T> SettingField<T> {
@@ -0,0 +1,127 @@
+use std::rc::Rc;
+
+use gpui::{
+    AnyElement, App, AppContext as _, Entity, IntoElement, ParentElement as _, SharedString,
+    StyleRefinement, Styled, Subscription, Window, div, prelude::FluentBuilder as _,
+};
+
+use crate::{
+    ActiveTheme as _, AxisExt, StyledExt, h_flex,
+    setting::{
+        AnySettingField, NumberFieldOptions, RenderOptions,
+        fields::{SettingFieldRender, get_value, set_value},
+    },
+    slider::{Slider, SliderEvent, SliderState},
+};
+
+pub(crate) struct SliderField {
+    options: NumberFieldOptions,
+}
+
+impl SliderField {
+    pub(crate) fn new(options: Option<&NumberFieldOptions>) -> Self {
+        Self {
+            options: options.cloned().unwrap_or_default(),
+        }
+    }
+}
+
+/// Format the `value` with the decimal places of the `step`, e.g.: `0.3` for the step `0.1`.
+///
+/// The value is stored as `f32` by the slider, so it is not exact, e.g.: `0.30000001192092896`.
+fn format_value(value: f64, step: f64) -> String {
+    let decimals = step
+        .to_string()
+        .split_once('.')
+        .map_or(0, |(_, decimals)| decimals.len().min(6));
+    format!("{:.*}", decimals, value)
+}
+
+struct State {
+    slider: Entity<SliderState>,
+    _subscription: Subscription,
+}
+
+impl SettingFieldRender for SliderField {
+    fn render(
+        &self,
+        field: Rc<dyn AnySettingField>,
+        options: &RenderOptions,
+        style: &StyleRefinement,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> AnyElement {
+        let value = get_value::<f64>(&field, cx);
+        let set_value = set_value::<f64>(&field, cx);
+        let slider_options = self.options.clone();
+
+        let slider = window
+            .use_keyed_state(
+                SharedString::from(format!(
+                    "slider-state-{}-{}-{}",
+                    options.page_ix, options.group_ix, options.item_ix
+                )),
+                cx,
+                |_, cx| {
+                    let slider = cx.new(|_| {
+                        SliderState::new()
+                            .min(slider_options.min as f32)
+                            .max(slider_options.max as f32)
+                            .step(slider_options.step as f32)
+                            .default_value(value as f32)
+                    });
+                    let _subscription = cx.subscribe(&slider, move |_, _, event, cx| {
+                        let SliderEvent::Change(value) = event;
+                        set_value(value.start() as f64, cx);
+                    });
+
+                    State {
+                        slider,
+                        _subscription,
+                    }
+                },
+            )
+            .read(cx)
+            .slider
+            .clone();
+
+        // Sync the value changed outside, e.g.: reset to default.
+        if slider.read(cx).value().start() != value as f32 {
+            slider.update(cx, |slider, cx| slider.set_value(value as f32, window, cx));
+        }
+
+        h_flex()
+            .gap_3()
+            .map(|this| {
+                if options.layout.is_horizontal() {
+                    this.w_48()
+                } else {
+                    this.w_full()
+                }
+            })
+            .refine_style(style)
+            .child(Slider::new(&slider).flex_1())
+            .child(
+                div()
+                    .min_w_8()
+                    .text_sm()
+                    .text_right()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(format_value(value, self.options.step)),
+            )
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_value;
+
+    #[test]
+    fn test_format_value() {
+        assert_eq!(format_value(0.3f32 as f64, 0.1), "0.3");
+        assert_eq!(format_value(0.25f32 as f64, 0.05), "0.25");
+        assert_eq!(format_value(14., 1.), "14");
+        assert_eq!(format_value(13.6, 2.), "14");
+    }
+}
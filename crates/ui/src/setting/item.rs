@@ -9,7 +9,10 @@ use crate::{
     label::Label,
     setting::{
         AnySettingField, ElementField, RenderOptions,
-        fields::{BoolField, DropdownField, NumberField, SettingFieldRender, StringField},
+        fields::{
+            BoolField, DropdownField, KeyBindingField, NumberField, SettingFieldRender,
+            SliderField, StringField,
+        },
     },
     text::Text,
     v_flex,
@@ -131,6 +134,12 @@ impl SettingItem {
             t if t == TypeId::of::<f64>() && field_type.is_number_input() => {
                 Box::new(NumberField::new(field_type.number_input_options()))
             }
+            t if t == TypeId::of::<f64>() && field_type.is_slider() => {
+                Box::new(SliderField::new(field_type.number_input_options()))
+            }
+            t if t == TypeId::of::<SharedString>() && field_type.is_keybinding() => {
+                Box::new(KeyBindingField)
+            }
             t if t == TypeId::of::<SharedString>() && field_type.is_input() => {
                 Box::new(StringField::<SharedString>::new())
             }
@@ -3,9 +3,11 @@ mod group;
 mod item;
 mod page;
 mod settings;
+mod store;
 
 pub use fields::*;
 pub use group::*;
 pub use item::*;
 pub use page::*;
 pub use settings::*;
+pub use store::*;
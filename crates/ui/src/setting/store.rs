@@ -0,0 +1,206 @@
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use gpui::{App, Context, Entity, EventEmitter, Task};
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::animation::UiTestMode;
+
+/// The delay to save the changes, e.g.: dragging a slider changes the value on each frame.
+const SAVE_DELAY: Duration = Duration::from_millis(300);
+
+/// Events emitted by the [`SettingsStore`].
+#[derive(Debug, Clone)]
+pub enum SettingsStoreEvent {
+    /// The settings value has been changed.
+    Changed,
+}
+
+/// A store of a serializable settings struct, to bind the setting fields and persist as JSON.
+///
+/// ```ignore
+/// #[derive(Clone, Default, Serialize, Deserialize)]
+/// struct AppSettings {
+///     dark_mode: bool,
+///     font_size: f64,
+/// }
+///
+/// let store = cx.new(|_| SettingsStore::<AppSettings>::load("settings.json"));
+///
+/// SettingItem::new(
+///     "Dark Mode",
+///     SettingField::switch(
+///         SettingsStore::getter(&store, |settings| settings.dark_mode),
+///         SettingsStore::setter(&store, |settings, value| settings.dark_mode = value),
+///     ),
+/// )
+/// ```
+pub struct SettingsStore<T> {
+    value: T,
+    path: Option<PathBuf>,
+    _save_task: Option<Task<()>>,
+}
+
+impl<T: 'static> EventEmitter<SettingsStoreEvent> for SettingsStore<T> {}
+
+impl<T> SettingsStore<T>
+where
+    T: Serialize + DeserializeOwned + Default + 'static,
+{
+    /// Create a new store with the given value, it is not persisted.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            path: None,
+            _save_task: None,
+        }
+    }
+
+    /// Load the settings from the JSON file at `path`, and save to it when changed.
+    ///
+    /// The default value is used if the file does not exist or is invalid.
+    pub fn load(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let value = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| match serde_json::from_str(&content) {
+                Ok(value) => Some(value),
+                Err(err) => {
+                    tracing::error!("failed to parse settings {}: {}", path.display(), err);
+                    None
+                }
+            })
+            .unwrap_or_default();
+
+        Self {
+            value,
+            path: Some(path),
+            _save_task: None,
+        }
+    }
+
+    /// Returns the settings value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the path of the JSON file to persist.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Update the settings value, emits [`SettingsStoreEvent::Changed`] and saves to the file.
+    ///
+    /// The file is saved in the background once the changes are idle for a moment (300ms),
+    /// call [`SettingsStore::save`] to save immediately, e.g.: before quitting the app.
+    pub fn update(&mut self, f: impl FnOnce(&mut T), cx: &mut Context<Self>) {
+        f(&mut self.value);
+        self.save_later(cx);
+        cx.emit(SettingsStoreEvent::Changed);
+        cx.notify();
+    }
+
+    fn save_later(&mut self, cx: &mut Context<Self>) {
+        let Some(path) = self.path.clone() else {
+            return;
+        };
+
+        let delay = UiTestMode::delay(SAVE_DELAY, cx);
+        self._save_task = Some(cx.spawn(async move |this, cx| {
+            cx.background_executor().timer(delay).await;
+            let Ok(content) =
+                this.read_with(cx, |this, _| serde_json::to_string_pretty(&this.value))
+            else {
+                return;
+            };
+
+            let result = cx
+                .background_spawn(async move { write_settings(&path, &content?) })
+                .await;
+            if let Err(err) = result {
+                tracing::error!("failed to save settings: {}", err);
+            }
+        }));
+    }
+
+    /// Save the settings value to the JSON file, do nothing if the store has no path.
+    pub fn save(&self) -> anyhow::Result<()> {
+        let Some(path) = self.path.as_ref() else {
+            return Ok(());
+        };
+
+        write_settings(path, &serde_json::to_string_pretty(&self.value)?)
+    }
+
+    /// Returns a getter to read a field of the settings, for the `value` of a [`super::SettingField`].
+    pub fn getter<V>(
+        store: &Entity<Self>,
+        get: impl Fn(&T) -> V + 'static,
+    ) -> impl Fn(&App) -> V + 'static {
+        let store = store.clone();
+        move |cx| get(store.read(cx).value())
+    }
+
+    /// Returns a setter to update a field of the settings, for the `set_value` of a [`super::SettingField`].
+    pub fn setter<V>(
+        store: &Entity<Self>,
+        set: impl Fn(&mut T, V) + 'static,
+    ) -> impl Fn(V, &mut App) + 'static {
+        let store = store.clone();
+        move |value, cx| store.update(cx, |store, cx| store.update(|v| set(v, value), cx))
+    }
+}
+
+fn write_settings(path: &Path, content: &str) -> anyhow::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, content)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{AppContext as _, TestAppContext};
+    use serde::{Deserialize, Serialize};
+
+    use super::{SAVE_DELAY, SettingsStore};
+
+    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+    struct TestSettings {
+        dark_mode: bool,
+        font_size: f64,
+    }
+
+    #[gpui::test]
+    fn test_settings_store(cx: &mut TestAppContext) {
+        let path = std::env::temp_dir().join(format!(
+            "gpui-component-settings-{}.json",
+            std::process::id()
+        ));
+        _ = std::fs::remove_file(&path);
+
+        let store = cx.new(|_| SettingsStore::<TestSettings>::load(path.clone()));
+        cx.update(|cx| {
+            assert_eq!(store.read(cx).value(), &TestSettings::default());
+
+            let get = SettingsStore::getter(&store, |settings| settings.font_size);
+            let set = SettingsStore::setter(&store, |settings, value| settings.font_size = value);
+            set(12., cx);
+            set(14., cx);
+            assert_eq!(get(cx), 14.);
+        });
+
+        // The changes are saved after the delay.
+        cx.run_until_parked();
+        assert!(!path.exists());
+        cx.executor().advance_clock(SAVE_DELAY);
+        cx.run_until_parked();
+
+        let loaded = cx.new(|_| SettingsStore::<TestSettings>::load(path.clone()));
+        loaded.read_with(cx, |store, _| assert_eq!(store.value().font_size, 14.));
+        _ = std::fs::remove_file(&path);
+    }
+}
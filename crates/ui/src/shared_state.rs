@@ -0,0 +1,162 @@
+//! Share the entities and events between the windows of an application.
+//!
+//! The entities in GPUI belong to the [`App`], not the window, so a window can use the entity
+//! created in another window. The problem is to find it, this module keeps the shared entities
+//! by their type, and provides [`Broadcast`] to send the events to all windows.
+use std::{any::TypeId, collections::HashMap, marker::PhantomData};
+
+use gpui::{AnyEntity, App, AppContext as _, Context, Entity, EventEmitter, Global, Window};
+
+#[derive(Default)]
+struct SharedEntities(HashMap<TypeId, AnyEntity>);
+
+impl Global for SharedEntities {}
+
+/// A channel to broadcast the events of type `E` to all the windows.
+///
+/// There is only one channel for each event type, get it by [`Broadcast::channel`] and
+/// subscribe it in the views of each window.
+///
+/// ```ignore
+/// #[derive(Clone)]
+/// struct SessionChanged(SharedString);
+///
+/// // In the view of each window.
+/// let channel = Broadcast::<SessionChanged>::channel(cx);
+/// cx.subscribe_in(&channel, window, |this, _, event: &SessionChanged, window, cx| {
+///     this.session_id = event.0.clone();
+///     cx.notify();
+/// })
+/// .detach();
+///
+/// // Anywhere in the app.
+/// cx.broadcast(SessionChanged("new-session".into()));
+/// ```
+pub struct Broadcast<E: 'static> {
+    _event: PhantomData<E>,
+}
+
+impl<E: 'static> EventEmitter<E> for Broadcast<E> {}
+
+impl<E: 'static> Broadcast<E> {
+    /// Returns the channel of the event type `E`, it is created at the first call.
+    pub fn channel(cx: &mut App) -> Entity<Self> {
+        cx.shared_entity(|_| Self {
+            _event: PhantomData,
+        })
+    }
+}
+
+/// Extension trait for [`App`] to share the entities and events between windows.
+pub trait SharedStateExt {
+    /// Returns the shared entity of type `T`, or create it by `build` if not exists.
+    ///
+    /// The entity is kept alive until [`SharedStateExt::remove_shared_entity`] is called,
+    /// so the windows opened later get the same entity, e.g.: the session store.
+    fn shared_entity<T: 'static>(&mut self, build: impl FnOnce(&mut Context<T>) -> T) -> Entity<T>;
+
+    /// Returns the shared entity of type `T`, if it has been created.
+    fn try_shared_entity<T: 'static>(&self) -> Option<Entity<T>>;
+
+    /// Set the shared entity of type `T`, replacing the previous one.
+    fn set_shared_entity<T: 'static>(&mut self, entity: Entity<T>);
+
+    /// Remove the shared entity of type `T`, returns the removed entity.
+    fn remove_shared_entity<T: 'static>(&mut self) -> Option<Entity<T>>;
+
+    /// Emit the `event` to the subscribers of the [`Broadcast<E>`] channel in all windows.
+    fn broadcast<E: 'static>(&mut self, event: E);
+
+    /// Run the `f` in each opened window, e.g.: to push a notification to all windows.
+    ///
+    /// The window that can not be updated (e.g.: it is being updated) is skipped.
+    fn update_windows(&mut self, f: impl FnMut(&mut Window, &mut App));
+}
+
+impl SharedStateExt for App {
+    fn shared_entity<T: 'static>(&mut self, build: impl FnOnce(&mut Context<T>) -> T) -> Entity<T> {
+        if let Some(entity) = self.try_shared_entity::<T>() {
+            return entity;
+        }
+
+        let entity = self.new(build);
+        self.set_shared_entity(entity.clone());
+        entity
+    }
+
+    fn try_shared_entity<T: 'static>(&self) -> Option<Entity<T>> {
+        self.try_global::<SharedEntities>()?
+            .0
+            .get(&TypeId::of::<T>())
+            .and_then(|entity| entity.clone().downcast::<T>().ok())
+    }
+
+    fn set_shared_entity<T: 'static>(&mut self, entity: Entity<T>) {
+        self.default_global::<SharedEntities>()
+            .0
+            .insert(TypeId::of::<T>(), entity.into_any());
+    }
+
+    fn remove_shared_entity<T: 'static>(&mut self) -> Option<Entity<T>> {
+        self.default_global::<SharedEntities>()
+            .0
+            .remove(&TypeId::of::<T>())
+            .and_then(|entity| entity.downcast::<T>().ok())
+    }
+
+    fn broadcast<E: 'static>(&mut self, event: E) {
+        Broadcast::<E>::channel(self).update(self, |_, cx| cx.emit(event));
+    }
+
+    fn update_windows(&mut self, mut f: impl FnMut(&mut Window, &mut App)) {
+        for handle in self.windows() {
+            _ = handle.update(self, |_, window, cx| f(window, cx));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use gpui::TestAppContext;
+
+    use super::{Broadcast, SharedStateExt as _};
+
+    struct Store(usize);
+
+    #[gpui::test]
+    fn test_shared_entity(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            assert!(cx.try_shared_entity::<Store>().is_none());
+
+            let store = cx.shared_entity(|_| Store(1));
+            let same = cx.shared_entity(|_| Store(2));
+            assert_eq!(store, same);
+            assert_eq!(same.read(cx).0, 1);
+
+            let removed = cx.remove_shared_entity::<Store>();
+            assert_eq!(removed, Some(store));
+            assert!(cx.try_shared_entity::<Store>().is_none());
+        });
+    }
+
+    #[gpui::test]
+    fn test_broadcast(cx: &mut TestAppContext) {
+        let received = Rc::new(RefCell::new(vec![]));
+
+        cx.update(|cx| {
+            let channel = Broadcast::<usize>::channel(cx);
+            let received = received.clone();
+            cx.subscribe(&channel, move |_, event: &usize, _| {
+                received.borrow_mut().push(*event);
+            })
+            .detach();
+
+            cx.broadcast(1usize);
+            cx.broadcast(2usize);
+        });
+
+        assert_eq!(*received.borrow(), vec![1, 2]);
+    }
+}
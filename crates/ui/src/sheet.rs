@@ -13,6 +13,7 @@ use crate::{
     ActiveTheme, FocusTrapElement as _, IconName, Placement, Sizable, StyledExt as _,
     WindowExt as _,
     actions::Cancel,
+    animation::motion_duration,
     button::{Button, ButtonVariants as _},
     dialog::overlay_color,
     h_flex,
@@ -274,7 +275,7 @@ impl RenderOnce for Sheet {
                             })
                             .with_animation(
                                 "slide",
-                                Animation::new(Duration::from_secs_f64(0.15)),
+                                Animation::new(motion_duration(Duration::from_secs_f64(0.15), cx)),
                                 move |this, delta| {
                                     let y = px(-100.) + delta * px(100.);
                                     this.map(|this| match placement {
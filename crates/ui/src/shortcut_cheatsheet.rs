@@ -0,0 +1,352 @@
+use std::rc::Rc;
+
+use gpui::{
+    App, AppContext as _, Context, Entity, FocusHandle, Focusable, IntoElement, KeyBinding,
+    Keystroke, ParentElement as _, RenderOnce, SharedString, StyleRefinement, Styled, Subscription,
+    Window, div, is_no_action, prelude::FluentBuilder as _, px,
+};
+use rust_i18n::t;
+
+use crate::{
+    ActiveTheme as _, IconName, StyledExt as _, WindowExt as _,
+    command_palette::{fuzzy_match, humanize_action_name},
+    h_flex,
+    input::{Input, InputEvent, InputState},
+    kbd::Kbd,
+    scroll::ScrollableElement as _,
+    v_flex,
+};
+
+/// A keyboard shortcut in the [`ShortcutCheatsheet`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shortcut {
+    /// The humanized name of the action, e.g.: `Editor: Move Up`.
+    pub title: SharedString,
+    /// The keystrokes to trigger the action.
+    pub keystrokes: Vec<Keystroke>,
+}
+
+/// The shortcuts in a key context, e.g.: `Editor` or `Input && !readonly`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShortcutGroup {
+    /// The key context predicate, empty for the global shortcuts.
+    pub context: SharedString,
+    pub shortcuts: Vec<Shortcut>,
+}
+
+/// Group the `(context, shortcut)` pairs by context, the global shortcuts (empty context) first.
+///
+/// The duplicated shortcuts in the same context are removed, the order in the group is kept.
+fn group_shortcuts(
+    shortcuts: impl IntoIterator<Item = (SharedString, Shortcut)>,
+) -> Vec<ShortcutGroup> {
+    let mut groups: Vec<ShortcutGroup> = vec![];
+    for (context, shortcut) in shortcuts {
+        match groups.iter_mut().find(|group| group.context == context) {
+            Some(group) => {
+                if !group.shortcuts.contains(&shortcut) {
+                    group.shortcuts.push(shortcut);
+                }
+            }
+            None => groups.push(ShortcutGroup {
+                context,
+                shortcuts: vec![shortcut],
+            }),
+        }
+    }
+
+    groups.sort_by(|a, b| a.context.cmp(&b.context));
+    groups
+}
+
+/// Filter the shortcuts by fuzzy matching the `query` with the title, the keystrokes or the context.
+///
+/// The empty groups are removed.
+fn filter_groups(groups: &[ShortcutGroup], query: &str) -> Vec<ShortcutGroup> {
+    let query = query.trim();
+    if query.is_empty() {
+        return groups.to_vec();
+    }
+
+    groups
+        .iter()
+        .filter_map(|group| {
+            let context_matched = fuzzy_match(query, &group.context).is_some();
+            let shortcuts = group
+                .shortcuts
+                .iter()
+                .filter(|shortcut| {
+                    context_matched
+                        || fuzzy_match(query, &shortcut.title).is_some()
+                        || fuzzy_match(query, &Kbd::format_keystrokes(&shortcut.keystrokes))
+                            .is_some()
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+
+            (!shortcuts.is_empty()).then(|| ShortcutGroup {
+                context: group.context.clone(),
+                shortcuts,
+            })
+        })
+        .collect()
+}
+
+/// State of the [`ShortcutCheatsheet`].
+///
+/// The shortcuts are collected from the registered [`KeyBinding`]s of the app when it is opened.
+pub struct ShortcutCheatsheetState {
+    query_input: Entity<InputState>,
+    groups: Vec<ShortcutGroup>,
+    binding_filter: Option<Rc<dyn Fn(&KeyBinding) -> bool>>,
+    /// Whether the shortcuts need to be reloaded before next render.
+    dirty: bool,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ShortcutCheatsheetState {
+    /// Create a new shortcut cheatsheet state.
+    pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let query_input = cx.new(|cx| {
+            InputState::new(window, cx).placeholder(t!("ShortcutCheatsheet.placeholder"))
+        });
+
+        let _subscriptions =
+            vec![
+                cx.subscribe_in(&query_input, window, |_, _, event: &InputEvent, _, cx| {
+                    if let InputEvent::Change = event {
+                        cx.notify();
+                    }
+                }),
+            ];
+
+        Self {
+            query_input,
+            groups: vec![],
+            binding_filter: None,
+            dirty: true,
+            _subscriptions,
+        }
+    }
+
+    /// Set a filter to decide which key bindings are listed, e.g.: to hide the bindings of the
+    /// internal components.
+    pub fn binding_filter(mut self, filter: impl Fn(&KeyBinding) -> bool + 'static) -> Self {
+        self.binding_filter = Some(Rc::new(filter));
+        self.dirty = true;
+        self
+    }
+
+    /// Returns the shortcuts grouped by the key context.
+    pub fn groups(&self) -> &[ShortcutGroup] {
+        &self.groups
+    }
+
+    /// Reload the shortcuts from the registered key bindings.
+    pub fn reload(&mut self, cx: &mut Context<Self>) {
+        self.dirty = false;
+
+        let keymap = cx.key_bindings();
+        let keymap = keymap.borrow();
+        let shortcuts = keymap
+            .bindings()
+            .filter(|binding| !binding.keystrokes().is_empty())
+            .filter(|binding| !is_no_action(binding.action()))
+            .filter(|binding| {
+                self.binding_filter
+                    .as_ref()
+                    .is_none_or(|filter| filter(binding))
+            })
+            .map(|binding| {
+                let context = binding
+                    .predicate()
+                    .map(|predicate| predicate.to_string())
+                    .unwrap_or_default();
+
+                (
+                    SharedString::from(context),
+                    Shortcut {
+                        title: humanize_action_name(binding.action().name()).into(),
+                        keystrokes: Kbd::from_binding(binding).keystrokes().to_vec(),
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+
+        self.groups = group_shortcuts(shortcuts);
+        cx.notify();
+    }
+
+    /// Open the cheatsheet in a Dialog.
+    pub fn open(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.reload(cx);
+        self.query_input
+            .update(cx, |input, cx| input.set_value("", window, cx));
+
+        let state = cx.entity();
+        window.open_dialog(cx, move |dialog, _, _| {
+            dialog
+                .title(t!("ShortcutCheatsheet.title"))
+                .w(px(560.))
+                .child(ShortcutCheatsheet::new(&state))
+        });
+        self.query_input
+            .update(cx, |input, cx| input.focus(window, cx));
+    }
+}
+
+impl Focusable for ShortcutCheatsheetState {
+    fn focus_handle(&self, cx: &App) -> FocusHandle {
+        self.query_input.focus_handle(cx)
+    }
+}
+
+/// A searchable cheatsheet of the keyboard shortcuts, grouped by the key context.
+///
+/// Use [`ShortcutCheatsheetState::open`] to open it in a Dialog (e.g.: on `?`), or render it inline.
+#[derive(IntoElement)]
+pub struct ShortcutCheatsheet {
+    state: Entity<ShortcutCheatsheetState>,
+    style: StyleRefinement,
+}
+
+impl ShortcutCheatsheet {
+    /// Create a new ShortcutCheatsheet with the given state.
+    pub fn new(state: &Entity<ShortcutCheatsheetState>) -> Self {
+        Self {
+            state: state.clone(),
+            style: StyleRefinement::default(),
+        }
+    }
+}
+
+impl Styled for ShortcutCheatsheet {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl RenderOnce for ShortcutCheatsheet {
+    fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+        self.state.update(cx, |state, cx| {
+            if state.dirty {
+                state.reload(cx);
+            }
+        });
+        let state = self.state.read(cx);
+        let query_input = state.query_input.clone();
+        let groups = filter_groups(&state.groups, &query_input.read(cx).value());
+
+        v_flex()
+            .w_full()
+            .gap_3()
+            .refine_style(&self.style)
+            .child(
+                Input::new(&query_input)
+                    .prefix(IconName::Search)
+                    .cleanable(true),
+            )
+            .child(
+                v_flex()
+                    .max_h(px(420.))
+                    .gap_4()
+                    .when(groups.is_empty(), |this| {
+                        this.child(
+                            div()
+                                .text_sm()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(t!("ShortcutCheatsheet.empty")),
+                        )
+                    })
+                    .children(groups.into_iter().map(|group| {
+                        let context: SharedString = if group.context.is_empty() {
+                            t!("ShortcutCheatsheet.global").into()
+                        } else {
+                            group.context
+                        };
+
+                        v_flex()
+                            .gap_1()
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .font_semibold()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(context),
+                            )
+                            .children(group.shortcuts.into_iter().map(|shortcut| {
+                                h_flex()
+                                    .gap_3()
+                                    .justify_between()
+                                    .text_sm()
+                                    .child(div().flex_1().truncate().child(shortcut.title))
+                                    .child(Kbd::with_keystrokes(shortcut.keystrokes))
+                            }))
+                    }))
+                    .overflow_y_scrollbar(),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shortcut(title: &'static str, keys: &str) -> Shortcut {
+        Shortcut {
+            title: title.into(),
+            keystrokes: keys
+                .split(' ')
+                .map(|key| Keystroke::parse(key).unwrap())
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_group_shortcuts() {
+        let groups = group_shortcuts([
+            ("Input".into(), shortcut("Input: Copy", "ctrl-c")),
+            ("".into(), shortcut("Quit", "ctrl-q")),
+            ("Input".into(), shortcut("Input: Paste", "ctrl-v")),
+            ("Input".into(), shortcut("Input: Copy", "ctrl-c")),
+        ]);
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].context, "");
+        assert_eq!(groups[0].shortcuts, vec![shortcut("Quit", "ctrl-q")]);
+        assert_eq!(groups[1].context, "Input");
+        assert_eq!(
+            groups[1].shortcuts,
+            vec![
+                shortcut("Input: Copy", "ctrl-c"),
+                shortcut("Input: Paste", "ctrl-v")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_filter_groups() {
+        let groups = group_shortcuts([
+            ("".into(), shortcut("Quit", "ctrl-q")),
+            ("Input".into(), shortcut("Input: Copy", "ctrl-c")),
+            ("Input".into(), shortcut("Input: Paste", "ctrl-v")),
+            ("Table".into(), shortcut("Table: Select All", "ctrl-a")),
+        ]);
+
+        assert_eq!(filter_groups(&groups, " "), groups);
+
+        let filtered = filter_groups(&groups, "paste");
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(
+            filtered[0].shortcuts,
+            vec![shortcut("Input: Paste", "ctrl-v")]
+        );
+
+        // Match the context, all the shortcuts in the group are kept.
+        let filtered = filter_groups(&groups, "table");
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].context, "Table");
+
+        assert!(filter_groups(&groups, "xyz").is_empty());
+    }
+}
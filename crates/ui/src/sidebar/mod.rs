@@ -26,6 +26,7 @@ pub use menu::*;
 
 const DEFAULT_WIDTH: Pixels = px(255.);
 const COLLAPSED_WIDTH: Pixels = px(48.);
+const COLLAPSE_DURATION: Duration = Duration::from_millis(200);
 
 pub trait SidebarItem: Collapsible + Clone {
     fn render(
@@ -337,13 +338,39 @@ impl<E: SidebarItem> RenderOnce for Sidebar<E> {
             .overflow_hidden()
             .child(sidebar);
 
-        Transition::new(Duration::from_millis(200))
-            .ease(ease_in_out_cubic)
-            .width(from_w, to_w)
+        collapse_transition(from_w, to_w)
             .apply(
                 wrapper,
                 ElementId::NamedInteger("sidebar-w".into(), collapsed as u64),
+                cx,
             )
             .into_any_element()
     }
 }
+
+/// The transition of the sidebar width when collapsing or expanding, it is instant in
+/// [`crate::animation::UiTestMode`] or when the reduced motion is preferred.
+fn collapse_transition(from: Pixels, to: Pixels) -> Transition {
+    Transition::new(COLLAPSE_DURATION)
+        .ease(ease_in_out_cubic)
+        .width(from, to)
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::TestAppContext;
+
+    use super::{COLLAPSE_DURATION, COLLAPSED_WIDTH, DEFAULT_WIDTH, collapse_transition};
+    use crate::animation::{INSTANT, UiTestMode};
+
+    #[gpui::test]
+    fn test_collapse_transition(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            let transition = collapse_transition(DEFAULT_WIDTH, COLLAPSED_WIDTH);
+            assert_eq!(transition.motion_duration(cx), COLLAPSE_DURATION);
+
+            UiTestMode::set_enabled(true, cx);
+            assert_eq!(transition.motion_duration(cx), INSTANT);
+        });
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{ActiveTheme, StyledExt};
+use crate::{animation::is_motion_reduced, ActiveTheme, StyledExt};
 use gpui::{
     bounce, div, ease_in_out, Animation, AnimationExt, IntoElement, RenderOnce, StyleRefinement,
     Styled,
@@ -36,7 +36,7 @@ impl Styled for Skeleton {
 
 impl RenderOnce for Skeleton {
     fn render(self, _: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
-        div()
+        let el = div()
             .w_full()
             .h_4()
             .bg(if self.secondary {
@@ -44,16 +44,22 @@ impl RenderOnce for Skeleton {
             } else {
                 cx.theme().skeleton
             })
-            .refine_style(&self.style)
-            .with_animation(
-                "skeleton",
-                Animation::new(Duration::from_secs(2))
-                    .repeat()
-                    .with_easing(bounce(ease_in_out)),
-                move |this, delta| {
-                    let v = 1.0 - delta * 0.5;
-                    this.opacity(v)
-                },
-            )
+            .refine_style(&self.style);
+
+        if is_motion_reduced(cx) {
+            return el.into_any_element();
+        }
+
+        el.with_animation(
+            "skeleton",
+            Animation::new(Duration::from_secs(2))
+                .repeat()
+                .with_easing(bounce(ease_in_out)),
+            move |this, delta| {
+                let v = 1.0 - delta * 0.5;
+                this.opacity(v)
+            },
+        )
+        .into_any_element()
     }
 }
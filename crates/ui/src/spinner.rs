@@ -1,5 +1,5 @@
 use instant::Duration;
-use crate::{Icon, IconName, Sizable, Size};
+use crate::{animation::is_motion_reduced, progress::ProgressCircle, Icon, IconName, Sizable, Size};
 use gpui::{
     div, ease_in_out, percentage, prelude::FluentBuilder as _, Animation, AnimationExt as _, App,
     Hsla, IntoElement, ParentElement, RenderOnce, Styled as _, Transformation, Window,
@@ -12,6 +12,7 @@ pub struct Spinner {
     icon: Icon,
     speed: Duration,
     color: Option<Hsla>,
+    circular: bool,
 }
 
 impl Spinner {
@@ -22,6 +23,7 @@ impl Spinner {
             speed: Duration::from_secs_f64(0.8),
             icon: Icon::new(IconName::Loader),
             color: None,
+            circular: false,
         }
     }
 
@@ -40,6 +42,14 @@ impl Spinner {
         self.color = Some(color);
         self
     }
+
+    /// Use a circular spinner, a rotating arc on a ring track, instead of the icon.
+    ///
+    /// Default is `false`.
+    pub fn circular(mut self, circular: bool) -> Self {
+        self.circular = circular;
+        self
+    }
 }
 
 impl Sizable for Spinner {
@@ -50,18 +60,56 @@ impl Sizable for Spinner {
 }
 
 impl RenderOnce for Spinner {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        if self.circular {
+            let color = self.color.unwrap_or(window.text_style().color);
+
+            return div()
+                .relative()
+                .flex_shrink_0()
+                .map(|this| match self.size {
+                    Size::Size(px) => this.size(px),
+                    Size::XSmall => this.size_3(),
+                    Size::Small => this.size_3p5(),
+                    Size::Medium => this.size_4(),
+                    Size::Large => this.size_6(),
+                })
+                .map(|this| {
+                    if is_motion_reduced(cx) {
+                        this.child(ProgressCircle::render_circle(0., 25., color))
+                            .into_any_element()
+                    } else {
+                        this.with_animation(
+                            "circle",
+                            Animation::new(self.speed).repeat(),
+                            move |this, delta| {
+                                let start = delta * 100.;
+                                this.child(ProgressCircle::render_circle(start, start + 25., color))
+                            },
+                        )
+                        .into_any_element()
+                    }
+                })
+                .into_any_element();
+        }
+
+        let icon = self
+            .icon
+            .with_size(self.size)
+            .when_some(self.color, |this, color| this.text_color(color));
+
         div()
-            .child(
-                self.icon
-                    .with_size(self.size)
-                    .when_some(self.color, |this, color| this.text_color(color))
-                    .with_animation(
+            .map(|this| {
+                if is_motion_reduced(cx) {
+                    this.child(icon)
+                } else {
+                    this.child(icon.with_animation(
                         "circle",
                         Animation::new(self.speed).repeat().with_easing(ease_in_out),
                         |this, delta| this.transform(Transformation::rotate(percentage(delta))),
-                    ),
-            )
-            .into_element()
+                    ))
+                }
+            })
+            .into_any_element()
     }
 }
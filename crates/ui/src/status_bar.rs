@@ -0,0 +1,200 @@
+use std::rc::Rc;
+
+use gpui::{
+    Anchor, AnyElement, App, ClickEvent, Context, ElementId, IntoElement, ParentElement, Pixels,
+    RenderOnce, SharedString, StyleRefinement, Styled, Window, prelude::FluentBuilder as _, px,
+};
+
+use crate::{
+    ActiveTheme as _, Icon, Sizable as _, StyledExt as _,
+    button::{Button, ButtonVariants as _},
+    h_flex,
+    popover::{Popover, PopoverState},
+};
+
+pub const STATUS_BAR_HEIGHT: Pixels = px(26.);
+
+/// An item of the [`StatusBar`], e.g.: the git branch, the cursor position or the running tasks.
+///
+/// ```ignore
+/// StatusBarItem::new("branch")
+///     .icon(IconName::Github)
+///     .label("main")
+///     .popover(|_, _, _| branch_list.clone())
+/// ```
+#[derive(IntoElement)]
+pub struct StatusBarItem {
+    id: ElementId,
+    icon: Option<Icon>,
+    label: Option<SharedString>,
+    tooltip: Option<SharedString>,
+    loading: bool,
+    on_click: Option<Rc<dyn Fn(&ClickEvent, &mut Window, &mut App)>>,
+    popover: Option<
+        Rc<dyn Fn(&mut PopoverState, &mut Window, &mut Context<PopoverState>) -> AnyElement>,
+    >,
+}
+
+impl StatusBarItem {
+    /// Create a new status bar item.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            icon: None,
+            label: None,
+            tooltip: None,
+            loading: false,
+            on_click: None,
+            popover: None,
+        }
+    }
+
+    /// Set the icon of the item.
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Set the label of the item.
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set the tooltip of the item.
+    pub fn tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Set to show a spinner instead of the icon, e.g.: when there are background tasks.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Set the callback when the item is clicked.
+    pub fn on_click(
+        mut self,
+        on_click: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_click = Some(Rc::new(on_click));
+        self
+    }
+
+    /// Set the content of the popover to open when the item is clicked.
+    pub fn popover<F, E>(mut self, content: F) -> Self
+    where
+        E: IntoElement,
+        F: Fn(&mut PopoverState, &mut Window, &mut Context<PopoverState>) -> E + 'static,
+    {
+        self.popover = Some(Rc::new(move |state, window, cx| {
+            content(state, window, cx).into_any_element()
+        }));
+        self
+    }
+}
+
+impl RenderOnce for StatusBarItem {
+    fn render(self, _: &mut Window, _: &mut App) -> impl IntoElement {
+        let button = Button::new(self.id.clone())
+            .ghost()
+            .xsmall()
+            .loading(self.loading)
+            .when_some(self.icon, |this, icon| this.icon(icon))
+            .when_some(self.label, |this, label| this.label(label))
+            .when_some(self.tooltip, |this, tooltip| this.tooltip(tooltip))
+            .when_some(self.on_click, |this, on_click| {
+                this.on_click(move |event, window, cx| on_click(event, window, cx))
+            });
+
+        match self.popover {
+            Some(content) => Popover::new(self.id)
+                .anchor(Anchor::BottomLeft)
+                .trigger(button)
+                .content(move |state, window, cx| content(state, window, cx))
+                .into_any_element(),
+            None => button.into_any_element(),
+        }
+    }
+}
+
+/// A status bar at the bottom of the window, with the left, center and right segments.
+///
+/// ```ignore
+/// StatusBar::new()
+///     .left(StatusBarItem::new("branch").icon(IconName::Github).label("main"))
+///     .center(StatusBarItem::new("status").label(self.status.clone()))
+///     .right(StatusBarItem::new("tasks").loading(self.is_running).label("Indexing"))
+/// ```
+#[derive(IntoElement)]
+pub struct StatusBar {
+    style: StyleRefinement,
+    left: Vec<AnyElement>,
+    center: Vec<AnyElement>,
+    right: Vec<AnyElement>,
+}
+
+impl StatusBar {
+    /// Create a new status bar.
+    pub fn new() -> Self {
+        Self {
+            style: StyleRefinement::default(),
+            left: vec![],
+            center: vec![],
+            right: vec![],
+        }
+    }
+
+    /// Add an item to the left segment, e.g.: a [`StatusBarItem`].
+    pub fn left(mut self, item: impl IntoElement) -> Self {
+        self.left.push(item.into_any_element());
+        self
+    }
+
+    /// Add an item to the center segment.
+    pub fn center(mut self, item: impl IntoElement) -> Self {
+        self.center.push(item.into_any_element());
+        self
+    }
+
+    /// Add an item to the right segment.
+    pub fn right(mut self, item: impl IntoElement) -> Self {
+        self.right.push(item.into_any_element());
+        self
+    }
+}
+
+impl Styled for StatusBar {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl RenderOnce for StatusBar {
+    fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+        h_flex()
+            .w_full()
+            .flex_none()
+            .h(STATUS_BAR_HEIGHT)
+            .px_1()
+            .gap_1()
+            .justify_between()
+            .border_t_1()
+            .border_color(cx.theme().title_bar_border)
+            .bg(cx.theme().title_bar)
+            .text_xs()
+            .text_color(cx.theme().muted_foreground)
+            .refine_style(&self.style)
+            .child(h_flex().flex_1().min_w_0().gap_1().children(self.left))
+            .child(h_flex().flex_none().gap_1().children(self.center))
+            .child(
+                h_flex()
+                    .flex_1()
+                    .min_w_0()
+                    .gap_1()
+                    .justify_end()
+                    .children(self.right),
+            )
+    }
+}
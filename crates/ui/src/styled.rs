@@ -1,7 +1,7 @@
-use crate::ActiveTheme;
+use crate::{ActiveTheme, Density};
 use gpui::{
-    App, BoxShadow, Corners, DefiniteLength, Div, Edges, FocusHandle, Hsla, ParentElement, Pixels,
-    Refineable, StyleRefinement, Styled, Window, div, point, px,
+    App, BoxShadow, Corners, DefiniteLength, Div, Edges, FocusHandle, Global, Hsla, ParentElement,
+    Pixels, Refineable, StyleRefinement, Styled, Window, div, point, px,
 };
 use serde::{Deserialize, Serialize};
 
@@ -266,6 +266,17 @@ impl Size {
         }
     }
 
+    /// Returns the size with the given [`Density`].
+    ///
+    /// The [`Density::Compact`] uses [`Size::Small`] for the default [`Size::Medium`], the other
+    /// sizes set explicitly, e.g.: `.small()` or `.large()`, are not changed.
+    pub fn with_density(&self, density: Density) -> Self {
+        match (density, self) {
+            (Density::Compact, Size::Medium) => Size::Small,
+            (_, size) => *size,
+        }
+    }
+
     /// Return the max size between two sizes.
     ///
     /// e.g. `Size::XSmall.max(Size::Small)` will return `Size::XSmall`.
@@ -496,14 +507,41 @@ impl<T: Styled> StyleSized<T> for T {
     }
 }
 
-pub(crate) trait FocusableExt<T: ParentElement + Styled + Sized> {
+/// The global state to decide whether to show the focus ring, like the CSS `:focus-visible`.
+///
+/// The [`Root`](crate::Root) set it to visible on key down, and to hidden on mouse down,
+/// so the focus ring is only shown when navigating with the keyboard.
+#[derive(Debug, Clone, Copy)]
+pub struct FocusVisible(bool);
+
+impl Global for FocusVisible {}
+
+impl FocusVisible {
+    /// Returns true if the focus ring should be shown, default: true.
+    pub fn is_visible(cx: &App) -> bool {
+        cx.try_global::<Self>().is_none_or(|this| this.0)
+    }
+
+    /// Set the focus ring visible or not, and refresh the window if changed.
+    pub(crate) fn set(visible: bool, window: &mut Window, cx: &mut App) {
+        if Self::is_visible(cx) != visible {
+            cx.set_global(Self(visible));
+            window.refresh();
+        }
+    }
+}
+
+/// A trait to add the focus ring to the focusable elements.
+pub trait FocusableExt<T: ParentElement + Styled + Sized> {
     /// Add focus ring to the element.
+    ///
+    /// The ring is only shown when the focus is visible, see [`FocusVisible`].
     fn focus_ring(self, is_focused: bool, margins: Pixels, window: &Window, cx: &App) -> Self;
 }
 
 impl<T: ParentElement + Styled + Sized> FocusableExt<T> for T {
     fn focus_ring(mut self, is_focused: bool, margins: Pixels, window: &Window, cx: &App) -> Self {
-        if !is_focused {
+        if !is_focused || !FocusVisible::is_visible(cx) {
             return self;
         }
 
@@ -576,7 +614,7 @@ pub trait Collapsible {
 mod tests {
     use gpui::px;
 
-    use crate::Size;
+    use crate::{Density, Size};
 
     #[test]
     fn test_size_max_min() {
@@ -598,6 +636,16 @@ mod tests {
         assert_eq!(Size::Size(px(10.)).max(Size::Size(px(20.))), Size::Size(px(10.)));
     }
 
+    #[test]
+    fn test_size_with_density() {
+        assert_eq!(Size::Medium.with_density(Density::Compact), Size::Small);
+        assert_eq!(Size::Small.with_density(Density::Compact), Size::Small);
+        assert_eq!(Size::Large.with_density(Density::Compact), Size::Large);
+        assert_eq!(Size::XSmall.with_density(Density::Compact), Size::XSmall);
+        assert_eq!(Size::Size(px(30.)).with_density(Density::Compact), Size::Size(px(30.)));
+        assert_eq!(Size::Medium.with_density(Density::Comfortable), Size::Medium);
+    }
+
     #[test]
     fn test_size_as_str() {
         assert_eq!(Size::XSmall.as_str(), "xs");
@@ -0,0 +1,171 @@
+//! A full color SVG image with `currentColor` resolved to the theme color,
+//! e.g.: the illustrations of the empty states, or the custom brand icons.
+use std::sync::{Arc, LazyLock};
+
+use gpui::{
+    App, ElementId, Hsla, Image, ImageFormat, ImageSource, IntoElement, ObjectFit, RenderOnce,
+    SharedString, StyleRefinement, Styled, StyledImage as _, Window, div, img,
+    prelude::FluentBuilder as _,
+};
+use regex::Regex;
+
+use crate::{ActiveTheme as _, Colorize as _, StyledExt as _, profiler::Profiler};
+
+static CURRENT_COLOR: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)currentcolor").unwrap());
+
+/// The source of the [`SvgImage`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SvgSource {
+    /// The path of the SVG in the Assets bundle of the application.
+    Path(SharedString),
+    /// The SVG content.
+    Data(SharedString),
+}
+
+#[derive(Default)]
+struct SvgImageState {
+    /// The source and color of the cached image.
+    key: Option<(SvgSource, Hsla)>,
+    image: Option<Arc<Image>>,
+}
+
+/// A full color SVG image, the `currentColor` in the SVG is resolved to the theme color.
+///
+/// Unlike the `svg` element that renders the SVG as a monochrome mask, the other colors of
+/// the SVG are kept, so the illustrations can adapt to the light and dark mode by the
+/// `currentColor`.
+///
+/// ```ignore
+/// SvgImage::new("empty").path("illustrations/empty.svg").size_32()
+///
+/// SvgImage::new("logo")
+///     .data(r#"<svg ...><path fill="currentColor" ... /></svg>"#)
+///     .color(cx.theme().primary)
+/// ```
+#[derive(IntoElement)]
+pub struct SvgImage {
+    id: ElementId,
+    source: Option<SvgSource>,
+    color: Option<Hsla>,
+    object_fit: ObjectFit,
+    style: StyleRefinement,
+}
+
+impl SvgImage {
+    /// Create a new SVG image, the `id` is used to cache the colored image.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            source: None,
+            color: None,
+            object_fit: ObjectFit::Contain,
+            style: StyleRefinement::default(),
+        }
+    }
+
+    /// Set the path of the SVG in the Assets bundle.
+    pub fn path(mut self, path: impl Into<SharedString>) -> Self {
+        self.source = Some(SvgSource::Path(path.into()));
+        self
+    }
+
+    /// Set the SVG content.
+    pub fn data(mut self, data: impl Into<SharedString>) -> Self {
+        self.source = Some(SvgSource::Data(data.into()));
+        self
+    }
+
+    /// Set the color of `currentColor`, default: the theme foreground color.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Set the object fit of the image, default: [`ObjectFit::Contain`].
+    pub fn object_fit(mut self, object_fit: ObjectFit) -> Self {
+        self.object_fit = object_fit;
+        self
+    }
+}
+
+impl Styled for SvgImage {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+/// Replace the `currentColor` (case-insensitive) in the `svg` with the `color`.
+fn replace_current_color(svg: &str, color: Hsla) -> String {
+    CURRENT_COLOR
+        .replace_all(svg, color.to_hex().as_str())
+        .into_owned()
+}
+
+/// Returns the content of the `source`, or `None` if the asset is not found.
+fn load_svg(source: &SvgSource, cx: &App) -> Option<SharedString> {
+    match source {
+        SvgSource::Data(data) => Some(data.clone()),
+        SvgSource::Path(path) => {
+            let bytes = cx.asset_source().load(path).ok().flatten()?;
+            Some(String::from_utf8_lossy(&bytes).into_owned().into())
+        }
+    }
+}
+
+impl RenderOnce for SvgImage {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let color = self.color.unwrap_or(cx.theme().foreground);
+        let state = window.use_keyed_state(self.id, cx, |_, _| SvgImageState::default());
+
+        let image = self.source.and_then(|source| {
+            let key = (source, color);
+            if state.read(cx).key.as_ref() != Some(&key) {
+                Profiler::count("svg_image.cache_miss", 1, window, cx);
+                let image = load_svg(&key.0, cx).map(|svg| {
+                    let svg = replace_current_color(&svg, color);
+                    Arc::new(Image::from_bytes(ImageFormat::Svg, svg.into_bytes()))
+                });
+                state.update(cx, |state, _| {
+                    state.key = Some(key);
+                    state.image = image;
+                });
+            } else {
+                Profiler::count("svg_image.cache_hit", 1, window, cx);
+            }
+            state.read(cx).image.clone()
+        });
+
+        div()
+            .refine_style(&self.style)
+            .when_some(image, |this, image| {
+                this.child(
+                    img(ImageSource::Image(image))
+                        .size_full()
+                        .object_fit(self.object_fit),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{Hsla, rgb};
+
+    use super::replace_current_color;
+
+    #[test]
+    fn test_replace_current_color() {
+        let color: Hsla = rgb(0xf8fafc).into();
+        assert_eq!(
+            replace_current_color(
+                r##"<path fill="currentColor" stroke="CurrentColor" /><path fill="red" />"##,
+                color
+            ),
+            r##"<path fill="#F8FAFC" stroke="#F8FAFC" /><path fill="red" />"##
+        );
+        assert_eq!(
+            replace_current_color(r##"<path style="color: currentcolor" />"##, color),
+            r##"<path style="color: #F8FAFC" />"##
+        );
+    }
+}
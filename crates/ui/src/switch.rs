@@ -1,5 +1,8 @@
 use crate::{
-    ActiveTheme, Disableable, Side, Sizable, Size, StyledExt, h_flex, text::Text,
+    ActiveTheme, Disableable, Side, Sizable, Size, StyledExt,
+    animation::{UiTestMode, motion_duration},
+    h_flex,
+    text::Text,
     tooltip::ComponentTooltip,
 };
 use gpui::{
@@ -163,10 +166,11 @@ impl RenderOnce for Switch {
                                     let prev_checked = toggle_state.read(cx);
                                     if !self.disabled && *prev_checked != checked {
                                         let duration = Duration::from_secs_f64(0.15);
+                                        let delay = UiTestMode::delay(duration, cx);
                                         cx.spawn({
                                             let toggle_state = toggle_state.clone();
                                             async move |cx| {
-                                                cx.background_executor().timer(duration).await;
+                                                cx.background_executor().timer(delay).await;
                                                 _ = toggle_state
                                                     .update(cx, |this, _| *this = checked);
                                             }
@@ -175,7 +179,7 @@ impl RenderOnce for Switch {
 
                                         this.with_animation(
                                             ElementId::NamedInteger("move".into(), checked as u64),
-                                            Animation::new(duration),
+                                            Animation::new(motion_duration(duration, cx)),
                                             move |this, delta| {
                                                 let max_x = bg_width - bar_width - inset * 2;
                                                 let x = if checked {
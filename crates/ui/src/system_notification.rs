@@ -0,0 +1,119 @@
+//! Post OS-native notifications, e.g.: to alert the user that a long-running job is completed
+//! while the app is in the background.
+//!
+//! This module requires the `system-notification` feature.
+use std::rc::Rc;
+
+use anyhow::Result;
+use gpui::{App, SharedString, Window};
+
+/// A notification shown by the notification center of the operating system.
+///
+/// Unlike the [`Notification`](crate::notification::Notification), it is visible when the
+/// window is in the background, clicking it activates the window.
+///
+/// ```ignore
+/// if !window.is_window_active() {
+///     SystemNotification::new("Agent finished")
+///         .body("The task has been completed.")
+///         .on_click(|window, cx| { /* show the result */ })
+///         .show(window, cx);
+/// }
+/// ```
+pub struct SystemNotification {
+    title: SharedString,
+    body: Option<SharedString>,
+    icon: Option<SharedString>,
+    on_click: Option<Rc<dyn Fn(&mut Window, &mut App)>>,
+}
+
+impl SystemNotification {
+    /// Create a new system notification with the given title.
+    pub fn new(title: impl Into<SharedString>) -> Self {
+        Self {
+            title: title.into(),
+            body: None,
+            icon: None,
+            on_click: None,
+        }
+    }
+
+    /// Set the body text of the notification.
+    pub fn body(mut self, body: impl Into<SharedString>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Set the icon of the notification, a file path or an icon name of the freedesktop theme.
+    ///
+    /// The icon is ignored on the platforms that always use the app icon, e.g.: macOS.
+    pub fn icon(mut self, icon: impl Into<SharedString>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Set the callback when the notification is clicked, it is called after the window is activated.
+    ///
+    /// The click is only reported by the freedesktop notification servers (Linux and BSD),
+    /// on the other platforms, the system brings the app to front without calling it.
+    pub fn on_click(mut self, on_click: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_click = Some(Rc::new(on_click));
+        self
+    }
+
+    /// Show the notification, the error is logged if the notification can not be posted.
+    pub fn show(self, window: &mut Window, cx: &mut App) {
+        let mut notification = notify_rust::Notification::new();
+        notification.summary(&self.title);
+        if let Some(body) = &self.body {
+            notification.body(body);
+        }
+        if let Some(icon) = &self.icon {
+            notification.icon(icon);
+        }
+
+        // Posting and waiting for the click are blocking, so do them in a dedicated thread.
+        let (clicked_tx, clicked_rx) = async_channel::bounded::<()>(1);
+        std::thread::spawn(move || {
+            if let Err(err) = post(notification, clicked_tx) {
+                tracing::error!("failed to show system notification: {}", err);
+            }
+        });
+
+        let on_click = self.on_click;
+        window
+            .spawn(cx, async move |cx| {
+                if clicked_rx.recv().await.is_err() {
+                    return;
+                }
+
+                _ = cx.update(|window, cx| {
+                    window.activate_window();
+                    if let Some(on_click) = on_click {
+                        on_click(window, cx);
+                    }
+                });
+            })
+            .detach();
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn post(
+    mut notification: notify_rust::Notification,
+    clicked: async_channel::Sender<()>,
+) -> Result<()> {
+    notification.action("default", "");
+    notification.show()?.wait_for_action(|action| {
+        if action == "default" {
+            _ = clicked.try_send(());
+        }
+    });
+    Ok(())
+}
+
+#[cfg(not(all(unix, not(target_os = "macos"))))]
+fn post(notification: notify_rust::Notification, _: async_channel::Sender<()>) -> Result<()> {
+    notification.show()?;
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use std::{cell::RefCell, rc::Rc, time::Duration};
 
 use gpui::{
-    Anchor, Animation, AnimationExt as _, AnyElement, App, Bounds, Div, Edges, ElementId,
+    Anchor, Animation, AnimationExt as _, AnyElement, App, Axis, Bounds, Div, Edges, ElementId,
     InteractiveElement, IntoElement, ParentElement, Pixels, RenderOnce, ScrollHandle, SharedString,
     Stateful, StatefulInteractiveElement as _, StyleRefinement, Styled, Window, div,
     prelude::FluentBuilder as _, px,
@@ -10,8 +10,9 @@ use rust_i18n::t;
 use smallvec::SmallVec;
 
 use super::{Tab, TabVariant};
-use crate::animation::{Lerp, ease_in_out_cubic};
+use crate::animation::{Lerp, ease_in_out_cubic, motion_duration};
 use crate::button::{Button, ButtonVariants as _};
+use crate::drag_drop::{DragItem, ReorderEvent, SortableExt as _};
 use crate::menu::{DropdownMenu as _, PopupMenuItem};
 use crate::{
     ActiveTheme, ElementExt, Icon, IconName, Selectable, Sizable, Size, StyledExt, h_flex,
@@ -51,6 +52,7 @@ pub struct TabBar {
     size: Size,
     menu: bool,
     on_click: Option<Rc<dyn Fn(&usize, &mut Window, &mut App) + 'static>>,
+    on_reorder: Option<Rc<dyn Fn(&ReorderEvent, &mut Window, &mut App) + 'static>>,
 }
 
 impl TabBar {
@@ -70,6 +72,7 @@ impl TabBar {
             last_empty_space: div().w_3().into_any_element(),
             selected_index: None,
             on_click: None,
+            on_reorder: None,
             menu: false,
         }
     }
@@ -163,6 +166,21 @@ impl TabBar {
         self
     }
 
+    /// Set the on_reorder callback to reorder the tabs by drag and drop.
+    ///
+    /// The tabs are not reordered by the TabBar, you should move the tab `from` index
+    /// to `to` index in your data, see [`move_item`](crate::drag_drop::move_item).
+    ///
+    /// When the TabBar is tracked by [`TabBar::track_scroll`], it will auto scroll when
+    /// dragging a tab near the edges.
+    pub fn on_reorder<F>(mut self, on_reorder: F) -> Self
+    where
+        F: Fn(&ReorderEvent, &mut Window, &mut App) + 'static,
+    {
+        self.on_reorder = Some(Rc::new(on_reorder));
+        self
+    }
+
     /// Render the sliding indicator element for animated tab switching.
     fn render_indicator(
         &self,
@@ -238,7 +256,8 @@ impl TabBar {
             })
             .with_animation(
                 ElementId::NamedInteger("tab-ind".into(), epoch),
-                Animation::new(Duration::from_millis(200)).with_easing(ease_in_out_cubic),
+                Animation::new(motion_duration(Duration::from_millis(200), cx))
+                    .with_easing(ease_in_out_cubic),
                 move |el, delta| {
                     let left = Lerp::lerp(&from_left, &to_left, delta);
                     let width = Lerp::lerp(&from_width, &to_width, delta);
@@ -400,6 +419,8 @@ impl RenderOnce for TabBar {
         let mut item_metas: Vec<(Option<SharedString>, Option<Icon>, bool)> = Vec::new();
         let selected_index = self.selected_index;
         let on_click = self.on_click.clone();
+        let on_reorder = self.on_reorder.clone();
+        let drag_group = SharedString::from(format!("{}-tab-reorder", self.id));
 
         self.base
             .group("tab-bar")
@@ -436,6 +457,9 @@ impl RenderOnce for TabBar {
                         .overflow_x_scroll()
                         .when_some(self.scroll_handle, |this, scroll_handle| {
                             this.track_scroll(&scroll_handle)
+                                .when(on_reorder.is_some(), |this| {
+                                    this.auto_scroll_on_drag(&scroll_handle, Axis::Horizontal)
+                                })
                         })
                         .when_some(bounds_rc.clone(), |this, rc| {
                             this.on_prepaint(move |bounds, _, _| {
@@ -464,7 +488,8 @@ impl RenderOnce for TabBar {
                                     this.on_click(move |_, window, cx| on_click(&ix, window, cx))
                                 });
 
-                            if let Some(ref rc) = bounds_rc {
+                            let label = tab.label.clone();
+                            let tab = if let Some(ref rc) = bounds_rc {
                                 let rc = rc.clone();
                                 div()
                                     .on_prepaint(move |bounds, _, _| {
@@ -476,6 +501,24 @@ impl RenderOnce for TabBar {
                                     .into_any_element()
                             } else {
                                 tab.into_any_element()
+                            };
+
+                            if let Some(on_reorder) = on_reorder.clone() {
+                                div()
+                                    .id(ix)
+                                    .sortable(
+                                        DragItem {
+                                            group: drag_group.clone(),
+                                            ix,
+                                            label,
+                                        },
+                                        Axis::Horizontal,
+                                        move |event, window, cx| on_reorder(event, window, cx),
+                                    )
+                                    .child(tab)
+                                    .into_any_element()
+                            } else {
+                                tab
                             }
                         }))
                         .when(has_suffix_or_menu, |this| this.child(self.last_empty_space)),
@@ -137,8 +137,10 @@ where
     fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
         let bordered = self.options.bordered;
         let focus_handle = self.state.focus_handle(cx);
+        let mut options = self.options;
+        options.size = options.size.with_density(cx.theme().density);
         self.state.update(cx, |state, _| {
-            state.options = self.options;
+            state.options = options;
         });
 
         div()
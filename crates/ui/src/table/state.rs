@@ -9,12 +9,13 @@ use crate::{
     h_flex,
     menu::{ContextMenuExt, PopupMenu},
     scroll::{ScrollableMask, Scrollbar},
+    selection::{SelectionMode as RowSelectionMode, SelectionState},
     v_flex,
 };
 use gpui::{
     AppContext, Axis, Bounds, ClickEvent, Context, Div, DragMoveEvent, EventEmitter, FocusHandle,
-    Focusable, InteractiveElement, IntoElement, ListSizingBehavior, MouseButton, MouseDownEvent,
-    ParentElement, Pixels, Point, Render, ScrollStrategy, SharedString, Stateful,
+    Focusable, InteractiveElement, IntoElement, ListSizingBehavior, Modifiers, MouseButton,
+    MouseDownEvent, ParentElement, Pixels, Point, Render, ScrollStrategy, SharedString, Stateful,
     StatefulInteractiveElement as _, Styled, Task, UniformListScrollHandle, Window, div,
     prelude::FluentBuilder, px, uniform_list,
 };
@@ -216,7 +217,7 @@ pub struct TableState<D: TableDelegate> {
     pub vertical_scroll_handle: UniformListScrollHandle,
     pub horizontal_scroll_handle: VirtualListScrollHandle,
 
-    selected_row: Option<usize>,
+    row_selection: SelectionState,
     selection_mode: SelectionMode,
     right_clicked_row: Option<usize>,
     right_clicked_cell: Option<(usize, usize)>,
@@ -248,7 +249,7 @@ where
             horizontal_scroll_handle: VirtualListScrollHandle::new(),
             vertical_scroll_handle: UniformListScrollHandle::new(),
             selection_mode: SelectionMode::Row,
-            selected_row: None,
+            row_selection: SelectionState::new(RowSelectionMode::Single, 0),
             right_clicked_row: None,
             right_clicked_cell: None,
             selected_col: None,
@@ -360,14 +361,35 @@ where
         cx.notify();
     }
 
+    /// Set the selection mode of the rows, default is [`RowSelectionMode::Single`].
+    ///
+    /// In [`RowSelectionMode::Multiple`], `shift` click to select a range of rows, `cmd` (`ctrl`
+    /// on Linux and Windows) click to toggle a row.
+    pub fn row_selection_mode(mut self, mode: RowSelectionMode) -> Self {
+        self.row_selection = SelectionState::new(mode, self.row_selection.len());
+        self
+    }
+
+    /// Returns the selection of the rows.
+    pub fn row_selection(&self) -> &SelectionState {
+        &self.row_selection
+    }
+
     /// Returns the selected row index.
+    ///
+    /// In [`RowSelectionMode::Multiple`], this is the last selected row, see
+    /// [`TableState::row_selection`] for all the selected rows.
     pub fn selected_row(&self) -> Option<usize> {
-        self.selected_row
+        self.row_selection.selected_active_index()
     }
 
     /// Sets the selected row to the given index.
     pub fn set_selected_row(&mut self, row_ix: usize, cx: &mut Context<Self>) {
-        let is_down = match self.selected_row {
+        self.select_row(row_ix, &Modifiers::default(), cx);
+    }
+
+    fn select_row(&mut self, row_ix: usize, modifiers: &Modifiers, cx: &mut Context<Self>) {
+        let is_down = match self.selected_row() {
             Some(selected_row) => row_ix > selected_row,
             None => true,
         };
@@ -375,13 +397,12 @@ where
         cx.stop_propagation();
         self.selection_mode = SelectionMode::Row;
         self.right_clicked_row = None;
-        self.selected_row = Some(row_ix);
-        if let Some(row_ix) = self.selected_row {
-            self.vertical_scroll_handle.scroll_to_item(
-                row_ix,
-                if is_down { ScrollStrategy::Bottom } else { ScrollStrategy::Top },
-            );
-        }
+        self.row_selection.set_len_inner(self.delegate.rows_count(cx));
+        self.row_selection.click_inner(row_ix, modifiers.shift, modifiers.secondary());
+        self.vertical_scroll_handle.scroll_to_item(
+            row_ix,
+            if is_down { ScrollStrategy::Bottom } else { ScrollStrategy::Top },
+        );
         cx.emit(TableEvent::SelectRow(row_ix));
         cx.emit(TableEvent::RightClickedRow(None));
         cx.notify();
@@ -462,7 +483,7 @@ where
     /// Clear the selection of the table.
     pub fn clear_selection(&mut self, cx: &mut Context<Self>) {
         self.selection_mode = SelectionMode::Row;
-        self.selected_row = None;
+        self.row_selection.reset(self.delegate.rows_count(cx));
         self.selected_col = None;
         self.selected_cell = None;
         cx.emit(TableEvent::ClearSelection);
@@ -627,7 +648,7 @@ where
             return;
         }
 
-        self.set_selected_row(row_ix, cx);
+        self.select_row(row_ix, &e.modifiers(), cx);
 
         if e.click_count() == 2 {
             cx.emit(TableEvent::DoubleClickedRow(row_ix));
@@ -671,7 +692,7 @@ where
     }
 
     fn has_selection(&self) -> bool {
-        self.selected_row.is_some() || self.selected_col.is_some() || self.selected_cell.is_some()
+        self.row_selection.selected_count() > 0 || self.selected_col.is_some() || self.selected_cell.is_some()
     }
 
     pub(super) fn action_cancel(&mut self, _: &Cancel, _: &mut Window, cx: &mut Context<Self>) {
@@ -712,7 +733,7 @@ where
         }
 
         // Row selection mode
-        let mut selected_row = self.selected_row.unwrap_or(0);
+        let mut selected_row = self.selected_row().unwrap_or(0);
         if selected_row > 0 {
             selected_row = selected_row.saturating_sub(1);
         } else {
@@ -754,7 +775,7 @@ where
         }
 
         // Row selection mode
-        let selected_row = match self.selected_row {
+        let selected_row = match self.selected_row() {
             Some(selected_row) if selected_row < rows_count.saturating_sub(1) => selected_row + 1,
             Some(selected_row) => {
                 if self.loop_selection {
@@ -834,7 +855,7 @@ where
         }
 
         // Row selection mode
-        let current = self.selected_row.unwrap_or(0);
+        let current = self.selected_row().unwrap_or(0);
         let target = current.saturating_sub(step);
         self.set_selected_row(target, cx);
     }
@@ -866,7 +887,7 @@ where
         }
 
         // Row selection mode
-        let current = self.selected_row.unwrap_or(0);
+        let current = self.selected_row().unwrap_or(0);
         let max_row = rows_count.saturating_sub(1);
         let target = (current + step).min(max_row);
         self.set_selected_row(target, cx);
@@ -1638,7 +1659,7 @@ where
     ) -> Stateful<Div> {
         let horizontal_scroll_handle = self.horizontal_scroll_handle.clone();
         let is_stripe_row = self.options.stripe && row_ix % 2 != 0;
-        let is_selected = self.selected_row == Some(row_ix);
+        let is_selected = self.row_selection.is_selected(row_ix);
         let view = cx.entity().clone();
         let row_height = self.options.size.table_row_height();
 
@@ -1878,25 +1899,23 @@ where
                 )
                 // Row selected style
                 // Note: Don't show row selection if a cell is selected
-                .when_some(self.selected_row, |this, _| {
-                    this.when(is_selected && self.selection_mode.is_row(), |this| {
-                        this.map(|this| {
-                            if cx.theme().list.active_highlight {
-                                this.border_color(gpui::transparent_white()).child(
-                                    div()
-                                        .top(if row_ix == 0 { px(0.) } else { px(-1.) })
-                                        .left(px(0.))
-                                        .right(px(0.))
-                                        .bottom(px(-1.))
-                                        .absolute()
-                                        .bg(cx.theme().table_active)
-                                        .border_1()
-                                        .border_color(cx.theme().table_active_border),
-                                )
-                            } else {
-                                this.bg(cx.theme().accent)
-                            }
-                        })
+                .when(is_selected && self.selection_mode.is_row(), |this| {
+                    this.map(|this| {
+                        if cx.theme().list.active_highlight {
+                            this.border_color(gpui::transparent_white()).child(
+                                div()
+                                    .top(if row_ix == 0 { px(0.) } else { px(-1.) })
+                                    .left(px(0.))
+                                    .right(px(0.))
+                                    .bottom(px(-1.))
+                                    .absolute()
+                                    .bg(cx.theme().table_active)
+                                    .border_1()
+                                    .border_color(cx.theme().table_active_border),
+                            )
+                        } else {
+                            this.bg(cx.theme().accent)
+                        }
                     })
                 })
                 // Row right click row style
@@ -2214,3 +2233,74 @@ where
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gpui::{App, Context, IntoElement, Modifiers, TestAppContext, Window};
+
+    use super::{RowSelectionMode, TableState};
+    use crate::{
+        table::{Column, TableDelegate},
+        test::TestWindow,
+    };
+
+    struct Rows(usize);
+
+    impl TableDelegate for Rows {
+        fn columns_count(&self, _: &App) -> usize {
+            1
+        }
+
+        fn rows_count(&self, _: &App) -> usize {
+            self.0
+        }
+
+        fn column(&self, _: usize, _: &App) -> Column {
+            Column::new("name", "Name")
+        }
+
+        fn render_td(
+            &mut self,
+            row_ix: usize,
+            _: usize,
+            _: &mut Window,
+            _: &mut Context<TableState<Self>>,
+        ) -> impl IntoElement {
+            format!("Row {}", row_ix)
+        }
+    }
+
+    #[gpui::test]
+    fn test_row_selection(cx: &mut TestAppContext) {
+        let mut window = TestWindow::new(cx, |window, cx| {
+            TableState::new(Rows(5), window, cx).row_selection_mode(RowSelectionMode::Multiple)
+        });
+
+        window.update(|table, _, cx| {
+            let shift = Modifiers { shift: true, ..Default::default() };
+            let selected = |table: &TableState<Rows>| -> Vec<usize> {
+                table.row_selection().selected_indices().collect()
+            };
+
+            table.set_selected_row(1, cx);
+            table.select_row(3, &shift, cx);
+            assert_eq!(selected(table), vec![1, 2, 3]);
+            assert_eq!(table.selected_row(), Some(3));
+
+            table.select_row(4, &Modifiers::secondary_key(), cx);
+            assert_eq!(selected(table), vec![1, 2, 3, 4]);
+            table.select_row(2, &Modifiers::secondary_key(), cx);
+            assert_eq!(selected(table), vec![1, 3, 4]);
+            // The toggled off row is not the selected row.
+            assert_eq!(table.selected_row(), None);
+
+            // Out of range.
+            table.set_selected_row(5, cx);
+            assert_eq!(selected(table), vec![1, 3, 4]);
+
+            table.clear_selection(cx);
+            assert_eq!(table.selected_row(), None);
+            assert_eq!(table.row_selection().selected_count(), 0);
+        });
+    }
+}
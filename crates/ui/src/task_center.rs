@@ -0,0 +1,274 @@
+//! A registry of the background tasks, e.g.: the file loaders, the indexers or the exporters,
+//! to show the progress and cancel them from the [`StatusBar`](crate::status_bar::StatusBar).
+use gpui::{
+    App, AppContext as _, Context, Entity, IntoElement, ParentElement, Render, SharedString,
+    Styled, Task, Window, div, prelude::FluentBuilder as _,
+};
+use rust_i18n::t;
+
+use crate::{
+    ActiveTheme as _, IconName, SharedStateExt as _, Sizable as _,
+    button::{Button, ButtonVariants as _},
+    h_flex,
+    progress::Progress,
+    status_bar::StatusBarItem,
+    v_flex,
+};
+
+/// The id of a task in the [`TaskCenter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TaskId(usize);
+
+/// A background task in the [`TaskCenter`].
+pub struct BackgroundTask {
+    id: TaskId,
+    title: SharedString,
+    message: Option<SharedString>,
+    progress: Option<f32>,
+    /// Dropping the task cancels it.
+    task: Option<Task<()>>,
+}
+
+impl BackgroundTask {
+    /// Returns the id of the task.
+    pub fn id(&self) -> TaskId {
+        self.id
+    }
+
+    /// Returns the title of the task.
+    pub fn title(&self) -> &SharedString {
+        &self.title
+    }
+
+    /// Returns the message of the task, e.g.: the current file.
+    pub fn message(&self) -> Option<&SharedString> {
+        self.message.as_ref()
+    }
+
+    /// Returns the progress of the task in 0.0 to 100.0, `None` if it is indeterminate.
+    pub fn progress(&self) -> Option<f32> {
+        self.progress
+    }
+
+    /// Returns true if the task can be cancelled.
+    pub fn is_cancellable(&self) -> bool {
+        self.task.is_some()
+    }
+}
+
+/// A registry of the running background tasks, shared by all windows.
+///
+/// The tasks are reported by [`TaskCenter::track`] or [`TaskCenter::start`], and shown by
+/// [`TaskCenter::status_item`] with a popover to list them.
+///
+/// ```ignore
+/// let center = TaskCenter::global(cx);
+/// center.update(cx, |center, cx| {
+///     let task = cx.background_spawn(async move { index_files().await });
+///     center.track("Indexing", task, cx);
+/// });
+/// ```
+#[derive(Default)]
+pub struct TaskCenter {
+    tasks: Vec<BackgroundTask>,
+    next_id: usize,
+}
+
+impl TaskCenter {
+    /// Returns the global task center.
+    pub fn global(cx: &mut App) -> Entity<Self> {
+        cx.shared_entity(|_| Self::default())
+    }
+
+    /// Returns the running tasks.
+    pub fn tasks(&self) -> &[BackgroundTask] {
+        &self.tasks
+    }
+
+    /// Returns true if there is any running task.
+    pub fn is_busy(&self) -> bool {
+        !self.tasks.is_empty()
+    }
+
+    /// Start a task that is finished manually by [`TaskCenter::finish`], it can not be cancelled
+    /// unless a [`Task`] is attached by [`TaskCenter::set_task`].
+    pub fn start(&mut self, title: impl Into<SharedString>, cx: &mut Context<Self>) -> TaskId {
+        let id = TaskId(self.next_id);
+        self.next_id += 1;
+        self.tasks.push(BackgroundTask {
+            id,
+            title: title.into(),
+            message: None,
+            progress: None,
+            task: None,
+        });
+        cx.notify();
+        id
+    }
+
+    /// Track the `task` until it is completed, cancelling it drops the `task`.
+    pub fn track<T: 'static>(
+        &mut self,
+        title: impl Into<SharedString>,
+        task: Task<T>,
+        cx: &mut Context<Self>,
+    ) -> TaskId {
+        let id = self.start(title, cx);
+        self.set_task(id, task, cx);
+        id
+    }
+
+    /// Attach the `task` to a started task, so it can be cancelled, and finished when completed.
+    ///
+    /// This is useful to report the progress from the `task` by the [`TaskId`].
+    pub fn set_task<T: 'static>(&mut self, id: TaskId, task: Task<T>, cx: &mut Context<Self>) {
+        let task = cx.spawn(async move |this, cx| {
+            task.await;
+            _ = this.update(cx, |this, cx| this.finish(id, cx));
+        });
+        if let Some(item) = self.tasks.iter_mut().find(|item| item.id == id) {
+            item.task = Some(task);
+            cx.notify();
+        }
+    }
+
+    /// Set the progress of the task in 0.0 to 100.0, `None` for indeterminate.
+    pub fn set_progress(&mut self, id: TaskId, progress: Option<f32>, cx: &mut Context<Self>) {
+        if let Some(item) = self.tasks.iter_mut().find(|item| item.id == id) {
+            item.progress = progress.map(|progress| progress.clamp(0., 100.));
+            cx.notify();
+        }
+    }
+
+    /// Set the message of the task, e.g.: the current file.
+    pub fn set_message(
+        &mut self,
+        id: TaskId,
+        message: impl Into<SharedString>,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(item) = self.tasks.iter_mut().find(|item| item.id == id) {
+            item.message = Some(message.into());
+            cx.notify();
+        }
+    }
+
+    /// Remove the task when it is completed.
+    pub fn finish(&mut self, id: TaskId, cx: &mut Context<Self>) {
+        self.tasks.retain(|item| item.id != id);
+        cx.notify();
+    }
+
+    /// Cancel the task, the tracked [`Task`] is dropped.
+    pub fn cancel(&mut self, id: TaskId, cx: &mut Context<Self>) {
+        self.finish(id, cx);
+    }
+
+    /// Returns a [`StatusBarItem`] with a spinner when busy, and a popover to list the tasks.
+    ///
+    /// Observe the [`TaskCenter::global`] in the view to update it when the tasks changed.
+    pub fn status_item(cx: &mut App) -> StatusBarItem {
+        let center = Self::global(cx);
+        let tasks = &center.read(cx).tasks;
+        let label: Option<SharedString> = match tasks.len() {
+            0 => None,
+            1 => Some(tasks[0].title.clone()),
+            n => Some(format!("{} (+{})", tasks[0].title, n - 1).into()),
+        };
+
+        StatusBarItem::new("task-center")
+            .icon(IconName::CircleCheck)
+            .loading(!tasks.is_empty())
+            .when_some(label, |this, label| this.label(label))
+            .popover(move |_, _, _| center.clone())
+    }
+}
+
+impl Render for TaskCenter {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .w_64()
+            .gap_3()
+            .text_sm()
+            .when(self.tasks.is_empty(), |this| {
+                this.child(
+                    div()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(t!("TaskCenter.empty")),
+                )
+            })
+            .children(self.tasks.iter().map(|item| {
+                let id = item.id;
+
+                v_flex()
+                    .gap_1()
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .justify_between()
+                            .child(div().flex_1().truncate().child(item.title.clone()))
+                            .when(item.is_cancellable(), |this| {
+                                this.child(
+                                    Button::new(("cancel", id.0))
+                                        .ghost()
+                                        .xsmall()
+                                        .icon(IconName::Close)
+                                        .tooltip(t!("TaskCenter.cancel"))
+                                        .on_click(cx.listener(move |this, _, _, cx| {
+                                            this.cancel(id, cx);
+                                        })),
+                                )
+                            }),
+                    )
+                    .child(
+                        Progress::new(("progress", id.0))
+                            .xsmall()
+                            .loading(item.progress.is_none())
+                            .value(item.progress.unwrap_or_default()),
+                    )
+                    .when_some(item.message.clone(), |this, message| {
+                        this.child(
+                            div()
+                                .text_xs()
+                                .truncate()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(message),
+                        )
+                    })
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::TestAppContext;
+
+    use super::TaskCenter;
+
+    #[gpui::test]
+    fn test_task_center(cx: &mut TestAppContext) {
+        let center = cx.update(|cx| TaskCenter::global(cx));
+
+        let id = center.update(cx, |center, cx| {
+            let id = center.start("Indexing", cx);
+            center.set_progress(id, Some(120.), cx);
+            id
+        });
+        center.read_with(cx, |center, _| {
+            assert!(center.is_busy());
+            assert_eq!(center.tasks()[0].progress(), Some(100.));
+            assert!(!center.tasks()[0].is_cancellable());
+        });
+
+        center.update(cx, |center, cx| center.finish(id, cx));
+        center.read_with(cx, |center, _| assert!(!center.is_busy()));
+
+        center.update(cx, |center, cx| {
+            let task = cx.background_spawn(async {});
+            center.track("Exporting", task, cx);
+            assert!(center.tasks()[0].is_cancellable());
+        });
+        cx.run_until_parked();
+        center.read_with(cx, |center, _| assert!(!center.is_busy()));
+    }
+}
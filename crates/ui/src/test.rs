@@ -0,0 +1,261 @@
+//! A headless driver to test the interactions of the components, e.g.: typing in an input,
+//! clicking the items of a list, and asserting on the state and the emitted events.
+//!
+//! This module requires the `test-support` feature, add it in the `dev-dependencies`:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! gpui = { version = "*", features = ["test-support"] }
+//! gpui-component = { version = "*", features = ["test-support"] }
+//! ```
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+use gpui::{
+    App, AppContext as _, Context, Entity, EventEmitter, Modifiers, MouseButton, Pixels, Point,
+    Render, Subscription, TestAppContext, VisualTestContext, Window,
+};
+
+use crate::{Root, animation::UiTestMode};
+
+/// A headless window with the view mounted in the [`Root`], to drive the interactions.
+///
+/// The components are initialized and the [`UiTestMode`] is enabled, so the animations are
+/// completed instantly, and the debounce timers are resolved by [`TestWindow::run_until_parked`].
+///
+/// ```ignore
+/// #[gpui::test]
+/// fn test_composer(cx: &mut TestAppContext) {
+///     let mut window = TestWindow::new(cx, |window, cx| Composer::new(window, cx));
+///     let input = window.read(|composer, _| composer.input.clone());
+///     let events = window.record_events::<InputEvent, _>(&input);
+///
+///     window.update(|composer, window, cx| {
+///         composer.input.update(cx, |input, cx| input.focus(window, cx))
+///     });
+///     window.type_text("Hello");
+///     window.keystrokes("enter");
+///
+///     let events = events.take();
+///     assert!(matches!(events.last(), Some(InputEvent::PressEnter { .. })));
+/// }
+/// ```
+pub struct TestWindow<V: 'static> {
+    view: Entity<V>,
+    cx: VisualTestContext,
+}
+
+impl<V: Render> TestWindow<V> {
+    /// Initialize the components, and open a window with the view built by `build`.
+    pub fn new(
+        cx: &mut TestAppContext,
+        build: impl FnOnce(&mut Window, &mut Context<V>) -> V,
+    ) -> Self {
+        cx.update(|cx| {
+            crate::init(cx);
+            UiTestMode::set_enabled(true, cx);
+        });
+
+        let mut view = None;
+        let window = cx.update(|cx| {
+            cx.open_window(Default::default(), |window, cx| {
+                let entity = cx.new(|cx| build(window, cx));
+                view = Some(entity.clone());
+                cx.new(|cx| Root::new(entity, window, cx))
+            })
+            .expect("failed to open the test window")
+        });
+
+        let mut this = Self {
+            view: view.expect("the view is built"),
+            cx: VisualTestContext::from_window(window.into(), cx),
+        };
+        this.run_until_parked();
+        this
+    }
+
+    /// Returns the mounted view.
+    pub fn view(&self) -> &Entity<V> {
+        &self.view
+    }
+
+    /// Returns the [`VisualTestContext`] of the window, for the interactions not covered here.
+    pub fn cx(&mut self) -> &mut VisualTestContext {
+        &mut self.cx
+    }
+
+    /// Read the view, e.g.: to assert on the state.
+    pub fn read<R>(&mut self, f: impl FnOnce(&V, &App) -> R) -> R {
+        let view = self.view.clone();
+        self.cx.update(|_, cx| f(view.read(cx), cx))
+    }
+
+    /// Update the view, and run until the tasks are parked.
+    pub fn update<R>(&mut self, f: impl FnOnce(&mut V, &mut Window, &mut Context<V>) -> R) -> R {
+        let view = self.view.clone();
+        let result = self
+            .cx
+            .update(|window, cx| view.update(cx, |view, cx| f(view, window, cx)));
+        self.run_until_parked();
+        result
+    }
+
+    /// Record the events of the type `E` emitted by the `entity`.
+    pub fn record_events<E, T>(&mut self, entity: &Entity<T>) -> EventRecorder<E>
+    where
+        E: Clone + 'static,
+        T: EventEmitter<E>,
+    {
+        let events = Rc::new(RefCell::new(vec![]));
+        let _subscription = self.cx.update(|_, cx| {
+            let events = events.clone();
+            cx.subscribe(entity, move |_, event: &E, _| {
+                events.borrow_mut().push(event.clone());
+            })
+        });
+
+        EventRecorder {
+            events,
+            _subscription,
+        }
+    }
+
+    /// Type the `text` to the focused input, like the IME input.
+    pub fn type_text(&mut self, text: &str) {
+        self.cx.simulate_input(text);
+        self.run_until_parked();
+    }
+
+    /// Dispatch the space separated `keystrokes` to the focused element, e.g.: `"cmd-a backspace"`.
+    pub fn keystrokes(&mut self, keystrokes: &str) {
+        self.cx.simulate_keystrokes(keystrokes);
+        self.run_until_parked();
+    }
+
+    /// Click with the left button at the `position` of the window.
+    pub fn click(&mut self, position: Point<Pixels>) {
+        self.cx.simulate_click(position, Modifiers::default());
+        self.run_until_parked();
+    }
+
+    /// Click with the left button at the center of the element with the `selector`,
+    /// set by `.debug_selector(|| "selector".into())` on the element.
+    ///
+    /// Panics if the element is not painted.
+    pub fn click_on(&mut self, selector: &'static str) {
+        let bounds = self
+            .cx
+            .debug_bounds(selector)
+            .unwrap_or_else(|| panic!("the element `{}` is not painted", selector));
+        self.click(bounds.center());
+    }
+
+    /// Move the mouse to the `position`, e.g.: to hover an element.
+    pub fn mouse_move(&mut self, position: Point<Pixels>) {
+        self.cx
+            .simulate_mouse_move(position, None, Modifiers::default());
+        self.run_until_parked();
+    }
+
+    /// Drag with the left button from the `from` position to the `to` position.
+    pub fn drag(&mut self, from: Point<Pixels>, to: Point<Pixels>) {
+        self.cx
+            .simulate_mouse_down(from, MouseButton::Left, Modifiers::default());
+        self.cx
+            .simulate_mouse_move(to, MouseButton::Left, Modifiers::default());
+        self.cx
+            .simulate_mouse_up(to, MouseButton::Left, Modifiers::default());
+        self.run_until_parked();
+    }
+
+    /// Advance the clock of the executor by the `duration`, and run the timers due,
+    /// e.g.: the auto hide of the notifications.
+    pub fn advance_clock(&mut self, duration: Duration) {
+        self.cx.executor().advance_clock(duration);
+        self.run_until_parked();
+    }
+
+    /// Run until all the pending tasks are parked, and draw the window.
+    pub fn run_until_parked(&mut self) {
+        self.cx.run_until_parked();
+    }
+}
+
+/// The events recorded by [`TestWindow::record_events`], the recording stops when dropped.
+pub struct EventRecorder<E> {
+    events: Rc<RefCell<Vec<E>>>,
+    _subscription: Subscription,
+}
+
+impl<E: Clone> EventRecorder<E> {
+    /// Returns the recorded events.
+    pub fn events(&self) -> Vec<E> {
+        self.events.borrow().clone()
+    }
+
+    /// Returns and clears the recorded events.
+    pub fn take(&self) -> Vec<E> {
+        std::mem::take(&mut *self.events.borrow_mut())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{
+        AppContext as _, Context, Entity, InteractiveElement as _, IntoElement, ParentElement as _,
+        Render, StatefulInteractiveElement as _, Styled as _, TestAppContext, Window, div, px,
+    };
+
+    use super::TestWindow;
+    use crate::input::{Input, InputEvent, InputState};
+
+    struct Composer {
+        input: Entity<InputState>,
+        sent: usize,
+    }
+
+    impl Render for Composer {
+        fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+            div().size_full().child(Input::new(&self.input)).child(
+                div()
+                    .id("send")
+                    .debug_selector(|| "send".into())
+                    .size(px(40.))
+                    .on_click(cx.listener(|this, _, _, _| this.sent += 1)),
+            )
+        }
+    }
+
+    #[gpui::test]
+    fn test_window_interactions(cx: &mut TestAppContext) {
+        let mut window = TestWindow::new(cx, |window, cx| Composer {
+            input: cx.new(|cx| InputState::new(window, cx)),
+            sent: 0,
+        });
+        let input = window.read(|composer, _| composer.input.clone());
+        let events = window.record_events::<InputEvent, _>(&input);
+
+        window.update(|composer, window, cx| {
+            composer
+                .input
+                .update(cx, |input, cx| input.focus(window, cx))
+        });
+        window.type_text("Hello");
+        window.keystrokes("backspace");
+        assert_eq!(window.read(|_, cx| input.read(cx).value()), "Hell");
+        assert!(
+            events
+                .take()
+                .iter()
+                .any(|event| matches!(event, InputEvent::Change))
+        );
+
+        window.keystrokes("enter");
+        assert!(matches!(
+            events.take().as_slice(),
+            [InputEvent::PressEnter { secondary: false }]
+        ));
+
+        window.click_on("send");
+        assert_eq!(window.read(|composer, _| composer.sent), 1);
+    }
+}
@@ -105,6 +105,10 @@ impl Inline {
         if !text_view_state.has_selection() {
             return (is_selectable, false, None);
         }
+        if text_view_state.selected_all {
+            let selection = (!self.text.is_empty()).then(|| (0..self.text.len()).into());
+            return (true, true, selection);
+        }
 
         let Some((selection_start, selection_end)) = text_view_state.selection_points() else {
             return (is_selectable, false, None);
@@ -5,7 +5,7 @@ use std::{
 };
 
 use gpui::{
-    AnyElement, App, DefiniteLength, Div, ElementId, FontStyle, FontWeight, Half, HighlightStyle,
+    AnyElement, App, DefiniteLength, Div, ElementId, FontStyle, FontWeight, HighlightStyle,
     InteractiveElement as _, IntoElement, Length, ObjectFit, ParentElement, SharedString,
     SharedUri, StatefulInteractiveElement, Styled, StyledImage as _, Window, div, img,
     prelude::FluentBuilder as _, px, relative, rems,
@@ -14,7 +14,9 @@ use markdown::mdast;
 use ropey::Rope;
 
 use crate::{
-    ActiveTheme as _, Icon, IconName, StyledExt, h_flex,
+    ActiveTheme as _, Disableable as _, Root, Sizable as _, StyledExt, WindowExt as _,
+    checkbox::Checkbox,
+    h_flex,
     highlighter::{HighlightTheme, SyntaxHighlighter},
     text::{
         CodeBlockActionsFn,
@@ -560,32 +562,18 @@ impl CodeBlock {
         div()
             .when(!options.is_last, |this| this.pb(style.paragraph_gap))
             .child(
-                div()
-                    .id(("codeblock", options.ix))
-                    .p_3()
-                    .rounded(cx.theme().radius)
-                    .bg(cx.theme().muted)
-                    .font_family(cx.theme().mono_font_family.clone())
-                    .text_size(cx.theme().mono_font_size)
-                    .relative()
-                    .refine_style(&style.code_block)
-                    .child(Inline::new(
+                crate::code_block::CodeBlock::new(("codeblock", options.ix), self.code())
+                    .wrap_toggle(false)
+                    .copyable(false)
+                    .content(Inline::new(
                         "code",
                         self.state.clone(),
                         vec![],
                         self.styles.clone(),
                     ))
+                    .refine_style(&style.code_block)
                     .when_some(node_cx.code_block_actions.clone(), |this, actions| {
-                        this.child(
-                            div()
-                                .id("actions")
-                                .absolute()
-                                .top_2()
-                                .right_2()
-                                .bg(cx.theme().muted)
-                                .rounded(cx.theme().radius)
-                                .child(actions(&self, window, cx)),
-                        )
+                        this.actions(actions(&self, window, cx))
                     }),
             )
             .into_any_element()
@@ -672,6 +660,16 @@ impl Paragraph {
                                     cx.open_url(&link.url);
                                 })
                         })
+                        .when(image.link.is_none(), |this| {
+                            // Click to view the image in a Lightbox, the Root is required.
+                            let url = image.url.clone();
+                            this.cursor_pointer().on_click(move |_, window, cx| {
+                                if window.root::<Root>().flatten().is_some() {
+                                    cx.stop_propagation();
+                                    window.open_lightbox([url.clone()], 0, cx);
+                                }
+                            })
+                        })
                         .into_any_element(),
                 );
 
@@ -921,6 +919,13 @@ impl BlockNode {
     }
 }
 
+/// Returns the id of the task list checkbox, keyed by the source offset of the list item to be
+/// unique in the document, the nested lists restart the `ix` from 0.
+fn task_checkbox_id(span: Option<Span>, ix: usize, child_ix: usize) -> ElementId {
+    let key = span.map_or(ix, |span| span.start);
+    ElementId::Name(format!("task-{}-{}", key, child_ix).into())
+}
+
 impl BlockNode {
     fn render_list_item(
         item: &BlockNode,
@@ -935,7 +940,7 @@ impl BlockNode {
                 children,
                 spread,
                 checked,
-                ..
+                span,
             } => v_flex()
                 .id(("li", options.ix))
                 .w_full()
@@ -998,24 +1003,15 @@ impl BlockNode {
                                         .when_some(*checked, |this, checked| {
                                             // Todo list checkbox
                                             this.child(
-                                                div()
-                                                    .flex()
-                                                    .mt(rems(0.4))
-                                                    .mr_1p5()
-                                                    .size(rems(0.875))
-                                                    .items_center()
-                                                    .justify_center()
-                                                    .rounded(cx.theme().radius.half())
-                                                    .border_1()
-                                                    .border_color(cx.theme().primary)
-                                                    .text_color(cx.theme().primary_foreground)
-                                                    .when(checked, |this| {
-                                                        this.bg(cx.theme().primary).child(
-                                                            Icon::new(IconName::Check)
-                                                                .size_2()
-                                                                .text_xs(),
-                                                        )
-                                                    }),
+                                                div().flex().mt(rems(0.4)).mr_1p5().child(
+                                                    Checkbox::new(task_checkbox_id(
+                                                        *span, options.ix, child_ix,
+                                                    ))
+                                                    .small()
+                                                    .checked(checked)
+                                                    .disabled(true)
+                                                    .tab_stop(false),
+                                                ),
                                             )
                                         })
                                         .child(
@@ -1272,3 +1268,54 @@ impl BlockNode {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use gpui::ElementId;
+
+    use super::{BlockNode, NodeContext, task_checkbox_id};
+    use crate::{highlighter::HighlightTheme, text::format};
+
+    fn collect_task_ids(nodes: &[BlockNode], ids: &mut Vec<ElementId>) {
+        for (ix, node) in nodes.iter().enumerate() {
+            match node {
+                BlockNode::Root { children, .. } | BlockNode::List { children, .. } => {
+                    collect_task_ids(children, ids)
+                }
+                BlockNode::ListItem {
+                    children,
+                    checked,
+                    span,
+                    ..
+                } => {
+                    for (child_ix, child) in children.iter().enumerate() {
+                        if checked.is_some() && matches!(child, BlockNode::Paragraph(_)) {
+                            ids.push(task_checkbox_id(*span, ix, child_ix));
+                        }
+                    }
+                    collect_task_ids(children, ids);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    #[test]
+    fn test_task_checkbox_id() {
+        let source = "- [ ] one\n- [x] two\n  - [ ] nested\n\n---\n\n- [ ] three\n- [x] four\n";
+        let document = format::markdown::parse(
+            source,
+            &mut NodeContext::default(),
+            &HighlightTheme::default_light(),
+        )
+        .unwrap();
+
+        let mut ids = vec![];
+        collect_task_ids(&document.blocks, &mut ids);
+        assert_eq!(ids.len(), 5);
+        // The items at the same index of the different lists have the different ids.
+        assert_eq!(ids.iter().collect::<HashSet<_>>().len(), ids.len());
+    }
+}
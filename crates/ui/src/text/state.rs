@@ -14,7 +14,7 @@ use crate::{
     ActiveTheme, ElementExt,
     async_util::{Sender, Receiver, unbounded},
     highlighter::HighlightTheme,
-    input::{self, Copy},
+    input::{self, Copy, SelectAll},
     text::{
         CodeBlockActionsFn, TextViewStyle,
         document::ParsedDocument,
@@ -31,6 +31,10 @@ pub(crate) fn init(cx: &mut App) {
         KeyBinding::new("cmd-c", input::Copy, Some(CONTEXT)),
         #[cfg(not(target_os = "macos"))]
         KeyBinding::new("ctrl-c", input::Copy, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-a", input::SelectAll, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-a", input::SelectAll, Some(CONTEXT)),
     ]);
 }
 
@@ -59,6 +63,8 @@ pub struct TextViewState {
     pub(super) is_selecting: bool,
     /// The local (in TextView) position of the selection.
     selection_positions: (Option<Point<Pixels>>, Option<Point<Pixels>>),
+    /// Whether all the text is selected, the whole text of each inline is selected.
+    pub(super) selected_all: bool,
 
     pub(super) parsed_content: ParsedContent,
     text: SharedString,
@@ -111,6 +117,7 @@ impl TextViewState {
             focus_handle,
             bounds: Bounds::default(),
             selection_positions: (None, None),
+            selected_all: false,
             selectable: false,
             scrollable: false,
             list_state: ListState::new(0, gpui::ListAlignment::Top, px(1000.)),
@@ -200,8 +207,23 @@ impl TextViewState {
         self.bounds = bounds;
     }
 
+    /// Select all the text, this requires the text view to be selectable.
+    ///
+    /// NOTE: For the scrollable text view, only the rendered content will be copied.
+    pub fn select_all(&mut self, cx: &mut Context<Self>) {
+        if !self.selectable {
+            return;
+        }
+
+        self.selection_positions = (None, None);
+        self.selected_all = true;
+        self.is_selecting = false;
+        cx.notify();
+    }
+
     pub(super) fn clear_selection(&mut self) {
         self.selection_positions = (None, None);
+        self.selected_all = false;
         self.is_selecting = false;
     }
 
@@ -214,6 +236,7 @@ impl TextViewState {
         };
         let pos = pos - self.bounds.origin - scroll_offset;
         self.selection_positions = (Some(pos), Some(pos));
+        self.selected_all = false;
         self.is_selecting = true;
     }
 
@@ -234,6 +257,10 @@ impl TextViewState {
     }
 
     pub(crate) fn has_selection(&self) -> bool {
+        if self.selected_all {
+            return true;
+        }
+
         if let (Some(start), Some(end)) = self.selection_positions {
             start != end
         } else {
@@ -266,6 +293,15 @@ impl TextViewState {
         cx.write_to_clipboard(ClipboardItem::new_string(selected_text));
     }
 
+    pub(super) fn on_action_select_all(
+        &mut self,
+        _: &SelectAll,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.select_all(cx);
+    }
+
     pub(crate) fn is_selectable(&self) -> bool {
         self.selectable
     }
@@ -7,11 +7,12 @@ use gpui::{
     MouseUpEvent, ParentElement, Pixels, SharedString, StyleRefinement, Styled, Window, div,
 };
 
-use crate::StyledExt;
+use crate::clipboard::Clipboard;
 use crate::scroll::ScrollableElement;
 use crate::text::TextViewFormat;
 use crate::text::node::CodeBlock;
 use crate::text::state::TextViewState;
+use crate::{ActiveTheme as _, StyledExt};
 use crate::{global_state::GlobalState, text::TextViewStyle};
 
 /// Type for code block actions generator function.
@@ -44,6 +45,7 @@ pub struct TextView {
     style: StyleRefinement,
     selectable: bool,
     scrollable: bool,
+    raw: bool,
     code_block_actions: Option<Arc<CodeBlockActionsFn>>,
 }
 
@@ -65,6 +67,7 @@ impl TextView {
             style: StyleRefinement::default(),
             selectable: false,
             scrollable: false,
+            raw: false,
             code_block_actions: None,
         }
     }
@@ -80,6 +83,7 @@ impl TextView {
             state: None,
             selectable: false,
             scrollable: false,
+            raw: false,
             code_block_actions: None,
         }
     }
@@ -95,6 +99,7 @@ impl TextView {
             state: None,
             selectable: false,
             scrollable: false,
+            raw: false,
             code_block_actions: None,
         }
     }
@@ -128,6 +133,15 @@ impl TextView {
         self
     }
 
+    /// Set to show the raw source instead of the rendered content, default is false.
+    ///
+    /// The source is displayed in a monospace font with a copy button,
+    /// this is useful to inspect malformed Markdown or HTML.
+    pub fn raw(mut self, raw: bool) -> Self {
+        self.raw = raw;
+        self
+    }
+
     /// Set custom block actions for code blocks.
     ///
     /// The closure receives the [`CodeBlock`],
@@ -144,6 +158,38 @@ impl TextView {
     }
 }
 
+impl TextView {
+    fn render_raw(source: SharedString, scrollable: bool, cx: &App) -> AnyElement {
+        let content = div()
+            .id("raw")
+            .debug_selector(|| "text-view-raw".into())
+            .w_full()
+            .font_family(cx.theme().mono_font_family.clone())
+            .text_size(cx.theme().mono_font_size)
+            .child(source.clone());
+
+        div()
+            .relative()
+            .w_full()
+            .when(scrollable, |this| this.size_full())
+            .map(|this| {
+                if scrollable {
+                    this.child(content.size_full().overflow_y_scrollbar())
+                } else {
+                    this.child(content)
+                }
+            })
+            .child(
+                div()
+                    .absolute()
+                    .top_0()
+                    .right_0()
+                    .child(Clipboard::new("copy-raw").value(source)),
+            )
+            .into_any_element()
+    }
+}
+
 impl IntoElement for TextView {
     type Element = Self;
 
@@ -210,16 +256,22 @@ impl Element for TextView {
 
         let focus_handle = state.read(cx).focus_handle.clone();
         let list_state = state.read(cx).list_state.clone();
+        let raw_source = self.raw.then(|| state.read(cx).source());
 
         let mut el = div()
             .key_context("TextView")
             .track_focus(&focus_handle)
-            .when(self.scrollable, |this| {
-                this.size_full().vertical_scrollbar(&list_state)
+            .when(self.scrollable, |this| this.size_full())
+            .when(self.scrollable && !self.raw, |this| {
+                this.vertical_scrollbar(&list_state)
             })
             .relative()
             .on_action(window.listener_for(&state, TextViewState::on_action_copy))
-            .child(state.clone())
+            .on_action(window.listener_for(&state, TextViewState::on_action_select_all))
+            .map(|this| match raw_source {
+                Some(source) => this.child(Self::render_raw(source, self.scrollable, cx)),
+                None => this.child(state.clone()),
+            })
             .refine_style(&self.style)
             .into_any_element();
         let layout_id = el.request_layout(window, cx);
@@ -269,6 +321,9 @@ impl Element for TextView {
                         return;
                     }
 
+                    // Focus the text view to copy or select all by the keyboard.
+                    let focus_handle = state.read(cx).focus_handle.clone();
+                    focus_handle.focus(window, cx);
                     state.update(cx, |state, _| {
                         state.start_selection(event.position);
                     });
@@ -340,13 +395,17 @@ mod tests {
 
     struct TextViewTestRoot {
         text_view: Entity<TextViewState>,
+        raw: bool,
     }
 
     impl TextViewTestRoot {
         fn new(text: &str, cx: &mut Context<Self>) -> Self {
             let text = text.to_string();
             let text_view = cx.new(|cx| TextViewState::markdown(&text, cx));
-            Self { text_view }
+            Self {
+                text_view,
+                raw: false,
+            }
         }
     }
 
@@ -355,15 +414,54 @@ mod tests {
             div()
                 .w(px(160.))
                 .child(
-                    div()
-                        .h(px(24.))
-                        .overflow_hidden()
-                        .child(TextView::new(&self.text_view).selectable(true)),
+                    div().h(px(24.)).overflow_hidden().child(
+                        TextView::new(&self.text_view)
+                            .selectable(true)
+                            .raw(self.raw),
+                    ),
                 )
                 .child(div().h(px(40.)).child("footer"))
         }
     }
 
+    #[test]
+    fn test_text_view_raw_builder() {
+        let view = TextView::markdown("raw", "# Hello");
+        assert!(!view.raw);
+
+        let view = view.raw(true);
+        assert!(view.raw);
+    }
+
+    #[gpui::test]
+    fn raw_mode_does_not_parse_markdown(cx: &mut TestAppContext) {
+        cx.update(crate::init);
+        let (view, cx) = cx.add_window_view(|_, cx| TextViewTestRoot::new("# Hello **world**", cx));
+        let cx: &mut VisualTestContext = cx;
+        cx.run_until_parked();
+        assert!(cx.debug_bounds("text-view-raw").is_none());
+
+        view.update(cx, |root, cx| {
+            root.raw = true;
+            cx.notify();
+        });
+        cx.run_until_parked();
+
+        // The source is rendered as is, and the copy button copies it with the markdown syntax.
+        let bounds = cx
+            .debug_bounds("text-view-raw")
+            .expect("the raw source is painted");
+        cx.simulate_click(
+            point(bounds.right() - px(8.), bounds.top() + px(8.)),
+            Modifiers::default(),
+        );
+        cx.run_until_parked();
+        assert_eq!(
+            cx.read_from_clipboard().and_then(|item| item.text()),
+            Some("# Hello **world**".to_string())
+        );
+    }
+
     #[gpui::test]
     fn clipped_markdown_link_does_not_open(cx: &mut TestAppContext) {
         cx.update(crate::init);
@@ -406,4 +504,19 @@ mod tests {
             "unexpected selection: {selected_text:?}"
         );
     }
+
+    #[gpui::test]
+    fn select_all_selects_the_whole_text(cx: &mut TestAppContext) {
+        cx.update(crate::init);
+        let (view, cx) = cx.add_window_view(|_, cx| TextViewTestRoot::new("Hello **world**", cx));
+        let cx: &mut VisualTestContext = cx;
+        cx.run_until_parked();
+
+        let text_view = view.read_with(cx, |root, _| root.text_view.clone());
+        text_view.update(cx, |state, cx| state.select_all(cx));
+        cx.run_until_parked();
+
+        let selected_text = text_view.read_with(cx, |state, _| state.selected_text());
+        assert_eq!(selected_text.trim(), "Hello world");
+    }
 }
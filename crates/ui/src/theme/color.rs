@@ -738,6 +738,53 @@ pub fn try_parse_color(color: &str) -> Result<Hsla> {
     Ok(hsla)
 }
 
+/// Returns the [WCAG relative luminance](https://www.w3.org/TR/WCAG21/#dfn-relative-luminance)
+/// of the color in range `0.0..=1.0`, the alpha is ignored.
+pub fn relative_luminance(color: Hsla) -> f32 {
+    #[inline]
+    fn channel(c: f32) -> f32 {
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    }
+
+    let rgb = color.to_rgb();
+    0.2126 * channel(rgb.r) + 0.7152 * channel(rgb.g) + 0.0722 * channel(rgb.b)
+}
+
+/// Returns the [WCAG contrast ratio](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio)
+/// between two colors in range `1.0..=21.0`.
+///
+/// The AA level requires `4.5` for normal text and `3.0` for large text or UI components.
+pub fn contrast_ratio(a: Hsla, b: Hsla) -> f32 {
+    let (a, b) = (relative_luminance(a), relative_luminance(b));
+    let (lighter, darker) = if a > b { (a, b) } else { (b, a) };
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+/// Adjust the lightness of the `color` until the contrast ratio with the `background`
+/// is at least `min_ratio`, the hue and saturation are kept.
+///
+/// The color is darkened on the light background and lightened on the dark background.
+pub fn ensure_contrast(color: Hsla, background: Hsla, min_ratio: f32) -> Hsla {
+    let darken = relative_luminance(background) > 0.5;
+    let mut color = color;
+    while contrast_ratio(color, background) < min_ratio {
+        let l = if darken {
+            color.l - 0.02
+        } else {
+            color.l + 0.02
+        };
+        if !(0.0..=1.0).contains(&l) {
+            break;
+        }
+        color = color.lightness(l);
+    }
+    color
+}
+
 #[cfg(test)]
 mod tests {
     use gpui::{rgb, rgba};
@@ -892,6 +939,21 @@ mod tests {
         assert_eq!(color.lightness(74. / 100.), hsl(260., 94., 74.));
     }
 
+    #[test]
+    fn test_contrast_ratio() {
+        assert!((relative_luminance(white()) - 1.).abs() < 0.001);
+        assert_eq!(relative_luminance(black()), 0.);
+        assert!((contrast_ratio(white(), black()) - 21.).abs() < 0.01);
+        assert!((contrast_ratio(black(), white()) - 21.).abs() < 0.01);
+        assert_eq!(contrast_ratio(red_500(), red_500()), 1.);
+
+        let color = ensure_contrast(hsl(145., 93., 39.), white(), 4.5);
+        assert!(contrast_ratio(color, white()) >= 4.5);
+        assert_eq!(color.h, hsl(145., 93., 39.).h);
+        let color = ensure_contrast(hsl(145., 93., 39.), black(), 7.);
+        assert!(contrast_ratio(color, black()) >= 7.);
+    }
+
     #[test]
     fn test_try_parse_color() {
         assert_eq!(
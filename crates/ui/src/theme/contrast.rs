@@ -0,0 +1,60 @@
+use std::{
+    collections::HashSet,
+    sync::{LazyLock, Mutex},
+};
+
+use gpui::{App, Hsla};
+
+use crate::{ActiveTheme as _, Colorize as _, contrast_ratio};
+
+/// The minimum contrast ratio of the WCAG AA level for the normal text.
+pub const WCAG_AA: f32 = 4.5;
+/// The minimum contrast ratio of the WCAG AA level for the large text and the UI components.
+pub const WCAG_AA_LARGE: f32 = 3.0;
+
+/// The contrast checker is enabled in debug build with the env `GPUI_CONTRAST_CHECK=1`.
+static CONTRAST_CHECK_ENABLED: LazyLock<bool> =
+    LazyLock::new(|| cfg!(debug_assertions) && std::env::var("GPUI_CONTRAST_CHECK").is_ok());
+
+/// The reported color pairs, to log each failing pair only once.
+static REPORTED: LazyLock<Mutex<HashSet<String>>> = LazyLock::new(|| Mutex::new(HashSet::new()));
+
+/// Log a warning if the contrast ratio of the `foreground` on the `background` is less than `min_ratio`.
+///
+/// The translucent background is blended with the theme background before checking.
+///
+/// This is only enabled in debug build with the env `GPUI_CONTRAST_CHECK=1`.
+pub(crate) fn check_contrast(
+    name: &str,
+    foreground: Hsla,
+    background: Hsla,
+    min_ratio: f32,
+    cx: &App,
+) {
+    if !*CONTRAST_CHECK_ENABLED {
+        return;
+    }
+
+    let background = cx.theme().background.blend(background);
+    let foreground = background.blend(foreground);
+    let ratio = contrast_ratio(foreground, background);
+    if ratio >= min_ratio {
+        return;
+    }
+
+    let key = format!("{}:{}:{}", name, foreground.to_hex(), background.to_hex());
+    let Ok(mut reported) = REPORTED.lock() else {
+        return;
+    };
+    if reported.insert(key) {
+        tracing::warn!(
+            "{} contrast ratio {:.2} is less than {:.1} (WCAG AA), foreground: {}, background: {}, theme: {}",
+            name,
+            ratio,
+            min_ratio,
+            foreground.to_hex(),
+            background.to_hex(),
+            cx.theme().theme_name(),
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::Result;
+use gpui::{App, Global, Task};
+use serde::Deserialize;
+
+use crate::{Density, Theme, ThemeConfig, ThemeMode, ThemeSet};
+
+/// Wait for the events of a save to be coalesced, the editors may write the file several times.
+const RELOAD_DELAY: Duration = Duration::from_millis(50);
+
+/// The file watched by [`Theme::watch_file`], a [`ThemeSet`] with the optional layout tweaks.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ThemeFile {
+    #[serde(flatten)]
+    theme_set: ThemeSet,
+    density: Option<Density>,
+    ui_scale: Option<f32>,
+}
+
+impl ThemeFile {
+    fn load(path: &Path) -> Result<Self> {
+        Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+    }
+
+    /// Returns the theme of the `mode`, or the first theme if not found.
+    fn theme(&self, mode: ThemeMode) -> Option<&ThemeConfig> {
+        let themes = &self.theme_set.themes;
+        themes
+            .iter()
+            .find(|theme| theme.mode == mode)
+            .or_else(|| themes.first())
+    }
+
+    fn apply(&self, cx: &mut App) {
+        let mode = Theme::global(cx).mode;
+        if let Some(config) = self.theme(mode).cloned() {
+            let follow_system = Theme::global(cx).follow_system;
+            Theme::set(config, None, cx);
+            Theme::global_mut(cx).follow_system = follow_system;
+        }
+        if let Some(density) = self.density {
+            Theme::global_mut(cx).density = density;
+        }
+        if let Some(ui_scale) = self.ui_scale {
+            Theme::set_ui_scale(ui_scale, cx);
+        }
+        cx.refresh_windows();
+    }
+}
+
+/// Keeps the watcher of [`Theme::watch_file`] alive, replaced by the next call.
+struct ThemeFileWatcher {
+    _task: Task<()>,
+}
+
+impl Global for ThemeFileWatcher {}
+
+impl Theme {
+    /// Watch the theme JSON file at `path`, and apply it to the running windows when it is saved.
+    ///
+    /// This is only available in debug builds, to tweak the theme without restarting the app.
+    ///
+    /// The file is a [`ThemeSet`], the theme of the current mode (or the first theme) is applied,
+    /// and the optional `density` and `ui_scale` fields are applied as the layout tweaks:
+    ///
+    /// ```json
+    /// {
+    ///   "density": "compact",
+    ///   "ui_scale": 1.1,
+    ///   "themes": [{ "name": "Brand", "mode": "light", "radius": 8, "colors": {} }]
+    /// }
+    /// ```
+    ///
+    /// The invalid content is logged and ignored, only the last watched file is kept.
+    pub fn watch_file(path: impl Into<PathBuf>, cx: &mut App) -> Result<()> {
+        use notify::Watcher as _;
+
+        let path = path.into();
+        let file_name = path.file_name().map(|name| name.to_os_string());
+        // Watch the directory, the editors may replace the file when saving.
+        let dir = path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or(Path::new("."))
+            .to_path_buf();
+
+        let (tx, rx) = smol::channel::bounded(100);
+        let mut watcher =
+            notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+                let Ok(event) = res else {
+                    return;
+                };
+
+                let is_changed = matches!(
+                    event.kind,
+                    notify::EventKind::Create(_) | notify::EventKind::Modify(_)
+                );
+                if is_changed
+                    && event
+                        .paths
+                        .iter()
+                        .any(|path| path.file_name() == file_name.as_deref())
+                {
+                    _ = tx.try_send(());
+                }
+            })?;
+        watcher.watch(&dir, notify::RecursiveMode::NonRecursive)?;
+
+        reload_theme_file(&path, cx);
+        let task = cx.spawn(async move |cx| {
+            let _watcher = watcher;
+            while rx.recv().await.is_ok() {
+                cx.background_executor().timer(RELOAD_DELAY).await;
+                while rx.try_recv().is_ok() {}
+
+                _ = cx.update(|cx| reload_theme_file(&path, cx));
+            }
+        });
+        cx.set_global(ThemeFileWatcher { _task: task });
+
+        Ok(())
+    }
+}
+
+fn reload_theme_file(path: &Path, cx: &mut App) {
+    match ThemeFile::load(path) {
+        Ok(file) => {
+            tracing::info!("Reload theme file: {}", path.display());
+            file.apply(cx);
+        }
+        Err(err) => tracing::error!("failed to load theme file {}: {}", path.display(), err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ThemeFile;
+    use crate::{Density, ThemeMode, ThemeSet};
+
+    #[test]
+    fn test_theme_file() {
+        let path = std::env::temp_dir().join(format!(
+            "gpui-component-theme-file-{}.json",
+            std::process::id()
+        ));
+        std::fs::write(
+            &path,
+            r#"{
+                "density": "compact",
+                "themes": [
+                    { "name": "Brand Light", "mode": "light", "radius": 8 },
+                    { "name": "Brand Dark", "mode": "dark" }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let file = ThemeFile::load(&path).unwrap();
+        _ = std::fs::remove_file(&path);
+        assert_eq!(file.density, Some(Density::Compact));
+        assert_eq!(file.ui_scale, None);
+        assert_eq!(
+            file.theme(ThemeMode::Dark).map(|theme| theme.name.as_str()),
+            Some("Brand Dark")
+        );
+        assert_eq!(
+            file.theme(ThemeMode::Light).map(|theme| theme.radius),
+            Some(Some(8))
+        );
+
+        let file = ThemeFile {
+            theme_set: ThemeSet::from_json(
+                r#"{ "themes": [{ "name": "Brand Dark", "mode": "dark" }] }"#,
+            )
+            .unwrap(),
+            ..Default::default()
+        };
+        assert_eq!(
+            file.theme(ThemeMode::Light)
+                .map(|theme| theme.name.as_str()),
+            Some("Brand Dark")
+        );
+        assert!(ThemeFile::load(&path).is_err());
+    }
+}
@@ -2,7 +2,7 @@ use crate::{
     highlighter::HighlightTheme, list::ListSettings, notification::NotificationSettings,
     scroll::ScrollbarShow, sheet::SheetSettings,
 };
-use gpui::{App, Global, Hsla, Pixels, SharedString, Window, WindowAppearance, px};
+use gpui::{App, Global, Hsla, Pixels, SharedString, Task, Window, WindowAppearance, px};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::{
@@ -12,13 +12,20 @@ use std::{
 };
 
 mod color;
+mod contrast;
+#[cfg(all(debug_assertions, not(target_family = "wasm")))]
+mod hot_reload;
 mod registry;
 mod schema;
+mod scope;
 mod theme_color;
 
 pub use color::*;
+pub(crate) use contrast::check_contrast;
+pub use contrast::{WCAG_AA, WCAG_AA_LARGE};
 pub use registry::*;
 pub use schema::*;
+pub use scope::{ThemeScope, ThemeScopeState};
 pub use theme_color::*;
 
 pub fn init(cx: &mut App) {
@@ -27,6 +34,7 @@ pub fn init(cx: &mut App) {
     // Ensure theme is loaded directly on startup for WASM compatibility
     Theme::change(ThemeMode::Light, None, cx);
     Theme::sync_scrollbar_appearance(cx);
+    Theme::sync_reduced_motion(cx);
 }
 
 pub trait ActiveTheme {
@@ -34,9 +42,12 @@ pub trait ActiveTheme {
 }
 
 impl ActiveTheme for App {
+    /// Returns the theme of the innermost [`ThemeScope`] if present, otherwise the global theme.
     #[inline(always)]
     fn theme(&self) -> &Theme {
-        Theme::global(self)
+        self.try_global::<scope::ThemeScopeStack>()
+            .and_then(|stack| stack.0.last())
+            .unwrap_or_else(|| Theme::global(self))
     }
 }
 
@@ -48,11 +59,26 @@ pub struct Theme {
     pub light_theme: Rc<ThemeConfig>,
     pub dark_theme: Rc<ThemeConfig>,
 
+    /// The active mode of the theme, this is always `Light` or `Dark`.
     pub mode: ThemeMode,
+    /// Whether to follow the OS appearance, set by [`Theme::change`] with [`ThemeMode::System`].
+    pub follow_system: bool,
+    /// The density of the components, default is [`Density::Comfortable`].
+    pub density: Density,
     /// The font family for the application, default is `.SystemUIFont`.
     pub font_family: SharedString,
     /// The base font size for the application, default is 16px.
     pub font_size: Pixels,
+    /// The UI scale of the application to multiply the rem size, default is `1.0`.
+    ///
+    /// Use [`Theme::set_ui_scale`] to change it.
+    pub ui_scale: f32,
+    /// Whether to reduce the animations, like the CSS `prefers-reduced-motion`, default: false.
+    ///
+    /// It is detected from the OS on init by [`Theme::sync_reduced_motion`], and can be overridden
+    /// by [`Theme::set_prefers_reduced_motion`].
+    /// When enabled, the transitions complete instantly and the repeating animations are stopped.
+    pub prefers_reduced_motion: bool,
     /// The monospace font family for the application.
     ///
     /// Defaults to:
@@ -108,7 +134,27 @@ impl DerefMut for Theme {
 
 impl Global for Theme {}
 
+/// The callbacks registered by [`Theme::on_ui_scale_change`].
+#[derive(Default)]
+struct UiScaleObservers(Vec<Rc<dyn Fn(f32, &mut App)>>);
+
+impl Global for UiScaleObservers {}
+
+/// The pending detection of [`Theme::sync_reduced_motion`], dropped to cancel it.
+struct ReducedMotionDetection {
+    _task: Task<()>,
+}
+
+impl Global for ReducedMotionDetection {}
+
 impl Theme {
+    /// The minimum UI scale.
+    pub const MIN_UI_SCALE: f32 = 0.5;
+    /// The maximum UI scale.
+    pub const MAX_UI_SCALE: f32 = 3.;
+    /// The UI scale step for the zoom in and zoom out actions.
+    pub const UI_SCALE_STEP: f32 = 0.1;
+
     /// Returns the global theme reference
     #[inline(always)]
     pub fn global(cx: &App) -> &Theme {
@@ -157,9 +203,64 @@ impl Theme {
         };
     }
 
+    /// Sync the [`Theme::prefers_reduced_motion`] with the system accessibility setting.
+    ///
+    /// Only macOS and GNOME are detected, otherwise the current value is kept.
+    ///
+    /// The setting is read by a command on the background executor, so the init is not blocked,
+    /// and applied when it is finished. The value set by the app in the meantime is kept.
+    pub fn sync_reduced_motion(cx: &mut App) {
+        let initial = Theme::global(cx).prefers_reduced_motion;
+        let detect = cx.background_spawn(async { system_prefers_reduced_motion() });
+        let task = cx.spawn(async move |cx| {
+            let Some(reduced) = detect.await else {
+                return;
+            };
+
+            _ = cx.update(|cx| {
+                let current = Theme::global(cx).prefers_reduced_motion;
+                // Overridden by the app settings before the detection finished.
+                if current != initial {
+                    return;
+                }
+
+                if current != reduced {
+                    Theme::global_mut(cx).prefers_reduced_motion = reduced;
+                    cx.refresh_windows();
+                }
+            });
+        });
+        cx.set_global(ReducedMotionDetection { _task: task });
+    }
+
+    /// Set the [`Theme::prefers_reduced_motion`] by the app settings and refresh all windows,
+    /// the pending detection of [`Theme::sync_reduced_motion`] is cancelled.
+    pub fn set_prefers_reduced_motion(reduced: bool, cx: &mut App) {
+        if cx.has_global::<ReducedMotionDetection>() {
+            cx.remove_global::<ReducedMotionDetection>();
+        }
+
+        Theme::global_mut(cx).prefers_reduced_motion = reduced;
+        cx.refresh_windows();
+    }
+
     /// Change the theme mode.
+    ///
+    /// Use [`ThemeMode::System`] to follow the OS appearance, the theme will be switched
+    /// automatically when the appearance changed.
     pub fn change(mode: impl Into<ThemeMode>, window: Option<&mut Window>, cx: &mut App) {
         let mode = mode.into();
+        let follow_system = mode.is_system();
+        let mode = if follow_system {
+            window
+                .as_ref()
+                .map(|window| window.appearance())
+                .unwrap_or_else(|| cx.window_appearance())
+                .into()
+        } else {
+            mode
+        };
+
         if !cx.has_global::<Theme>() {
             let mut theme = Theme::default();
             theme.light_theme = ThemeRegistry::global(cx).default_light_theme().clone();
@@ -169,6 +270,7 @@ impl Theme {
 
         let theme = cx.global_mut::<Theme>();
         theme.mode = mode;
+        theme.follow_system = follow_system;
         if mode.is_dark() {
             theme.apply_config(&theme.dark_theme.clone());
         } else {
@@ -180,6 +282,67 @@ impl Theme {
         }
     }
 
+    /// Set the theme config as the active theme at runtime.
+    ///
+    /// The [`ThemeMode`] is switched to the mode of the theme, and the theme is also used
+    /// as the light or dark theme for the later [`Theme::change`].
+    ///
+    /// ```ignore
+    /// let theme = ThemeSet::from_json(json)?.themes.remove(0);
+    /// Theme::set(theme, Some(window), cx);
+    /// ```
+    pub fn set(config: impl Into<Rc<ThemeConfig>>, window: Option<&mut Window>, cx: &mut App) {
+        let config = config.into();
+        if !cx.has_global::<Theme>() {
+            Self::change(config.mode, None, cx);
+        }
+
+        let theme = Theme::global_mut(cx);
+        theme.follow_system = false;
+        theme.apply_config(&config);
+
+        if let Some(window) = window {
+            window.refresh();
+        }
+    }
+
+    /// Set the UI scale of the application and refresh all windows, the `scale` is clamped
+    /// to [`Theme::MIN_UI_SCALE`]..=[`Theme::MAX_UI_SCALE`].
+    ///
+    /// The rem size of the windows is `font_size * ui_scale`, so all the rem-based sizes are scaled.
+    pub fn set_ui_scale(scale: f32, cx: &mut App) {
+        let scale = ((scale * 100.).round() / 100.).clamp(Self::MIN_UI_SCALE, Self::MAX_UI_SCALE);
+        if Theme::global(cx).ui_scale == scale {
+            return;
+        }
+
+        Theme::global_mut(cx).ui_scale = scale;
+        cx.refresh_windows();
+
+        let observers = cx
+            .try_global::<UiScaleObservers>()
+            .map(|observers| observers.0.clone())
+            .unwrap_or_default();
+        for observer in observers {
+            observer(scale, cx);
+        }
+    }
+
+    /// Register a callback to be called with the new scale when the UI scale is changed by
+    /// [`Theme::set_ui_scale`], including the zoom key bindings of the [`crate::Root`].
+    ///
+    /// Use it to save the scale in the app settings, and restore it by [`Theme::set_ui_scale`]
+    /// on startup.
+    pub fn on_ui_scale_change(f: impl Fn(f32, &mut App) + 'static, cx: &mut App) {
+        cx.default_global::<UiScaleObservers>().0.push(Rc::new(f));
+    }
+
+    /// Returns the rem size of the windows, the `font_size` multiplied by the `ui_scale`.
+    #[inline]
+    pub fn rem_size(&self) -> Pixels {
+        self.font_size * self.ui_scale
+    }
+
     /// Get the input background color.
     ///
     /// For dark, use a transparent color mixed with the input border: `cx.theme().input`,
@@ -207,9 +370,13 @@ impl From<&ThemeColor> for Theme {
     fn from(colors: &ThemeColor) -> Self {
         Theme {
             mode: ThemeMode::default(),
+            follow_system: false,
+            density: Density::default(),
             transparent: Hsla::transparent_black(),
             font_family: ".SystemUIFont".into(),
             font_size: px(16.),
+            ui_scale: 1.,
+            prefers_reduced_motion: false,
             mono_font_family: if cfg!(target_os = "macos") {
                 // https://en.wikipedia.org/wiki/Menlo_(typeface)
                 "Menlo".into()
@@ -256,6 +423,8 @@ pub enum ThemeMode {
     #[default]
     Light,
     Dark,
+    /// Follow the OS appearance, this is resolved to `Light` or `Dark` by [`Theme::change`].
+    System,
 }
 
 impl ThemeMode {
@@ -264,11 +433,17 @@ impl ThemeMode {
         matches!(self, Self::Dark)
     }
 
-    /// Return lower_case theme name: `light`, `dark`.
+    #[inline(always)]
+    pub fn is_system(&self) -> bool {
+        matches!(self, Self::System)
+    }
+
+    /// Return lower_case theme name: `light`, `dark`, `system`.
     pub fn name(&self) -> &'static str {
         match self {
             ThemeMode::Light => "light",
             ThemeMode::Dark => "dark",
+            ThemeMode::System => "system",
         }
     }
 }
@@ -281,3 +456,107 @@ impl From<WindowAppearance> for ThemeMode {
         }
     }
 }
+
+/// Returns the reduced motion setting of the OS, `None` if it is unknown.
+#[cfg(any(target_os = "macos", target_os = "linux", target_os = "freebsd"))]
+fn system_prefers_reduced_motion() -> Option<bool> {
+    // Keep the tests independent of the machine.
+    if cfg!(test) {
+        return None;
+    }
+
+    #[cfg(target_os = "macos")]
+    let (program, args, reduced) = (
+        "defaults",
+        ["read", "com.apple.universalaccess", "reduceMotion"],
+        "1",
+    );
+    #[cfg(not(target_os = "macos"))]
+    let (program, args, reduced) = (
+        "gsettings",
+        ["get", "org.gnome.desktop.interface", "enable-animations"],
+        "false",
+    );
+
+    let output = std::process::Command::new(program)
+        .args(args)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(String::from_utf8_lossy(&output.stdout).trim() == reduced)
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "freebsd")))]
+fn system_prefers_reduced_motion() -> Option<bool> {
+    None
+}
+
+/// The density of the components, to scale the default paddings, row heights and control sizes.
+///
+/// The density is applied to Button, Input, Table and List, e.g.: a default `Medium` Button is
+/// rendered as `Small` in the compact mode, the sizes set explicitly are not changed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Density {
+    /// Smaller paddings and sizes, for data-dense apps.
+    Compact,
+    #[default]
+    Comfortable,
+}
+
+impl Density {
+    #[inline(always)]
+    pub fn is_compact(&self) -> bool {
+        matches!(self, Self::Compact)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::{ActiveTheme as _, Theme};
+
+    #[gpui::test]
+    fn test_set_prefers_reduced_motion(cx: &mut gpui::TestAppContext) {
+        cx.update(|cx| {
+            super::init(cx);
+            Theme::set_prefers_reduced_motion(true, cx);
+        });
+        cx.run_until_parked();
+        cx.update(|cx| assert!(cx.theme().prefers_reduced_motion));
+    }
+
+    #[gpui::test]
+    fn test_set_ui_scale(cx: &mut gpui::TestAppContext) {
+        cx.update(|cx| {
+            super::init(cx);
+
+            let scales = Rc::new(RefCell::new(vec![]));
+            Theme::on_ui_scale_change(
+                {
+                    let scales = scales.clone();
+                    move |scale, _| scales.borrow_mut().push(scale)
+                },
+                cx,
+            );
+
+            Theme::set_ui_scale(1.25, cx);
+            assert_eq!(cx.theme().ui_scale, 1.25);
+            // Not changed.
+            Theme::set_ui_scale(1.25, cx);
+            Theme::set_ui_scale(100., cx);
+            assert_eq!(cx.theme().ui_scale, Theme::MAX_UI_SCALE);
+            Theme::set_ui_scale(0., cx);
+            assert_eq!(cx.theme().ui_scale, Theme::MIN_UI_SCALE);
+
+            assert_eq!(
+                *scales.borrow(),
+                vec![1.25, Theme::MAX_UI_SCALE, Theme::MIN_UI_SCALE]
+            );
+        });
+    }
+}
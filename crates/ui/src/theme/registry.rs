@@ -10,6 +10,8 @@ use std::{
 };
 
 const DEFAULT_THEME: &str = include_str!("./default-theme.json");
+/// The bundled themes beyond the default light and dark themes, e.g.: `WeChat Light`, `High Contrast Dark`.
+const PRESET_THEMES: &str = include_str!("./preset-themes.json");
 pub(crate) static DEFAULT_THEME_COLORS: LazyLock<
     HashMap<ThemeMode, (Arc<ThemeColor>, Arc<HighlightTheme>)>,
 > = LazyLock::new(|| {
@@ -180,6 +182,19 @@ impl ThemeRegistry {
                 (name, Rc::clone(theme))
             })
             .collect();
+        self.insert_preset_themes();
+    }
+
+    /// Insert the bundled preset themes, the themes with the same name will not be replaced.
+    fn insert_preset_themes(&mut self) {
+        let preset_themes = serde_json::from_str::<ThemeSet>(PRESET_THEMES)
+            .expect("failed to parse preset themes.")
+            .themes;
+        for theme in preset_themes {
+            if !self.themes.contains_key(&theme.name) {
+                self.themes.insert(theme.name.clone(), Rc::new(theme));
+            }
+        }
     }
 
     #[cfg(not(target_family = "wasm"))]
@@ -281,6 +296,7 @@ impl ThemeRegistry {
             self.themes
                 .insert(theme.name.clone(), Rc::new(theme.clone()));
         }
+        self.insert_preset_themes();
 
         Ok(())
     }
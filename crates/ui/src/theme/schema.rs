@@ -1,5 +1,6 @@
 use std::{rc::Rc, sync::Arc};
 
+use anyhow::Result;
 use gpui::{SharedString, px};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -25,6 +26,32 @@ pub struct ThemeSet {
     pub themes: Vec<ThemeConfig>,
 }
 
+impl ThemeSet {
+    /// Parse a theme set from the JSON content.
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Serialize the theme set to the pretty JSON content.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Load a theme set from the JSON file.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn load(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    /// Save the theme set to the JSON file, it can be loaded by [`ThemeSet::load`]
+    /// or [`ThemeRegistry::watch_dir`](crate::ThemeRegistry::watch_dir).
+    #[cfg(not(target_family = "wasm"))]
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> Result<()> {
+        std::fs::write(path, self.to_json()?)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(default)]
 pub struct ThemeConfig {
@@ -69,6 +96,28 @@ pub struct ThemeConfig {
     pub highlight: Option<HighlightThemeStyle>,
 }
 
+impl ThemeConfig {
+    /// Create a theme config with all tokens (colors, radii, fonts) of the given [`Theme`].
+    ///
+    /// This is useful to save the current theme, e.g.: after customizing it at runtime.
+    pub fn from_theme(name: impl Into<SharedString>, theme: &Theme) -> Self {
+        Self {
+            is_default: false,
+            name: name.into(),
+            mode: theme.mode,
+            font_size: Some(theme.font_size.as_f32()),
+            font_family: Some(theme.font_family.clone()),
+            mono_font_family: Some(theme.mono_font_family.clone()),
+            mono_font_size: Some(theme.mono_font_size.as_f32()),
+            radius: Some(theme.radius.as_f32().round() as usize),
+            radius_lg: Some(theme.radius_lg.as_f32().round() as usize),
+            shadow: Some(theme.shadow),
+            colors: ThemeConfigColors::from(&theme.colors),
+            highlight: Some(theme.highlight_theme.style.clone()),
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone, JsonSchema, Serialize, Deserialize)]
 pub struct ThemeConfigColors {
     /// Used for accents such as hover background on MenuItem, ListItem, etc.
@@ -422,6 +471,136 @@ pub struct ThemeConfigColors {
     yellow_light: Option<String>,
 }
 
+impl From<&ThemeColor> for ThemeConfigColors {
+    fn from(colors: &ThemeColor) -> Self {
+        macro_rules! export_colors {
+            ($($field:ident),+ $(,)?) => {
+                Self {
+                    $($field: Some(colors.$field.to_hex().into()),)+
+                    ..Default::default()
+                }
+            };
+        }
+
+        export_colors!(
+            accent,
+            accent_foreground,
+            accordion,
+            accordion_hover,
+            background,
+            border,
+            button_primary,
+            button_primary_active,
+            button_primary_foreground,
+            button_primary_hover,
+            group_box,
+            group_box_foreground,
+            caret,
+            chart_1,
+            chart_2,
+            chart_3,
+            chart_4,
+            chart_5,
+            chart_bullish,
+            chart_bearish,
+            danger,
+            danger_active,
+            danger_foreground,
+            danger_hover,
+            description_list_label,
+            description_list_label_foreground,
+            drag_border,
+            drop_target,
+            foreground,
+            info,
+            info_active,
+            info_foreground,
+            info_hover,
+            input,
+            link,
+            link_active,
+            link_hover,
+            list,
+            list_active,
+            list_active_border,
+            list_even,
+            list_head,
+            list_hover,
+            muted,
+            muted_foreground,
+            popover,
+            popover_foreground,
+            primary,
+            primary_active,
+            primary_foreground,
+            primary_hover,
+            progress_bar,
+            ring,
+            scrollbar,
+            scrollbar_thumb,
+            scrollbar_thumb_hover,
+            secondary,
+            secondary_active,
+            secondary_foreground,
+            secondary_hover,
+            selection,
+            sidebar,
+            sidebar_accent,
+            sidebar_accent_foreground,
+            sidebar_border,
+            sidebar_foreground,
+            sidebar_primary,
+            sidebar_primary_foreground,
+            skeleton,
+            slider_bar,
+            slider_thumb,
+            success,
+            success_foreground,
+            success_hover,
+            success_active,
+            switch,
+            switch_thumb,
+            tab,
+            tab_active,
+            tab_active_foreground,
+            tab_bar,
+            tab_bar_segmented,
+            tab_foreground,
+            table,
+            table_active,
+            table_active_border,
+            table_even,
+            table_head,
+            table_head_foreground,
+            table_foot,
+            table_foot_foreground,
+            table_hover,
+            table_row_border,
+            title_bar,
+            title_bar_border,
+            tiles,
+            warning,
+            warning_active,
+            warning_hover,
+            warning_foreground,
+            overlay,
+            window_border,
+            blue,
+            blue_light,
+            cyan,
+            cyan_light,
+            green,
+            green_light,
+            magenta,
+            magenta_light,
+            red,
+            red_light,
+            yellow,
+            yellow_light,
+        )
+    }
+}
+
 impl ThemeColor {
     /// Create a new `ThemeColor` from a `ThemeConfig`.
     pub(crate) fn apply_config(&mut self, config: &ThemeConfig, default_theme: &ThemeColor) {
@@ -713,3 +892,37 @@ impl Theme {
         self.mode = config.mode;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_set_json() {
+        let mut theme = Theme::from(ThemeColor::light().as_ref());
+        theme.radius = px(10.);
+        theme.colors.primary = try_parse_color("#07C160").unwrap();
+
+        let theme_set = ThemeSet {
+            name: "Brand".into(),
+            themes: vec![ThemeConfig::from_theme("Brand Light", &theme)],
+            ..Default::default()
+        };
+        let json = theme_set.to_json().unwrap();
+        let theme_set = ThemeSet::from_json(&json).unwrap();
+
+        let config = &theme_set.themes[0];
+        assert_eq!(config.name, "Brand Light");
+        assert_eq!(config.mode, ThemeMode::Light);
+        assert_eq!(config.radius, Some(10));
+        assert_eq!(
+            config.colors.primary.as_ref().map(|c| c.to_lowercase()),
+            Some("#07c160".to_string())
+        );
+
+        let mut restored = Theme::from(ThemeColor::dark().as_ref());
+        restored.apply_config(&Rc::new(config.clone()));
+        assert_eq!(restored.radius, px(10.));
+        assert_eq!(restored.primary.to_hex(), theme.primary.to_hex());
+    }
+}
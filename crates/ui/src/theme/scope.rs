@@ -0,0 +1,152 @@
+use std::rc::Rc;
+
+use gpui::{
+    AnyElement, App, Bounds, Div, Element, ElementId, GlobalElementId, InspectorElementId,
+    IntoElement, LayoutId, ParentElement, Pixels, StyleRefinement, Styled, Window, div,
+};
+
+use crate::{ActiveTheme as _, Theme};
+
+/// The stack of the scoped themes, the last one is the active theme for [`ActiveTheme`](crate::ActiveTheme).
+#[derive(Default)]
+pub(crate) struct ThemeScopeStack(pub(crate) Vec<Theme>);
+
+impl gpui::Global for ThemeScopeStack {}
+
+/// A container to override the theme tokens for the children, without changing the global [`Theme`].
+///
+/// The overrides are applied to a copy of the current theme, so the nested scopes are
+/// supported, e.g.: a chat bubble with a different accent color.
+///
+/// ```ignore
+/// ThemeScope::new(|theme| {
+///     theme.accent = theme.green;
+///     theme.background = theme.green_light;
+/// })
+/// .child(Button::new("ok").primary().label("OK"))
+/// ```
+///
+/// NOTE: The overrides only affect the elements rendered inside the scope via `cx.theme()`,
+/// the event handlers that read the theme later will use the global theme.
+pub struct ThemeScope {
+    overrides: Rc<dyn Fn(&mut Theme)>,
+    base: Div,
+}
+
+impl ThemeScope {
+    /// Create a new theme scope with the `overrides` to change the tokens of the theme.
+    pub fn new(overrides: impl Fn(&mut Theme) + 'static) -> Self {
+        Self {
+            overrides: Rc::new(overrides),
+            base: div(),
+        }
+    }
+
+    /// Run `f` with the scoped theme as the active theme.
+    fn with_theme<R>(theme: Theme, cx: &mut App, f: impl FnOnce(&mut App) -> R) -> (R, Theme) {
+        cx.default_global::<ThemeScopeStack>().0.push(theme);
+        let result = f(cx);
+        let theme = cx
+            .global_mut::<ThemeScopeStack>()
+            .0
+            .pop()
+            .expect("BUG: the theme scope stack should not be empty.");
+        (result, theme)
+    }
+}
+
+impl ParentElement for ThemeScope {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.base.extend(elements);
+    }
+}
+
+impl Styled for ThemeScope {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl IntoElement for ThemeScope {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+/// The layout state of the [`ThemeScope`], to keep the scoped theme between the phases.
+pub struct ThemeScopeState {
+    element: AnyElement,
+    theme: Option<Theme>,
+}
+
+impl ThemeScopeState {
+    fn scoped<R>(&mut self, cx: &mut App, f: impl FnOnce(&mut AnyElement, &mut App) -> R) -> R {
+        let theme = self
+            .theme
+            .take()
+            .expect("BUG: the scoped theme is missing.");
+        let element = &mut self.element;
+        let (result, theme) = ThemeScope::with_theme(theme, cx, |cx| f(element, cx));
+        self.theme = Some(theme);
+        result
+    }
+}
+
+impl Element for ThemeScope {
+    type RequestLayoutState = ThemeScopeState;
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static std::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let mut theme = cx.theme().clone();
+        (self.overrides)(&mut theme);
+
+        let mut state = ThemeScopeState {
+            element: std::mem::replace(&mut self.base, div()).into_any_element(),
+            theme: Some(theme),
+        };
+        let layout_id = state.scoped(cx, |element, cx| element.request_layout(window, cx));
+
+        (layout_id, state)
+    }
+
+    fn prepaint(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: Option<&InspectorElementId>,
+        _: Bounds<Pixels>,
+        state: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        state.scoped(cx, |element, cx| element.prepaint(window, cx));
+    }
+
+    fn paint(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: Option<&InspectorElementId>,
+        _: Bounds<Pixels>,
+        state: &mut Self::RequestLayoutState,
+        _: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        state.scoped(cx, |element, cx| element.paint(window, cx));
+    }
+}
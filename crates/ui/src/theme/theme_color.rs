@@ -1,6 +1,9 @@
 use std::sync::Arc;
 
-use crate::{ThemeMode, theme::DEFAULT_THEME_COLORS};
+use crate::{
+    Colorize as _, ThemeMode, black, contrast_ratio, ensure_contrast, relative_luminance,
+    theme::DEFAULT_THEME_COLORS, white,
+};
 
 use gpui::Hsla;
 use schemars::JsonSchema;
@@ -254,4 +257,104 @@ impl ThemeColor {
     pub fn dark() -> Arc<Self> {
         DEFAULT_THEME_COLORS[&ThemeMode::Dark].0.clone()
     }
+
+    /// Create the theme colors from a brand `accent` color, based on the default colors of the `mode`.
+    ///
+    /// See also [`ThemeColor::apply_accent`].
+    pub fn from_accent(accent: Hsla, mode: ThemeMode) -> Self {
+        let mut colors = if mode.is_dark() {
+            *Self::dark()
+        } else {
+            *Self::light()
+        };
+        colors.apply_accent(accent);
+        colors
+    }
+
+    /// Derive the accent related colors (primary, hover, active, ring, selection, link, chart, etc.)
+    /// from a brand `accent` color.
+    ///
+    /// The accent is adjusted to keep a contrast ratio of at least `3.0` with the background,
+    /// and the foreground on it is white or black to keep the text readable.
+    pub fn apply_accent(&mut self, accent: Hsla) {
+        let is_dark = relative_luminance(self.background) < 0.5;
+        let active_darken = if is_dark { 0.2 } else { 0.1 };
+
+        let primary = ensure_contrast(accent.opacity(1.), self.background, 3.);
+        let white = white();
+        let black = black();
+        let primary_foreground = if contrast_ratio(white, primary) >= 4.5
+            || contrast_ratio(white, primary) >= contrast_ratio(black, primary)
+        {
+            white
+        } else {
+            black
+        };
+
+        self.primary = primary;
+        self.primary_foreground = primary_foreground;
+        self.primary_hover = self.background.blend(primary.opacity(0.9));
+        self.primary_active = primary.darken(active_darken);
+        self.button_primary = self.primary;
+        self.button_primary_foreground = self.primary_foreground;
+        self.button_primary_hover = self.primary_hover;
+        self.button_primary_active = self.primary_active;
+
+        // Tint the neutral accents with the brand color.
+        self.accent = self.background.blend(primary.opacity(0.1));
+        self.accent_foreground = self.foreground;
+        self.list_hover = self.accent.opacity(0.6);
+        self.table_hover = self.list_hover;
+        self.sidebar_accent = self.accent;
+        self.sidebar_accent_foreground = self.accent_foreground;
+
+        self.list_active = self.background.blend(primary.opacity(0.1)).opacity(0.2);
+        self.list_active_border = self.background.blend(primary.opacity(0.6));
+        self.table_active = self.list_active;
+        self.table_active_border = self.list_active_border;
+
+        self.caret = primary;
+        self.ring = primary;
+        self.selection = primary.opacity(0.3);
+        self.link = ensure_contrast(primary, self.background, 4.5);
+        self.link_hover = self.link.opacity(0.8);
+        self.link_active = self.link;
+        self.drag_border = primary.opacity(0.65);
+        self.drop_target = primary.opacity(0.2);
+        self.progress_bar = primary;
+        self.slider_bar = primary;
+        self.slider_thumb = primary_foreground;
+        self.sidebar_primary = primary;
+        self.sidebar_primary_foreground = primary_foreground;
+
+        self.chart_1 = primary.lighten(0.4);
+        self.chart_2 = primary.lighten(0.2);
+        self.chart_3 = primary;
+        self.chart_4 = primary.darken(0.2);
+        self.chart_5 = primary.darken(0.4);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ThemeColor;
+    use crate::{ThemeMode, black, contrast_ratio, try_parse_color};
+
+    #[test]
+    fn test_from_accent() {
+        let accent = try_parse_color("#07C160").unwrap();
+
+        let light = ThemeColor::from_accent(accent, ThemeMode::Light);
+        assert!(contrast_ratio(light.primary, light.background) >= 3.);
+        assert!(contrast_ratio(light.link, light.background) >= 4.5);
+        assert_eq!(light.primary.h, accent.h);
+
+        let dark = ThemeColor::from_accent(accent, ThemeMode::Dark);
+        assert!(contrast_ratio(dark.primary, dark.background) >= 3.);
+        assert!(contrast_ratio(dark.link, dark.background) >= 4.5);
+
+        // A light accent should use the dark foreground.
+        let yellow = ThemeColor::from_accent(try_parse_color("#FFE066").unwrap(), ThemeMode::Dark);
+        assert_eq!(yellow.primary_foreground, black());
+    }
 }
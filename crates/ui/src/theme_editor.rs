@@ -0,0 +1,322 @@
+//! A live editor of the theme color tokens, the changes are applied to the [`Theme`]
+//! immediately and can be exported as a JSON theme file.
+use std::rc::Rc;
+
+use anyhow::Result;
+use gpui::{
+    App, AppContext as _, Context, Entity, Hsla, InteractiveElement as _, IntoElement,
+    ParentElement as _, RenderOnce, SharedString, StatefulInteractiveElement as _, StyleRefinement,
+    Styled, Subscription, Window, div, prelude::FluentBuilder as _, px,
+};
+use rust_i18n::t;
+
+use crate::{
+    ActiveTheme as _, Colorize as _, IconName, Sizable as _, StyledExt as _, Theme, ThemeConfig,
+    ThemeConfigColors, ThemeSet, WindowExt as _,
+    button::{Button, ButtonVariants as _},
+    color_picker::{ColorPicker, ColorPickerEvent, ColorPickerState},
+    h_flex,
+    input::{Input, InputEvent, InputState},
+    notification::Notification,
+    scroll::ScrollableElement as _,
+    try_parse_color, v_flex,
+};
+
+/// Returns the color tokens of the `colors`, e.g.: `("accent.background", "#f5f5f5")`,
+/// sorted by the key.
+fn color_tokens(colors: &ThemeConfigColors) -> Vec<(SharedString, SharedString)> {
+    let Ok(serde_json::Value::Object(map)) = serde_json::to_value(colors) else {
+        return vec![];
+    };
+
+    let mut tokens = map
+        .into_iter()
+        .filter_map(|(key, value)| Some((key.into(), value.as_str()?.to_string().into())))
+        .collect::<Vec<_>>();
+    tokens.sort_by(|a: &(SharedString, _), b| a.0.cmp(&b.0));
+    tokens
+}
+
+/// Set the color token of the `key` (e.g.: `accent.background`) in the `colors`.
+fn set_color_token(colors: &mut ThemeConfigColors, key: &str, value: &str) -> Result<()> {
+    let mut json = serde_json::to_value(&*colors)?;
+    let Some(map) = json.as_object_mut() else {
+        anyhow::bail!("invalid theme colors");
+    };
+    map.insert(key.to_string(), value.into());
+    *colors = serde_json::from_value(json)?;
+    Ok(())
+}
+
+/// State of the [`ThemeEditor`].
+///
+/// The edited theme is initialized from the current [`Theme`], use [`ThemeEditorState::reload`]
+/// after the theme is changed by others.
+pub struct ThemeEditorState {
+    config: ThemeConfig,
+    query_input: Entity<InputState>,
+    color_picker: Entity<ColorPickerState>,
+    selected: Option<SharedString>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ThemeEditorState {
+    /// Create a new theme editor state.
+    pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let query_input = cx.new(|cx| {
+            InputState::new(window, cx).placeholder(t!("ThemeEditor.search_placeholder"))
+        });
+        let color_picker = cx.new(|cx| ColorPickerState::new(window, cx));
+
+        let _subscriptions = vec![
+            cx.subscribe(&query_input, |_, _, event: &InputEvent, cx| {
+                if let InputEvent::Change = event {
+                    cx.notify();
+                }
+            }),
+            cx.subscribe(&color_picker, |this, _, event: &ColorPickerEvent, cx| {
+                let ColorPickerEvent::Change(Some(color)) = event else {
+                    return;
+                };
+                if let Some(key) = this.selected.clone() {
+                    this.set_color(&key, *color, cx);
+                }
+            }),
+        ];
+
+        let theme = cx.theme();
+        Self {
+            config: ThemeConfig::from_theme(theme.theme_name().clone(), theme),
+            query_input,
+            color_picker,
+            selected: None,
+            _subscriptions,
+        }
+    }
+
+    /// Reload the edited theme from the current [`Theme`].
+    pub fn reload(&mut self, cx: &mut Context<Self>) {
+        let theme = cx.theme();
+        self.config = ThemeConfig::from_theme(theme.theme_name().clone(), theme);
+        cx.notify();
+    }
+
+    /// Returns the edited theme config.
+    pub fn config(&self) -> &ThemeConfig {
+        &self.config
+    }
+
+    /// Set the name of the edited theme, used when exporting.
+    pub fn set_name(&mut self, name: impl Into<SharedString>, cx: &mut Context<Self>) {
+        self.config.name = name.into();
+        cx.notify();
+    }
+
+    /// Select the token of the `key` to edit it in the color picker.
+    pub fn select(&mut self, key: SharedString, window: &mut Window, cx: &mut Context<Self>) {
+        let color = color_tokens(&self.config.colors)
+            .into_iter()
+            .find(|(token, _)| token == &key)
+            .and_then(|(_, value)| try_parse_color(&value).ok());
+        if let Some(color) = color {
+            self.color_picker
+                .update(cx, |picker, cx| picker.set_value(color, window, cx));
+        }
+
+        self.selected = Some(key);
+        cx.notify();
+    }
+
+    /// Set the color of the token of the `key`, and apply it to the [`Theme`] immediately.
+    pub fn set_color(&mut self, key: &str, color: Hsla, cx: &mut Context<Self>) {
+        if let Err(err) = set_color_token(&mut self.config.colors, key, &color.to_hex()) {
+            tracing::error!("failed to set theme color {}: {}", key, err);
+            return;
+        }
+
+        Theme::global_mut(cx).apply_config(&Rc::new(self.config.clone()));
+        cx.refresh_windows();
+        cx.notify();
+    }
+
+    /// Export the edited theme to the JSON file, it can be loaded by the [`crate::ThemeRegistry`].
+    #[cfg(not(target_family = "wasm"))]
+    pub fn export(&self, path: impl AsRef<std::path::Path>) -> Result<()> {
+        ThemeSet {
+            name: self.config.name.clone(),
+            themes: vec![self.config.clone()],
+            ..Default::default()
+        }
+        .save(path)
+    }
+
+    /// Prompt for a path to export the edited theme.
+    #[cfg(not(target_family = "wasm"))]
+    fn prompt_export(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let dir = std::env::current_dir().unwrap_or_default();
+        let file_name = format!("{}.json", self.config.name.to_lowercase().replace(' ', "-"));
+        let receiver = cx.prompt_for_new_path(&dir, Some(&file_name));
+
+        cx.spawn_in(window, async move |this, cx| {
+            let Some(path) = receiver.await.ok().and_then(|path| path.ok()).flatten() else {
+                return;
+            };
+            _ = this.update_in(cx, |this, window, cx| {
+                if let Err(err) = this.export(&path) {
+                    window.push_notification(Notification::error(err.to_string()), cx);
+                }
+            });
+        })
+        .detach();
+    }
+}
+
+/// A live theme editor that lists all the color tokens of the [`Theme`], the selected token can
+/// be edited by a [`ColorPicker`], and the result can be exported to a theme file.
+///
+/// ```ignore
+/// let editor = cx.new(|cx| ThemeEditorState::new(window, cx));
+///
+/// ThemeEditor::new(&editor)
+/// ```
+#[derive(IntoElement)]
+pub struct ThemeEditor {
+    state: Entity<ThemeEditorState>,
+    style: StyleRefinement,
+}
+
+impl ThemeEditor {
+    /// Create a new theme editor with the given state.
+    pub fn new(state: &Entity<ThemeEditorState>) -> Self {
+        Self {
+            state: state.clone(),
+            style: StyleRefinement::default(),
+        }
+    }
+}
+
+impl Styled for ThemeEditor {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl RenderOnce for ThemeEditor {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let state = self.state.read(cx);
+        let query = state.query_input.read(cx).value().to_lowercase();
+        let selected = state.selected.clone();
+        let tokens = color_tokens(&state.config.colors)
+            .into_iter()
+            .filter(|(key, _)| key.to_lowercase().contains(query.trim()))
+            .collect::<Vec<_>>();
+
+        v_flex()
+            .size_full()
+            .gap_3()
+            .refine_style(&self.style)
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Input::new(&state.query_input)
+                            .prefix(IconName::Search)
+                            .cleanable(true)
+                            .flex_1(),
+                    )
+                    .when(cfg!(not(target_family = "wasm")), |this| {
+                        this.child(
+                            Button::new("export")
+                                .outline()
+                                .label(t!("ThemeEditor.export"))
+                                .on_click(window.listener_for(
+                                    &self.state,
+                                    |state, _, window, cx| {
+                                        #[cfg(not(target_family = "wasm"))]
+                                        state.prompt_export(window, cx);
+                                    },
+                                )),
+                        )
+                    }),
+            )
+            .child(h_flex().h_8().gap_2().text_sm().map(|this| {
+                match selected.clone() {
+                    Some(key) => this
+                        .child(ColorPicker::new(&state.color_picker).small())
+                        .child(key),
+                    None => this
+                        .text_color(cx.theme().muted_foreground)
+                        .child(t!("ThemeEditor.select_token")),
+                }
+            }))
+            .child(
+                v_flex()
+                    .flex_1()
+                    .min_h(px(200.))
+                    .children(tokens.into_iter().map(|(key, value)| {
+                        let color = try_parse_color(&value).unwrap_or_default();
+                        let is_selected = selected.as_ref() == Some(&key);
+
+                        h_flex()
+                            .id(key.clone())
+                            .gap_2()
+                            .px_2()
+                            .py_1()
+                            .rounded(cx.theme().radius)
+                            .cursor_pointer()
+                            .hover(|this| this.bg(cx.theme().accent))
+                            .when(is_selected, |this| this.bg(cx.theme().accent))
+                            .child(
+                                div()
+                                    .flex_none()
+                                    .size_4()
+                                    .rounded_sm()
+                                    .border_1()
+                                    .border_color(cx.theme().border)
+                                    .bg(color),
+                            )
+                            .child(div().flex_1().text_sm().truncate().child(key.clone()))
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(value),
+                            )
+                            .on_click(window.listener_for(
+                                &self.state,
+                                move |state, _, window, cx| {
+                                    state.select(key.clone(), window, cx);
+                                },
+                            ))
+                    }))
+                    .overflow_y_scrollbar(),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{color_tokens, set_color_token};
+    use crate::ThemeConfigColors;
+
+    #[test]
+    fn test_color_tokens() {
+        let mut colors = ThemeConfigColors::default();
+        assert!(color_tokens(&colors).is_empty());
+
+        set_color_token(&mut colors, "primary.background", "#07c160").unwrap();
+        set_color_token(&mut colors, "accent.background", "#f5f5f5").unwrap();
+        assert_eq!(colors.primary.as_deref(), Some("#07c160"));
+        assert_eq!(
+            color_tokens(&colors),
+            vec![
+                ("accent.background".into(), "#f5f5f5".into()),
+                ("primary.background".into(), "#07c160".into()),
+            ]
+        );
+
+        // Unknown keys are ignored.
+        set_color_token(&mut colors, "unknown", "#000000").unwrap();
+        assert_eq!(color_tokens(&colors).len(), 2);
+    }
+}
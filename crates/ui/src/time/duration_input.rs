@@ -0,0 +1,419 @@
+use std::time::Duration;
+
+use gpui::{
+    App, Context, ElementId, Empty, Entity, EventEmitter, FocusHandle, Focusable,
+    InteractiveElement as _, IntoElement, KeyBinding, KeyDownEvent, MouseButton,
+    ParentElement as _, Render, RenderOnce, SharedString, StyleRefinement, Styled, Subscription,
+    Window, div, prelude::FluentBuilder as _, px,
+};
+
+use crate::{
+    ActiveTheme, Disableable, Sizable, Size, StyleSized as _, StyledExt as _,
+    actions::{SelectDown, SelectLeft, SelectRight, SelectUp},
+    h_flex,
+    input::{Delete, input_style},
+};
+
+use super::utils::type_segment_digit;
+
+const CONTEXT: &'static str = "DurationInput";
+pub(crate) fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("delete", Delete, Some(CONTEXT)),
+        KeyBinding::new("backspace", Delete, Some(CONTEXT)),
+        KeyBinding::new("up", SelectUp, Some(CONTEXT)),
+        KeyBinding::new("down", SelectDown, Some(CONTEXT)),
+        KeyBinding::new("left", SelectLeft, Some(CONTEXT)),
+        KeyBinding::new("right", SelectRight, Some(CONTEXT)),
+    ])
+}
+
+/// The max duration can be displayed in `hh:mm:ss` format.
+const MAX_DURATION: Duration = Duration::from_secs(99 * 3600 + 59 * 60 + 59);
+
+/// Events emitted by the DurationInput.
+#[derive(Clone)]
+pub enum DurationInputEvent {
+    Change(Duration),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DurationSegment {
+    Hours,
+    Minutes,
+    Seconds,
+}
+
+impl DurationSegment {
+    fn unit_secs(&self) -> u64 {
+        match self {
+            DurationSegment::Hours => 3600,
+            DurationSegment::Minutes => 60,
+            DurationSegment::Seconds => 1,
+        }
+    }
+
+    fn max(&self) -> u32 {
+        match self {
+            DurationSegment::Hours => 99,
+            DurationSegment::Minutes | DurationSegment::Seconds => 59,
+        }
+    }
+
+    fn value(&self, duration: Duration) -> u64 {
+        let secs = duration.as_secs();
+        match self {
+            DurationSegment::Hours => secs / 3600,
+            DurationSegment::Minutes => secs / 60 % 60,
+            DurationSegment::Seconds => secs % 60,
+        }
+    }
+}
+
+/// Step the segment by `delta` units, carrying into the other segments and clamping to `0..=max`.
+fn step_duration(
+    duration: Duration,
+    segment: DurationSegment,
+    delta: i64,
+    max: Duration,
+) -> Duration {
+    let secs = duration.as_secs() as i64 + delta * segment.unit_secs() as i64;
+    Duration::from_secs(secs.max(0) as u64).min(max)
+}
+
+/// Replace the value of the segment, clamping to `0..=max`.
+fn set_duration_segment(
+    duration: Duration,
+    segment: DurationSegment,
+    value: u64,
+    max: Duration,
+) -> Duration {
+    let secs = duration.as_secs() - segment.value(duration) * segment.unit_secs()
+        + value.min(segment.max() as u64) * segment.unit_secs();
+    Duration::from_secs(secs).min(max)
+}
+
+/// Use to store the state of the duration input.
+pub struct DurationInputState {
+    focus_handle: FocusHandle,
+    duration: Duration,
+    max: Duration,
+    seconds: bool,
+    active_segment: usize,
+    pending_digit: Option<u32>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl Focusable for DurationInputState {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+impl EventEmitter<DurationInputEvent> for DurationInputState {}
+
+impl DurationInputState {
+    /// Create a duration input state.
+    pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let focus_handle = cx.focus_handle();
+        let _subscriptions = vec![cx.on_blur(&focus_handle, window, |this, _, cx| {
+            this.pending_digit = None;
+            cx.notify();
+        })];
+
+        Self {
+            focus_handle,
+            duration: Duration::ZERO,
+            max: MAX_DURATION,
+            seconds: true,
+            active_segment: 0,
+            pending_digit: None,
+            _subscriptions,
+        }
+    }
+
+    /// Set whether to show the seconds segment, default: true.
+    pub fn seconds(mut self, seconds: bool) -> Self {
+        self.seconds = seconds;
+        self
+    }
+
+    /// Set the max duration, default and upper limit is `99:59:59`.
+    pub fn max(mut self, max: Duration) -> Self {
+        self.max = max.min(MAX_DURATION);
+        self
+    }
+
+    /// Get the duration of the duration input.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Set the duration of the duration input.
+    pub fn set_duration(&mut self, duration: Duration, _: &mut Window, cx: &mut Context<Self>) {
+        self.update_duration(duration, false, cx);
+    }
+
+    fn segments(&self) -> Vec<DurationSegment> {
+        let mut segments = vec![DurationSegment::Hours, DurationSegment::Minutes];
+        if self.seconds {
+            segments.push(DurationSegment::Seconds);
+        }
+        segments
+    }
+
+    fn current_segment(&self) -> DurationSegment {
+        let segments = self.segments();
+        segments[self.active_segment.min(segments.len() - 1)]
+    }
+
+    fn update_duration(&mut self, duration: Duration, emit: bool, cx: &mut Context<Self>) {
+        let mut duration = Duration::from_secs(duration.as_secs()).min(self.max);
+        if !self.seconds {
+            duration = Duration::from_secs(duration.as_secs() / 60 * 60);
+        }
+
+        if self.duration == duration {
+            return;
+        }
+
+        self.duration = duration;
+        if emit {
+            cx.emit(DurationInputEvent::Change(duration));
+        }
+        cx.notify();
+    }
+
+    fn set_active_segment(&mut self, ix: usize, cx: &mut Context<Self>) {
+        self.active_segment = ix.min(self.segments().len() - 1);
+        self.pending_digit = None;
+        cx.notify();
+    }
+
+    fn step(&mut self, delta: i64, cx: &mut Context<Self>) {
+        self.pending_digit = None;
+        let duration = step_duration(self.duration, self.current_segment(), delta, self.max);
+        self.update_duration(duration, true, cx);
+    }
+
+    fn on_up(&mut self, _: &SelectUp, _: &mut Window, cx: &mut Context<Self>) {
+        self.step(1, cx);
+    }
+
+    fn on_down(&mut self, _: &SelectDown, _: &mut Window, cx: &mut Context<Self>) {
+        self.step(-1, cx);
+    }
+
+    fn on_left(&mut self, _: &SelectLeft, _: &mut Window, cx: &mut Context<Self>) {
+        self.set_active_segment(self.active_segment.saturating_sub(1), cx);
+    }
+
+    fn on_right(&mut self, _: &SelectRight, _: &mut Window, cx: &mut Context<Self>) {
+        self.set_active_segment(self.active_segment + 1, cx);
+    }
+
+    fn on_delete(&mut self, _: &Delete, _: &mut Window, cx: &mut Context<Self>) {
+        self.pending_digit = None;
+        let duration = set_duration_segment(self.duration, self.current_segment(), 0, self.max);
+        self.update_duration(duration, true, cx);
+    }
+
+    fn on_key_down(&mut self, event: &KeyDownEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let keystroke = &event.keystroke;
+        let key = keystroke.key.as_str();
+        if keystroke.modifiers.modified() || key.len() != 1 {
+            return;
+        }
+        let Some(digit) = key.chars().next().and_then(|c| c.to_digit(10)) else {
+            return;
+        };
+
+        let segment = self.current_segment();
+        let (value, complete) = type_segment_digit(&mut self.pending_digit, digit, segment.max());
+        let duration = set_duration_segment(self.duration, segment, value as u64, self.max);
+
+        cx.stop_propagation();
+        self.update_duration(duration, true, cx);
+        if complete {
+            self.set_active_segment(self.active_segment + 1, cx);
+        }
+    }
+}
+
+impl Render for DurationInputState {
+    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+        Empty
+    }
+}
+
+/// A DurationInput element to input a duration in `hh:mm:ss` format.
+///
+/// Use `up` and `down` keys to step the active segment, `left` and `right` to move
+/// between segments, and type digits to input the value directly.
+#[derive(IntoElement)]
+pub struct DurationInput {
+    id: ElementId,
+    style: StyleRefinement,
+    state: Entity<DurationInputState>,
+    size: Size,
+    appearance: bool,
+    disabled: bool,
+}
+
+impl Sizable for DurationInput {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl Focusable for DurationInput {
+    fn focus_handle(&self, cx: &App) -> FocusHandle {
+        self.state.focus_handle(cx)
+    }
+}
+
+impl Styled for DurationInput {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl Disableable for DurationInput {
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl DurationInput {
+    /// Create a new DurationInput with the given [`DurationInputState`].
+    pub fn new(state: &Entity<DurationInputState>) -> Self {
+        Self {
+            id: ("duration-input", state.entity_id()).into(),
+            state: state.clone(),
+            size: Size::default(),
+            style: StyleRefinement::default(),
+            appearance: true,
+            disabled: false,
+        }
+    }
+
+    /// Set appearance of the duration input, if false, it will be in a minimal style.
+    pub fn appearance(mut self, appearance: bool) -> Self {
+        self.appearance = appearance;
+        self
+    }
+}
+
+impl RenderOnce for DurationInput {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let is_focused = self.focus_handle(cx).is_focused(window);
+        let state = self.state.read(cx);
+        let segments = state.segments();
+        let active_segment = state.active_segment.min(segments.len() - 1);
+        let duration = state.duration;
+
+        let (bg, fg) = input_style(self.disabled, cx);
+
+        h_flex()
+            .id(self.id.clone())
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle(cx).tab_stop(true))
+            .when(!self.disabled, |this| {
+                this.on_action(window.listener_for(&self.state, DurationInputState::on_delete))
+                    .on_action(window.listener_for(&self.state, DurationInputState::on_up))
+                    .on_action(window.listener_for(&self.state, DurationInputState::on_down))
+                    .on_action(window.listener_for(&self.state, DurationInputState::on_left))
+                    .on_action(window.listener_for(&self.state, DurationInputState::on_right))
+                    .on_key_down(window.listener_for(&self.state, DurationInputState::on_key_down))
+            })
+            .flex_none()
+            .w_full()
+            .items_center()
+            .overflow_hidden()
+            .when(self.appearance, |this| {
+                this.bg(bg)
+                    .text_color(fg)
+                    .when(self.disabled, |this| this.opacity(0.5))
+                    .border_1()
+                    .border_color(cx.theme().input)
+                    .rounded(cx.theme().radius)
+                    .when(cx.theme().shadow, |this| this.shadow_xs())
+                    .when(is_focused, |this| this.focused_border(cx))
+            })
+            .input_text_size(self.size)
+            .input_size(self.size)
+            .refine_style(&self.style)
+            .children(segments.iter().enumerate().map(|(ix, segment)| {
+                let is_active = is_focused && ix == active_segment;
+                let text: SharedString = format!("{:02}", segment.value(duration)).into();
+
+                h_flex().when(ix > 0, |this| this.child(":")).child(
+                    div()
+                        .id(ix)
+                        .rounded(cx.theme().radius.min(px(4.)))
+                        .when(is_active, |this| this.bg(cx.theme().selection))
+                        .child(text)
+                        .when(!self.disabled, |this| {
+                            this.on_mouse_down(
+                                MouseButton::Left,
+                                window.listener_for(&self.state, move |state, _, _, cx| {
+                                    state.set_active_segment(ix, cx);
+                                }),
+                            )
+                        }),
+                )
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{DurationSegment, MAX_DURATION, set_duration_segment, step_duration};
+
+    fn hms(h: u64, m: u64, s: u64) -> Duration {
+        Duration::from_secs(h * 3600 + m * 60 + s)
+    }
+
+    #[test]
+    fn test_step_duration() {
+        assert_eq!(
+            step_duration(hms(0, 59, 0), DurationSegment::Minutes, 1, MAX_DURATION),
+            hms(1, 0, 0)
+        );
+        assert_eq!(
+            step_duration(hms(1, 0, 0), DurationSegment::Seconds, -1, MAX_DURATION),
+            hms(0, 59, 59)
+        );
+        assert_eq!(
+            step_duration(hms(0, 0, 30), DurationSegment::Minutes, -1, MAX_DURATION),
+            Duration::ZERO
+        );
+        assert_eq!(
+            step_duration(hms(1, 30, 0), DurationSegment::Hours, 1, hms(2, 0, 0)),
+            hms(2, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_set_duration_segment() {
+        assert_eq!(
+            set_duration_segment(hms(1, 2, 3), DurationSegment::Minutes, 45, MAX_DURATION),
+            hms(1, 45, 3)
+        );
+        assert_eq!(
+            set_duration_segment(hms(1, 2, 3), DurationSegment::Hours, 12, MAX_DURATION),
+            hms(12, 2, 3)
+        );
+        assert_eq!(
+            set_duration_segment(hms(1, 2, 3), DurationSegment::Seconds, 99, MAX_DURATION),
+            hms(1, 2, 59)
+        );
+        assert_eq!(
+            set_duration_segment(hms(1, 2, 3), DurationSegment::Hours, 5, hms(2, 0, 0)),
+            hms(2, 0, 0)
+        );
+    }
+}
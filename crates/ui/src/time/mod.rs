@@ -1,3 +1,5 @@
 pub mod calendar;
 pub mod date_picker;
+pub mod duration_input;
+pub mod time_picker;
 mod utils;
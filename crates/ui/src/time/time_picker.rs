@@ -0,0 +1,789 @@
+use chrono::{NaiveTime, Timelike as _};
+use gpui::{
+    App, ClickEvent, Context, ElementId, Empty, Entity, EventEmitter, FocusHandle, Focusable,
+    InteractiveElement as _, IntoElement, KeyBinding, KeyDownEvent, MouseButton,
+    ParentElement as _, Render, RenderOnce, SharedString, StatefulInteractiveElement as _,
+    StyleRefinement, Styled, Subscription, Window, anchored, deferred, div,
+    prelude::FluentBuilder as _, px,
+};
+use rust_i18n::t;
+
+use crate::{
+    ActiveTheme, Disableable, Icon, IconName, Sizable, Size, StyleSized as _, StyledExt as _,
+    actions::{Cancel, Confirm, SelectDown, SelectLeft, SelectRight, SelectUp},
+    h_flex,
+    input::{Delete, clear_button, input_style},
+};
+
+use super::utils::type_segment_digit;
+
+const CONTEXT: &'static str = "TimePicker";
+pub(crate) fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("enter", Confirm { secondary: false }, Some(CONTEXT)),
+        KeyBinding::new("escape", Cancel, Some(CONTEXT)),
+        KeyBinding::new("delete", Delete, Some(CONTEXT)),
+        KeyBinding::new("backspace", Delete, Some(CONTEXT)),
+        KeyBinding::new("up", SelectUp, Some(CONTEXT)),
+        KeyBinding::new("down", SelectDown, Some(CONTEXT)),
+        KeyBinding::new("left", SelectLeft, Some(CONTEXT)),
+        KeyBinding::new("right", SelectRight, Some(CONTEXT)),
+    ])
+}
+
+/// Events emitted by the TimePicker.
+#[derive(Clone)]
+pub enum TimePickerEvent {
+    Change(Option<NaiveTime>),
+}
+
+/// The hour format of the time picker.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HourFormat {
+    /// 12-hour clock with AM/PM, e.g.: `09:30 PM`.
+    Hour12,
+    /// 24-hour clock, e.g.: `21:30`.
+    #[default]
+    Hour24,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TimeSegment {
+    Hour,
+    Minute,
+    Second,
+    Period,
+}
+
+impl TimeSegment {
+    fn max(&self, hour_format: HourFormat) -> u32 {
+        match self {
+            TimeSegment::Hour => match hour_format {
+                HourFormat::Hour12 => 12,
+                HourFormat::Hour24 => 23,
+            },
+            TimeSegment::Minute | TimeSegment::Second => 59,
+            TimeSegment::Period => 1,
+        }
+    }
+}
+
+/// Returns the value of the segment to display, the hour is in 1..=12 for [`HourFormat::Hour12`].
+fn segment_value(time: NaiveTime, segment: TimeSegment, hour_format: HourFormat) -> u32 {
+    match segment {
+        TimeSegment::Hour => match hour_format {
+            HourFormat::Hour12 => match time.hour() % 12 {
+                0 => 12,
+                hour => hour,
+            },
+            HourFormat::Hour24 => time.hour(),
+        },
+        TimeSegment::Minute => time.minute(),
+        TimeSegment::Second => time.second(),
+        TimeSegment::Period => (time.hour() >= 12) as u32,
+    }
+}
+
+/// Set the value of the segment, the value is the displayed value (see [`segment_value`]).
+fn set_segment_value(
+    time: NaiveTime,
+    segment: TimeSegment,
+    value: u32,
+    hour_format: HourFormat,
+) -> NaiveTime {
+    let value = value.min(segment.max(hour_format));
+    let is_pm = time.hour() >= 12;
+    match segment {
+        TimeSegment::Hour => match hour_format {
+            HourFormat::Hour12 => time.with_hour(value % 12 + if is_pm { 12 } else { 0 }),
+            HourFormat::Hour24 => time.with_hour(value),
+        },
+        TimeSegment::Minute => time.with_minute(value),
+        TimeSegment::Second => time.with_second(value),
+        TimeSegment::Period => time.with_hour(time.hour() % 12 + value * 12),
+    }
+    .unwrap_or(time)
+}
+
+/// Step the segment by `delta`, the minute segment steps by `minute_step`.
+///
+/// The segment wraps around without carrying to the next segment, like the native time inputs.
+fn step_segment(time: NaiveTime, segment: TimeSegment, delta: i32, minute_step: u32) -> NaiveTime {
+    match segment {
+        TimeSegment::Hour => time.with_hour((time.hour() as i32 + delta).rem_euclid(24) as u32),
+        TimeSegment::Minute => {
+            let step = minute_step.max(1) as i32;
+            let minute = time.minute() as i32 / step * step + delta * step;
+            time.with_minute(minute.rem_euclid(60) as u32)
+        }
+        TimeSegment::Second => {
+            time.with_second((time.second() as i32 + delta).rem_euclid(60) as u32)
+        }
+        TimeSegment::Period => time.with_hour((time.hour() + 12) % 24),
+    }
+    .unwrap_or(time)
+}
+
+fn format_segment(
+    time: Option<NaiveTime>,
+    segment: TimeSegment,
+    hour_format: HourFormat,
+) -> SharedString {
+    let Some(time) = time else {
+        return "--".into();
+    };
+
+    match segment {
+        TimeSegment::Period => match segment_value(time, segment, hour_format) {
+            0 => "AM".into(),
+            _ => "PM".into(),
+        },
+        _ => format!("{:02}", segment_value(time, segment, hour_format)).into(),
+    }
+}
+
+/// Use to store the state of the time picker.
+pub struct TimePickerState {
+    focus_handle: FocusHandle,
+    time: Option<NaiveTime>,
+    hour_format: HourFormat,
+    seconds: bool,
+    minute_step: u32,
+    open: bool,
+    active_segment: usize,
+    pending_digit: Option<u32>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl Focusable for TimePickerState {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+impl EventEmitter<TimePickerEvent> for TimePickerState {}
+
+impl TimePickerState {
+    /// Create a time picker state.
+    pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let focus_handle = cx.focus_handle();
+        let _subscriptions = vec![cx.on_blur(&focus_handle, window, |this, _, cx| {
+            this.pending_digit = None;
+            cx.notify();
+        })];
+
+        Self {
+            focus_handle,
+            time: None,
+            hour_format: HourFormat::default(),
+            seconds: false,
+            minute_step: 1,
+            open: false,
+            active_segment: 0,
+            pending_digit: None,
+            _subscriptions,
+        }
+    }
+
+    /// Set the hour format of the time picker, default: [`HourFormat::Hour24`].
+    pub fn hour_format(mut self, hour_format: HourFormat) -> Self {
+        self.hour_format = hour_format;
+        self
+    }
+
+    /// Set whether to show the seconds segment, default: false.
+    pub fn seconds(mut self, seconds: bool) -> Self {
+        self.seconds = seconds;
+        self
+    }
+
+    /// Set the minute step used by the keyboard stepping and the dropdown, default: 1.
+    pub fn minute_step(mut self, minute_step: u32) -> Self {
+        self.minute_step = minute_step.clamp(1, 30);
+        self
+    }
+
+    /// Get the time of the time picker.
+    pub fn time(&self) -> Option<NaiveTime> {
+        self.time
+    }
+
+    /// Set the time of the time picker.
+    pub fn set_time(
+        &mut self,
+        time: impl Into<Option<NaiveTime>>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.update_time(time.into(), false, cx);
+    }
+
+    fn segments(&self) -> Vec<TimeSegment> {
+        let mut segments = vec![TimeSegment::Hour, TimeSegment::Minute];
+        if self.seconds {
+            segments.push(TimeSegment::Second);
+        }
+        if self.hour_format == HourFormat::Hour12 {
+            segments.push(TimeSegment::Period);
+        }
+        segments
+    }
+
+    fn current_segment(&self) -> TimeSegment {
+        let segments = self.segments();
+        segments[self.active_segment.min(segments.len() - 1)]
+    }
+
+    fn update_time(&mut self, time: Option<NaiveTime>, emit: bool, cx: &mut Context<Self>) {
+        let time = time.map(|time| time.with_nanosecond(0).unwrap_or(time));
+        let time = if self.seconds {
+            time
+        } else {
+            time.map(|time| time.with_second(0).unwrap_or(time))
+        };
+
+        if self.time == time {
+            return;
+        }
+
+        self.time = time;
+        if emit {
+            cx.emit(TimePickerEvent::Change(time));
+        }
+        cx.notify();
+    }
+
+    fn set_active_segment(&mut self, ix: usize, cx: &mut Context<Self>) {
+        self.active_segment = ix.min(self.segments().len() - 1);
+        self.pending_digit = None;
+        cx.notify();
+    }
+
+    fn select_segment_value(
+        &mut self,
+        segment: TimeSegment,
+        value: u32,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let time = self.time.unwrap_or(NaiveTime::MIN);
+        self.update_time(
+            Some(set_segment_value(time, segment, value, self.hour_format)),
+            true,
+            cx,
+        );
+        self.focus_handle.focus(window, cx);
+    }
+
+    fn step(&mut self, delta: i32, cx: &mut Context<Self>) {
+        self.pending_digit = None;
+        let time = match self.time {
+            Some(time) => step_segment(time, self.current_segment(), delta, self.minute_step),
+            None => NaiveTime::MIN,
+        };
+        self.update_time(Some(time), true, cx);
+    }
+
+    fn on_up(&mut self, _: &SelectUp, _: &mut Window, cx: &mut Context<Self>) {
+        self.step(1, cx);
+    }
+
+    fn on_down(&mut self, _: &SelectDown, _: &mut Window, cx: &mut Context<Self>) {
+        self.step(-1, cx);
+    }
+
+    fn on_left(&mut self, _: &SelectLeft, _: &mut Window, cx: &mut Context<Self>) {
+        self.set_active_segment(self.active_segment.saturating_sub(1), cx);
+    }
+
+    fn on_right(&mut self, _: &SelectRight, _: &mut Window, cx: &mut Context<Self>) {
+        self.set_active_segment(self.active_segment + 1, cx);
+    }
+
+    fn on_escape(&mut self, _: &Cancel, _: &mut Window, cx: &mut Context<Self>) {
+        if !self.open {
+            cx.propagate();
+        }
+
+        self.open = false;
+        cx.notify();
+    }
+
+    fn on_enter(&mut self, _: &Confirm, _: &mut Window, cx: &mut Context<Self>) {
+        self.open = !self.open;
+        cx.notify();
+    }
+
+    fn on_delete(&mut self, _: &Delete, window: &mut Window, cx: &mut Context<Self>) {
+        self.clean(&ClickEvent::default(), window, cx);
+    }
+
+    fn on_key_down(&mut self, event: &KeyDownEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let keystroke = &event.keystroke;
+        if keystroke.modifiers.modified() {
+            return;
+        }
+
+        let segment = self.current_segment();
+        let time = self.time.unwrap_or(NaiveTime::MIN);
+        let (time, complete) = match (segment, keystroke.key.as_str()) {
+            (TimeSegment::Period, "a") => {
+                (set_segment_value(time, segment, 0, self.hour_format), true)
+            }
+            (TimeSegment::Period, "p") => {
+                (set_segment_value(time, segment, 1, self.hour_format), true)
+            }
+            (TimeSegment::Period, _) => return,
+            (_, key) => {
+                if key.len() != 1 {
+                    return;
+                }
+                let Some(digit) = key.chars().next().and_then(|c| c.to_digit(10)) else {
+                    return;
+                };
+
+                let max = segment.max(self.hour_format);
+                let (value, complete) = type_segment_digit(&mut self.pending_digit, digit, max);
+                (
+                    set_segment_value(time, segment, value, self.hour_format),
+                    complete,
+                )
+            }
+        };
+
+        cx.stop_propagation();
+        self.update_time(Some(time), true, cx);
+        if complete {
+            self.set_active_segment(self.active_segment + 1, cx);
+        }
+    }
+
+    fn clean(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        cx.stop_propagation();
+        self.pending_digit = None;
+        self.update_time(None, true, cx);
+    }
+
+    fn toggle_dropdown(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        self.open = !self.open;
+        cx.notify();
+    }
+}
+
+impl Render for TimePickerState {
+    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+        Empty
+    }
+}
+
+/// A TimePicker element to select a time of the day.
+///
+/// Use `up` and `down` keys to step the active segment, `left` and `right` to move
+/// between segments, and type digits to input the value directly.
+#[derive(IntoElement)]
+pub struct TimePicker {
+    id: ElementId,
+    style: StyleRefinement,
+    state: Entity<TimePickerState>,
+    cleanable: bool,
+    placeholder: Option<SharedString>,
+    size: Size,
+    appearance: bool,
+    disabled: bool,
+}
+
+impl Sizable for TimePicker {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl Focusable for TimePicker {
+    fn focus_handle(&self, cx: &App) -> FocusHandle {
+        self.state.focus_handle(cx)
+    }
+}
+
+impl Styled for TimePicker {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl Disableable for TimePicker {
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl TimePicker {
+    /// Create a new TimePicker with the given [`TimePickerState`].
+    pub fn new(state: &Entity<TimePickerState>) -> Self {
+        Self {
+            id: ("time-picker", state.entity_id()).into(),
+            state: state.clone(),
+            cleanable: false,
+            placeholder: None,
+            size: Size::default(),
+            style: StyleRefinement::default(),
+            appearance: true,
+            disabled: false,
+        }
+    }
+
+    /// Set the placeholder of the time picker, default: "Select time".
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Set whether to show the clear button when the time is not empty, default is false.
+    pub fn cleanable(mut self, cleanable: bool) -> Self {
+        self.cleanable = cleanable;
+        self
+    }
+
+    /// Set appearance of the time picker, if false, the time picker will be in a minimal style.
+    pub fn appearance(mut self, appearance: bool) -> Self {
+        self.appearance = appearance;
+        self
+    }
+
+    fn render_column(
+        &self,
+        segment: TimeSegment,
+        values: Vec<u32>,
+        window: &Window,
+        cx: &App,
+    ) -> impl IntoElement {
+        let state = self.state.read(cx);
+        let hour_format = state.hour_format;
+        let selected = state
+            .time
+            .map(|time| segment_value(time, segment, hour_format));
+
+        div()
+            .id(SharedString::from(format!("{:?}", segment)))
+            .h(px(220.))
+            .min_w(px(48.))
+            .overflow_y_scroll()
+            .children(values.into_iter().map(|value| {
+                let is_selected = selected == Some(value);
+                let label = format_segment(
+                    Some(set_segment_value(
+                        NaiveTime::MIN,
+                        segment,
+                        value,
+                        hour_format,
+                    )),
+                    segment,
+                    hour_format,
+                );
+
+                div()
+                    .id(value as usize)
+                    .px_2()
+                    .py_1()
+                    .text_center()
+                    .rounded(cx.theme().radius)
+                    .cursor_pointer()
+                    .when(is_selected, |this| {
+                        this.bg(cx.theme().accent)
+                            .text_color(cx.theme().accent_foreground)
+                    })
+                    .when(!is_selected, |this| {
+                        this.hover(|this| this.bg(cx.theme().accent.opacity(0.5)))
+                    })
+                    .child(label)
+                    .on_click(
+                        window.listener_for(&self.state, move |state, _, window, cx| {
+                            state.select_segment_value(segment, value, window, cx);
+                        }),
+                    )
+            }))
+    }
+}
+
+impl RenderOnce for TimePicker {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        // This for keep focus border style, when click on the popup.
+        let is_focused = self.focus_handle(cx).contains_focused(window, cx);
+        let state = self.state.read(cx);
+        let segments = state.segments();
+        let active_segment = state.active_segment.min(segments.len() - 1);
+        let hour_format = state.hour_format;
+        let minute_step = state.minute_step;
+        let time = state.time;
+        let open = state.open;
+        let show_clean = self.cleanable && time.is_some();
+        let placeholder = self
+            .placeholder
+            .clone()
+            .unwrap_or_else(|| t!("TimePicker.placeholder").into());
+
+        let (bg, fg) = input_style(self.disabled, cx);
+
+        let columns = segments
+            .iter()
+            .map(|segment| {
+                let values = match segment {
+                    TimeSegment::Hour => match hour_format {
+                        HourFormat::Hour12 => (1..=12).collect(),
+                        HourFormat::Hour24 => (0..24).collect(),
+                    },
+                    TimeSegment::Minute => (0..60).step_by(minute_step as usize).collect(),
+                    TimeSegment::Second => (0..60).collect(),
+                    TimeSegment::Period => vec![0, 1],
+                };
+                (*segment, values)
+            })
+            .collect::<Vec<_>>();
+
+        div()
+            .id(self.id.clone())
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle(cx).tab_stop(true))
+            .when(!self.disabled, |this| {
+                this.on_action(window.listener_for(&self.state, TimePickerState::on_enter))
+                    .on_action(window.listener_for(&self.state, TimePickerState::on_delete))
+                    .on_action(window.listener_for(&self.state, TimePickerState::on_up))
+                    .on_action(window.listener_for(&self.state, TimePickerState::on_down))
+                    .on_action(window.listener_for(&self.state, TimePickerState::on_left))
+                    .on_action(window.listener_for(&self.state, TimePickerState::on_right))
+                    .on_key_down(window.listener_for(&self.state, TimePickerState::on_key_down))
+            })
+            .when(open, |this| {
+                this.on_action(window.listener_for(&self.state, TimePickerState::on_escape))
+            })
+            .flex_none()
+            .w_full()
+            .relative()
+            .input_text_size(self.size)
+            .refine_style(&self.style)
+            .child(
+                div()
+                    .id("time-picker-input")
+                    .relative()
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .when(self.appearance, |this| {
+                        this.bg(bg)
+                            .text_color(fg)
+                            .when(self.disabled, |this| this.opacity(0.5))
+                            .border_1()
+                            .border_color(cx.theme().input)
+                            .rounded(cx.theme().radius)
+                            .when(cx.theme().shadow, |this| this.shadow_xs())
+                            .when(is_focused, |this| this.focused_border(cx))
+                    })
+                    .overflow_hidden()
+                    .input_text_size(self.size)
+                    .input_size(self.size)
+                    .when(!open && !self.disabled, |this| {
+                        this.on_click(
+                            window.listener_for(&self.state, TimePickerState::toggle_dropdown),
+                        )
+                    })
+                    .child(
+                        h_flex()
+                            .w_full()
+                            .items_center()
+                            .justify_between()
+                            .gap_1()
+                            .child(
+                                h_flex()
+                                    .w_full()
+                                    .overflow_hidden()
+                                    .when(time.is_none() && !is_focused, |this| {
+                                        this.text_color(cx.theme().muted_foreground)
+                                            .child(placeholder)
+                                    })
+                                    .when(time.is_some() || is_focused, |this| {
+                                        this.children(segments.iter().enumerate().map(
+                                            |(ix, segment)| {
+                                                let is_active = is_focused && ix == active_segment;
+                                                h_flex()
+                                                    .when(ix > 0, |this| {
+                                                        this.child(match segment {
+                                                            TimeSegment::Period => " ",
+                                                            _ => ":",
+                                                        })
+                                                    })
+                                                    .child(
+                                                        div()
+                                                            .id(ix)
+                                                            .rounded(cx.theme().radius.min(px(4.)))
+                                                            .when(time.is_none(), |this| {
+                                                                this.text_color(
+                                                                    cx.theme().muted_foreground,
+                                                                )
+                                                            })
+                                                            .when(is_active, |this| {
+                                                                this.bg(cx.theme().selection)
+                                                            })
+                                                            .child(format_segment(
+                                                                time,
+                                                                *segment,
+                                                                hour_format,
+                                                            ))
+                                                            .when(!self.disabled, |this| {
+                                                                this.on_mouse_down(
+                                                                    MouseButton::Left,
+                                                                    window.listener_for(
+                                                                        &self.state,
+                                                                        move |state, _, _, cx| {
+                                                                            state
+                                                                                .set_active_segment(
+                                                                                    ix, cx,
+                                                                                );
+                                                                        },
+                                                                    ),
+                                                                )
+                                                            }),
+                                                    )
+                                            },
+                                        ))
+                                    }),
+                            )
+                            .when(!self.disabled, |this| {
+                                this.when(show_clean, |this| {
+                                    this.child(clear_button(cx).on_click(
+                                        window.listener_for(&self.state, TimePickerState::clean),
+                                    ))
+                                })
+                                .when(!show_clean, |this| {
+                                    this.child(
+                                        Icon::new(IconName::Clock)
+                                            .xsmall()
+                                            .text_color(cx.theme().muted_foreground),
+                                    )
+                                })
+                            }),
+                    ),
+            )
+            .when(open, |this| {
+                this.child(
+                    deferred(
+                        anchored().snap_to_window_with_margin(px(8.)).child(
+                            h_flex()
+                                .occlude()
+                                .mt_1p5()
+                                .p_1()
+                                .gap_1()
+                                .items_start()
+                                .border_1()
+                                .border_color(cx.theme().border)
+                                .shadow_lg()
+                                .rounded((cx.theme().radius * 2.).min(px(8.)))
+                                .bg(cx.theme().popover)
+                                .text_color(cx.theme().popover_foreground)
+                                .on_mouse_up_out(
+                                    MouseButton::Left,
+                                    window.listener_for(&self.state, |view, _, window, cx| {
+                                        view.on_escape(&Cancel, window, cx);
+                                    }),
+                                )
+                                .children(columns.into_iter().map(|(segment, values)| {
+                                    self.render_column(segment, values, window, cx)
+                                })),
+                        ),
+                    )
+                    .with_priority(2),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveTime;
+
+    use super::{HourFormat, TimeSegment, format_segment, set_segment_value, step_segment};
+
+    fn time(h: u32, m: u32, s: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, s).unwrap()
+    }
+
+    #[test]
+    fn test_step_segment() {
+        assert_eq!(
+            step_segment(time(23, 0, 0), TimeSegment::Hour, 1, 1),
+            time(0, 0, 0)
+        );
+        assert_eq!(
+            step_segment(time(0, 0, 0), TimeSegment::Hour, -1, 1),
+            time(23, 0, 0)
+        );
+        assert_eq!(
+            step_segment(time(10, 59, 0), TimeSegment::Minute, 1, 1),
+            time(10, 0, 0)
+        );
+        assert_eq!(
+            step_segment(time(10, 7, 0), TimeSegment::Minute, 1, 15),
+            time(10, 15, 0)
+        );
+        assert_eq!(
+            step_segment(time(10, 0, 0), TimeSegment::Minute, -1, 15),
+            time(10, 45, 0)
+        );
+        assert_eq!(
+            step_segment(time(10, 0, 0), TimeSegment::Second, -1, 1),
+            time(10, 0, 59)
+        );
+        assert_eq!(
+            step_segment(time(10, 0, 0), TimeSegment::Period, 1, 1),
+            time(22, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_set_segment_value() {
+        let h12 = HourFormat::Hour12;
+        let h24 = HourFormat::Hour24;
+
+        assert_eq!(
+            set_segment_value(time(21, 30, 0), TimeSegment::Hour, 12, h12),
+            time(12, 30, 0)
+        );
+        assert_eq!(
+            set_segment_value(time(9, 30, 0), TimeSegment::Hour, 12, h12),
+            time(0, 30, 0)
+        );
+        assert_eq!(
+            set_segment_value(time(9, 30, 0), TimeSegment::Period, 1, h12),
+            time(21, 30, 0)
+        );
+        assert_eq!(
+            set_segment_value(time(9, 30, 0), TimeSegment::Hour, 99, h24),
+            time(23, 30, 0)
+        );
+        assert_eq!(
+            set_segment_value(time(9, 30, 0), TimeSegment::Minute, 5, h24),
+            time(9, 5, 0)
+        );
+    }
+
+    #[test]
+    fn test_format_segment() {
+        let h12 = HourFormat::Hour12;
+        assert_eq!(format_segment(None, TimeSegment::Hour, h12), "--");
+        assert_eq!(
+            format_segment(Some(time(0, 5, 0)), TimeSegment::Hour, h12),
+            "12"
+        );
+        assert_eq!(
+            format_segment(Some(time(0, 5, 0)), TimeSegment::Minute, h12),
+            "05"
+        );
+        assert_eq!(
+            format_segment(Some(time(13, 5, 0)), TimeSegment::Period, h12),
+            "PM"
+        );
+        assert_eq!(
+            format_segment(Some(time(13, 5, 0)), TimeSegment::Hour, HourFormat::Hour24),
+            "13"
+        );
+    }
+}
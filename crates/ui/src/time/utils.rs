@@ -85,11 +85,33 @@ pub(crate) fn days_in_month(year: i32, month: u32) -> Vec<Vec<NaiveDate>> {
     days
 }
 
+/// Apply a typed digit to a 2-digit time segment (e.g. hour, minute).
+///
+/// The `pending` digit is the first digit typed into the segment, if the new value
+/// can not be extended by another digit without exceeding `max`, the segment is complete.
+///
+/// Returns the new value of the segment and whether the segment is complete.
+pub(crate) fn type_segment_digit(pending: &mut Option<u32>, digit: u32, max: u32) -> (u32, bool) {
+    if let Some(first) = pending.take() {
+        let value = first * 10 + digit;
+        if value <= max {
+            return (value, true);
+        }
+    }
+
+    if digit * 10 > max {
+        (digit.min(max), true)
+    } else {
+        *pending = Some(digit);
+        (digit, false)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::{Datelike, NaiveDate};
 
-    use super::{days_in_month, NaiveDateExt};
+    use super::{days_in_month, type_segment_digit, NaiveDateExt};
 
     #[test]
     fn test_days_in_month() {
@@ -178,4 +200,22 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_type_segment_digit() {
+        let mut pending = None;
+        assert_eq!(type_segment_digit(&mut pending, 1, 59), (1, false));
+        assert_eq!(type_segment_digit(&mut pending, 5, 59), (15, true));
+        assert_eq!(pending, None);
+
+        // 7 can not be extended to a valid minute.
+        assert_eq!(type_segment_digit(&mut pending, 7, 59), (7, true));
+
+        // 2 then 5 exceeds 23 hours, restart with 5.
+        assert_eq!(type_segment_digit(&mut pending, 2, 23), (2, false));
+        assert_eq!(type_segment_digit(&mut pending, 5, 23), (5, true));
+
+        assert_eq!(type_segment_digit(&mut pending, 0, 12), (0, false));
+        assert_eq!(type_segment_digit(&mut pending, 9, 12), (9, true));
+    }
 }
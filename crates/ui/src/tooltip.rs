@@ -1,15 +1,15 @@
 use std::{cell::Cell, rc::Rc, time::Duration};
 
 use gpui::{
-    Action, Anchor, AnyElement, AnyView, App, AppContext, Bounds, Context, ElementId, Half,
-    IntoElement, ParentElement, Pixels, Render, SharedString, StatefulInteractiveElement,
-    StyleRefinement, Styled, Task, Window, anchored, deferred, div, point,
-    prelude::FluentBuilder, px,
+    Action, Anchor, AnyElement, AnyView, App, AppContext, Bounds, Context, ElementId, FocusHandle,
+    IntoElement, ParentElement, Pixels, Render, SharedString, Size, StatefulInteractiveElement,
+    StyleRefinement, Styled, Task, Window, anchored, deferred, div, point, prelude::FluentBuilder,
+    px,
 };
 
 use crate::{
-    ActiveTheme, StyledExt,
-    animation::{Transition, ease_in_out_cubic, ease_out_cubic},
+    ActiveTheme, ElementExt as _, Placement, StyledExt,
+    animation::{Transition, UiTestMode, ease_in_out_cubic, ease_out_cubic},
     h_flex,
     kbd::Kbd,
     root::Root,
@@ -151,11 +151,80 @@ const ENTER_DURATION: Duration = Duration::from_millis(150);
 /// Duration of the position-slide animation when switching tooltips.
 const SLIDE_DURATION: Duration = Duration::from_millis(200);
 
+/// Options for a managed tooltip, see [`TooltipExt::tooltip_with`].
+#[derive(Clone)]
+pub struct TooltipOptions {
+    placement: Placement,
+    delay: Duration,
+    focus_handle: Option<FocusHandle>,
+}
+
+impl Default for TooltipOptions {
+    fn default() -> Self {
+        Self {
+            placement: Placement::Top,
+            delay: SHOW_DELAY,
+            focus_handle: None,
+        }
+    }
+}
+
+impl TooltipOptions {
+    /// Create a new tooltip options with default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the preferred placement of the tooltip, default is [`Placement::Top`].
+    ///
+    /// The tooltip will flip to the opposite side if there is not enough space in the window.
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Set the delay before showing the tooltip, default is 500ms.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Show the tooltip when the focus handle is focused, e.g. by keyboard navigation.
+    pub fn track_focus(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle.clone());
+        self
+    }
+}
+
 /// Content for a managed tooltip.
 #[derive(Clone)]
 pub(crate) struct TooltipContent {
     pub build: Rc<dyn Fn(&mut Window, &mut App) -> AnyView>,
     pub trigger_bounds: Bounds<Pixels>,
+    pub placement: Placement,
+    pub delay: Duration,
+}
+
+/// Returns the placement to use for the tooltip, flip to the opposite side
+/// if the preferred placement does not fit in the viewport.
+pub(crate) fn resolve_placement(
+    placement: Placement,
+    trigger_bounds: Bounds<Pixels>,
+    tooltip_size: Size<Pixels>,
+    viewport_size: Size<Pixels>,
+) -> Placement {
+    let fits = |placement: Placement| match placement {
+        Placement::Top => trigger_bounds.top() - tooltip_size.height >= px(0.),
+        Placement::Bottom => trigger_bounds.bottom() + tooltip_size.height <= viewport_size.height,
+        Placement::Left => trigger_bounds.left() - tooltip_size.width >= px(0.),
+        Placement::Right => trigger_bounds.right() + tooltip_size.width <= viewport_size.width,
+    };
+
+    if fits(placement) || !fits(placement.opposite()) {
+        placement
+    } else {
+        placement.opposite()
+    }
 }
 
 /// Manages tooltip lifecycle: delay, grace period, animations, and rendering.
@@ -169,6 +238,10 @@ pub struct TooltipOverlay {
     had_recent_tooltip: bool,
     animation_epoch: usize,
     is_switching: bool,
+    /// The last measured size of the tooltip, used to resolve the placement.
+    tooltip_size: Rc<Cell<Size<Pixels>>>,
+    /// The focus handle of the trigger that shows the tooltip by focus.
+    focused_trigger: Option<FocusHandle>,
 
     _show_task: Option<Task<()>>,
     _hide_task: Option<Task<()>>,
@@ -183,6 +256,8 @@ impl TooltipOverlay {
             had_recent_tooltip: false,
             animation_epoch: 0,
             is_switching: false,
+            tooltip_size: Rc::new(Cell::new(Size::default())),
+            focused_trigger: None,
             _show_task: None,
             _hide_task: None,
         }
@@ -219,8 +294,9 @@ impl TooltipOverlay {
             // New: delay then show with slideDown
             let epoch = self.next_epoch();
             let content = content.clone();
+            let delay = UiTestMode::delay(content.delay, cx);
             self._show_task = Some(cx.spawn_in(window, async move |this, cx| {
-                cx.background_executor().timer(SHOW_DELAY).await;
+                cx.background_executor().timer(delay).await;
                 let _ = this.update_in(cx, |this, _, cx| {
                     if this.epoch != epoch {
                         return;
@@ -249,8 +325,9 @@ impl TooltipOverlay {
         let epoch = self.next_epoch();
         self.had_recent_tooltip = true;
 
+        let delay = UiTestMode::delay(GRACE_PERIOD, cx);
         self._hide_task = Some(cx.spawn_in(window, async move |this, cx| {
-            cx.background_executor().timer(GRACE_PERIOD).await;
+            cx.background_executor().timer(delay).await;
             let _ = this.update_in(cx, |this, _, cx| {
                 if this.epoch != epoch {
                     return;
@@ -275,22 +352,45 @@ impl Render for TooltipOverlay {
         let animation_epoch = self.animation_epoch;
         let is_switching = self.is_switching;
         let prev_trigger_bounds = self.prev_trigger_bounds;
-
-        let anchor_position = point(
-            trigger_bounds.origin.x + trigger_bounds.size.width.half(),
-            trigger_bounds.origin.y,
+        let placement = resolve_placement(
+            content.placement,
+            trigger_bounds,
+            self.tooltip_size.get(),
+            window.viewport_size(),
         );
 
+        let center = trigger_bounds.center();
+        let (anchor_position, anchor) = match placement {
+            Placement::Top => (point(center.x, trigger_bounds.top()), Anchor::BottomCenter),
+            Placement::Bottom => (point(center.x, trigger_bounds.bottom()), Anchor::TopCenter),
+            Placement::Left => (point(trigger_bounds.left(), center.y), Anchor::RightCenter),
+            Placement::Right => (point(trigger_bounds.right(), center.y), Anchor::LeftCenter),
+        };
+
+        // Measure the tooltip to flip the placement if there is not enough space.
+        let tooltip_size = self.tooltip_size.clone();
+        let measured = div()
+            .child(content_view)
+            .on_prepaint(move |bounds, window, _| {
+                if tooltip_size.get() != bounds.size {
+                    tooltip_size.set(bounds.size);
+                    window.refresh();
+                }
+            });
+
         deferred(
             anchored()
                 .snap_to_window_with_margin(px(4.))
                 .position(anchor_position)
-                .anchor(Anchor::BottomCenter)
-                .child(div().child(content_view).map(|el| {
+                .anchor(anchor)
+                .child(measured.map(|el| {
                     if is_switching {
                         let Some(prev_bounds) = prev_trigger_bounds else {
                             return el.into_any_element();
                         };
+                        if placement.is_horizontal() {
+                            return el.into_any_element();
+                        }
 
                         let is_same_y =
                             (trigger_bounds.origin.y - prev_bounds.origin.y).abs() < px(10.);
@@ -312,13 +412,19 @@ impl Render for TooltipOverlay {
                                     "tooltip-slide".into(),
                                     animation_epoch as u64,
                                 ),
+                                cx,
                             )
                             .into_any_element()
                     } else {
-                        // New tooltip: slideDown + fadeIn
+                        // New tooltip: slide towards the placement side + fadeIn
                         Transition::new(ENTER_DURATION)
                             .ease(ease_out_cubic)
-                            .slide_y(px(4.), px(0.))
+                            .map(|this| match placement {
+                                Placement::Top => this.slide_y(px(4.), px(0.)),
+                                Placement::Bottom => this.slide_y(px(-4.), px(0.)),
+                                Placement::Left => this.slide_x(px(4.), px(0.)),
+                                Placement::Right => this.slide_x(px(-4.), px(0.)),
+                            })
                             .fade(0.0, 1.0)
                             .apply(
                                 el,
@@ -326,6 +432,7 @@ impl Render for TooltipOverlay {
                                     "tooltip-enter".into(),
                                     animation_epoch as u64,
                                 ),
+                                cx,
                             )
                             .into_any_element()
                     }
@@ -338,6 +445,44 @@ impl Render for TooltipOverlay {
 
 // ── Extension trait for managed tooltips ─────────────────────────────────────
 
+/// Extension trait to add a managed tooltip to any stateful interactive element.
+///
+/// Unlike GPUI's `tooltip`, the managed tooltip is rendered by [`Root`] with a show delay,
+/// smart placement and enter animations, and can be triggered by keyboard focus.
+pub trait TooltipExt: StatefulInteractiveElement + crate::ElementExt + Sized {
+    /// Add a text tooltip.
+    fn tooltip_text(self, text: impl Into<SharedString>) -> Self {
+        let text: SharedString = text.into();
+        self.tooltip_with(TooltipOptions::default(), move |window, cx| {
+            Tooltip::new(text.clone()).build(window, cx)
+        })
+    }
+
+    /// Add a tooltip with a custom element.
+    fn tooltip_element<E, F>(self, builder: F) -> Self
+    where
+        E: IntoElement,
+        F: Fn(&mut Window, &mut App) -> E + 'static,
+    {
+        let builder = Rc::new(builder);
+        self.tooltip_with(TooltipOptions::default(), move |window, cx| {
+            let builder = builder.clone();
+            Tooltip::element(move |window, cx| builder(window, cx)).build(window, cx)
+        })
+    }
+
+    /// Add a tooltip with [`TooltipOptions`] to configure the placement, delay and focus trigger.
+    fn tooltip_with(
+        self,
+        options: TooltipOptions,
+        build: impl Fn(&mut Window, &mut App) -> AnyView + 'static,
+    ) -> Self {
+        ManagedTooltipExt::managed_tooltip_with(self, options, build)
+    }
+}
+
+impl<E: StatefulInteractiveElement + crate::ElementExt> TooltipExt for E {}
+
 // ── Shared tooltip state for components ─────────────────────────────────────
 
 /// Shared tooltip state that components (Button, Switch, Checkbox, Radio, etc.)
@@ -382,31 +527,77 @@ pub(crate) trait ManagedTooltipExt:
         self,
         build_tooltip: impl Fn(&mut Window, &mut App) -> AnyView + 'static,
     ) -> Self {
-        let build_tooltip = Rc::new(build_tooltip);
+        self.managed_tooltip_with(TooltipOptions::default(), build_tooltip)
+    }
+
+    fn managed_tooltip_with(
+        self,
+        options: TooltipOptions,
+        build_tooltip: impl Fn(&mut Window, &mut App) -> AnyView + 'static,
+    ) -> Self {
+        let build_tooltip: Rc<dyn Fn(&mut Window, &mut App) -> AnyView> = Rc::new(build_tooltip);
         let trigger_bounds_cell: Rc<Cell<Bounds<Pixels>>> = Rc::new(Cell::new(Bounds::default()));
         let bounds_writer = trigger_bounds_cell.clone();
+        let TooltipOptions {
+            placement,
+            delay,
+            focus_handle,
+        } = options;
+        let make_content = {
+            let build_tooltip = build_tooltip.clone();
+            move |trigger_bounds| TooltipContent {
+                build: build_tooltip.clone(),
+                trigger_bounds,
+                placement,
+                delay,
+            }
+        };
+
+        self.on_prepaint({
+            let make_content = make_content.clone();
+            let focus_handle = focus_handle.clone();
+            move |bounds, window, cx| {
+                bounds_writer.set(bounds);
+
+                // Show the tooltip when the trigger is focused by keyboard.
+                let Some(focus_handle) = focus_handle else {
+                    return;
+                };
+                let Some(overlay) = Root::tooltip_overlay(window, cx) else {
+                    return;
+                };
+                let focused = focus_handle.is_focused(window);
+                let is_showing = overlay.read(cx).focused_trigger.as_ref() == Some(&focus_handle);
+                if focused == is_showing {
+                    return;
+                }
 
-        self.on_prepaint(move |bounds, _, _| {
-            bounds_writer.set(bounds);
+                let content = make_content(bounds);
+                window.defer(cx, move |window, cx| {
+                    overlay.update(cx, |o, cx| {
+                        if focused {
+                            o.focused_trigger = Some(focus_handle);
+                            o.request_show(content, window, cx);
+                        } else {
+                            o.focused_trigger = None;
+                            o.request_hide(window, cx);
+                        }
+                    });
+                });
+            }
         })
         .on_hover({
-            let trigger_bounds_cell = trigger_bounds_cell.clone();
-            let build_tooltip = build_tooltip.clone();
             move |hovered, window, cx| {
                 if let Some(overlay) = Root::tooltip_overlay(window, cx) {
                     if *hovered {
-                        let bounds = trigger_bounds_cell.get();
+                        let content = make_content(trigger_bounds_cell.get());
                         overlay.update(cx, |o: &mut TooltipOverlay, cx| {
-                            o.request_show(
-                                TooltipContent {
-                                    build: build_tooltip.clone(),
-                                    trigger_bounds: bounds,
-                                },
-                                window,
-                                cx,
-                            );
+                            o.request_show(content, window, cx);
                         });
-                    } else {
+                    } else if !focus_handle
+                        .as_ref()
+                        .is_some_and(|handle| handle.is_focused(window))
+                    {
                         overlay.update(cx, |o: &mut TooltipOverlay, cx| {
                             o.request_hide(window, cx);
                         });
@@ -418,3 +609,46 @@ pub(crate) trait ManagedTooltipExt:
 }
 
 impl<E: StatefulInteractiveElement + crate::ElementExt> ManagedTooltipExt for E {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gpui::{bounds, size};
+
+    #[test]
+    fn test_resolve_placement() {
+        let viewport = size(px(800.), px(600.));
+        let tooltip = size(px(120.), px(40.));
+
+        let middle = bounds(point(px(300.), px(300.)), size(px(80.), px(24.)));
+        assert_eq!(
+            resolve_placement(Placement::Top, middle, tooltip, viewport),
+            Placement::Top
+        );
+        assert_eq!(
+            resolve_placement(Placement::Right, middle, tooltip, viewport),
+            Placement::Right
+        );
+
+        // Not enough space above, flip to bottom.
+        let top = bounds(point(px(300.), px(10.)), size(px(80.), px(24.)));
+        assert_eq!(
+            resolve_placement(Placement::Top, top, tooltip, viewport),
+            Placement::Bottom
+        );
+
+        // Not enough space on the right, flip to left.
+        let right = bounds(point(px(720.), px(300.)), size(px(60.), px(24.)));
+        assert_eq!(
+            resolve_placement(Placement::Right, right, tooltip, viewport),
+            Placement::Left
+        );
+
+        // Neither side fits, keep the preferred placement.
+        let tall = size(px(120.), px(700.));
+        assert_eq!(
+            resolve_placement(Placement::Bottom, middle, tall, viewport),
+            Placement::Bottom
+        );
+    }
+}
@@ -1,18 +1,21 @@
 use std::{cell::RefCell, ops::Range, rc::Rc};
 
 use gpui::{
-    App, Context, ElementId, Entity, FocusHandle, InteractiveElement as _, IntoElement, KeyBinding,
-    ListSizingBehavior, MouseButton, ParentElement, Render, RenderOnce, SharedString,
-    StyleRefinement, Styled, UniformListScrollHandle, Window, div, prelude::FluentBuilder as _,
-    uniform_list,
+    App, AppContext as _, Axis, Context, DragMoveEvent, ElementId, Entity, EntityId, FocusHandle,
+    InteractiveElement as _, IntoElement, KeyBinding, ListSizingBehavior, Modifiers, MouseButton,
+    MouseDownEvent, ParentElement, Render, RenderOnce, SharedString,
+    StatefulInteractiveElement as _, StyleRefinement, Styled, UniformListScrollHandle, Window, div,
+    prelude::FluentBuilder as _, px, uniform_list,
 };
 
 use crate::{
-    Selectable as _, StyledExt,
+    ActiveTheme as _, Selectable as _, StyledExt,
     actions::{Confirm, SelectDown, SelectLeft, SelectRight, SelectUp},
+    drag_drop::{DragItem, SortableExt as _, move_item},
     list::ListItem,
     menu::{ContextMenuExt as _, PopupMenu},
     scroll::ScrollableElement,
+    selection::{SelectionMode, SelectionState},
 };
 
 const CONTEXT: &str = "Tree";
@@ -73,6 +76,8 @@ pub struct TreeItem {
 pub struct TreeEntry {
     item: TreeItem,
     depth: usize,
+    /// The id of the parent item, `None` for the root items.
+    parent_id: Option<SharedString>,
 }
 
 impl TreeEntry {
@@ -93,6 +98,16 @@ impl TreeEntry {
         self.depth == 0
     }
 
+    /// The drag and drop group of the item, only the siblings can be reordered.
+    fn sort_group(&self, tree_id: EntityId) -> SharedString {
+        format!(
+            "tree-{}:{}",
+            tree_id,
+            self.parent_id.as_deref().unwrap_or_default()
+        )
+        .into()
+    }
+
     /// Whether this item is a folder (has children).
     #[inline]
     pub fn is_folder(&self) -> bool {
@@ -175,6 +190,24 @@ impl TreeItem {
         self.state.borrow().expanded
     }
 
+    /// Move the item `from_id` to the position of its sibling `to_id`, searching the descendants.
+    ///
+    /// Returns false if the items are not siblings.
+    fn move_sibling(items: &mut Vec<TreeItem>, from_id: &str, to_id: &str) -> bool {
+        let from = items.iter().position(|item| item.id.as_ref() == from_id);
+        let to = items.iter().position(|item| item.id.as_ref() == to_id);
+        match (from, to) {
+            (Some(from), Some(to)) => {
+                move_item(items, from, to);
+                true
+            }
+            (None, None) => items
+                .iter_mut()
+                .any(|item| Self::move_sibling(&mut item.children, from_id, to_id)),
+            _ => false,
+        }
+    }
+
     fn find_ancestors(&self, target_id: &SharedString) -> Option<Vec<TreeItem>> {
         if self.id == *target_id {
             return Some(vec![]);
@@ -196,8 +229,12 @@ pub struct TreeState {
     focus_handle: FocusHandle,
     entries: Vec<TreeEntry>,
     scroll_handle: UniformListScrollHandle,
-    selected_ix: Option<usize>,
+    selection: SelectionState,
     right_clicked_ix: Option<usize>,
+    reorderable: bool,
+    /// The `(from, to)` entry indexes of the dragged item and the drop target under the mouse.
+    drop_target: Option<(usize, usize)>,
+    on_reorder: Option<Rc<dyn Fn(&[TreeItem], &mut Window, &mut App)>>,
     render_item: Rc<dyn Fn(usize, &TreeEntry, bool, &mut Window, &mut App) -> ListItem>,
     context_menu_builder: Option<
         Rc<dyn Fn(usize, &TreeEntry, PopupMenu, &mut Window, &mut Context<TreeState>) -> PopupMenu>,
@@ -208,8 +245,11 @@ impl TreeState {
     /// Create a new empty tree state.
     pub fn new(cx: &mut App) -> Self {
         Self {
-            selected_ix: None,
+            selection: SelectionState::new(SelectionMode::Single, 0),
             right_clicked_ix: None,
+            reorderable: false,
+            drop_target: None,
+            on_reorder: None,
             focus_handle: cx.focus_handle(),
             scroll_handle: UniformListScrollHandle::default(),
             entries: Vec::new(),
@@ -223,8 +263,9 @@ impl TreeState {
         let items = items.into();
         self.entries.clear();
         for item in items.into_iter() {
-            self.add_entry(item, 0);
+            self.add_entry(item, 0, None);
         }
+        self.selection.reset(self.entries.len());
         self
     }
 
@@ -233,24 +274,122 @@ impl TreeState {
         let items = items.into();
         self.entries.clear();
         for item in items.into_iter() {
-            self.add_entry(item, 0);
+            self.add_entry(item, 0, None);
         }
-        self.selected_ix = None;
+        self.selection.reset(self.entries.len());
         self.right_clicked_ix = None;
         cx.notify();
     }
 
+    /// Set the selection mode, default is [`SelectionMode::Single`].
+    ///
+    /// In [`SelectionMode::Multiple`], `shift` click to select a range, `cmd` (`ctrl` on Linux and
+    /// Windows) click to toggle an entry.
+    pub fn selection_mode(mut self, mode: SelectionMode) -> Self {
+        self.selection = SelectionState::new(mode, self.entries.len());
+        self
+    }
+
+    /// Returns the selection of the entries.
+    pub fn selection(&self) -> &SelectionState {
+        &self.selection
+    }
+
+    /// Set whether the items can be reordered by drag and drop, default is `false`.
+    ///
+    /// Only the sibling items can be reordered, see [`TreeState::move_entry`].
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// Set whether the items can be reordered by drag and drop.
+    pub fn set_reorderable(&mut self, reorderable: bool, cx: &mut Context<Self>) {
+        self.reorderable = reorderable;
+        cx.notify();
+    }
+
+    /// Set the callback to be called with the root items in the new order after an item is
+    /// moved by drag and drop, e.g.: to save the order.
+    pub fn on_reorder(mut self, f: impl Fn(&[TreeItem], &mut Window, &mut App) + 'static) -> Self {
+        self.on_reorder = Some(Rc::new(f));
+        self
+    }
+
+    /// Returns the root tree items, with the children in the current order.
+    pub fn root_items(&self) -> Vec<TreeItem> {
+        self.entries
+            .iter()
+            .filter(|e| e.is_root())
+            .map(|e| e.item.clone())
+            .collect()
+    }
+
+    /// Move the entry `from` index to the position of the entry `to` index.
+    ///
+    /// Only the sibling entries (with the same parent) can be moved, returns false if not moved.
+    /// The selection is kept on the same item.
+    pub fn move_entry(&mut self, from: usize, to: usize, cx: &mut Context<Self>) -> bool {
+        if !self.move_entry_inner(from, to) {
+            return false;
+        }
+
+        cx.notify();
+        true
+    }
+
+    fn move_entry_inner(&mut self, from: usize, to: usize) -> bool {
+        let (Some(from_entry), Some(to_entry)) = (self.entries.get(from), self.entries.get(to))
+        else {
+            return false;
+        };
+        if from == to || from_entry.parent_id != to_entry.parent_id {
+            return false;
+        }
+
+        let from_id = from_entry.item.id.clone();
+        let to_id = to_entry.item.id.clone();
+        let right_clicked_id = self
+            .right_clicked_ix
+            .and_then(|ix| self.entries.get(ix))
+            .map(|entry| entry.item.id.clone());
+
+        let mut root_items = self.root_items();
+        if !TreeItem::move_sibling(&mut root_items, &from_id, &to_id) {
+            return false;
+        }
+
+        self.set_root_items(root_items);
+        self.right_clicked_ix = self.position_of(right_clicked_id);
+        true
+    }
+
+    fn position_of(&self, id: Option<SharedString>) -> Option<usize> {
+        let id = id?;
+        self.entries.iter().position(|entry| entry.item.id == id)
+    }
+
     /// Get the currently selected index, if any.
+    ///
+    /// In [`SelectionMode::Multiple`], this is the last selected one, see
+    /// [`TreeState::selection`] for all the selected entries.
     pub fn selected_index(&self) -> Option<usize> {
-        self.selected_ix
+        self.selection.selected_active_index()
     }
 
     /// Set the selected index, or `None` to clear selection.
     pub fn set_selected_index(&mut self, ix: Option<usize>, cx: &mut Context<Self>) {
-        self.selected_ix = ix;
+        self.select_ix(ix);
         cx.notify();
     }
 
+    fn select_ix(&mut self, ix: Option<usize>) {
+        match ix {
+            Some(ix) => self.selection.click_inner(ix, false, false),
+            None => self.selection.reset(self.entries.len()),
+        }
+    }
+
     /// Set the selected index by tree item, or `None` to clear selection.
     pub fn set_selected_item(&mut self, item: Option<&TreeItem>, cx: &mut Context<Self>) {
         if let Some(item) = item {
@@ -259,23 +398,24 @@ impl TreeState {
                 .iter()
                 .position(|entry| entry.item.id == item.id);
             if ix.is_some() {
-                self.selected_ix = ix;
+                self.select_ix(ix);
             } else {
                 self.expand_ancestors(item.id.clone());
-                self.selected_ix = self
+                let ix = self
                     .entries
                     .iter()
                     .position(|entry| entry.item.id == item.id);
+                self.select_ix(ix);
             }
         } else {
-            self.selected_ix = None;
+            self.select_ix(None);
         }
         cx.notify();
     }
 
     /// Get the currently selected tree item, if any.
     pub fn selected_item(&self) -> Option<&TreeItem> {
-        self.selected_ix
+        self.selected_index()
             .and_then(|ix| self.entries.get(ix).map(|entry| &entry.item))
     }
 
@@ -285,7 +425,7 @@ impl TreeState {
 
     /// Get the currently selected entry, if any.
     pub fn selected_entry(&self) -> Option<&TreeEntry> {
-        self.selected_ix.and_then(|ix| self.entries.get(ix))
+        self.selected_index().and_then(|ix| self.entries.get(ix))
     }
 
     fn expand_ancestors(&mut self, target_id: SharedString) {
@@ -309,14 +449,15 @@ impl TreeState {
         self.rebuild_entries();
     }
 
-    fn add_entry(&mut self, item: TreeItem, depth: usize) {
+    fn add_entry(&mut self, item: TreeItem, depth: usize, parent_id: Option<SharedString>) {
         self.entries.push(TreeEntry {
             item: item.clone(),
             depth,
+            parent_id,
         });
         if item.is_expanded() {
             for child in &item.children {
-                self.add_entry(child.clone(), depth + 1);
+                self.add_entry(child.clone(), depth + 1, Some(item.id.clone()));
             }
         }
     }
@@ -335,16 +476,27 @@ impl TreeState {
     }
 
     fn rebuild_entries(&mut self) {
-        let root_items: Vec<TreeItem> = self
+        self.set_root_items(self.root_items());
+    }
+
+    /// Rebuild the entries by the `root_items`, and keep the selection on the same items.
+    fn set_root_items(&mut self, root_items: Vec<TreeItem>) {
+        let ids = self
             .entries
             .iter()
-            .filter(|e| e.is_root())
-            .map(|e| e.item.clone())
-            .collect();
+            .map(|entry| entry.item.id.clone())
+            .collect::<Vec<_>>();
+
         self.entries.clear();
         for item in root_items.into_iter() {
-            self.add_entry(item, 0);
+            self.add_entry(item, 0, None);
         }
+
+        let entries = &self.entries;
+        self.selection.remap(entries.len(), |ix| {
+            let id = ids.get(ix)?;
+            entries.iter().position(|entry| &entry.item.id == id)
+        });
     }
 
     pub fn focus(&mut self, window: &mut Window, cx: &mut App) {
@@ -352,7 +504,7 @@ impl TreeState {
     }
 
     fn on_action_confirm(&mut self, _: &Confirm, _: &mut Window, cx: &mut Context<Self>) {
-        if let Some(selected_ix) = self.selected_ix {
+        if let Some(selected_ix) = self.selected_index() {
             if let Some(entry) = self.entries.get(selected_ix) {
                 if entry.is_folder() {
                     self.toggle_expand(selected_ix);
@@ -363,7 +515,7 @@ impl TreeState {
     }
 
     fn on_action_left(&mut self, _: &SelectLeft, _: &mut Window, cx: &mut Context<Self>) {
-        if let Some(selected_ix) = self.selected_ix {
+        if let Some(selected_ix) = self.selected_index() {
             if let Some(entry) = self.entries.get(selected_ix) {
                 if entry.is_folder() && entry.is_expanded() {
                     self.toggle_expand(selected_ix);
@@ -374,7 +526,7 @@ impl TreeState {
     }
 
     fn on_action_right(&mut self, _: &SelectRight, _: &mut Window, cx: &mut Context<Self>) {
-        if let Some(selected_ix) = self.selected_ix {
+        if let Some(selected_ix) = self.selected_index() {
             if let Some(entry) = self.entries.get(selected_ix) {
                 if entry.is_folder() && !entry.is_expanded() {
                     self.toggle_expand(selected_ix);
@@ -385,7 +537,7 @@ impl TreeState {
     }
 
     fn on_action_up(&mut self, _: &SelectUp, _: &mut Window, cx: &mut Context<Self>) {
-        let mut selected_ix = self.selected_ix.unwrap_or(0);
+        let mut selected_ix = self.selected_index().unwrap_or(0);
 
         if selected_ix > 0 {
             selected_ix = selected_ix - 1;
@@ -393,29 +545,58 @@ impl TreeState {
             selected_ix = self.entries.len().saturating_sub(1);
         }
 
-        self.selected_ix = Some(selected_ix);
+        self.select_ix(Some(selected_ix));
         self.scroll_handle
             .scroll_to_item(selected_ix, gpui::ScrollStrategy::Top);
         cx.notify();
     }
 
     fn on_action_down(&mut self, _: &SelectDown, _: &mut Window, cx: &mut Context<Self>) {
-        let mut selected_ix = self.selected_ix.unwrap_or(0);
+        let mut selected_ix = self.selected_index().unwrap_or(0);
         if selected_ix + 1 < self.entries.len() {
             selected_ix = selected_ix + 1;
         } else {
             selected_ix = 0;
         }
 
-        self.selected_ix = Some(selected_ix);
+        self.select_ix(Some(selected_ix));
         self.scroll_handle
             .scroll_to_item(selected_ix, gpui::ScrollStrategy::Bottom);
         cx.notify();
     }
 
-    fn on_entry_click(&mut self, ix: usize, _: &mut Window, cx: &mut Context<Self>) {
-        self.selected_ix = Some(ix);
-        self.toggle_expand(ix);
+    fn on_entry_drop(
+        &mut self,
+        from: usize,
+        to: usize,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.drop_target = None;
+        if !self.move_entry(from, to, cx) {
+            cx.notify();
+            return;
+        }
+
+        if let Some(on_reorder) = self.on_reorder.clone() {
+            on_reorder(&self.root_items(), window, cx);
+        }
+    }
+
+    fn on_entry_click(
+        &mut self,
+        ix: usize,
+        modifiers: &Modifiers,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.selection
+            .click_inner(ix, modifiers.shift, modifiers.secondary());
+        // Keep the folder as is when selecting multiple entries.
+        let multiple = self.selection.mode().is_multiple();
+        if !multiple || !(modifiers.shift || modifiers.secondary()) {
+            self.toggle_expand(ix);
+        }
         cx.notify();
     }
 }
@@ -424,11 +605,16 @@ impl Render for TreeState {
     fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         let render_item = self.render_item.clone();
         let state = cx.entity().clone();
+        let reorderable = self.reorderable;
+        let scroll_handle = self.scroll_handle.0.borrow().base_handle.clone();
 
         div()
             .id("tree-state")
             .size_full()
             .relative()
+            .when(reorderable, |this| {
+                this.auto_scroll_on_drag(&scroll_handle, Axis::Vertical)
+            })
             .context_menu({
                 let state = state.clone();
                 move |menu, window, cx: &mut Context<PopupMenu>| {
@@ -460,15 +646,26 @@ impl Render for TreeState {
             .child(
                 uniform_list("entries", self.entries.len(), {
                     cx.processor(move |state, visible_range: Range<usize>, window, cx| {
+                        // The drag is dropped out of the entries.
+                        if !cx.has_active_drag() {
+                            state.drop_target = None;
+                        }
+
                         let mut items = Vec::with_capacity(visible_range.len());
                         for ix in visible_range {
                             let entry = &state.entries[ix];
-                            let selected = Some(ix) == state.selected_ix;
+                            let selected = state.selection.is_selected(ix);
                             let right_clicked = Some(ix) == state.right_clicked_ix;
+                            // Show the indicator below the target if the item is moved down.
+                            let drop_below = state
+                                .drop_target
+                                .filter(|&(_, to)| to == ix)
+                                .map(|(from, to)| from < to);
                             let item = (render_item)(ix, entry, selected, window, cx);
 
                             let el = div()
                                 .id(ix)
+                                .relative()
                                 .child(
                                     item.disabled(entry.item().is_disabled())
                                         .selected(selected)
@@ -478,8 +675,8 @@ impl Render for TreeState {
                                     this.on_mouse_down(
                                         MouseButton::Left,
                                         cx.listener({
-                                            move |this, _, window, cx| {
-                                                this.on_entry_click(ix, window, cx);
+                                            move |this, e: &MouseDownEvent, window, cx| {
+                                                this.on_entry_click(ix, &e.modifiers, window, cx);
                                             }
                                         }),
                                     )
@@ -490,6 +687,63 @@ impl Render for TreeState {
                                             cx.notify();
                                         }),
                                     )
+                                })
+                                .when(reorderable && !entry.item().is_disabled(), |this| {
+                                    let group = entry.sort_group(cx.entity_id());
+                                    this.on_drag(
+                                        DragItem::new(group.clone(), ix)
+                                            .label(entry.item().label.clone()),
+                                        |drag, _, _, cx| cx.new(|_| drag.clone()),
+                                    )
+                                    .on_drag_move(cx.listener({
+                                        let group = group.clone();
+                                        move |this, e: &DragMoveEvent<DragItem>, _, cx| {
+                                            let drag = e.drag(cx);
+                                            let target = (drag.group == group && drag.ix != ix)
+                                                .then_some((drag.ix, ix));
+                                            let hovered = e.bounds.contains(&e.event.position);
+                                            let is_target =
+                                                this.drop_target.is_some_and(|(_, to)| to == ix);
+
+                                            if hovered && this.drop_target != target {
+                                                this.drop_target = target;
+                                                cx.notify();
+                                            } else if !hovered && is_target {
+                                                this.drop_target = None;
+                                                cx.notify();
+                                            }
+                                        }
+                                    }))
+                                    .on_drop(cx.listener(
+                                        move |this, drag: &DragItem, window, cx| {
+                                            if drag.group != group || drag.ix == ix {
+                                                return;
+                                            }
+
+                                            cx.stop_propagation();
+                                            this.on_entry_drop(drag.ix, ix, window, cx);
+                                        },
+                                    ))
+                                })
+                                // An overlay to keep the size of the entry.
+                                .when_some(drop_below, |this, below| {
+                                    this.child(
+                                        div()
+                                            .absolute()
+                                            .left_0()
+                                            .right_0()
+                                            .h(px(2.))
+                                            .map(
+                                                |this| {
+                                                    if below {
+                                                        this.bottom_0()
+                                                    } else {
+                                                        this.top_0()
+                                                    }
+                                                },
+                                            )
+                                            .bg(cx.theme().drag_border),
+                                    )
                                 });
 
                             items.push(el)
@@ -674,4 +928,183 @@ mod tests {
             );
         })
     }
+
+    #[gpui::test]
+    fn test_move_entry(cx: &mut gpui::TestAppContext) {
+        use super::TreeItem;
+
+        let items = vec![
+            TreeItem::new("src", "src")
+                .expanded(true)
+                .child(TreeItem::new("src/lib.rs", "lib.rs"))
+                .child(TreeItem::new("src/main.rs", "main.rs"))
+                .child(TreeItem::new("src/mod.rs", "mod.rs")),
+            TreeItem::new("Cargo.toml", "Cargo.toml"),
+            TreeItem::new("README.md", "README.md"),
+        ];
+
+        let state = cx.new(|cx| TreeState::new(cx).items(items));
+        state.update(cx, |state, cx| {
+            state.set_selected_index(Some(1), cx);
+
+            assert!(state.move_entry_inner(3, 1));
+            assert_entries(
+                &state.entries,
+                indoc! {
+                    r#"
+                src
+                    mod.rs
+                    lib.rs
+                    main.rs
+                Cargo.toml
+                README.md
+                "#
+                },
+            );
+            assert_eq!(state.selected_item().unwrap().label.as_str(), "lib.rs");
+
+            assert!(state.move_entry_inner(0, 5));
+            assert_entries(
+                &state.entries,
+                indoc! {
+                    r#"
+                Cargo.toml
+                README.md
+                src
+                    mod.rs
+                    lib.rs
+                    main.rs
+                "#
+                },
+            );
+            assert_eq!(state.selected_index(), Some(4));
+
+            // Not siblings
+            assert!(!state.move_entry_inner(0, 3));
+            assert!(!state.move_entry_inner(1, 1));
+        })
+    }
+
+    #[gpui::test]
+    fn test_on_reorder(cx: &mut gpui::TestAppContext) {
+        use super::TreeItem;
+        use crate::test::TestWindow;
+        use std::{cell::RefCell, rc::Rc};
+
+        let items = vec![
+            TreeItem::new("src", "src").child(TreeItem::new("src/lib.rs", "lib.rs")),
+            TreeItem::new("Cargo.toml", "Cargo.toml"),
+            TreeItem::new("README.md", "README.md"),
+        ];
+
+        let order = Rc::new(RefCell::new(Vec::<String>::new()));
+        let mut window = TestWindow::new(cx, {
+            let order = order.clone();
+            move |_, cx| {
+                TreeState::new(cx)
+                    .items(items)
+                    .reorderable(true)
+                    .on_reorder(move |items, _, _| {
+                        *order.borrow_mut() =
+                            items.iter().map(|item| item.id.to_string()).collect();
+                    })
+            }
+        });
+
+        window.update(|state, window, cx| {
+            state.drop_target = Some((2, 0));
+            state.on_entry_drop(2, 0, window, cx);
+            assert_eq!(state.drop_target, None);
+        });
+        assert_eq!(
+            order.borrow().as_slice(),
+            ["README.md", "src", "Cargo.toml"]
+        );
+
+        // Not moved, the callback is not called.
+        order.borrow_mut().clear();
+        window.update(|state, window, cx| state.on_entry_drop(0, 0, window, cx));
+        assert!(order.borrow().is_empty());
+    }
+
+    #[gpui::test]
+    fn test_sort_group(cx: &mut gpui::TestAppContext) {
+        use super::TreeItem;
+
+        let items = vec![
+            TreeItem::new("src", "src")
+                .expanded(true)
+                .child(TreeItem::new("src/lib.rs", "lib.rs"))
+                .child(TreeItem::new("src/main.rs", "main.rs")),
+            TreeItem::new("tests", "tests")
+                .expanded(true)
+                .child(TreeItem::new("tests/a.rs", "a.rs")),
+        ];
+
+        let state = cx.new(|cx| TreeState::new(cx).items(items));
+        let tree_id = state.entity_id();
+        state.update(cx, |state, _| {
+            let groups = state
+                .entries
+                .iter()
+                .map(|entry| entry.sort_group(tree_id))
+                .collect::<Vec<_>>();
+
+            // The roots and the children of the same folder are in the same group.
+            assert_eq!(groups[0], groups[3]);
+            assert_eq!(groups[1], groups[2]);
+            // The children at the same depth of the different folders are not.
+            assert_ne!(groups[1], groups[4]);
+            assert_ne!(groups[0], groups[1]);
+
+            // Not siblings, though at the same depth.
+            assert!(!state.move_entry_inner(2, 4));
+        })
+    }
+
+    #[gpui::test]
+    fn test_multiple_selection(cx: &mut gpui::TestAppContext) {
+        use super::TreeItem;
+        use crate::selection::SelectionMode;
+
+        let items = vec![
+            TreeItem::new("src", "src")
+                .expanded(true)
+                .child(TreeItem::new("src/lib.rs", "lib.rs"))
+                .child(TreeItem::new("src/main.rs", "main.rs")),
+            TreeItem::new("Cargo.toml", "Cargo.toml"),
+            TreeItem::new("README.md", "README.md"),
+        ];
+
+        let state = cx.new(|cx| {
+            TreeState::new(cx)
+                .items(items)
+                .selection_mode(SelectionMode::Multiple)
+        });
+        state.update(cx, |state, _| {
+            let selected = |state: &TreeState| -> Vec<String> {
+                state
+                    .selection()
+                    .selected_indices()
+                    .map(|ix| state.entries[ix].item().label.to_string())
+                    .collect()
+            };
+
+            state.selection.click_inner(1, false, false);
+            state.selection.click_inner(4, false, true);
+            assert_eq!(selected(state), vec!["lib.rs", "README.md"]);
+
+            // The hidden entries are unselected, the others are kept on the same items.
+            state.toggle_expand(0);
+            assert_eq!(selected(state), vec!["README.md"]);
+            assert_eq!(state.selected_item().unwrap().label.as_str(), "README.md");
+
+            state.toggle_expand(0);
+            state.selection.click_inner(1, true, false);
+            assert_eq!(
+                selected(state),
+                vec!["lib.rs", "main.rs", "Cargo.toml", "README.md"]
+            );
+        })
+    }
 }
@@ -0,0 +1,144 @@
+use gpui::{
+    Animation, AnimationExt as _, App, Hsla, IntoElement, ParentElement, Pixels, RenderOnce,
+    SharedString, StyleRefinement, Styled, Window, div, prelude::FluentBuilder as _, px,
+};
+use instant::Duration;
+
+use crate::{
+    ActiveTheme as _, Sizable, Size, StyledExt as _, animation::is_motion_reduced, h_flex,
+};
+
+/// The number of the dots.
+const DOTS: usize = 3;
+
+/// An animated indicator with pulsing dots, e.g.: "Assistant is thinking…" before the first
+/// chunk of the answer arrives.
+///
+/// ```ignore
+/// TypingIndicator::new().label("Thinking…")
+/// ```
+#[derive(IntoElement)]
+pub struct TypingIndicator {
+    style: StyleRefinement,
+    size: Size,
+    color: Option<Hsla>,
+    label: Option<SharedString>,
+    speed: Duration,
+}
+
+impl TypingIndicator {
+    /// Create a new typing indicator.
+    pub fn new() -> Self {
+        Self {
+            style: StyleRefinement::default(),
+            size: Size::Medium,
+            color: None,
+            label: None,
+            speed: Duration::from_secs_f64(1.2),
+        }
+    }
+
+    /// Set the color of the dots, default is the `muted_foreground` color of the theme.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Set the label to show after the dots.
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set the duration of an animation cycle, default is 1.2s.
+    pub fn speed(mut self, speed: Duration) -> Self {
+        self.speed = speed;
+        self
+    }
+}
+
+impl Sizable for TypingIndicator {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl Styled for TypingIndicator {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+/// Returns the opacity of the dot at `ix` at the `delta` of the animation cycle,
+/// the dots are pulsed one by one.
+fn dot_opacity(delta: f32, ix: usize) -> f32 {
+    let phase = (delta - ix as f32 / DOTS as f32).rem_euclid(1.);
+    // A triangle wave that peaks at the middle of the phase.
+    let wave = 1. - (phase * 2. - 1.).abs();
+    0.3 + 0.7 * wave
+}
+
+impl RenderOnce for TypingIndicator {
+    fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+        let color = self.color.unwrap_or(cx.theme().muted_foreground);
+        let dot_size: Pixels = match self.size {
+            Size::Size(size) => size,
+            Size::XSmall => px(4.),
+            Size::Small => px(5.),
+            Size::Medium => px(6.),
+            Size::Large => px(8.),
+        };
+        let motion_reduced = is_motion_reduced(cx);
+        let speed = self.speed;
+
+        h_flex()
+            .gap_2()
+            .text_color(cx.theme().muted_foreground)
+            .map(|this| match self.size {
+                Size::XSmall | Size::Small => this.text_xs(),
+                Size::Large => this.text_base(),
+                _ => this.text_sm(),
+            })
+            .refine_style(&self.style)
+            .child(
+                h_flex()
+                    .flex_none()
+                    .gap(dot_size * 0.6)
+                    .children((0..DOTS).map(|ix| {
+                        let dot = div().size(dot_size).rounded_full().bg(color);
+                        if motion_reduced {
+                            dot.opacity(0.6).into_any_element()
+                        } else {
+                            dot.with_animation(
+                                ("typing-dot", ix),
+                                Animation::new(speed).repeat(),
+                                move |this, delta| this.opacity(dot_opacity(delta, ix)),
+                            )
+                            .into_any_element()
+                        }
+                    })),
+            )
+            .when_some(self.label, |this, label| this.child(label))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::dot_opacity;
+
+    #[test]
+    fn test_dot_opacity() {
+        let approx_eq = |a: f32, b: f32| (a - b).abs() < 1e-5;
+        assert!(approx_eq(dot_opacity(0., 0), 0.3));
+        assert!(approx_eq(dot_opacity(0.5, 0), 1.));
+        assert!(approx_eq(dot_opacity(1., 0), 0.3));
+
+        // The next dot is behind the first one.
+        assert!(dot_opacity(0.5, 1) < dot_opacity(0.5, 0));
+        assert!(approx_eq(
+            dot_opacity(0.25 + 2. / 3., 2),
+            dot_opacity(0.25, 0)
+        ));
+    }
+}
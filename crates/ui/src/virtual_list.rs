@@ -13,8 +13,10 @@
 use std::{
     cell::RefCell,
     cmp,
+    collections::{BTreeSet, HashMap},
     ops::{Deref, Range},
     rc::Rc,
+    time::Duration,
 };
 
 use gpui::{
@@ -24,14 +26,49 @@ use gpui::{
     ScrollHandle, ScrollStrategy, Size, Stateful, StatefulInteractiveElement, StyleRefinement,
     Styled, Window, div, point, px, size,
 };
+use instant::Instant;
 use smallvec::SmallVec;
 
-use crate::{AxisExt, scroll::ScrollbarHandle};
+use crate::{
+    AxisExt,
+    animation::{Transition, UiTestMode, motion_duration},
+    profiler::Profiler,
+    scroll::ScrollbarHandle,
+};
+
+/// The delay to report the width change during the live resizing.
+const WIDTH_CHANGE_DEBOUNCE: Duration = Duration::from_millis(100);
+/// The default number of the invalidated items out of the view to measure per frame.
+const DEFAULT_MEASURE_BUDGET: usize = 10;
 
 struct VirtualListScrollHandleState {
     axis: Axis,
     items_count: usize,
     pub deferred_scroll_to_item: Option<DeferredScrollToItem>,
+    /// The sizes measured by the list for the invalidated items, they override the `item_sizes`.
+    measured_sizes: HashMap<usize, Size<Pixels>>,
+    /// The items to measure in the next layout.
+    dirty_items: BTreeSet<usize>,
+    /// Whether all the items are to be measured in the next layout.
+    all_dirty: bool,
+    /// Increased when the `measured_sizes` is changed, to prepare the item sizes again.
+    measured_version: usize,
+}
+
+impl VirtualListScrollHandleState {
+    /// Update the `items_count` of the list.
+    ///
+    /// The appended items keep the measured sizes of the others, but the measured sizes are
+    /// forgotten when the items are removed, as the indexes of the items are shifted.
+    fn set_items_count(&mut self, items_count: usize) {
+        if items_count < self.items_count && !self.measured_sizes.is_empty() {
+            self.measured_sizes.clear();
+            self.dirty_items.clear();
+            self.all_dirty = true;
+            self.measured_version += 1;
+        }
+        self.items_count = items_count;
+    }
 }
 
 /// A scroll handle for [`VirtualList`].
@@ -87,6 +124,10 @@ impl VirtualListScrollHandle {
                 axis: Axis::Vertical,
                 items_count: 0,
                 deferred_scroll_to_item: None,
+                measured_sizes: HashMap::new(),
+                dirty_items: BTreeSet::new(),
+                all_dirty: false,
+                measured_version: 0,
             })),
             base_handle: ScrollHandle::default(),
         }
@@ -118,6 +159,37 @@ impl VirtualListScrollHandle {
         let items_count = self.state.borrow().items_count;
         self.scroll_to_item(items_count.saturating_sub(1), ScrollStrategy::Top);
     }
+
+    /// Mark the size of the item at the given index as dirty, e.g.: the content is changed.
+    ///
+    /// The list measures the item with its actual width (or height for the horizontal list)
+    /// in the next layout if it is visible, or in the following frames by the
+    /// [`VirtualList::measure_budget`]. The measured size overrides the one of the `item_sizes`,
+    /// until the item is invalidated again or [`Self::invalidate_all`] is called.
+    ///
+    /// The measured sizes are keyed by the index, they are kept when the items are appended,
+    /// and forgotten when the items count decreases. Call [`Self::invalidate_all`] when the
+    /// items are inserted before the end, e.g.: loading the older messages at the top.
+    ///
+    /// NOTE: The list must track this handle by [`VirtualList::track_scroll`], and the caller
+    /// must notify the view to render again.
+    pub fn invalidate_item(&self, ix: usize) {
+        self.state.borrow_mut().dirty_items.insert(ix);
+    }
+
+    /// Mark the sizes of all the items as dirty, and forget the measured sizes,
+    /// e.g.: the items are replaced, or the font size is changed.
+    ///
+    /// See also [`Self::invalidate_item`].
+    pub fn invalidate_all(&self) {
+        let mut state = self.state.borrow_mut();
+        state.dirty_items.clear();
+        state.all_dirty = true;
+        if !state.measured_sizes.is_empty() {
+            state.measured_sizes.clear();
+            state.measured_version += 1;
+        }
+    }
 }
 
 /// Create a [`VirtualList`] in vertical direction.
@@ -197,6 +269,10 @@ where
         item_sizes,
         render_items: Box::new(render_range),
         sizing_behavior: ListSizingBehavior::default(),
+        sticky_headers: Rc::new(vec![]),
+        appear_transition: None,
+        on_width_change: None,
+        measure_budget: DEFAULT_MEASURE_BUDGET,
     }
 }
 
@@ -212,6 +288,10 @@ pub struct VirtualList {
         dyn for<'a> Fn(Range<usize>, &'a mut Window, &'a mut App) -> SmallVec<[AnyElement; 64]>,
     >,
     sizing_behavior: ListSizingBehavior,
+    sticky_headers: Rc<Vec<usize>>,
+    appear_transition: Option<Transition>,
+    on_width_change: Option<Rc<dyn Fn(Pixels, &mut Window, &mut App)>>,
+    measure_budget: usize,
 }
 
 impl Styled for VirtualList {
@@ -233,6 +313,67 @@ impl VirtualList {
         self
     }
 
+    /// Set the indexes of the section header items, only for the vertical list.
+    ///
+    /// The header of the first visible section sticks to the top of the list,
+    /// and is pushed up by the next header. The indexes must be sorted in ascending order.
+    ///
+    /// NOTE: The header item should have a background to cover the items below it.
+    pub fn sticky_headers(mut self, ixs: impl Into<Rc<Vec<usize>>>) -> Self {
+        self.sticky_headers = ixs.into();
+        self
+    }
+
+    /// Set the [`Transition`] to animate the newly appended items when they appear,
+    /// e.g.: the new messages of a chat.
+    ///
+    /// The items appended when the `items_count` grows are animated once, the items rendered
+    /// at the first time and the items scrolled into the view are not animated.
+    ///
+    /// Only the transform effects (fade and slide) are meaningful, the animation does not
+    /// change the `item_sizes`, so the scroll position is not affected.
+    ///
+    /// ```ignore
+    /// v_virtual_list(view, "messages", item_sizes, render_items).appear_transition(
+    ///     Transition::new(Duration::from_millis(200))
+    ///         .fade(0., 1.)
+    ///         .slide_y(px(8.), px(0.)),
+    /// )
+    /// ```
+    pub fn appear_transition(mut self, transition: Transition) -> Self {
+        self.appear_transition = Some(transition);
+        self
+    }
+
+    /// Set the callback when the content width (excluding the paddings and borders) of the list
+    /// is changed, it is called with the initial width after the first layout.
+    ///
+    /// Use it to remeasure the `item_sizes` with the actual width, e.g.: the height of the wrapped
+    /// text. During the live resizing, it is called once the width stops changing for a moment.
+    ///
+    /// ```ignore
+    /// v_virtual_list(view, "messages", item_sizes, render_items).on_width_change({
+    ///     let view = cx.entity();
+    ///     move |width, window, cx| view.update(cx, |this, cx| this.remeasure(width, window, cx))
+    /// })
+    /// ```
+    pub fn on_width_change(mut self, f: impl Fn(Pixels, &mut Window, &mut App) + 'static) -> Self {
+        self.on_width_change = Some(Rc::new(f));
+        self
+    }
+
+    /// Set the number of the invalidated items out of the view to measure per frame, default: 10.
+    ///
+    /// The invalidated items in the view are always measured, the others are measured in the
+    /// following frames (the nearest to the view first), and their `item_sizes` are used as the
+    /// estimated sizes until then.
+    ///
+    /// See also [`VirtualListScrollHandle::invalidate_item`].
+    pub fn measure_budget(mut self, budget: usize) -> Self {
+        self.measure_budget = budget;
+        self
+    }
+
     /// Specify for table.
     ///
     /// Table is special, because the `scroll_handle` is based on Table head (That is not a virtual list).
@@ -297,23 +438,98 @@ impl VirtualList {
         window: &mut Window,
         cx: &mut App,
     ) -> Size<Pixels> {
-        if self.items_count == 0 {
-            return Size::default();
-        }
-
-        let item_ix = 0;
-        let mut items = (self.render_items)(item_ix..item_ix + 1, window, cx);
-        let Some(mut item_to_measure) = items.pop() else {
-            return Size::default();
-        };
         let available_space = size(
             list_width.map_or(AvailableSpace::MinContent, |width| {
                 AvailableSpace::Definite(width)
             }),
             AvailableSpace::MinContent,
         );
+        self.measure_item_at(0, available_space, window, cx)
+    }
+
+    fn measure_item_at(
+        &self,
+        ix: usize,
+        available_space: Size<AvailableSpace>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Size<Pixels> {
+        if ix >= self.items_count {
+            return Size::default();
+        }
+
+        let mut items = (self.render_items)(ix..ix + 1, window, cx);
+        let Some(mut item_to_measure) = items.pop() else {
+            return Size::default();
+        };
+        Profiler::count("virtual_list.measured", 1, window, cx);
         item_to_measure.layout_as_root(available_space, window, cx)
     }
+
+    /// Measure the invalidated items with the content size of the list, the visible items first,
+    /// and at most [`Self::measure_budget`] of the other items, the rest are measured in the
+    /// next frames.
+    ///
+    /// Returns the changed size of the measured items before the `visible_range`,
+    /// or `None` if no item is measured.
+    fn measure_dirty_items(
+        &self,
+        content_size: Size<Pixels>,
+        visible_range: Range<usize>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Option<Pixels> {
+        let dirty_items = {
+            let mut state = self.scroll_handle.state.borrow_mut();
+            if state.all_dirty {
+                state.all_dirty = false;
+                state.dirty_items = (0..self.items_count).collect();
+            }
+            let items_count = self.items_count;
+            state.dirty_items.retain(|ix| *ix < items_count);
+            let dirty_items = pick_dirty_items(
+                &mut state.dirty_items,
+                visible_range.clone(),
+                self.measure_budget,
+            );
+            if !state.dirty_items.is_empty() {
+                window.request_animation_frame();
+            }
+            dirty_items
+        };
+        if dirty_items.is_empty() {
+            return None;
+        }
+
+        let available_space = match self.axis {
+            Axis::Horizontal => size(
+                AvailableSpace::MinContent,
+                AvailableSpace::Definite(content_size.height),
+            ),
+            Axis::Vertical => size(
+                AvailableSpace::Definite(content_size.width),
+                AvailableSpace::MinContent,
+            ),
+        };
+        let measured_sizes = dirty_items
+            .into_iter()
+            .map(|ix| (ix, self.measure_item_at(ix, available_space, window, cx)))
+            .collect::<Vec<_>>();
+
+        let mut state = self.scroll_handle.state.borrow_mut();
+        let mut changed_size_before = px(0.);
+        for (ix, measured_size) in measured_sizes {
+            let old_size = state
+                .measured_sizes
+                .insert(ix, measured_size)
+                .unwrap_or(self.item_sizes[ix]);
+            if ix < visible_range.start {
+                changed_size_before += measured_size.along(self.axis) - old_size.along(self.axis);
+            }
+        }
+        state.measured_version += 1;
+        Some(changed_size_before)
+    }
 }
 
 /// Frame state used by the [VirtualItem].
@@ -330,6 +546,57 @@ pub struct ItemSizeLayout {
     sizes: Vec<Pixels>,
     origins: Vec<Pixels>,
     last_layout_bounds: Bounds<Pixels>,
+    /// The items count of the last layout, `None` before the first layout.
+    items_count: Option<usize>,
+    /// The ranges of the appended items and the time when they are appended.
+    appearing: Vec<(Range<usize>, Instant)>,
+    /// The `measured_version` of the scroll handle when the sizes are prepared.
+    measured_version: usize,
+}
+
+impl ItemSizeLayout {
+    /// Prepare the size and origin of each item by the axis, including the gap between items.
+    ///
+    /// The `measured_sizes` override the `item_sizes`, and the `longest_item_size` is used as
+    /// the content size of the other axis.
+    fn layout_items(
+        &mut self,
+        item_sizes: &[Size<Pixels>],
+        measured_sizes: &HashMap<usize, Size<Pixels>>,
+        axis: Axis,
+        gap: Pixels,
+        longest_item_size: Size<Pixels>,
+    ) {
+        let items_count = item_sizes.len();
+        self.sizes = item_sizes
+            .iter()
+            .enumerate()
+            .map(|(i, size)| {
+                let size = measured_sizes.get(&i).unwrap_or(size).along(axis);
+                if i + 1 == items_count {
+                    size
+                } else {
+                    size + gap
+                }
+            })
+            .collect::<Vec<_>>();
+
+        self.origins = self
+            .sizes
+            .iter()
+            .scan(px(0.), |cumulative, size| {
+                let origin = *cumulative;
+                *cumulative += *size;
+                Some(origin)
+            })
+            .collect::<Vec<_>>();
+
+        let total_size = px(self.sizes.iter().map(|size| size.as_f32()).sum::<f32>());
+        self.content_size = match axis {
+            Axis::Horizontal => size(total_size, longest_item_size.height),
+            Axis::Vertical => size(longest_item_size.width, total_size),
+        };
+    }
 }
 
 impl IntoElement for VirtualList {
@@ -363,6 +630,14 @@ impl Element for VirtualList {
         let font_size = window.text_style().font_size.to_pixels(rem_size);
         let mut size_layout = ItemSizeLayout::default();
         let longest_item_size = self.measure_item(None, window, cx);
+        self.scroll_handle
+            .state
+            .borrow_mut()
+            .set_items_count(self.items_count);
+        let appear_duration = self
+            .appear_transition
+            .as_ref()
+            .map(|transition| motion_duration(transition.duration, cx));
 
         let layout_id = self.base.interactivity().request_layout(
             global_id,
@@ -381,62 +656,28 @@ impl Element for VirtualList {
                             .along(self.axis)
                             .to_pixels(font_size.into(), rem_size);
 
-                        if state.items_sizes != self.item_sizes {
+                        let measured_version = self.scroll_handle.state.borrow().measured_version;
+                        if state.items_sizes != self.item_sizes
+                            || state.measured_version != measured_version
+                        {
                             state.items_sizes = self.item_sizes.clone();
-                            // Prepare each item's size by axis
-                            state.sizes = self
-                                .item_sizes
-                                .iter()
-                                .enumerate()
-                                .map(|(i, size)| {
-                                    let size = size.along(self.axis);
-                                    if i + 1 == self.items_count {
-                                        size
-                                    } else {
-                                        size + gap
-                                    }
-                                })
-                                .collect::<Vec<_>>();
-
-                            // Prepare each item's origin by axis
-                            state.origins = state
-                                .sizes
-                                .iter()
-                                .scan(px(0.), |cumulative, size| match self.axis {
-                                    Axis::Horizontal => {
-                                        let x = *cumulative;
-                                        *cumulative += *size;
-                                        Some(x)
-                                    }
-                                    Axis::Vertical => {
-                                        let y = *cumulative;
-                                        *cumulative += *size;
-                                        Some(y)
-                                    }
-                                })
-                                .collect::<Vec<_>>();
-
-                            state.content_size = if self.axis.is_horizontal() {
-                                Size {
-                                    width: px(state
-                                        .sizes
-                                        .iter()
-                                        .map(|size| size.as_f32())
-                                        .sum::<f32>()),
-                                    height: longest_item_size.height,
-                                }
-                            } else {
-                                Size {
-                                    width: longest_item_size.width,
-                                    height: px(state
-                                        .sizes
-                                        .iter()
-                                        .map(|size| size.as_f32())
-                                        .sum::<f32>()),
-                                }
-                            };
+                            state.measured_version = measured_version;
+                            state.layout_items(
+                                &self.item_sizes,
+                                &self.scroll_handle.state.borrow().measured_sizes,
+                                self.axis,
+                                gap,
+                                longest_item_size,
+                            );
                         }
 
+                        track_appearing(
+                            &mut state,
+                            self.items_count,
+                            appear_duration,
+                            Instant::now(),
+                        );
+
                         (state.clone(), state)
                     },
                 );
@@ -535,9 +776,6 @@ impl Element for VirtualList {
             .padding
             .to_pixels(bounds.size.into(), window.rem_size());
 
-        let item_sizes = &layout.size_layout.sizes;
-        let item_origins = &layout.size_layout.origins;
-
         let content_bounds = Bounds::from_corners(
             bounds.origin
                 + point(
@@ -551,6 +789,62 @@ impl Element for VirtualList {
                 ),
         );
 
+        if let Some(on_width_change) = self.on_width_change.clone()
+            && let Some(global_id) = global_id
+        {
+            let debounce = UiTestMode::delay(WIDTH_CHANGE_DEBOUNCE, cx);
+            let width =
+                window.with_element_state(global_id, |state: Option<WidthReport>, window| {
+                    let mut state = state.unwrap_or_default();
+                    let width = state.update(content_bounds.size.width, Instant::now(), debounce);
+                    if state.pending.is_some() {
+                        window.request_animation_frame();
+                    }
+                    (width, state)
+                });
+            if let Some(width) = width {
+                window.defer(cx, move |window, cx| on_width_change(width, window, cx));
+            }
+        }
+
+        let visible_range = visible_items(
+            &layout.size_layout.origins,
+            &layout.size_layout.sizes,
+            -self.scroll_handle.offset().along(self.axis),
+            content_bounds.size.along(self.axis),
+        );
+        if let Some(changed_size_before) =
+            self.measure_dirty_items(content_bounds.size, visible_range, window, cx)
+        {
+            // Keep the visible items in place when the items before them are resized.
+            if !changed_size_before.is_zero() {
+                let mut scroll_offset = self.scroll_handle.offset();
+                match self.axis {
+                    Axis::Horizontal => scroll_offset.x -= changed_size_before,
+                    Axis::Vertical => scroll_offset.y -= changed_size_before,
+                }
+                self.scroll_handle.set_offset(scroll_offset);
+            }
+
+            let rem_size = window.rem_size();
+            let font_size = window.text_style().font_size.to_pixels(rem_size);
+            let gap = style
+                .gap
+                .along(self.axis)
+                .to_pixels(font_size.into(), rem_size);
+            let longest_item_size = layout.size_layout.content_size;
+            layout.size_layout.layout_items(
+                &self.item_sizes,
+                &self.scroll_handle.state.borrow().measured_sizes,
+                self.axis,
+                gap,
+                longest_item_size,
+            );
+        }
+
+        let item_sizes = &layout.size_layout.sizes;
+        let item_origins = &layout.size_layout.origins;
+
         // Update scroll_handle with the item bounds
         let items_bounds = item_origins
             .iter()
@@ -575,7 +869,6 @@ impl Element for VirtualList {
 
         let mut scroll_state = self.scroll_handle.state.borrow_mut();
         scroll_state.axis = axis;
-        scroll_state.items_count = self.items_count;
 
         let mut scroll_offset = self.scroll_handle.offset();
         if let Some(scroll_to_item) = scroll_state.deferred_scroll_to_item.take() {
@@ -687,10 +980,48 @@ impl Element for VirtualList {
                         ..cmp::min(last_visible_element_ix, self.items_count);
 
                     let items = (self.render_items)(visible_range.clone(), window, cx);
+                    Profiler::count("virtual_list.items", items.len(), window, cx);
+                    let now = Instant::now();
+                    let appear = self.appear_transition.as_ref().zip(appear_duration);
+
+                    let sticky = if self.axis.is_vertical() {
+                        sticky_header(
+                            &self.sticky_headers,
+                            item_origins,
+                            item_sizes,
+                            -scroll_offset.y,
+                        )
+                    } else {
+                        None
+                    };
 
                     let content_mask = ContentMask { bounds };
                     window.with_content_mask(Some(content_mask), |window| {
+                        let mut sticky_item = None;
                         for (mut item, ix) in items.into_iter().zip(visible_range.clone()) {
+                            // Move the pinned header out of the list, to not render the same
+                            // item twice, its place is covered by the sticky header.
+                            if sticky.is_some_and(|(sticky_ix, _)| sticky_ix == ix) {
+                                sticky_item = Some(item);
+                                continue;
+                            }
+
+                            if let Some((transition, duration)) = appear
+                                && let Some(progress) = appear_progress(
+                                    &layout.size_layout.appearing,
+                                    ix,
+                                    now,
+                                    duration,
+                                )
+                            {
+                                // Wrap the item to animate the painting only, the size is kept.
+                                item = transition
+                                    .apply_at(div().relative().size_full(), progress)
+                                    .child(item)
+                                    .into_any_element();
+                                window.request_animation_frame();
+                            }
+
                             let item_origin = match self.axis {
                                 Axis::Horizontal => {
                                     content_bounds.origin
@@ -717,6 +1048,26 @@ impl Element for VirtualList {
                             item.prepaint_at(item_origin, window, cx);
                             layout.items.push(item);
                         }
+
+                        // Paint the sticky header after the items to be on top of them, it is
+                        // rendered only if it is not in the visible range.
+                        if let Some((ix, offset)) = sticky {
+                            let item = sticky_item
+                                .or_else(|| (self.render_items)(ix..ix + 1, window, cx).pop());
+                            if let Some(mut item) = item {
+                                let available_space = size(
+                                    AvailableSpace::Definite(content_bounds.size.width),
+                                    AvailableSpace::Definite(item_sizes[ix]),
+                                );
+                                item.layout_as_root(available_space, window, cx);
+                                item.prepaint_at(
+                                    content_bounds.origin + point(scroll_offset.x, offset),
+                                    window,
+                                    cx,
+                                );
+                                layout.items.push(item);
+                            }
+                        }
                     });
                 }
 
@@ -750,3 +1101,337 @@ impl Element for VirtualList {
         )
     }
 }
+
+/// The state to report the content width changes of the [`VirtualList`].
+#[derive(Default)]
+struct WidthReport {
+    /// The last reported width.
+    reported: Option<Pixels>,
+    /// The changed width not reported yet, and the time since it is kept.
+    pending: Option<(Pixels, Instant)>,
+}
+
+impl WidthReport {
+    /// Returns the width to report, the first width is reported immediately, and the changed
+    /// width is reported after it is kept for the `debounce` duration.
+    fn update(&mut self, width: Pixels, now: Instant, debounce: Duration) -> Option<Pixels> {
+        if width <= px(0.) || self.reported == Some(width) {
+            self.pending = None;
+            return None;
+        }
+
+        let since = match self.pending {
+            Some((pending_width, since)) if pending_width == width => since,
+            _ => now,
+        };
+        if self.reported.is_some() && now.saturating_duration_since(since) < debounce {
+            self.pending = Some((width, since));
+            return None;
+        }
+
+        self.reported = Some(width);
+        self.pending = None;
+        Some(width)
+    }
+}
+
+/// Record the items appended since the last layout, and forget the finished ones.
+///
+/// The items are only tracked when the `duration` is set, the first layout is not animated.
+fn track_appearing(
+    state: &mut ItemSizeLayout,
+    items_count: usize,
+    duration: Option<Duration>,
+    now: Instant,
+) {
+    let prev_count = state.items_count.replace(items_count);
+    let Some(duration) = duration else {
+        state.appearing.clear();
+        return;
+    };
+
+    state.appearing.retain(|(range, started_at)| {
+        range.end <= items_count && now.saturating_duration_since(*started_at) < duration
+    });
+    if let Some(prev_count) = prev_count
+        && items_count > prev_count
+    {
+        state.appearing.push((prev_count..items_count, now));
+    }
+}
+
+/// Returns the progress (0.0 to 1.0) of the appear animation of the item at `ix`,
+/// or `None` if the item is not appearing.
+fn appear_progress(
+    appearing: &[(Range<usize>, Instant)],
+    ix: usize,
+    now: Instant,
+    duration: Duration,
+) -> Option<f32> {
+    let (_, started_at) = appearing.iter().find(|(range, _)| range.contains(&ix))?;
+    let elapsed = now.saturating_duration_since(*started_at);
+    if elapsed >= duration {
+        return None;
+    }
+
+    Some(elapsed.as_secs_f32() / duration.as_secs_f32())
+}
+
+/// Returns the range of the items in the viewport, by the `scroll_top` (the scrolled distance)
+/// and the `viewport_size` along the axis.
+fn visible_items(
+    origins: &[Pixels],
+    sizes: &[Pixels],
+    scroll_top: Pixels,
+    viewport_size: Pixels,
+) -> Range<usize> {
+    let start = origins
+        .iter()
+        .zip(sizes)
+        .position(|(origin, size)| *origin + *size > scroll_top)
+        .unwrap_or(origins.len());
+    let end = origins.partition_point(|origin| *origin < scroll_top + viewport_size);
+    start..end.max(start)
+}
+
+/// Take the invalidated items to measure from the `dirty_items`: all in the `visible_range`,
+/// and at most `budget` of the others, the nearest to the visible range first.
+fn pick_dirty_items(
+    dirty_items: &mut BTreeSet<usize>,
+    visible_range: Range<usize>,
+    budget: usize,
+) -> Vec<usize> {
+    let mut picked = dirty_items
+        .range(visible_range.clone())
+        .copied()
+        .collect::<Vec<_>>();
+
+    let mut after = dirty_items.range(visible_range.end..).copied().peekable();
+    let mut before = dirty_items
+        .range(..visible_range.start)
+        .rev()
+        .copied()
+        .peekable();
+    for _ in 0..budget {
+        let next = match (after.peek(), before.peek()) {
+            (Some(&a), Some(&b)) if a - visible_range.end <= visible_range.start - b => {
+                after.next()
+            }
+            (Some(_), None) => after.next(),
+            (_, Some(_)) => before.next(),
+            (None, None) => None,
+        };
+        let Some(ix) = next else {
+            break;
+        };
+        picked.push(ix);
+    }
+
+    for ix in &picked {
+        dirty_items.remove(ix);
+    }
+    picked
+}
+
+/// Returns the index of the sticky header and its offset to the top of the list.
+///
+/// - `headers` is the sorted indexes of the header items.
+/// - `scroll_top` is the scrolled distance of the content.
+///
+/// Returns `None` if the first visible section header is not scrolled out of the top.
+fn sticky_header(
+    headers: &[usize],
+    origins: &[Pixels],
+    sizes: &[Pixels],
+    scroll_top: Pixels,
+) -> Option<(usize, Pixels)> {
+    let headers_count = headers.partition_point(|&ix| ix < origins.len());
+    let headers = &headers[..headers_count];
+
+    let pos = headers.partition_point(|&ix| origins[ix] < scroll_top);
+    let ix = *headers.get(pos.checked_sub(1)?)?;
+
+    // Push the header up when the next header is reaching the top.
+    let offset = match headers.get(pos) {
+        Some(&next_ix) => (origins[next_ix] - scroll_top - sizes[ix]).min(px(0.)),
+        None => px(0.),
+    };
+
+    Some((ix, offset))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{BTreeSet, HashMap},
+        time::Duration,
+    };
+
+    use gpui::{Axis, px, size};
+    use instant::Instant;
+
+    use super::{
+        ItemSizeLayout, VirtualListScrollHandle, WidthReport, appear_progress, pick_dirty_items,
+        sticky_header, track_appearing, visible_items,
+    };
+
+    #[test]
+    fn test_sticky_header() {
+        // 10 items with 10px height, headers at 0, 3 and 7.
+        let sizes = vec![px(10.); 10];
+        let origins = (0..10).map(|i| px(i as f32 * 10.)).collect::<Vec<_>>();
+        let headers = vec![0, 3, 7];
+
+        assert_eq!(sticky_header(&headers, &origins, &sizes, px(0.)), None);
+        assert_eq!(
+            sticky_header(&headers, &origins, &sizes, px(5.)),
+            Some((0, px(0.)))
+        );
+        // The next header at 30px is pushing the header up.
+        assert_eq!(
+            sticky_header(&headers, &origins, &sizes, px(25.)),
+            Some((0, px(-5.)))
+        );
+        assert_eq!(
+            sticky_header(&headers, &origins, &sizes, px(31.)),
+            Some((3, px(0.)))
+        );
+        assert_eq!(
+            sticky_header(&headers, &origins, &sizes, px(95.)),
+            Some((7, px(0.)))
+        );
+        assert_eq!(sticky_header(&[], &origins, &sizes, px(50.)), None);
+        // The out of range headers are ignored.
+        assert_eq!(
+            sticky_header(&[3, 20], &origins, &sizes, px(50.)),
+            Some((3, px(0.)))
+        );
+    }
+
+    #[test]
+    fn test_appearing_items() {
+        let duration = Duration::from_millis(200);
+        let now = Instant::now();
+        let mut state = ItemSizeLayout::default();
+
+        // The first layout is not animated.
+        track_appearing(&mut state, 10, Some(duration), now);
+        assert!(state.appearing.is_empty());
+
+        track_appearing(&mut state, 12, Some(duration), now);
+        assert_eq!(state.appearing.len(), 1);
+        assert_eq!(appear_progress(&state.appearing, 9, now, duration), None);
+        assert_eq!(
+            appear_progress(&state.appearing, 10, now, duration),
+            Some(0.)
+        );
+        assert_eq!(
+            appear_progress(&state.appearing, 11, now + duration / 2, duration),
+            Some(0.5)
+        );
+        assert_eq!(
+            appear_progress(&state.appearing, 11, now + duration, duration),
+            None
+        );
+
+        // The finished and the removed items are forgotten.
+        track_appearing(&mut state, 12, Some(duration), now + duration);
+        assert!(state.appearing.is_empty());
+        track_appearing(&mut state, 13, Some(duration), now);
+        track_appearing(&mut state, 5, Some(duration), now);
+        assert!(state.appearing.is_empty());
+    }
+
+    #[test]
+    fn test_width_report() {
+        let debounce = Duration::from_millis(100);
+        let now = Instant::now();
+        let mut report = WidthReport::default();
+
+        assert_eq!(report.update(px(0.), now, debounce), None);
+        assert_eq!(report.update(px(640.), now, debounce), Some(px(640.)));
+        assert_eq!(report.update(px(640.), now, debounce), None);
+
+        // The live resizing is reported after the width is kept for the debounce.
+        let ms = Duration::from_millis;
+        assert_eq!(report.update(px(700.), now, debounce), None);
+        assert_eq!(report.update(px(800.), now + ms(50), debounce), None);
+        assert_eq!(report.update(px(800.), now + ms(100), debounce), None);
+        assert!(report.pending.is_some());
+        assert_eq!(
+            report.update(px(800.), now + ms(150), debounce),
+            Some(px(800.))
+        );
+        assert!(report.pending.is_none());
+
+        // Resizing back to the reported width cancels the pending one.
+        assert_eq!(report.update(px(900.), now, debounce), None);
+        assert_eq!(report.update(px(800.), now, debounce), None);
+        assert!(report.pending.is_none());
+        assert_eq!(report.update(px(900.), now, Duration::ZERO), Some(px(900.)));
+    }
+
+    #[test]
+    fn test_layout_items() {
+        let item_sizes = vec![size(px(100.), px(30.)); 3];
+        let measured_sizes = HashMap::from([(1, size(px(200.), px(50.)))]);
+        let mut layout = ItemSizeLayout::default();
+        layout.layout_items(
+            &item_sizes,
+            &measured_sizes,
+            Axis::Vertical,
+            px(4.),
+            size(px(120.), px(0.)),
+        );
+
+        assert_eq!(layout.sizes, vec![px(34.), px(54.), px(30.)]);
+        assert_eq!(layout.origins, vec![px(0.), px(34.), px(88.)]);
+        assert_eq!(layout.content_size, size(px(120.), px(118.)));
+    }
+
+    #[test]
+    fn test_measured_sizes_on_items_count_change() {
+        let handle = VirtualListScrollHandle::new();
+        let mut state = handle.state.borrow_mut();
+        state.set_items_count(3);
+        state.measured_sizes.insert(1, size(px(200.), px(50.)));
+        let version = state.measured_version;
+
+        // Append the items.
+        state.set_items_count(5);
+        assert_eq!(state.measured_sizes.len(), 1);
+        assert_eq!(state.measured_version, version);
+        assert!(!state.all_dirty);
+
+        // Remove the items.
+        state.set_items_count(4);
+        assert!(state.measured_sizes.is_empty());
+        assert_eq!(state.measured_version, version + 1);
+        assert!(state.all_dirty);
+        assert_eq!(state.items_count, 4);
+    }
+
+    #[test]
+    fn test_measure_schedule() {
+        let sizes = vec![px(10.); 10];
+        let origins = (0..10).map(|i| px(i as f32 * 10.)).collect::<Vec<_>>();
+        assert_eq!(visible_items(&origins, &sizes, px(0.), px(25.)), 0..3);
+        assert_eq!(visible_items(&origins, &sizes, px(15.), px(20.)), 1..4);
+        assert_eq!(visible_items(&origins, &sizes, px(95.), px(20.)), 9..10);
+        assert_eq!(visible_items(&origins, &sizes, px(200.), px(20.)), 10..10);
+
+        let mut dirty_items = BTreeSet::from([0, 1, 4, 5, 6, 8, 9]);
+        // All the visible items, then the nearest ones.
+        assert_eq!(
+            pick_dirty_items(&mut dirty_items, 4..6, 2),
+            vec![4, 5, 6, 8]
+        );
+        assert_eq!(pick_dirty_items(&mut dirty_items, 4..6, 2), vec![9, 1]);
+        assert_eq!(
+            pick_dirty_items(&mut dirty_items, 4..6, 0),
+            Vec::<usize>::new()
+        );
+        assert_eq!(pick_dirty_items(&mut dirty_items, 4..6, 5), vec![0]);
+        assert!(dirty_items.is_empty());
+    }
+}
@@ -2,10 +2,11 @@ use crate::{
     Placement, Root,
     dialog::{AlertDialog, Dialog},
     input::InputState,
+    lightbox::{Lightbox, LightboxState},
     notification::Notification,
     sheet::Sheet,
 };
-use gpui::{App, Entity, Window};
+use gpui::{App, AppContext as _, Entity, Focusable as _, ImageSource, Window};
 use std::rc::Rc;
 
 /// Extension trait for [`Window`] to add dialog, sheet .. functionality.
@@ -52,6 +53,16 @@ pub trait WindowExt: Sized {
     where
         F: Fn(AlertDialog, &mut Window, &mut App) -> AlertDialog + 'static;
 
+    /// Opens a full-window [`Lightbox`] to view the `images`, start from the image at `ix`.
+    ///
+    /// The Lightbox is opened as a Dialog, use [`WindowExt::close_dialog`] to close it.
+    fn open_lightbox(
+        &mut self,
+        images: impl IntoIterator<Item = impl Into<ImageSource>>,
+        ix: usize,
+        cx: &mut App,
+    );
+
     /// Return true, if there is an active Dialog.
     fn has_active_dialog(&mut self, cx: &mut App) -> bool;
 
@@ -130,6 +141,22 @@ impl WindowExt for Window {
         })
     }
 
+    fn open_lightbox(
+        &mut self,
+        images: impl IntoIterator<Item = impl Into<ImageSource>>,
+        ix: usize,
+        cx: &mut App,
+    ) {
+        let state = cx.new(|cx| LightboxState::new(images, ix, self, cx));
+        let focus_handle = state.focus_handle(cx);
+
+        self.open_dialog(cx, move |dialog, window, cx| {
+            Lightbox::new(&state).into_dialog(dialog, window, cx)
+        });
+        // Focus the image viewer to use the keyboard shortcuts.
+        focus_handle.focus(self, cx);
+    }
+
     #[inline]
     fn has_active_dialog(&mut self, cx: &mut App) -> bool {
         Root::read(self, cx).active_dialogs.len() > 0